@@ -0,0 +1,189 @@
+#!/usr/bin/env rust
+
+
+use crate::white_point::WhitePoint;
+use crate::xyz::XYZ;
+
+
+/// Row major 3x3 matrix
+pub type Matrix = [[f64; 3]; 3];
+
+
+/// Chromatic adaptation transforms, each defined by matrix from `XYZ` to cone response space
+///
+/// **Note** adaptation is complete, i.e. the degree of adaptation of `CAT02` and `CAT16` is `1.0`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Method {
+    /// Bradford transform, used by ICC profiles and CSS Color 4
+    #[default]
+    Bradford,
+
+    /// Von Kries transform with Hunt-Pointer-Estevez cone responses
+    VonKries,
+
+    /// Scaling of `XYZ` values directly, the least accurate transform
+    XyzScaling,
+
+    /// Transform of CIECAM02 color appearance model
+    CAT02,
+
+    /// Transform of CAM16 color appearance model
+    CAT16,
+}
+
+
+impl Method {
+    /// Returns matrix from `XYZ` to cone response space of transform
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::adaptation::Method;
+    ///
+    /// assert_eq!(Method::XyzScaling.cone_response(), [
+    ///     [1.0, 0.0, 0.0],
+    ///     [0.0, 1.0, 0.0],
+    ///     [0.0, 0.0, 1.0],
+    /// ]);
+    /// ```
+    pub fn cone_response(&self) -> Matrix {
+        match self {
+            Self::Bradford => [
+                [0.8951, 0.2664, -0.1614],
+                [-0.7502, 1.7135, 0.0367],
+                [0.0389, -0.0685, 1.0296],
+            ],
+            Self::VonKries => [
+                [0.40024, 0.7076, -0.08081],
+                [-0.2263, 1.16532, 0.0457],
+                [0.0, 0.0, 0.91822],
+            ],
+            Self::XyzScaling => [
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [0.0, 0.0, 1.0],
+            ],
+            Self::CAT02 => [
+                [0.7328, 0.4296, -0.1624],
+                [-0.7036, 1.6975, 0.0061],
+                [0.003, 0.0136, 0.9834],
+            ],
+            Self::CAT16 => [
+                [0.401288, 0.650173, -0.051461],
+                [-0.250268, 1.204414, 0.045854],
+                [-0.002079, 0.048952, 0.953127],
+            ],
+        }
+    }
+
+    /// Returns matrix adapting `XYZ` values relative to source white point to be relative to
+    /// destination white point
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::adaptation::Method;
+    /// use color_operators::white_point::WhitePoint;
+    ///
+    /// let matrix = Method::Bradford.matrix(WhitePoint::D65, WhitePoint::D50);
+    /// let rounded: Vec<Vec<f64>> = matrix.iter().map(|row| {
+    ///     row.iter().map(|v| (v * 1000.0).round() / 1000.0).collect()
+    /// }).collect();
+    ///
+    /// assert_eq!(rounded, vec![
+    ///     vec![1.048, 0.023, -0.05],
+    ///     vec![0.03, 0.99, -0.017],
+    ///     vec![-0.009, 0.015, 0.752],
+    /// ]);
+    /// ```
+    pub fn matrix(&self, source: WhitePoint, destination: WhitePoint) -> Matrix {
+        let cone = self.cone_response();
+        let ( source_x, source_y, source_z ) = source.tristimulus();
+        let ( destination_x, destination_y, destination_z ) = destination.tristimulus();
+
+        let source = transform(&cone, [ source_x, source_y, source_z ]);
+        let destination = transform(&cone, [ destination_x, destination_y, destination_z ]);
+
+        let mut scale = [[0.0; 3]; 3];
+        for (index, row) in scale.iter_mut().enumerate() {
+            row[index] = destination[index] / source[index];
+        }
+
+        multiply(&invert(&cone), &multiply(&scale, &cone))
+    }
+
+    /// Returns `XYZ` values relative to source white point adapted to be relative to destination
+    /// white point
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::adaptation::Method;
+    /// use color_operators::white_point::WhitePoint;
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let ( x, y, z ) = WhitePoint::A.tristimulus();
+    /// let adapted = Method::CAT16.adapt(XYZ::new(x, y, z), WhitePoint::A, WhitePoint::D65);
+    /// let ( x, y, z ) = adapted.into();
+    ///
+    /// let ( white_x, white_y, white_z ) = WhitePoint::D65.tristimulus();
+    ///
+    /// assert!((x - white_x).abs() < 1e-9);
+    /// assert!((y - white_y).abs() < 1e-9);
+    /// assert!((z - white_z).abs() < 1e-9);
+    /// ```
+    pub fn adapt(&self, xyz: XYZ, source: WhitePoint, destination: WhitePoint) -> XYZ {
+        if source == destination {
+            return xyz;
+        }
+
+        let ( x, y, z ) = xyz.into();
+        let [ x, y, z ] = transform(&self.matrix(source, destination), [ x, y, z ]);
+        XYZ::new(x, y, z)
+    }
+}
+
+
+/// Returns vector transformed by matrix
+pub(crate) fn transform(matrix: &Matrix, vector: [f64; 3]) -> [f64; 3] {
+    let mut result = [0.0; 3];
+    for (value, row) in result.iter_mut().zip(matrix.iter()) {
+        *value = row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2];
+    }
+    result
+}
+
+
+/// Returns product of matrices, applying right matrix first
+pub(crate) fn multiply(left: &Matrix, right: &Matrix) -> Matrix {
+    let mut result = [[0.0; 3]; 3];
+    for (row, values) in result.iter_mut().enumerate() {
+        for (column, value) in values.iter_mut().enumerate() {
+            *value = (0..3).map(|index| left[row][index] * right[index][column]).sum();
+        }
+    }
+    result
+}
+
+
+/// Returns inverse of matrix, via adjugate divided by determinant
+///
+/// **Warning** singular matrices produce non-finite values
+pub(crate) fn invert(matrix: &Matrix) -> Matrix {
+    let m = matrix;
+    let cofactor = |row: usize, column: usize| -> f64 {
+        let ( r1, r2 ) = ( (row + 1) % 3, (row + 2) % 3 );
+        let ( c1, c2 ) = ( (column + 1) % 3, (column + 2) % 3 );
+        m[r1][c1] * m[r2][c2] - m[r1][c2] * m[r2][c1]
+    };
+
+    let determinant = m[0][0] * cofactor(0, 0) + m[0][1] * cofactor(0, 1) + m[0][2] * cofactor(0, 2);
+
+    let mut result = [[0.0; 3]; 3];
+    for (row, values) in result.iter_mut().enumerate() {
+        for (column, value) in values.iter_mut().enumerate() {
+            *value = cofactor(column, row) / determinant;
+        }
+    }
+    result
+}
@@ -0,0 +1,228 @@
+#!/usr/bin/env rust
+
+
+use crate::rgb::RGB;
+
+
+/// Blend modes defined by W3C Compositing and Blending Level 1, the same set used by CSS
+/// `mix-blend-mode` and most design tools
+///
+/// **Note** blending mixes source color into backdrop color, alpha and compositing are handled
+/// separately, e.g. via `composite::Operator`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BlendMode {
+    /// Source color replaces backdrop
+    #[default]
+    Normal,
+
+    /// Multiplies backdrop and source, result is always at least as dark as either
+    Multiply,
+
+    /// Complement of multiplying complements, result is always at least as light as either
+    Screen,
+
+    /// Multiplies or screens depending on backdrop, i.e. `HardLight` with arguments swapped
+    Overlay,
+
+    /// Darker of backdrop and source
+    Darken,
+
+    /// Lighter of backdrop and source
+    Lighten,
+
+    /// Brightens backdrop to reflect source
+    ColorDodge,
+
+    /// Darkens backdrop to reflect source
+    ColorBurn,
+
+    /// Multiplies or screens depending on source
+    HardLight,
+
+    /// Darkens or lightens depending on source, similar to diffused spotlight
+    SoftLight,
+
+    /// Absolute difference of backdrop and source
+    Difference,
+
+    /// Similar to `Difference` but lower in contrast
+    Exclusion,
+
+    /// Hue of source with saturation and luminosity of backdrop
+    Hue,
+
+    /// Saturation of source with hue and luminosity of backdrop
+    Saturation,
+
+    /// Hue and saturation of source with luminosity of backdrop
+    Color,
+
+    /// Luminosity of source with hue and saturation of backdrop
+    Luminosity,
+}
+
+
+impl BlendMode {
+    /// Returns `true` if blend mode operates on each color component independently
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::blend::BlendMode;
+    ///
+    /// assert!(BlendMode::Multiply.is_separable());
+    /// assert!(!BlendMode::Luminosity.is_separable());
+    /// ```
+    pub fn is_separable(&self) -> bool {
+        !matches!(self, Self::Hue | Self::Saturation | Self::Color | Self::Luminosity)
+    }
+
+    /// Returns result of blending source color into backdrop color
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::blend::BlendMode;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let backdrop = RGB::new(255, 128, 0);
+    /// let source = RGB::new(128, 128, 128);
+    ///
+    /// assert_eq!(BlendMode::Multiply.apply(backdrop.clone(), source.clone()), RGB::new(128, 64, 0));
+    /// assert_eq!(BlendMode::Screen.apply(backdrop, source), RGB::new(255, 192, 128));
+    /// ```
+    pub fn apply(&self, backdrop: RGB, source: RGB) -> RGB {
+        let backdrop = scale(backdrop);
+        let source = scale(source);
+
+        let result = match self {
+            Self::Hue => set_lum(set_sat(source, sat(backdrop)), lum(backdrop)),
+            Self::Saturation => set_lum(set_sat(backdrop, sat(source)), lum(backdrop)),
+            Self::Color => set_lum(source, lum(backdrop)),
+            Self::Luminosity => set_lum(backdrop, lum(source)),
+            _ => [
+                self.separable(backdrop[0], source[0]),
+                self.separable(backdrop[1], source[1]),
+                self.separable(backdrop[2], source[2]),
+            ],
+        };
+
+        let channel = |v: f64| -> u8 {
+            (v.min(1.0).max(0.0) * 255.0).round() as u8
+        };
+
+        RGB::new(channel(result[0]), channel(result[1]), channel(result[2]))
+    }
+
+    /// Returns result of separable blend mode for single backdrop and source component
+    fn separable(&self, backdrop: f64, source: f64) -> f64 {
+        match self {
+            Self::Multiply => backdrop * source,
+            Self::Screen => backdrop + source - backdrop * source,
+            Self::Overlay => Self::HardLight.separable(source, backdrop),
+            Self::Darken => backdrop.min(source),
+            Self::Lighten => backdrop.max(source),
+            Self::ColorDodge => {
+                if backdrop == 0.0 {
+                    0.0
+                } else if source == 1.0 {
+                    1.0
+                } else {
+                    (backdrop / (1.0 - source)).min(1.0)
+                }
+            },
+            Self::ColorBurn => {
+                if backdrop == 1.0 {
+                    1.0
+                } else if source == 0.0 {
+                    0.0
+                } else {
+                    1.0 - ((1.0 - backdrop) / source).min(1.0)
+                }
+            },
+            Self::HardLight => {
+                if source <= 0.5 {
+                    Self::Multiply.separable(backdrop, 2.0 * source)
+                } else {
+                    Self::Screen.separable(backdrop, 2.0 * source - 1.0)
+                }
+            },
+            Self::SoftLight => {
+                if source <= 0.5 {
+                    return backdrop - (1.0 - 2.0 * source) * backdrop * (1.0 - backdrop);
+                }
+                let d = if backdrop <= 0.25 {
+                    ((16.0 * backdrop - 12.0) * backdrop + 4.0) * backdrop
+                } else {
+                    backdrop.sqrt()
+                };
+                backdrop + (2.0 * source - 1.0) * (d - backdrop)
+            },
+            Self::Difference => (backdrop - source).abs(),
+            Self::Exclusion => backdrop + source - 2.0 * backdrop * source,
+            _ => source,
+        }
+    }
+}
+
+
+/// Returns red, green, blue scaled between `0.0` and `1.0`
+fn scale(rgb: RGB) -> [f64; 3] {
+    let ( red, green, blue ) = rgb.into();
+    [ red as f64 / 255.0, green as f64 / 255.0, blue as f64 / 255.0 ]
+}
+
+
+/// Luminosity as defined for non-separable blend modes
+fn lum(color: [f64; 3]) -> f64 {
+    0.3 * color[0] + 0.59 * color[1] + 0.11 * color[2]
+}
+
+
+/// Brings out of range components back between `0.0` and `1.0` while preserving luminosity
+fn clip_color(color: [f64; 3]) -> [f64; 3] {
+    let l = lum(color);
+    let n = color[0].min(color[1]).min(color[2]);
+    let x = color[0].max(color[1]).max(color[2]);
+
+    let mut result = color;
+    if n < 0.0 {
+        for v in result.iter_mut() {
+            *v = l + (*v - l) * l / (l - n);
+        }
+    }
+    if x > 1.0 {
+        for v in result.iter_mut() {
+            *v = l + (*v - l) * (1.0 - l) / (x - l);
+        }
+    }
+    result
+}
+
+
+/// Shifts color to given luminosity
+fn set_lum(color: [f64; 3], l: f64) -> [f64; 3] {
+    let d = l - lum(color);
+    clip_color([ color[0] + d, color[1] + d, color[2] + d ])
+}
+
+
+/// Saturation as defined for non-separable blend modes
+fn sat(color: [f64; 3]) -> f64 {
+    color[0].max(color[1]).max(color[2]) - color[0].min(color[1]).min(color[2])
+}
+
+
+/// Scales color to given saturation
+fn set_sat(color: [f64; 3], s: f64) -> [f64; 3] {
+    let mut order = [0, 1, 2];
+    order.sort_by(|a, b| color[*a].partial_cmp(&color[*b]).unwrap_or(std::cmp::Ordering::Equal));
+    let [ min, mid, max ] = order;
+
+    let mut result = [0.0; 3];
+    if color[max] > color[min] {
+        result[mid] = (color[mid] - color[min]) * s / (color[max] - color[min]);
+        result[max] = s;
+    }
+    result
+}
@@ -0,0 +1,53 @@
+#!/usr/bin/env rust
+
+
+use crate::cmyk::CMYK;
+
+
+/// Convert from array of 64-bit precision floating point numbers
+impl<T> From<[T; 4]> for CMYK
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::CMYK;
+    ///
+    /// let array = [ 0.0, 0.5, 0.25, 0.125 ];
+    /// let cmyk = CMYK::from(array);
+    ///
+    /// assert_eq!(cmyk.get("cyan"), Ok(0.0));
+    /// assert_eq!(cmyk.get("magenta"), Ok(0.5));
+    /// assert_eq!(cmyk.get("yellow"), Ok(0.25));
+    /// assert_eq!(cmyk.get("key"), Ok(0.125));
+    /// ```
+    fn from(array: [T; 4]) -> Self {
+        let cyan = array[0];
+        let magenta = array[1];
+        let yellow = array[2];
+        let key = array[3];
+        Self::new(cyan, magenta, yellow, key)
+    }
+}
+
+
+/// Convert into array of 64-bit precision floating point numbers
+impl Into<[f64; 4]> for CMYK {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::CMYK;
+    ///
+    /// let cmyk = CMYK::new(0.0, 0.5, 0.25, 0.125);
+    /// let array: [f64; 4] = cmyk.into();
+    ///
+    /// assert_eq!(array[0], 0.0);
+    /// assert_eq!(array[1], 0.5);
+    /// assert_eq!(array[2], 0.25);
+    /// assert_eq!(array[3], 0.125);
+    /// ```
+    fn into(self) -> [f64; 4] {
+        [ self.cyan, self.magenta, self.yellow, self.key ]
+    }
+}
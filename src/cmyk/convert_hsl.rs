@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::cmyk::CMYK;
+use crate::hsl::HSL;
+use crate::rgb::RGB;
+
+
+/// Convert from `HSL` to `CMYK`
+///
+/// **Note** uses `RGB::from(hsl)` internally
+impl From<HSL> for CMYK {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::CMYK;
+    /// use color_operators::hsl::HSL;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let hsl = HSL::from(rgb.clone());
+    /// let cmyk = CMYK::from(hsl);
+    ///
+    /// assert_eq!(RGB::from(cmyk), rgb);
+    /// ```
+    fn from(hsl: HSL) -> Self {
+        Self::from(RGB::from(hsl))
+    }
+}
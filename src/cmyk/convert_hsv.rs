@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::cmyk::CMYK;
+use crate::hsv::HSV;
+use crate::rgb::RGB;
+
+
+/// Convert from `HSV` to `CMYK`
+///
+/// **Note** uses `RGB::from(hsv)` internally
+impl From<HSV> for CMYK {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::CMYK;
+    /// use color_operators::hsv::HSV;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let hsv = HSV::from(rgb.clone());
+    /// let cmyk = CMYK::from(hsv);
+    ///
+    /// assert_eq!(RGB::from(cmyk), rgb);
+    /// ```
+    fn from(hsv: HSV) -> Self {
+        Self::from(RGB::from(hsv))
+    }
+}
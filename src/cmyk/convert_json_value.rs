@@ -0,0 +1,105 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+extern crate json;
+use json::JsonValue;
+
+
+use crate::cmyk::CMYK;
+use crate::error::{ json_f64, Error };
+
+
+/// Converts from `JsonValue` key value pares, or error for missing or invalid components
+impl TryFrom<&JsonValue> for CMYK {
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::cmyk::CMYK;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{ "cyan": "nope" }"#).unwrap();
+    ///
+    /// assert_eq!(CMYK::try_from(&data), Err(Error::InvalidComponent("cyan".to_string())));
+    /// ```
+    fn try_from(data: &JsonValue) -> Result<Self, Self::Error> {
+        let cyan = json_f64(data, "cyan")?;
+        let magenta = json_f64(data, "magenta")?;
+        let yellow = json_f64(data, "yellow")?;
+        let key = json_f64(data, "key")?;
+        Ok(Self::new(cyan, magenta, yellow, key))
+    }
+}
+
+
+/// Converts from `JsonValue` key value pares
+///
+/// **Note** missing or invalid components default individually, where `CMYK::try_from` returns
+/// error
+impl From<JsonValue> for CMYK {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::CMYK;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{
+    ///     "cyan": 0.0,
+    ///     "magenta": 0.5,
+    ///     "yellow": 0.25,
+    ///     "key": 0.125
+    /// }"#).unwrap();
+    ///
+    /// let cmyk = CMYK::from(data);
+    ///
+    /// assert_eq!(cmyk.get("cyan"), Ok(0.0));
+    /// assert_eq!(cmyk.get("magenta"), Ok(0.5));
+    /// assert_eq!(cmyk.get("yellow"), Ok(0.25));
+    /// assert_eq!(cmyk.get("key"), Ok(0.125));
+    /// ```
+    fn from(data: JsonValue) -> Self {
+        let cyan = data["cyan"].as_f64().unwrap_or_default();
+        let magenta = data["magenta"].as_f64().unwrap_or_default();
+        let yellow = data["yellow"].as_f64().unwrap_or_default();
+        let key = data["key"].as_f64().unwrap_or_default();
+        Self::new(cyan, magenta, yellow, key)
+    }
+}
+
+
+/// Converts to `JsonValue`
+impl Into<JsonValue> for CMYK {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::CMYK;
+    /// extern crate json;
+    /// use json::JsonValue;
+    ///
+    /// let cmyk = CMYK::new(0.0, 0.5, 0.25, 0.125);
+    /// let data: JsonValue = cmyk.into();
+    ///
+    /// let expected = json::parse(r#"{
+    ///     "cyan": 0.0,
+    ///     "magenta": 0.5,
+    ///     "yellow": 0.25,
+    ///     "key": 0.125
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(data, expected);
+    /// ```
+    fn into(self) -> JsonValue {
+        json::object!{
+            "cyan" => self.cyan,
+            "magenta" => self.magenta,
+            "yellow" => self.yellow,
+            "key" => self.key,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+#!/usr/bin/env rust
+
+
+use crate::cmyk::{ CMYK, Separation };
+use crate::rgb::RGB;
+
+
+/// Convert from `RGB` to `CMYK`
+///
+/// **Note** uses `Separation::naive`, see `CMYK::from_rgb` for under color removal and gray
+/// component replacement
+impl From<RGB> for CMYK {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::CMYK;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 0, 0);
+    /// let cmyk = CMYK::from(rgb);
+    ///
+    /// assert_eq!(cmyk.get("cyan"), Ok(0.0));
+    /// assert_eq!(cmyk.get("magenta"), Ok(1.0));
+    /// assert_eq!(cmyk.get("yellow"), Ok(1.0));
+    /// assert_eq!(cmyk.get("key"), Ok(0.0));
+    /// ```
+    fn from(rgb: RGB) -> Self {
+        Self::from_rgb(rgb, Separation::naive())
+    }
+}
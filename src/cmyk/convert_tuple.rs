@@ -0,0 +1,50 @@
+#!/usr/bin/env rust
+
+
+use crate::cmyk::CMYK;
+
+
+/// Convert from tuple of 64-bit precision floating point numbers
+impl<T> From<(T, T, T, T)> for CMYK
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::CMYK;
+    ///
+    /// let tuple = ( 0.0, 0.5, 0.25, 0.125 );
+    /// let cmyk = CMYK::from(tuple);
+    ///
+    /// assert_eq!(cmyk.get("cyan"), Ok(0.0));
+    /// assert_eq!(cmyk.get("magenta"), Ok(0.5));
+    /// assert_eq!(cmyk.get("yellow"), Ok(0.25));
+    /// assert_eq!(cmyk.get("key"), Ok(0.125));
+    /// ```
+    fn from(tuple: (T, T, T, T)) -> Self {
+        let ( cyan, magenta, yellow, key ) = tuple;
+        Self::new(cyan, magenta, yellow, key)
+    }
+}
+
+
+/// Convert into tuple of 64-bit precision floating point numbers
+impl Into<(f64, f64, f64, f64)> for CMYK {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::CMYK;
+    ///
+    /// let cmyk = CMYK::new(0.0, 0.5, 0.25, 0.125);
+    /// let ( cyan, magenta, yellow, key ) = cmyk.into();
+    ///
+    /// assert_eq!(cyan, 0.0);
+    /// assert_eq!(magenta, 0.5);
+    /// assert_eq!(yellow, 0.25);
+    /// assert_eq!(key, 0.125);
+    /// ```
+    fn into(self) -> (f64, f64, f64, f64) {
+        ( self.cyan, self.magenta, self.yellow, self.key )
+    }
+}
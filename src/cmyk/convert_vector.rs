@@ -0,0 +1,88 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+use crate::cmyk::CMYK;
+use crate::error::{ check_length, Error };
+
+
+/// Convert from slice of 64-bit precision floating point numbers, or error for invalid length
+impl<T> TryFrom<&[T]> for CMYK
+where
+    T: Into<f64> + Copy
+{
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::cmyk::CMYK;
+    ///
+    /// let cmyk = CMYK::try_from(&[0.0, 0.5, 0.25, 0.125][..]).unwrap();
+    /// assert_eq!(cmyk, CMYK::from(vec![0.0, 0.5, 0.25, 0.125]));
+    ///
+    /// let result = CMYK::try_from(&[0.0, 0.5][..]);
+    /// assert_eq!(result, Err(Error::Length { expected: 4, found: 2 }));
+    /// ```
+    fn try_from(values: &[T]) -> Result<Self, Self::Error> {
+        check_length(values.len(), 4, 4)?;
+        let cyan = values[0].into();
+        let magenta = values[1].into();
+        let yellow = values[2].into();
+        let key = values[3].into();
+        Ok(Self::new(cyan, magenta, yellow, key))
+    }
+}
+
+
+/// Convert from vector of 64-bit precision floating point numbers
+///
+/// **Note** this uses `CMYK::try_from` internally, values past the fourth are ignored, and too few
+/// values return default values
+impl<T> From<Vec<T>> for CMYK
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::CMYK;
+    ///
+    /// let vector = vec![0.0, 0.5, 0.25, 0.125];
+    /// let cmyk = CMYK::from(vector);
+    ///
+    /// assert_eq!(cmyk.get("cyan"), Ok(0.0));
+    /// assert_eq!(cmyk.get("magenta"), Ok(0.5));
+    /// assert_eq!(cmyk.get("yellow"), Ok(0.25));
+    /// assert_eq!(cmyk.get("key"), Ok(0.125));
+    /// ```
+    fn from(vector: Vec<T>) -> Self {
+        let length = vector.len().min(4);
+        Self::try_from(&vector[..length]).unwrap_or_default()
+    }
+}
+
+
+/// Convert into vector of 64-bit precision floating point numbers
+impl Into<Vec<f64>> for CMYK {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::CMYK;
+    ///
+    /// let cmyk = CMYK::new(0.0, 0.5, 0.25, 0.125);
+    /// let vector: Vec<f64> = cmyk.into();
+    ///
+    /// assert_eq!(vector[0], 0.0);
+    /// assert_eq!(vector[1], 0.5);
+    /// assert_eq!(vector[2], 0.25);
+    /// assert_eq!(vector[3], 0.125);
+    /// ```
+    fn into(self) -> Vec<f64> {
+        vec![ self.cyan, self.magenta, self.yellow, self.key ]
+    }
+}
@@ -0,0 +1,54 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::color::equality::alpha_eq;
+use crate::cmyk::CMYK;
+
+
+impl Eq for CMYK {}
+impl PartialEq for CMYK {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::CMYK;
+    ///
+    /// let left = CMYK::new(0.0, 0.5, 0.25, 0.125);
+    /// let right = CMYK::new(0.0, 0.5, 0.25, 0.125);
+    ///
+    /// assert_eq!(left, right);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        if self.cyan != other.cyan || self.magenta != other.magenta || self.yellow != other.yellow || self.key != other.key {
+            return false;
+        }
+        true
+    }
+}
+
+
+/// Converts right side from `Color` to `CMYK` prior to checking equality
+///
+/// **Note** colors with alpha are compared as `RGBA`, so are only equal when fully opaque
+impl PartialEq<Color> for CMYK {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::cmyk::CMYK;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let cmyk = CMYK::from(rgb.clone());
+    ///
+    /// assert!(cmyk == Color::from(rgb));
+    /// ```
+    fn eq(&self, other: &Color) -> bool {
+        if let Some(equal) = alpha_eq(&Color::from(self.clone()), other) {
+            return equal;
+        }
+
+        let other: CMYK = other.clone().into();
+        self == &other
+    }
+}
@@ -0,0 +1,430 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+
+use crate::error::Error;
+use crate::rgb::RGB;
+
+
+/// Adds color components for `CMYK` data structures
+mod add;
+
+/// Subtracts color components for `CMYK` data structures
+mod subtract;
+
+/// Equal and not-equal checks for `CMYK` data structures
+mod equality;
+
+/// Converts from/to array for `CMYK` data structures
+mod convert_array;
+
+/// Converts from/to `Color` enum
+mod convert_color;
+
+/// Converts from `HSL` to `CMYK` data structure
+mod convert_hsl;
+
+/// Converts from `HSV` to `CMYK` data structure
+mod convert_hsv;
+
+/// Converts from/to `JsonValue` for `CMYK` data structures
+mod convert_json_value;
+
+/// Converts from `RGB` to `CMYK` data structure
+mod convert_rgb;
+
+/// Converts from/to tuple for `CMYK` data structures
+mod convert_tuple;
+
+/// Converts from/to vector for `CMYK` data structures
+mod convert_vector;
+
+
+/// Data structure for Cyan, Magenta, Yellow, Key (black) encoded colors
+///
+/// **Note** each ink is expressed as coverage between `0.0` and `1.0`
+#[derive(Clone, Debug, Default)]
+pub struct CMYK {
+    cyan: f64,
+    magenta: f64,
+    yellow: f64,
+    key: f64,
+}
+
+
+/// Curve describing how much black ink replaces the gray component of cyan, magenta, yellow
+///
+/// **Note** black is `amount * ((gray - start) / (1.0 - start)) ^ exponent` for gray above
+/// `start`, and never exceeds the gray component
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BlackGeneration {
+    start: f64,
+    amount: f64,
+    exponent: f64,
+}
+
+
+/// Settings for separating `RGB` values into printable `CMYK` inks
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Separation {
+    black_generation: BlackGeneration,
+    total_ink_limit: f64,
+}
+
+
+impl CMYK {
+    /// Returns new instance of `CMYK` data structure
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::CMYK;
+    ///
+    /// let color = CMYK::new(0.0, 0.5, 0.25, 0.125);
+    ///
+    /// assert_eq!(color.get("cyan"), Ok(0.0));
+    /// assert_eq!(color.get("magenta"), Ok(0.5));
+    /// assert_eq!(color.get("yellow"), Ok(0.25));
+    /// assert_eq!(color.get("key"), Ok(0.125));
+    /// ```
+    pub fn new<T>(cyan: T, magenta: T, yellow: T, key: T) -> Self
+    where
+        T: Into<f64>
+    {
+        let cyan: f64 = cyan.into().min(1.0).max(0.0);
+        let magenta: f64 = magenta.into().min(1.0).max(0.0);
+        let yellow: f64 = yellow.into().min(1.0).max(0.0);
+        let key: f64 = key.into().min(1.0).max(0.0);
+        Self { cyan, magenta, yellow, key }
+    }
+
+    /// Returns named component value or error
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::CMYK;
+    ///
+    /// let color = CMYK::new(0.0, 0.5, 0.25, 0.125);
+    ///
+    /// assert_eq!(color.get("nothing"), Err("No color component named -> nothing".to_string()));
+    /// ```
+    pub fn get<S>(&self, component: S) -> Result<f64, String>
+    where
+        S: Into<String>
+    {
+        let component: String = component.into();
+        match component.as_str() {
+            "cyan" => Ok(self.cyan),
+            "magenta" => Ok(self.magenta),
+            "yellow" => Ok(self.yellow),
+            "key" => Ok(self.key),
+            _ => Err(format!("No color component named -> {}", component)),
+        }
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or default values
+    ///
+    /// **Note** missing or invalid components default individually, see `From<JsonValue>`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::CMYK;
+    ///
+    /// let cmyk = CMYK::from_json_string(r#"{ "cyan": 0.0, "magenta": 0.5, "yellow": 0.25, "key": 0.125 }"#);
+    ///
+    /// assert_eq!(cmyk.get("cyan"), Ok(0.0));
+    /// assert_eq!(cmyk.get("magenta"), Ok(0.5));
+    /// assert_eq!(cmyk.get("yellow"), Ok(0.25));
+    /// assert_eq!(cmyk.get("key"), Ok(0.125));
+    /// ```
+    pub fn from_json_string<S>(string: S) -> Self
+    where
+        S: Into<String>
+    {
+        json::parse(&string.into()).map(Self::from).unwrap_or_default()
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or error for invalid JSON and missing
+    /// or invalid components
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::error::Error;
+    /// use color_operators::cmyk::CMYK;
+    ///
+    /// let result = CMYK::try_from_json_str(r#"{ "cyan": 1 }"#);
+    ///
+    /// assert_eq!(result, Err(Error::MissingComponent("magenta".to_string())));
+    /// ```
+    pub fn try_from_json_str(string: &str) -> Result<Self, Error> {
+        let data = json::parse(string)?;
+        Self::try_from(&data)
+    }
+
+    /// Serializes data structure as JSON string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::CMYK;
+    ///
+    /// let cmyk = CMYK::new(0.0, 0.5, 0.25, 0.125);
+    /// let data = cmyk.to_json_string();
+    ///
+    /// let object = json::object!{
+    ///     "cyan" => 0.0,
+    ///     "magenta" => 0.5,
+    ///     "yellow" => 0.25,
+    ///     "key" => 0.125
+    /// };
+    ///
+    /// let expected = json::stringify(object);
+    ///
+    /// assert_eq!(data, expected);
+    /// ```
+    pub fn to_json_string(&self) -> String {
+        json::stringify(self.clone())
+    }
+
+    /// Converts hexadecimal string into `CMYK` using `Separation::naive`
+    ///
+    /// **Note** this method uses `RGB::from_hex_string` internally, which returns default values
+    /// for invalid input
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::CMYK;
+    ///
+    /// let cmyk = CMYK::from_hex_string("FF8000");
+    ///
+    /// assert_eq!(cmyk.get("cyan"), Ok(0.0));
+    /// assert_eq!(cmyk.get("yellow"), Ok(1.0));
+    /// assert_eq!(cmyk.get("key"), Ok(0.0));
+    /// ```
+    pub fn from_hex_string<S>(input: S) -> Self
+    where
+        S: Into<String>
+    {
+        Self::from(RGB::from_hex_string(input))
+    }
+
+    /// Converts hexadecimal string into `CMYK`, or returns error for invalid input
+    ///
+    /// **Note** this method uses `RGB::try_from_hex_string` internally
+    pub fn try_from_hex_string<S>(input: S) -> Result<Self, Error>
+    where
+        S: Into<String>
+    {
+        RGB::try_from_hex_string(input).map(Self::from)
+    }
+
+    /// Returns hexadecimal string representation of `CMYK` values
+    ///
+    /// **Note** this method uses `RGB::to_hex_string` internally
+    pub fn to_hex_string(&self) -> String {
+        RGB::from(self.clone()).to_hex_string()
+    }
+
+    /// Separates `RGB` values into inks with given black generation and ink limit settings
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::{ CMYK, Separation };
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(51, 51, 51);
+    ///
+    /// let naive = CMYK::from_rgb(rgb.clone(), Separation::naive());
+    /// assert_eq!(naive, CMYK::new(0.0, 0.0, 0.0, 0.8));
+    ///
+    /// let limited = CMYK::from_rgb(rgb, Separation::gray_component_replacement(0.5, 2.0));
+    ///
+    /// assert_eq!(limited.get("key"), Ok(0.4));
+    /// assert_eq!((limited.total_ink() * 1000.0).round(), 2000.0);
+    /// ```
+    pub fn from_rgb(rgb: RGB, separation: Separation) -> Self {
+        let ( red, green, blue ) = rgb.into();
+
+        let cyan = 1.0 - red as f64 / 255.0;
+        let magenta = 1.0 - green as f64 / 255.0;
+        let yellow = 1.0 - blue as f64 / 255.0;
+
+        let gray = cyan.min(magenta).min(yellow);
+        let key = separation.black_generation.black(gray);
+
+        let remove = |v: f64| -> f64 {
+            if key >= 1.0 {
+                return 0.0;
+            }
+            (v - key) / (1.0 - key)
+        };
+
+        let mut cyan = remove(cyan);
+        let mut magenta = remove(magenta);
+        let mut yellow = remove(yellow);
+        let mut key = key;
+
+        let limit = separation.total_ink_limit;
+        let total = cyan + magenta + yellow + key;
+        if total > limit {
+            if key >= limit {
+                key = limit;
+                cyan = 0.0;
+                magenta = 0.0;
+                yellow = 0.0;
+            } else {
+                let scale = (limit - key) / (cyan + magenta + yellow);
+                cyan *= scale;
+                magenta *= scale;
+                yellow *= scale;
+            }
+        }
+
+        Self::new(cyan, magenta, yellow, key)
+    }
+
+    /// Returns sum of all ink coverage, where `4.0` is 400%
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::CMYK;
+    ///
+    /// let cmyk = CMYK::new(0.0, 0.5, 0.25, 0.125);
+    ///
+    /// assert_eq!(cmyk.total_ink(), 0.875);
+    /// ```
+    pub fn total_ink(&self) -> f64 {
+        self.cyan + self.magenta + self.yellow + self.key
+    }
+
+    /// Attempts to rotate hue by some amount of degrees
+    ///
+    /// **Note** this method uses `RGB::rotate_hue` and `Separation::naive` internally
+    pub fn rotate_hue<T>(&self, amount: T) -> Self
+    where
+        T: Into<f64>
+    {
+        let rgb = RGB::from(self.clone());
+        Self::from(rgb.rotate_hue(amount))
+    }
+}
+
+
+impl BlackGeneration {
+    /// Returns new black generation curve
+    ///
+    /// **Note** `start` and `amount` are clamped between `0.0` and `1.0`, and `exponent` must be
+    /// greater than `0.0`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::BlackGeneration;
+    ///
+    /// let curve = BlackGeneration::new(0.5, 1.0, 1.0);
+    ///
+    /// assert_eq!(curve.black(0.25), 0.0);
+    /// assert_eq!(curve.black(0.75), 0.5);
+    /// assert_eq!(curve.black(1.0), 1.0);
+    /// ```
+    pub fn new<T>(start: T, amount: T, exponent: T) -> Self
+    where
+        T: Into<f64>
+    {
+        let start: f64 = start.into().min(1.0).max(0.0);
+        let amount: f64 = amount.into().min(1.0).max(0.0);
+        let exponent: f64 = exponent.into().max(f64::EPSILON);
+        Self { start, amount, exponent }
+    }
+
+    /// Returns amount of black ink for given gray component of cyan, magenta, yellow
+    pub fn black(&self, gray: f64) -> f64 {
+        if gray <= self.start {
+            return 0.0;
+        }
+        let position = (gray - self.start) / (1.0 - self.start);
+        (self.amount * position.powf(self.exponent)).min(gray)
+    }
+}
+
+
+impl Separation {
+    /// Returns new separation settings
+    ///
+    /// **Note** `total_ink_limit` is the maximum sum of all inks, e.g. `3.0` for 300%
+    pub fn new(black_generation: BlackGeneration, total_ink_limit: f64) -> Self {
+        let total_ink_limit = total_ink_limit.min(4.0).max(0.0);
+        Self { black_generation, total_ink_limit }
+    }
+
+    /// Black replaces all of the gray component and no ink limit is applied
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::{ CMYK, Separation };
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let cmyk = CMYK::from_rgb(RGB::new(0, 0, 0), Separation::naive());
+    ///
+    /// assert_eq!(cmyk, CMYK::new(0.0, 0.0, 0.0, 1.0));
+    /// ```
+    pub fn naive() -> Self {
+        Self::new(BlackGeneration::new(0.0, 1.0, 1.0), 4.0)
+    }
+
+    /// Under color removal, black only replaces gray within shadows, i.e. gray components above
+    /// one half
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::{ CMYK, Separation };
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let cmyk = CMYK::from_rgb(RGB::new(153, 153, 153), Separation::under_color_removal(3.0));
+    ///
+    /// assert_eq!(cmyk.get("key"), Ok(0.0));
+    /// ```
+    pub fn under_color_removal(total_ink_limit: f64) -> Self {
+        Self::new(BlackGeneration::new(0.5, 1.0, 1.0), total_ink_limit)
+    }
+
+    /// Gray component replacement, black replaces some `amount` of gray throughout all tones
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::{ CMYK, Separation };
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let cmyk = CMYK::from_rgb(RGB::new(0, 0, 0), Separation::gray_component_replacement(1.0, 3.0));
+    ///
+    /// assert_eq!(cmyk, CMYK::new(0.0, 0.0, 0.0, 1.0));
+    /// ```
+    pub fn gray_component_replacement(amount: f64, total_ink_limit: f64) -> Self {
+        Self::new(BlackGeneration::new(0.0, amount, 1.0), total_ink_limit)
+    }
+}
+
+
+impl Default for Separation {
+    fn default() -> Self {
+        Self::naive()
+    }
+}
+
+
+impl Display for CMYK {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "cyan: {}, magenta: {}, yellow: {}, key: {}", self.cyan, self.magenta, self.yellow, self.key)
+    }
+}
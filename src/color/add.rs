@@ -4,10 +4,21 @@
 use std::ops::Add;
 
 
+use crate::cmyk::CMYK;
 use crate::color::Color;
 use crate::hsl::HSL;
+use crate::hsla::HSLA;
 use crate::hsv::HSV;
+use crate::hsva::HSVA;
+use crate::hwb::HWB;
+use crate::lab::Lab;
+use crate::lch::LCh;
+use crate::oklab::Oklab;
+use crate::oklch::Oklch;
 use crate::rgb::RGB;
+use crate::rgba::RGBA;
+use crate::xyz::XYZ;
+use crate::ycbcr::YCbCr;
 
 
 /// Adds color components
@@ -32,23 +43,36 @@ impl Add for Color {
     /// assert_eq!(result, expected);
     /// ```
     fn add(self, other: Self) -> Self::Output {
-        match (self, other) {
-            (Color::HSL(left), Color::HSL(right)) => Self::HSL(left + right),
-            (Color::HSL(left), Color::HSV(right)) => Self::HSL(left + right),
-            (Color::HSL(left), Color::RGB(right)) => Self::HSL(left + right),
-
-            (Color::HSV(left), Color::HSL(right)) => Self::HSV(left + right),
-            (Color::HSV(left), Color::HSV(right)) => Self::HSV(left + right),
-            (Color::HSV(left), Color::RGB(right)) => Self::HSV(left + right),
-
-            (Color::RGB(left), Color::HSL(right)) => Self::RGB(left + right),
-            (Color::RGB(left), Color::HSV(right)) => Self::RGB(left + right),
-            (Color::RGB(left), Color::RGB(right)) => Self::RGB(left + right),
+        match self {
+            Color::CMYK(left) => Self::CMYK(left + other),
+            Color::HSL(left) => Self::HSL(left + other),
+            Color::HSLA(left) => Self::HSLA(left + other),
+            Color::HSV(left) => Self::HSV(left + other),
+            Color::HSVA(left) => Self::HSVA(left + other),
+            Color::HWB(left) => Self::HWB(left + other),
+            Color::Lab(left) => Self::Lab(left + other),
+            Color::LCh(left) => Self::LCh(left + other),
+            Color::Oklab(left) => Self::Oklab(left + other),
+            Color::Oklch(left) => Self::Oklch(left + other),
+            Color::RGB(left) => Self::RGB(left + other),
+            Color::RGBA(left) => Self::RGBA(left + other),
+            Color::XYZ(left) => Self::XYZ(left + other),
+            Color::YCbCr(left) => Self::YCbCr(left + other),
         }
     }
 }
 
 
+/// Converts right side from `CMYK` to `Color` prior to arithmetic
+impl Add<CMYK> for Color {
+    type Output = Self;
+
+    fn add(self, other: CMYK) -> Self::Output {
+        self + Self::from(other)
+    }
+}
+
+
 /// Converts right side from `HSL` to `Color` prior to arithmetic
 impl Add<HSL> for Color {
     type Output = Self;
@@ -69,11 +93,17 @@ impl Add<HSL> for Color {
     /// assert_eq!(result, expected);
     /// ```
     fn add(self, other: HSL) -> Self::Output {
-        match self {
-            Color::HSL(v) => Self::HSL(v + other),
-            Color::HSV(v) => Self::HSV(v + other),
-            Color::RGB(v) => Self::RGB(v + other),
-        }
+        self + Self::from(other)
+    }
+}
+
+
+/// Converts right side from `HSLA` to `Color` prior to arithmetic
+impl Add<HSLA> for Color {
+    type Output = Self;
+
+    fn add(self, other: HSLA) -> Self::Output {
+        self + Self::from(other)
     }
 }
 
@@ -98,11 +128,67 @@ impl Add<HSV> for Color {
     /// assert_eq!(result, expected);
     /// ```
     fn add(self, other: HSV) -> Self::Output {
-        match self {
-            Color::HSL(v) => Self::HSL(v + other),
-            Color::HSV(v) => Self::HSV(v + other),
-            Color::RGB(v) => Self::RGB(v + other),
-        }
+        self + Self::from(other)
+    }
+}
+
+
+/// Converts right side from `HSVA` to `Color` prior to arithmetic
+impl Add<HSVA> for Color {
+    type Output = Self;
+
+    fn add(self, other: HSVA) -> Self::Output {
+        self + Self::from(other)
+    }
+}
+
+
+/// Converts right side from `HWB` to `Color` prior to arithmetic
+impl Add<HWB> for Color {
+    type Output = Self;
+
+    fn add(self, other: HWB) -> Self::Output {
+        self + Self::from(other)
+    }
+}
+
+
+/// Converts right side from `Lab` to `Color` prior to arithmetic
+impl Add<Lab> for Color {
+    type Output = Self;
+
+    fn add(self, other: Lab) -> Self::Output {
+        self + Self::from(other)
+    }
+}
+
+
+/// Converts right side from `LCh` to `Color` prior to arithmetic
+impl Add<LCh> for Color {
+    type Output = Self;
+
+    fn add(self, other: LCh) -> Self::Output {
+        self + Self::from(other)
+    }
+}
+
+
+/// Converts right side from `Oklab` to `Color` prior to arithmetic
+impl Add<Oklab> for Color {
+    type Output = Self;
+
+    fn add(self, other: Oklab) -> Self::Output {
+        self + Self::from(other)
+    }
+}
+
+
+/// Converts right side from `Oklch` to `Color` prior to arithmetic
+impl Add<Oklch> for Color {
+    type Output = Self;
+
+    fn add(self, other: Oklch) -> Self::Output {
+        self + Self::from(other)
     }
 }
 
@@ -126,11 +212,36 @@ impl Add<RGB> for Color {
     /// assert_eq!(result, expected);
     /// ```
     fn add(self, other: RGB) -> Self::Output {
-        match self {
-            Color::HSL(v) => Self::HSL(v + other),
-            Color::HSV(v) => Self::HSV(v + other),
-            Color::RGB(v) => Self::RGB(v + other),
-        }
+        self + Self::from(other)
+    }
+}
+
+
+/// Converts right side from `RGBA` to `Color` prior to arithmetic
+impl Add<RGBA> for Color {
+    type Output = Self;
+
+    fn add(self, other: RGBA) -> Self::Output {
+        self + Self::from(other)
+    }
+}
+
+
+/// Converts right side from `XYZ` to `Color` prior to arithmetic
+impl Add<XYZ> for Color {
+    type Output = Self;
+
+    fn add(self, other: XYZ) -> Self::Output {
+        self + Self::from(other)
     }
 }
 
+
+/// Converts right side from `YCbCr` to `Color` prior to arithmetic
+impl Add<YCbCr> for Color {
+    type Output = Self;
+
+    fn add(self, other: YCbCr) -> Self::Output {
+        self + Self::from(other)
+    }
+}
@@ -0,0 +1,24 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::cmyk::CMYK;
+
+
+/// Convert from `CMYK` to `Color::CMYK`
+impl From<CMYK> for Color {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::cmyk::CMYK;
+    ///
+    /// let cmyk = CMYK::new(0.0, 0.5, 0.25, 0.125);
+    /// let c = Color::from(cmyk);
+    ///
+    /// assert!(c.is_cmyk());
+    /// ```
+    fn from(cmyk: CMYK) -> Self {
+        Self::CMYK(cmyk)
+    }
+}
@@ -0,0 +1,24 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::hsla::HSLA;
+
+
+/// Convert from `HSLA` to `Color::HSLA`
+impl From<HSLA> for Color {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::hsla::HSLA;
+    ///
+    /// let hsla = HSLA::new(60.0, 1.0, 0.5, 0.25);
+    /// let c = Color::from(hsla);
+    ///
+    /// assert!(c.is_hsla());
+    /// ```
+    fn from(hsla: HSLA) -> Self {
+        Self::HSLA(hsla)
+    }
+}
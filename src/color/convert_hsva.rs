@@ -0,0 +1,24 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::hsva::HSVA;
+
+
+/// Convert from `HSVA` to `Color::HSVA`
+impl From<HSVA> for Color {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::hsva::HSVA;
+    ///
+    /// let hsva = HSVA::new(60.0, 1.0, 0.5, 0.25);
+    /// let c = Color::from(hsva);
+    ///
+    /// assert!(c.is_hsva());
+    /// ```
+    fn from(hsva: HSVA) -> Self {
+        Self::HSVA(hsva)
+    }
+}
@@ -0,0 +1,24 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::hwb::HWB;
+
+
+/// Convert from `HWB` to `Color::HWB`
+impl From<HWB> for Color {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::hwb::HWB;
+    ///
+    /// let hwb = HWB::new(120.0, 0.25, 0.5);
+    /// let c = Color::from(hwb);
+    ///
+    /// assert!(c.is_hwb());
+    /// ```
+    fn from(hwb: HWB) -> Self {
+        Self::HWB(hwb)
+    }
+}
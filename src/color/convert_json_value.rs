@@ -0,0 +1,152 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+extern crate json;
+use json::JsonValue;
+
+
+use crate::cmyk::CMYK;
+use crate::color::Color;
+use crate::error::Error;
+use crate::hsl::HSL;
+use crate::hsla::HSLA;
+use crate::hsv::HSV;
+use crate::hsva::HSVA;
+use crate::hwb::HWB;
+use crate::lab::Lab;
+use crate::lch::LCh;
+use crate::oklab::Oklab;
+use crate::oklch::Oklch;
+use crate::rgb::RGB;
+use crate::rgba::RGBA;
+use crate::xyz::XYZ;
+use crate::ycbcr::YCbCr;
+
+
+/// Converts from `JsonValue` key value pares, where contained data structure is chosen by keys
+/// present, or error for missing or invalid components
+impl TryFrom<&JsonValue> for Color {
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::color::Color;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{ "l": 0.5, "c": 0.125, "h": 120.0 }"#).unwrap();
+    ///
+    /// assert_eq!(Color::try_from(&data), Ok(Color::new_oklch(0.5, 0.125, 120.0)));
+    /// ```
+    fn try_from(data: &JsonValue) -> Result<Self, Self::Error> {
+        from_json(data, true)
+    }
+}
+
+
+/// Converts from `JsonValue` key value pares, where contained data structure is chosen by keys
+/// present
+///
+/// **Note** missing or invalid components default individually, where `Color::try_from` returns
+/// error
+impl From<JsonValue> for Color {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{ "hue": 120.0, "lightness": 0.75 }"#).unwrap();
+    ///
+    /// assert_eq!(Color::from(data), Color::new_hsl(120.0, 0.0, 0.75));
+    /// ```
+    fn from(data: JsonValue) -> Self {
+        from_json(&data, false).unwrap_or_else(|_| Self::RGB(RGB::default()))
+    }
+}
+
+
+/// Converts contained data structure to `JsonValue`
+impl Into<JsonValue> for Color {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// extern crate json;
+    /// use json::JsonValue;
+    ///
+    /// let data: JsonValue = Color::new_rgb(255, 42, 90).into();
+    ///
+    /// assert_eq!(data, json::parse(r#"{ "red": 255, "green": 42, "blue": 90 }"#).unwrap());
+    /// ```
+    fn into(self) -> JsonValue {
+        match self {
+            Self::CMYK(v) => v.into(),
+            Self::HSL(v) => v.into(),
+            Self::HSLA(v) => v.into(),
+            Self::HSV(v) => v.into(),
+            Self::HSVA(v) => v.into(),
+            Self::HWB(v) => v.into(),
+            Self::Lab(v) => v.into(),
+            Self::LCh(v) => v.into(),
+            Self::Oklab(v) => v.into(),
+            Self::Oklch(v) => v.into(),
+            Self::RGB(v) => v.into(),
+            Self::RGBA(v) => v.into(),
+            Self::XYZ(v) => v.into(),
+            Self::YCbCr(v) => v.into(),
+        }
+    }
+}
+
+
+/// Returns contained data structure chosen by keys present, where strict conversion uses
+/// `TryFrom` and returns error, otherwise `From` defaults missing or invalid components
+fn from_json(data: &JsonValue, strict: bool) -> Result<Color, Error> {
+    if data.has_key("alpha") && data.has_key("lightness") {
+        convert::<HSLA>(data, strict)
+    } else if data.has_key("alpha") && data.has_key("value") {
+        convert::<HSVA>(data, strict)
+    } else if data.has_key("alpha") {
+        convert::<RGBA>(data, strict)
+    } else if data.has_key("key") {
+        convert::<CMYK>(data, strict)
+    } else if data.has_key("l") && data.has_key("c") {
+        convert::<Oklch>(data, strict)
+    } else if data.has_key("l") {
+        convert::<Oklab>(data, strict)
+    } else if data.has_key("chroma") {
+        convert::<LCh>(data, strict)
+    } else if data.has_key("a") {
+        convert::<Lab>(data, strict)
+    } else if data.has_key("lightness") {
+        convert::<HSL>(data, strict)
+    } else if data.has_key("value") {
+        convert::<HSV>(data, strict)
+    } else if data.has_key("whiteness") || data.has_key("blackness") {
+        convert::<HWB>(data, strict)
+    } else if data.has_key("cb") {
+        convert::<YCbCr>(data, strict)
+    } else if data.has_key("x") {
+        convert::<XYZ>(data, strict)
+    } else {
+        convert::<RGB>(data, strict)
+    }
+}
+
+
+/// Returns data structure converted from `JsonValue` as `Color`
+fn convert<T>(data: &JsonValue, strict: bool) -> Result<Color, Error>
+where
+    T: for<'a> TryFrom<&'a JsonValue, Error = Error> + From<JsonValue>,
+    Color: From<T>
+{
+    if strict {
+        return T::try_from(data).map(Color::from);
+    }
+    Ok(Color::from(T::from(data.clone())))
+}
@@ -0,0 +1,24 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::lab::Lab;
+
+
+/// Convert from `Lab` to `Color::Lab`
+impl From<Lab> for Color {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::lab::Lab;
+    ///
+    /// let lab = Lab::new(50.0, 25.0, -25.0);
+    /// let c = Color::from(lab);
+    ///
+    /// assert!(c.is_lab());
+    /// ```
+    fn from(lab: Lab) -> Self {
+        Self::Lab(lab)
+    }
+}
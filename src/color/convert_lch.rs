@@ -0,0 +1,24 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::lch::LCh;
+
+
+/// Convert from `LCh` to `Color::LCh`
+impl From<LCh> for Color {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::lch::LCh;
+    ///
+    /// let lch = LCh::new(50.0, 25.0, 120.0);
+    /// let c = Color::from(lch);
+    ///
+    /// assert!(c.is_lch());
+    /// ```
+    fn from(lch: LCh) -> Self {
+        Self::LCh(lch)
+    }
+}
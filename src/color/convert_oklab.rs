@@ -0,0 +1,24 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::oklab::Oklab;
+
+
+/// Convert from `Oklab` to `Color::Oklab`
+impl From<Oklab> for Color {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::oklab::Oklab;
+    ///
+    /// let oklab = Oklab::new(0.5, 0.125, -0.125);
+    /// let c = Color::from(oklab);
+    ///
+    /// assert!(c.is_oklab());
+    /// ```
+    fn from(oklab: Oklab) -> Self {
+        Self::Oklab(oklab)
+    }
+}
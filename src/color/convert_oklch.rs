@@ -0,0 +1,24 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::oklch::Oklch;
+
+
+/// Convert from `Oklch` to `Color::Oklch`
+impl From<Oklch> for Color {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::oklch::Oklch;
+    ///
+    /// let oklch = Oklch::new(0.5, 0.125, 120.0);
+    /// let c = Color::from(oklch);
+    ///
+    /// assert!(c.is_oklch());
+    /// ```
+    fn from(oklch: Oklch) -> Self {
+        Self::Oklch(oklch)
+    }
+}
@@ -0,0 +1,24 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::rgba::RGBA;
+
+
+/// Convert from `RGBA` to `Color::RGBA`
+impl From<RGBA> for Color {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let rgba = RGBA::new(255, 42, 90, 128);
+    /// let c = Color::from(rgba);
+    ///
+    /// assert!(c.is_rgba());
+    /// ```
+    fn from(rgba: RGBA) -> Self {
+        Self::RGBA(rgba)
+    }
+}
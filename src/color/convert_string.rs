@@ -0,0 +1,38 @@
+#!/usr/bin/env rust
+
+
+use std::str::FromStr;
+
+
+use crate::color::Color;
+use crate::css;
+use crate::error::Error;
+
+
+/// Parses CSS color syntax into `Color`
+///
+/// **Note** this uses `css::parse` internally, so hexadecimal requires leading `#`, where
+/// `RGB::from_hex_string` and `RGBA::from_hex_string` accept it without
+impl FromStr for Color {
+    type Err = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    ///
+    /// let c_rgb: Color = "#FF2A5A".parse().unwrap();
+    /// let c_rgba: Color = "#FF2A5A80".parse().unwrap();
+    /// let c_oklch: Color = "oklch(62.8% 0.25768 29.23)".parse().unwrap();
+    ///
+    /// assert!(c_rgb.is_rgb());
+    /// assert!(c_rgba.is_rgba());
+    /// assert!(c_oklch.is_oklch());
+    /// assert!("#FF2A".parse::<Color>().is_ok());
+    /// assert!("#FF2A5".parse::<Color>().is_err());
+    /// assert!("FF2A5A80".parse::<Color>().is_err());
+    /// ```
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        css::parse(string)
+    }
+}
@@ -0,0 +1,24 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::xyz::XYZ;
+
+
+/// Convert from `XYZ` to `Color::XYZ`
+impl From<XYZ> for Color {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let xyz = XYZ::new(0.5, 0.25, 0.125);
+    /// let c = Color::from(xyz);
+    ///
+    /// assert!(c.is_xyz());
+    /// ```
+    fn from(xyz: XYZ) -> Self {
+        Self::XYZ(xyz)
+    }
+}
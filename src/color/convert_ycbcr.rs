@@ -0,0 +1,24 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::ycbcr::YCbCr;
+
+
+/// Convert from `YCbCr` to `Color::YCbCr`
+impl From<YCbCr> for Color {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::ycbcr::YCbCr;
+    ///
+    /// let ycbcr = YCbCr::new(0.5, 0.25, -0.25);
+    /// let c = Color::from(ycbcr);
+    ///
+    /// assert!(c.is_ycbcr());
+    /// ```
+    fn from(ycbcr: YCbCr) -> Self {
+        Self::YCbCr(ycbcr)
+    }
+}
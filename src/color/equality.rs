@@ -1,61 +1,168 @@
 #!/usr/bin/env rust
 
 
+use crate::cmyk::CMYK;
 use crate::color::Color;
 use crate::hsl::HSL;
+use crate::hsla::HSLA;
 use crate::hsv::HSV;
+use crate::hsva::HSVA;
+use crate::hwb::HWB;
+use crate::lab::Lab;
+use crate::lch::LCh;
+use crate::oklab::Oklab;
+use crate::oklch::Oklch;
 use crate::rgb::RGB;
+use crate::rgba::RGBA;
+use crate::xyz::XYZ;
+use crate::ycbcr::YCbCr;
 
 
+/// Returns result of comparing as `RGBA` when colors differ in type and either carries alpha,
+/// treating missing alpha as fully opaque
+pub(crate) fn alpha_eq(left: &Color, right: &Color) -> Option<bool> {
+    let has_alpha = |color: &Color| matches!(color, Color::HSLA(_) | Color::HSVA(_) | Color::RGBA(_));
+    if std::mem::discriminant(left) == std::mem::discriminant(right) || !(has_alpha(left) || has_alpha(right)) {
+        return None;
+    }
+
+    let left: RGBA = left.clone().into();
+    let right: RGBA = right.clone().into();
+    Some(left == right)
+}
+
+
+/// **Note** colors without alpha are treated as fully opaque, so are only equal to colors with
+/// alpha when those are fully opaque
 impl Eq for Color {}
 impl PartialEq for Color {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    ///
+    /// assert!(Color::new_rgb(1, 2, 3) == Color::new_rgba(1, 2, 3, 255));
+    /// assert!(Color::new_rgba(1, 2, 3, 0) != Color::new_rgb(1, 2, 3));
+    /// assert!(Color::new_rgb(1, 2, 3) != Color::new_rgba(1, 2, 3, 0));
+    /// ```
     fn eq(&self, other: &Self) -> bool {
-        match (self, other) {
-            (Color::HSL(left), Color::HSL(right)) => left == right,
-            (Color::HSL(left), Color::HSV(right)) => left == right,
-            (Color::HSL(left), Color::RGB(right)) => left == right,
-
-            (Color::HSV(left), Color::HSL(right)) => left == right,
-            (Color::HSV(left), Color::HSV(right)) => left == right,
-            (Color::HSV(left), Color::RGB(right)) => left == right,
-
-            (Color::RGB(left), Color::HSL(right)) => left == right,
-            (Color::RGB(left), Color::HSV(right)) => left == right,
-            (Color::RGB(left), Color::RGB(right)) => left == right,
+        if let Some(equal) = alpha_eq(self, other) {
+            return equal;
+        }
+
+        match self {
+            Color::CMYK(left) => left == other,
+            Color::HSL(left) => left == other,
+            Color::HSLA(left) => left == other,
+            Color::HSV(left) => left == other,
+            Color::HSVA(left) => left == other,
+            Color::HWB(left) => left == other,
+            Color::Lab(left) => left == other,
+            Color::LCh(left) => left == other,
+            Color::Oklab(left) => left == other,
+            Color::Oklch(left) => left == other,
+            Color::RGB(left) => left == other,
+            Color::RGBA(left) => left == other,
+            Color::XYZ(left) => left == other,
+            Color::YCbCr(left) => left == other,
         }
     }
 }
 
 
+impl PartialEq<CMYK> for Color {
+    fn eq(&self, other: &CMYK) -> bool {
+        self == &Self::from(other.clone())
+    }
+}
+
+
 impl PartialEq<HSL> for Color {
     fn eq(&self, other: &HSL) -> bool {
-        match self {
-            Color::HSL(v) => v == other,
-            Color::HSV(v) => v == other,
-            Color::RGB(v) => v == other,
-        }
+        self == &Self::from(other.clone())
+    }
+}
+
+
+impl PartialEq<HSLA> for Color {
+    fn eq(&self, other: &HSLA) -> bool {
+        self == &Self::from(other.clone())
     }
 }
 
 
 impl PartialEq<HSV> for Color {
     fn eq(&self, other: &HSV) -> bool {
-        match self {
-            Color::HSL(v) => v == other,
-            Color::HSV(v) => v == other,
-            Color::RGB(v) => v == other,
-        }
+        self == &Self::from(other.clone())
+    }
+}
+
+
+impl PartialEq<HSVA> for Color {
+    fn eq(&self, other: &HSVA) -> bool {
+        self == &Self::from(other.clone())
+    }
+}
+
+
+impl PartialEq<HWB> for Color {
+    fn eq(&self, other: &HWB) -> bool {
+        self == &Self::from(other.clone())
+    }
+}
+
+
+impl PartialEq<Lab> for Color {
+    fn eq(&self, other: &Lab) -> bool {
+        self == &Self::from(other.clone())
+    }
+}
+
+
+impl PartialEq<LCh> for Color {
+    fn eq(&self, other: &LCh) -> bool {
+        self == &Self::from(other.clone())
+    }
+}
+
+
+impl PartialEq<Oklab> for Color {
+    fn eq(&self, other: &Oklab) -> bool {
+        self == &Self::from(other.clone())
+    }
+}
+
+
+impl PartialEq<Oklch> for Color {
+    fn eq(&self, other: &Oklch) -> bool {
+        self == &Self::from(other.clone())
     }
 }
 
 
 impl PartialEq<RGB> for Color {
     fn eq(&self, other: &RGB) -> bool {
-        match self {
-            Color::HSL(v) => v == other,
-            Color::HSV(v) => v == other,
-            Color::RGB(v) => v == other,
-        }
+        self == &Self::from(other.clone())
+    }
+}
+
+
+impl PartialEq<RGBA> for Color {
+    fn eq(&self, other: &RGBA) -> bool {
+        self == &Self::from(other.clone())
     }
 }
 
+
+impl PartialEq<XYZ> for Color {
+    fn eq(&self, other: &XYZ) -> bool {
+        self == &Self::from(other.clone())
+    }
+}
+
+
+impl PartialEq<YCbCr> for Color {
+    fn eq(&self, other: &YCbCr) -> bool {
+        self == &Self::from(other.clone())
+    }
+}
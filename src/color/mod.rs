@@ -1,9 +1,32 @@
 #!/usr/bin/env rust
 
 
+use std::convert::TryFrom;
+
+
+use crate::blend::BlendMode;
+use crate::cmyk::CMYK;
+use crate::css::Options;
+use crate::cvd::Deficiency;
+use crate::difference::DeltaE;
+use crate::error::Error;
+use crate::gamut;
 use crate::hsl::HSL;
+use crate::hsla::HSLA;
 use crate::hsv::HSV;
+use crate::hsva::HSVA;
+use crate::hwb::HWB;
+use crate::lab::Lab;
+use crate::lch::LCh;
+use crate::mix;
+use crate::mix::{ HueMethod, Space };
+use crate::oklab::Oklab;
+use crate::oklch::Oklch;
 use crate::rgb::RGB;
+use crate::rgb_space::RgbSpace;
+use crate::rgba::RGBA;
+use crate::xyz::XYZ;
+use crate::ycbcr::YCbCr;
 
 
 /// Adds color components for enumerable data structures
@@ -13,33 +36,125 @@ mod add;
 mod subtract;
 
 /// Equal and not-equal checks for enumerable data structures
-mod equality;
+pub(crate) mod equality;
+
+/// Converts from `CMYK` data structure into `Color::CMYK`
+mod convert_cmyk;
 
 /// Converts from `HSL` data structure into `Color::HSL`
 mod convert_hsl;
 
+/// Converts from `HSLA` data structure into `Color::HSLA`
+mod convert_hsla;
+
 /// Converts from `HSV` data structure into `Color::HSV`
 mod convert_hsv;
 
+/// Converts from `HSVA` data structure into `Color::HSVA`
+mod convert_hsva;
+
+/// Converts from `HWB` data structure into `Color::HWB`
+mod convert_hwb;
+
+/// Converts from/to `JsonValue` for `Color` enum
+mod convert_json_value;
+
+/// Converts from `Lab` data structure into `Color::Lab`
+mod convert_lab;
+
+/// Converts from `LCh` data structure into `Color::LCh`
+mod convert_lch;
+
+/// Converts from `Oklab` data structure into `Color::Oklab`
+mod convert_oklab;
+
+/// Converts from `Oklch` data structure into `Color::Oklch`
+mod convert_oklch;
+
 /// Converts from `RGB` data structure into `Color::RGB`
 mod convert_rgb;
 
+/// Converts from `RGBA` data structure into `Color::RGBA`
+mod convert_rgba;
+
+/// Parses CSS color syntax into `Color`
+mod convert_string;
+
+/// Converts from `XYZ` data structure into `Color::XYZ`
+mod convert_xyz;
+
+/// Converts from `YCbCr` data structure into `Color::YCbCr`
+mod convert_ycbcr;
+
 
 /// Contains instances of supported color data structures
 #[derive(Clone, Debug)]
 pub enum Color {
+    /// Instance of `CMYK` data structure
+    CMYK(CMYK),
+
     /// Instance of `HSL` data structure
     HSL(HSL),
 
+    /// Instance of `HSLA` data structure
+    HSLA(HSLA),
+
     /// Instance of `HSV` data structure
     HSV(HSV),
 
+    /// Instance of `HSVA` data structure
+    HSVA(HSVA),
+
+    /// Instance of `HWB` data structure
+    HWB(HWB),
+
+    /// Instance of `Lab` data structure
+    Lab(Lab),
+
+    /// Instance of `LCh` data structure
+    LCh(LCh),
+
+    /// Instance of `Oklab` data structure
+    Oklab(Oklab),
+
+    /// Instance of `Oklch` data structure
+    Oklch(Oklch),
+
     /// Instance of `RGB` data structure
     RGB(RGB),
+
+    /// Instance of `RGBA` data structure
+    RGBA(RGBA),
+
+    /// Instance of `XYZ` data structure
+    XYZ(XYZ),
+
+    /// Instance of `YCbCr` data structure
+    YCbCr(YCbCr),
 }
 
 
 impl Color {
+    /// Initializes and wrapps instance of `CMYK` within `Color`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::cmyk::CMYK;
+    ///
+    /// let c = Color::new_cmyk(0.0, 0.5, 0.25, 0.125);
+    /// let cmyk: CMYK = c.clone().into();
+    ///
+    /// assert_eq!(c, cmyk);
+    /// ```
+    pub fn new_cmyk<T>(cyan: T, magenta: T, yellow: T, key: T) -> Self
+    where
+        T: Into<f64>
+    {
+        Self::CMYK(CMYK::new(cyan, magenta, yellow, key))
+    }
+
     /// Initializes and wrapps instance of `HSL` within `Color`
     ///
     /// # Example
@@ -60,6 +175,26 @@ impl Color {
         Self::HSL(HSL::new(hue, saturation, lightness))
     }
 
+    /// Initializes and wrapps instance of `HSLA` within `Color`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::hsla::HSLA;
+    ///
+    /// let c = Color::new_hsla(60.0, 1.0, 0.5, 0.25);
+    /// let hsla: HSLA = c.clone().into();
+    ///
+    /// assert_eq!(c, hsla);
+    /// ```
+    pub fn new_hsla<T>(hue: T, saturation: T, lightness: T, alpha: T) -> Self
+    where
+        T: Into<f64>
+    {
+        Self::HSLA(HSLA::new(hue, saturation, lightness, alpha))
+    }
+
     /// Initializes and wrapps instance of `HSV` within `Color`
     ///
     /// # Example
@@ -80,6 +215,126 @@ impl Color {
         Self::HSV(HSV::new(hue, saturation, value))
     }
 
+    /// Initializes and wrapps instance of `HSVA` within `Color`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::hsva::HSVA;
+    ///
+    /// let c = Color::new_hsva(60.0, 1.0, 0.5, 0.25);
+    /// let hsva: HSVA = c.clone().into();
+    ///
+    /// assert_eq!(c, hsva);
+    /// ```
+    pub fn new_hsva<T>(hue: T, saturation: T, value: T, alpha: T) -> Self
+    where
+        T: Into<f64>
+    {
+        Self::HSVA(HSVA::new(hue, saturation, value, alpha))
+    }
+
+    /// Initializes and wrapps instance of `HWB` within `Color`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::hwb::HWB;
+    ///
+    /// let c = Color::new_hwb(120.0, 0.25, 0.5);
+    /// let hwb: HWB = c.clone().into();
+    ///
+    /// assert_eq!(c, hwb);
+    /// ```
+    pub fn new_hwb<T>(hue: T, whiteness: T, blackness: T) -> Self
+    where
+        T: Into<f64>
+    {
+        Self::HWB(HWB::new(hue, whiteness, blackness))
+    }
+
+    /// Initializes and wrapps instance of `Lab` within `Color`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::lab::Lab;
+    ///
+    /// let c = Color::new_lab(50.0, 25.0, -25.0);
+    /// let lab: Lab = c.clone().into();
+    ///
+    /// assert_eq!(c, lab);
+    /// ```
+    pub fn new_lab<T>(lightness: T, a: T, b: T) -> Self
+    where
+        T: Into<f64>
+    {
+        Self::Lab(Lab::new(lightness, a, b))
+    }
+
+    /// Initializes and wrapps instance of `LCh` within `Color`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::lch::LCh;
+    ///
+    /// let c = Color::new_lch(50.0, 25.0, 120.0);
+    /// let lch: LCh = c.clone().into();
+    ///
+    /// assert_eq!(c, lch);
+    /// ```
+    pub fn new_lch<T>(lightness: T, chroma: T, hue: T) -> Self
+    where
+        T: Into<f64>
+    {
+        Self::LCh(LCh::new(lightness, chroma, hue))
+    }
+
+    /// Initializes and wrapps instance of `Oklab` within `Color`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::oklab::Oklab;
+    ///
+    /// let c = Color::new_oklab(0.5, 0.125, -0.125);
+    /// let oklab: Oklab = c.clone().into();
+    ///
+    /// assert_eq!(c, oklab);
+    /// ```
+    pub fn new_oklab<T>(l: T, a: T, b: T) -> Self
+    where
+        T: Into<f64>
+    {
+        Self::Oklab(Oklab::new(l, a, b))
+    }
+
+    /// Initializes and wrapps instance of `Oklch` within `Color`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::oklch::Oklch;
+    ///
+    /// let c = Color::new_oklch(0.5, 0.125, 120.0);
+    /// let oklch: Oklch = c.clone().into();
+    ///
+    /// assert_eq!(c, oklch);
+    /// ```
+    pub fn new_oklch<T>(l: T, c: T, h: T) -> Self
+    where
+        T: Into<f64>
+    {
+        Self::Oklch(Oklch::new(l, c, h))
+    }
+
     /// Initializes and wrapps instance of `RGB` within `Color`
     ///
     /// # Example
@@ -100,6 +355,83 @@ impl Color {
         Self::RGB(RGB::new(red, green, blue))
     }
 
+    /// Initializes and wrapps instance of `RGBA` within `Color`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let c = Color::new_rgba(255, 42, 90, 128);
+    /// let rgba: RGBA = c.clone().into();
+    ///
+    /// assert_eq!(c, rgba);
+    /// ```
+    pub fn new_rgba<T>(red: T, green: T, blue: T, alpha: T) -> Self
+    where
+        T: Into<u8>
+    {
+        Self::RGBA(RGBA::new(red, green, blue, alpha))
+    }
+
+    /// Initializes and wrapps instance of `XYZ` within `Color`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let c = Color::new_xyz(0.5, 0.25, 0.125);
+    /// let xyz: XYZ = c.clone().into();
+    ///
+    /// assert_eq!(c, xyz);
+    /// ```
+    pub fn new_xyz<T>(x: T, y: T, z: T) -> Self
+    where
+        T: Into<f64>
+    {
+        Self::XYZ(XYZ::new(x, y, z))
+    }
+
+    /// Initializes and wrapps instance of `YCbCr` within `Color`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::ycbcr::YCbCr;
+    ///
+    /// let c = Color::new_ycbcr(0.5, 0.25, -0.25);
+    /// let ycbcr: YCbCr = c.clone().into();
+    ///
+    /// assert_eq!(c, ycbcr);
+    /// ```
+    pub fn new_ycbcr<T>(y: T, cb: T, cr: T) -> Self
+    where
+        T: Into<f64>
+    {
+        Self::YCbCr(YCbCr::new(y, cb, cr))
+    }
+
+    /// Check if `Color` contains a `CMYK` data structure
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    ///
+    /// let c = Color::new_cmyk(0.0, 0.5, 0.25, 0.125);
+    /// assert!(c.is_cmyk());
+    /// ```
+    pub fn is_cmyk(&self) -> bool {
+        match self {
+            Self::CMYK(_) => true,
+            _ => false,
+        }
+    }
+
     /// Check if `Color` contains a `HSL` data structure
     ///
     /// # Example
@@ -117,6 +449,23 @@ impl Color {
         }
     }
 
+    /// Check if `Color` contains a `HSLA` data structure
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    ///
+    /// let c = Color::new_hsla(60.0, 1.0, 0.5, 0.25);
+    /// assert!(c.is_hsla());
+    /// ```
+    pub fn is_hsla(&self) -> bool {
+        match self {
+            Self::HSLA(_) => true,
+            _ => false,
+        }
+    }
+
     /// Check if `Color` contains a `HSV` data structure
     ///
     /// # Example
@@ -134,6 +483,108 @@ impl Color {
         }
     }
 
+    /// Check if `Color` contains a `HSVA` data structure
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    ///
+    /// let c = Color::new_hsva(60.0, 1.0, 0.5, 0.25);
+    /// assert!(c.is_hsva());
+    /// ```
+    pub fn is_hsva(&self) -> bool {
+        match self {
+            Self::HSVA(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Check if `Color` contains a `HWB` data structure
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    ///
+    /// let c = Color::new_hwb(120.0, 0.25, 0.5);
+    /// assert!(c.is_hwb());
+    /// ```
+    pub fn is_hwb(&self) -> bool {
+        match self {
+            Self::HWB(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Check if `Color` contains a `Lab` data structure
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    ///
+    /// let c = Color::new_lab(50.0, 25.0, -25.0);
+    /// assert!(c.is_lab());
+    /// ```
+    pub fn is_lab(&self) -> bool {
+        match self {
+            Self::Lab(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Check if `Color` contains a `LCh` data structure
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    ///
+    /// let c = Color::new_lch(50.0, 25.0, 120.0);
+    /// assert!(c.is_lch());
+    /// ```
+    pub fn is_lch(&self) -> bool {
+        match self {
+            Self::LCh(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Check if `Color` contains a `Oklab` data structure
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    ///
+    /// let c = Color::new_oklab(0.5, 0.125, -0.125);
+    /// assert!(c.is_oklab());
+    /// ```
+    pub fn is_oklab(&self) -> bool {
+        match self {
+            Self::Oklab(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Check if `Color` contains a `Oklch` data structure
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    ///
+    /// let c = Color::new_oklch(0.5, 0.125, 120.0);
+    /// assert!(c.is_oklch());
+    /// ```
+    pub fn is_oklch(&self) -> bool {
+        match self {
+            Self::Oklch(_) => true,
+            _ => false,
+        }
+    }
+
     /// Check if `Color` contains a `RGB` data structure
     ///
     /// # Example
@@ -151,6 +602,57 @@ impl Color {
         }
     }
 
+    /// Check if `Color` contains a `RGBA` data structure
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    ///
+    /// let c = Color::new_rgba(255, 42, 90, 128);
+    /// assert!(c.is_rgba());
+    /// ```
+    pub fn is_rgba(&self) -> bool {
+        match self {
+            Self::RGBA(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Check if `Color` contains a `XYZ` data structure
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    ///
+    /// let c = Color::new_xyz(0.5, 0.25, 0.125);
+    /// assert!(c.is_xyz());
+    /// ```
+    pub fn is_xyz(&self) -> bool {
+        match self {
+            Self::XYZ(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Check if `Color` contains a `YCbCr` data structure
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    ///
+    /// let c = Color::new_ycbcr(0.5, 0.25, -0.25);
+    /// assert!(c.is_ycbcr());
+    /// ```
+    pub fn is_ycbcr(&self) -> bool {
+        match self {
+            Self::YCbCr(_) => true,
+            _ => false,
+        }
+    }
+
     /// Returns hexadecimal string representation of contained `Color` values
     ///
     /// # Example
@@ -165,15 +667,63 @@ impl Color {
     /// ```
     pub fn to_hex_string(&self) -> String {
         match self {
+            Self::CMYK(v) => v.to_hex_string(),
             Self::HSL(v) => v.to_hex_string(),
+            Self::HSLA(v) => v.to_hex_string(),
             Self::HSV(v) => v.to_hex_string(),
+            Self::HSVA(v) => v.to_hex_string(),
+            Self::HWB(v) => v.to_hex_string(),
+            Self::Lab(v) => v.to_hex_string(),
+            Self::LCh(v) => v.to_hex_string(),
+            Self::Oklab(v) => v.to_hex_string(),
+            Self::Oklch(v) => v.to_hex_string(),
             Self::RGB(v) => v.to_hex_string(),
+            Self::RGBA(v) => v.to_hex_string(),
+            Self::XYZ(v) => v.to_hex_string(),
+            Self::YCbCr(v) => v.to_hex_string(),
+        }
+    }
+
+    /// Returns CSS representation of contained data structure
+    ///
+    /// **Note** `RGB`, `RGBA`, `HSL`, `HSLA`, `HSV`, `Lab`, `LCh`, `Oklab`, and `Oklch` are written
+    /// with their own CSS functions, `HWB` via `HSV`, `HSVA` via `RGBA`, and all others via `RGB`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::css::{ Notation, Options, Syntax };
+    ///
+    /// let c_hsla = Color::new_hsla(120.0, 1.0, 0.25, 0.5);
+    /// let c_lab = Color::new_lab(100.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(c_hsla.to_css_string(Options::new().syntax(Syntax::Legacy)), "hsla(120, 100%, 25%, 0.5)");
+    /// assert_eq!(c_hsla.to_css_string(Options::new().notation(Notation::Hex)), "#00800080");
+    /// assert_eq!(c_lab.to_css_string(Options::new().named(true)), "white");
+    /// ```
+    pub fn to_css_string(&self, options: Options) -> String {
+        match self {
+            Self::HSL(v) => v.to_css_string(options),
+            Self::HSLA(v) => v.to_css_string(options),
+            Self::HSV(v) => v.to_css_string(options),
+            Self::HSVA(v) => RGBA::from(v.clone()).to_css_string(options),
+            Self::HWB(v) => HSV::from(v.clone()).to_css_string(options),
+            Self::Lab(v) => v.to_css_string(options),
+            Self::LCh(v) => v.to_css_string(options),
+            Self::Oklab(v) => v.to_css_string(options),
+            Self::Oklch(v) => v.to_css_string(options),
+            Self::RGB(v) => v.to_css_string(options),
+            Self::RGBA(v) => v.to_css_string(options),
+            _ => RGB::from(self.clone()).to_css_string(options),
         }
     }
 
     /// Returns parsed JSON string for color key/value pares
     ///
-    /// **Note** defaults to `Color::RGB` with `0` for all values
+    /// **Note** invalid JSON defaults to `Color::RGB` with `0` for all values, and missing or
+    /// invalid components default individually, see `Color::try_from_json_str` for reporting errors
+    /// instead
     ///
     /// # Example
     ///
@@ -196,6 +746,14 @@ impl Color {
     ///
     /// assert!(c_hsv.is_hsv());
     ///
+    /// let c_hwb = Color::from_json_string(r#"{
+    ///     "hue": 120.0,
+    ///     "whiteness": 0.25,
+    ///     "blackness": 0.5
+    /// }"#);
+    ///
+    /// assert!(c_hwb.is_hwb());
+    ///
     /// let c_rgb = Color::from_json_string(r#"{
     ///     "red": 255,
     ///     "green": 42,
@@ -203,30 +761,65 @@ impl Color {
     /// }"#);
     ///
     /// assert!(c_rgb.is_rgb());
+    ///
+    /// let c_rgba = Color::from_json_string(r#"{
+    ///     "red": 255,
+    ///     "green": 42,
+    ///     "blue": 90,
+    ///     "alpha": 128
+    /// }"#);
+    ///
+    /// assert!(c_rgba.is_rgba());
+    ///
+    /// let c_lab = Color::from_json_string(r#"{
+    ///     "lightness": 50.0,
+    ///     "a": 25.0,
+    ///     "b": -25.0
+    /// }"#);
+    ///
+    /// assert!(c_lab.is_lab());
+    ///
+    /// let c_oklch = Color::from_json_string(r#"{
+    ///     "l": 0.5,
+    ///     "c": 0.125,
+    ///     "h": 120.0
+    /// }"#);
+    ///
+    /// assert!(c_oklch.is_oklch());
     /// ```
     pub fn from_json_string<S>(string: S) -> Self
     where
         S: Into<String>
     {
-        let object = match json::parse(&string.into()) {
-            Ok(data) => data,
-            Err(e) => {
-                println!("Warning: ignoring error -> {:?}", e);
-                json::object!{
-                    "red": 0,
-                    "green": 0,
-                    "blue": 0,
-                }
-            }
-        };
-
-        if object.has_key("lightness") {
-            Self::from(HSL::from(object))
-        } else if object.has_key("value") {
-            Self::from(HSV::from(object))
-        } else {
-            Self::from(RGB::from(object))
-        }
+        json::parse(&string.into()).map(Self::from).unwrap_or_else(|_| Self::RGB(RGB::default()))
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or error for invalid JSON and missing
+    /// or invalid components
+    ///
+    /// **Note** contained data structure is chosen by keys present, same as `Color::from_json_string`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::error::Error;
+    ///
+    /// let c_hsl = Color::try_from_json_str(r#"{
+    ///     "hue": 120.0,
+    ///     "saturation": 1.0,
+    ///     "lightness": 0.75
+    /// }"#).unwrap();
+    ///
+    /// assert!(c_hsl.is_hsl());
+    ///
+    /// let result = Color::try_from_json_str(r#"{ "hue": 120.0, "lightness": 0.75 }"#);
+    ///
+    /// assert_eq!(result, Err(Error::MissingComponent("saturation".to_string())));
+    /// ```
+    pub fn try_from_json_str(string: &str) -> Result<Self, Error> {
+        let data = json::parse(string)?;
+        Self::try_from(&data)
     }
 
     /// Serializes contained data structure as JSON string
@@ -251,34 +844,334 @@ impl Color {
     /// ```
     pub fn to_json_string(&self) -> String {
         match self {
+            Self::CMYK(v) => v.to_json_string(),
             Self::HSL(v) => v.to_json_string(),
+            Self::HSLA(v) => v.to_json_string(),
             Self::HSV(v) => v.to_json_string(),
+            Self::HSVA(v) => v.to_json_string(),
+            Self::HWB(v) => v.to_json_string(),
+            Self::Lab(v) => v.to_json_string(),
+            Self::LCh(v) => v.to_json_string(),
+            Self::Oklab(v) => v.to_json_string(),
+            Self::Oklch(v) => v.to_json_string(),
             Self::RGB(v) => v.to_json_string(),
+            Self::RGBA(v) => v.to_json_string(),
+            Self::XYZ(v) => v.to_json_string(),
+            Self::YCbCr(v) => v.to_json_string(),
         }
     }
 
     /// Attempts to rotate hue by some amount of degrees
     ///
-    /// **Note** this method uses `HSL::rotate_hue` or `HSV::rotate_hue` internally
+    /// **Note** this method uses `rotate_hue` of contained data structure internally
     pub fn rotate_hue<T>(&self, amount: T) -> Self
     where
         T: Into<f64>
     {
         match self {
+            Self::CMYK(v) => Self::CMYK(v.rotate_hue(amount)),
             Self::HSL(v) => Self::HSL(v.rotate_hue(amount)),
+            Self::HSLA(v) => Self::HSLA(v.rotate_hue(amount)),
             Self::HSV(v) => Self::HSV(v.rotate_hue(amount)),
+            Self::HSVA(v) => Self::HSVA(v.rotate_hue(amount)),
+            Self::HWB(v) => Self::HWB(v.rotate_hue(amount)),
+            Self::Lab(v) => Self::Lab(v.rotate_hue(amount)),
+            Self::LCh(v) => Self::LCh(v.rotate_hue(amount)),
+            Self::Oklab(v) => Self::Oklab(v.rotate_hue(amount)),
+            Self::Oklch(v) => Self::Oklch(v.rotate_hue(amount)),
             Self::RGB(v) => Self::RGB(v.rotate_hue(amount)),
+            Self::RGBA(v) => Self::RGBA(v.rotate_hue(amount)),
+            Self::XYZ(v) => Self::XYZ(v.rotate_hue(amount)),
+            Self::YCbCr(v) => Self::YCbCr(v.rotate_hue(amount)),
         }
     }
 
     /// Attempts to rotate this color with another
     ///
-    /// **Note** wraps on overflow values
+    /// **Note** wraps on overflow values, and device independent data structures use
+    /// `RGB::rotate_rgb` internally
     pub fn rotate_rgb(&self, other: Self) -> Self {
         match self {
+            Self::CMYK(v) => Self::CMYK(CMYK::from(RGB::from(v.clone()).rotate_rgb(other.into()))),
             Self::HSL(v) => Self::HSL(v.rotate_rgb(other.into())),
+            Self::HSLA(v) => Self::HSLA(v.rotate_rgb(other.into())),
             Self::HSV(v) => Self::HSV(v.rotate_rgb(other.into())),
+            Self::HSVA(v) => Self::HSVA(v.rotate_rgb(other.into())),
+            Self::HWB(v) => Self::HWB(HWB::from(RGB::from(v.clone()).rotate_rgb(other.into()))),
+            Self::Lab(v) => Self::Lab(Lab::from(RGB::from(v.clone()).rotate_rgb(other.into()))),
+            Self::LCh(v) => Self::LCh(LCh::from(RGB::from(v.clone()).rotate_rgb(other.into()))),
+            Self::Oklab(v) => Self::Oklab(Oklab::from(RGB::from(v.clone()).rotate_rgb(other.into()))),
+            Self::Oklch(v) => Self::Oklch(Oklch::from(RGB::from(v.clone()).rotate_rgb(other.into()))),
             Self::RGB(v) => Self::RGB(v.rotate_rgb(other.into())),
+            Self::RGBA(v) => Self::RGBA(v.rotate_rgb(other.into())),
+            Self::XYZ(v) => Self::XYZ(XYZ::from(RGB::from(v.clone()).rotate_rgb(other.into()))),
+            Self::YCbCr(v) => Self::YCbCr(YCbCr::from(RGB::from(v.clone()).rotate_rgb(other.into()))),
+        }
+    }
+
+    /// Blends source color into contained backdrop color, then composites result over backdrop by
+    /// alpha of source
+    ///
+    /// **Note** result is converted to type of contained data structure, which is opaque when
+    /// backdrop has no alpha
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::blend::BlendMode;
+    /// use color_operators::color::Color;
+    ///
+    /// let backdrop = Color::new_rgb(255, 128, 0);
+    ///
+    /// let result = backdrop.blend(Color::new_rgb(128, 128, 128), BlendMode::Multiply);
+    /// assert!(result.is_rgb());
+    /// assert_eq!(result, Color::new_rgb(128, 64, 0));
+    ///
+    /// let result = backdrop.blend(Color::new_rgba(128, 128, 128, 51), BlendMode::Multiply);
+    /// assert_eq!(result, Color::new_rgb(230, 115, 0));
+    /// ```
+    pub fn blend(&self, source: Self, mode: BlendMode) -> Self {
+        let blend = |backdrop: RGBA| RGB::from(backdrop.blend(source.clone().into(), mode));
+        match self {
+            Self::CMYK(v) => Self::CMYK(CMYK::from(blend(RGBA::from(RGB::from(v.clone()))))),
+            Self::HSL(v) => Self::HSL(HSL::from(blend(RGBA::from(RGB::from(v.clone()))))),
+            Self::HSLA(v) => Self::HSLA(v.blend(source.into(), mode)),
+            Self::HSV(v) => Self::HSV(HSV::from(blend(RGBA::from(RGB::from(v.clone()))))),
+            Self::HSVA(v) => Self::HSVA(v.blend(source.into(), mode)),
+            Self::HWB(v) => Self::HWB(HWB::from(blend(RGBA::from(RGB::from(v.clone()))))),
+            Self::Lab(v) => Self::Lab(Lab::from(blend(RGBA::from(RGB::from(v.clone()))))),
+            Self::LCh(v) => Self::LCh(LCh::from(blend(RGBA::from(RGB::from(v.clone()))))),
+            Self::Oklab(v) => Self::Oklab(Oklab::from(blend(RGBA::from(RGB::from(v.clone()))))),
+            Self::Oklch(v) => Self::Oklch(Oklch::from(blend(RGBA::from(RGB::from(v.clone()))))),
+            Self::RGB(v) => Self::RGB(blend(RGBA::from(v.clone()))),
+            Self::RGBA(v) => Self::RGBA(v.blend(source.into(), mode)),
+            Self::XYZ(v) => Self::XYZ(XYZ::from(blend(RGBA::from(RGB::from(v.clone()))))),
+            Self::YCbCr(v) => Self::YCbCr(YCbCr::from(blend(RGBA::from(RGB::from(v.clone()))))),
+        }
+    }
+
+    /// Returns color interpolated within space, where amount of `0.0` is contained color and `1.0`
+    /// is other color
+    ///
+    /// **Note** amount is clamped between `0.0` and `1.0`, hue method only applies to cylindrical
+    /// spaces, and achromatic colors adopt hue of other color as defined by CSS Color 4
+    ///
+    /// **Note** result is converted to type of contained data structure, alpha is interpolated
+    /// premultiplied and kept only by data structures with alpha
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::mix::{ HueMethod, Space };
+    ///
+    /// let red = Color::new_rgb(255, 0, 0);
+    /// let blue = Color::new_rgb(0, 0, 255);
+    ///
+    /// assert_eq!(red.mix(&blue, 0.5, Space::Srgb, HueMethod::Shorter), Color::new_rgb(128, 0, 128));
+    /// assert_eq!(red.mix(&blue, 0.5, Space::HSL, HueMethod::Shorter), Color::new_rgb(255, 0, 255));
+    /// assert_eq!(red.mix(&blue, 0.5, Space::HSL, HueMethod::Longer), Color::new_rgb(0, 255, 0));
+    ///
+    /// let transparent = Color::new_rgba(0, 0, 255, 0);
+    ///
+    /// assert_eq!(Color::new_rgba(255, 0, 0, 255).mix(&transparent, 0.5, Space::Srgb, HueMethod::Shorter), Color::new_rgba(255, 0, 0, 128));
+    /// ```
+    pub fn mix<T>(&self, other: &T, amount: f64, space: Space, hue_method: HueMethod) -> Self
+    where
+        T: Clone + Into<Self>
+    {
+        let ( mixed, alpha ) = mix::interpolate(self.clone(), other.clone().into(), amount, space, hue_method);
+        self.convert_like(mixed, alpha)
+    }
+
+    /// Returns perceptual difference, ΔE, between contained color and other color
+    ///
+    /// **Note** contained color is reference for formulas that are not symmetric
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::difference::DeltaE;
+    ///
+    /// let reference = Color::new_lab(50.0, 0.0, 0.0);
+    /// let sample = Color::new_lab(50.0, -1.0, 2.0);
+    ///
+    /// let round = |v: f64| (v * 10_000.0).round() / 10_000.0;
+    ///
+    /// assert_eq!(round(reference.difference(&sample, DeltaE::CIEDE2000)), 2.3669);
+    /// ```
+    pub fn difference<T>(&self, other: &T, formula: DeltaE) -> f64
+    where
+        T: Clone + Into<Self>
+    {
+        formula.between(self.clone(), other.clone().into())
+    }
+
+    /// Returns `true` if difference to other color is within tolerance, i.e. colors are visually
+    /// identical for tolerances around `1.0`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::difference::DeltaE;
+    /// use color_operators::hsl::HSL;
+    ///
+    /// let c_rgb = Color::new_rgb(255, 42, 90);
+    /// let hsl = HSL::new(346.5, 1.0, 0.58);
+    ///
+    /// assert_ne!(c_rgb, hsl);
+    /// assert!(c_rgb.is_close(&hsl, DeltaE::CIEDE2000, 1.0));
+    /// assert!(!c_rgb.is_close(&Color::new_rgb(255, 42, 120), DeltaE::CIEDE2000, 1.0));
+    /// ```
+    pub fn is_close<T>(&self, other: &T, formula: DeltaE, tolerance: f64) -> bool
+    where
+        T: Clone + Into<Self>
+    {
+        self.difference(other, formula) <= tolerance
+    }
+
+    /// Returns WCAG 2 relative luminance between `0.0` and `1.0`
+    ///
+    /// **Note** contained color is converted to `RGB`, alpha is ignored
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    ///
+    /// assert_eq!(Color::new_hsl(0.0, 0.0, 1.0).relative_luminance(), 1.0);
+    /// ```
+    pub fn relative_luminance(&self) -> f64 {
+        RGB::from(self.clone()).relative_luminance()
+    }
+
+    /// Returns WCAG 2 contrast ratio, between `1.0` and `21.0`, with other color
+    ///
+    /// **Note** both colors are converted to `RGB`, alpha is ignored
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::hsv::HSV;
+    ///
+    /// let c_rgb = Color::new_rgb(255, 255, 255);
+    ///
+    /// assert_eq!(c_rgb.contrast_ratio(&HSV::new(0.0, 0.0, 0.0)), 21.0);
+    /// ```
+    pub fn contrast_ratio<T>(&self, other: &T) -> f64
+    where
+        T: Clone + Into<Self>
+    {
+        let other: Self = other.clone().into();
+        RGB::from(self.clone()).contrast_ratio(&RGB::from(other))
+    }
+
+    /// Returns color as perceived with color vision deficiency at severity between `0.0` and `1.0`
+    ///
+    /// **Note** contained color is simulated as `RGB` and converted back to same type, alpha is kept
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::cvd::Deficiency;
+    ///
+    /// let c_rgba = Color::new_rgba(255, 0, 0, 128);
+    ///
+    /// assert_eq!(c_rgba.simulate_cvd(Deficiency::Protanopia, 1.0), Color::new_rgba(109, 95, 0, 128));
+    /// ```
+    pub fn simulate_cvd(&self, deficiency: Deficiency, severity: f64) -> Self {
+        let rgb = RGB::from(self.clone()).simulate_cvd(deficiency, severity);
+        self.convert_like(Self::RGB(rgb), mix::opacity(self))
+    }
+
+    /// Returns color adjusted to remain distinguishable with color vision deficiency at severity
+    /// between `0.0` and `1.0`
+    ///
+    /// **Note** contained color is adjusted as `RGB` and converted back to same type, alpha is kept
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::cvd::Deficiency;
+    ///
+    /// let c_rgba = Color::new_rgba(255, 0, 0, 128);
+    ///
+    /// assert_eq!(c_rgba.daltonize(Deficiency::Protanopia, 1.0), Color::new_rgba(255, 184, 203, 128));
+    /// ```
+    pub fn daltonize(&self, deficiency: Deficiency, severity: f64) -> Self {
+        let rgb = RGB::from(self.clone()).daltonize(deficiency, severity);
+        self.convert_like(Self::RGB(rgb), mix::opacity(self))
+    }
+
+    /// Returns `true` when contained color may be shown within space without clamping
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::rgb_space::RgbSpace;
+    ///
+    /// let c_lab = Color::new_lab(50.0, 80.0, 0.0);
+    ///
+    /// assert!(!c_lab.in_gamut(&RgbSpace::srgb()));
+    /// assert!(c_lab.in_gamut(&RgbSpace::display_p3()));
+    /// ```
+    pub fn in_gamut(&self, space: &RgbSpace) -> bool {
+        gamut::in_gamut(self.clone(), space)
+    }
+
+    /// Returns color brought within gamut of space by method, converted back to same type
+    ///
+    /// **Note** `RGB` based data structures are sRGB, so colors mapped to wider spaces are clamped
+    /// when converted back to them, alpha is kept
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::gamut::Method;
+    /// use color_operators::oklch::Oklch;
+    /// use color_operators::rgb_space::RgbSpace;
+    ///
+    /// let c_oklch = Color::new_oklch(0.7, 0.3, 30.0);
+    /// let mapped = c_oklch.to_gamut(&RgbSpace::srgb(), Method::Css);
+    ///
+    /// assert!(mapped.in_gamut(&RgbSpace::srgb()));
+    ///
+    /// let ( l, c, h ) = Oklch::from(mapped).into();
+    ///
+    /// assert!((l - 0.7).abs() < 0.02);
+    /// assert!(c < 0.3);
+    /// assert_eq!(h.round(), 30.0);
+    /// ```
+    pub fn to_gamut(&self, space: &RgbSpace, method: gamut::Method) -> Self {
+        let xyz = gamut::to_xyz(space, gamut::map(self.clone(), space, method));
+        self.convert_like(Self::XYZ(xyz), mix::opacity(self))
+    }
+
+    /// Returns color converted to type of contained data structure, where alpha between `0.0` and
+    /// `1.0` is kept only by data structures with alpha
+    pub(crate) fn convert_like(&self, color: Self, alpha: f64) -> Self {
+        match self {
+            Self::CMYK(_) => Self::CMYK(CMYK::from(color)),
+            Self::HSL(_) => Self::HSL(HSL::from(color)),
+            Self::HSLA(_) => Self::HSLA(HSLA::from_hsl(HSL::from(color), alpha)),
+            Self::HSV(_) => Self::HSV(HSV::from(color)),
+            Self::HSVA(_) => Self::HSVA(HSVA::from_hsv(HSV::from(color), alpha)),
+            Self::HWB(_) => Self::HWB(HWB::from(color)),
+            Self::Lab(_) => Self::Lab(Lab::from(color)),
+            Self::LCh(_) => Self::LCh(LCh::from(color)),
+            Self::Oklab(_) => Self::Oklab(Oklab::from(color)),
+            Self::Oklch(_) => Self::Oklch(Oklch::from(color)),
+            Self::RGB(_) => Self::RGB(RGB::from(color)),
+            Self::RGBA(_) => Self::RGBA(RGBA::from_rgb(RGB::from(color), (alpha * 255.0).round() as u8)),
+            Self::XYZ(_) => Self::XYZ(XYZ::from(color)),
+            Self::YCbCr(_) => Self::YCbCr(YCbCr::from(color)),
         }
     }
 }
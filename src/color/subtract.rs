@@ -4,10 +4,21 @@
 use std::ops::Sub;
 
 
+use crate::cmyk::CMYK;
 use crate::color::Color;
 use crate::hsl::HSL;
+use crate::hsla::HSLA;
 use crate::hsv::HSV;
+use crate::hsva::HSVA;
+use crate::hwb::HWB;
+use crate::lab::Lab;
+use crate::lch::LCh;
+use crate::oklab::Oklab;
+use crate::oklch::Oklch;
 use crate::rgb::RGB;
+use crate::rgba::RGBA;
+use crate::xyz::XYZ;
+use crate::ycbcr::YCbCr;
 
 
 /// Adds color components
@@ -32,23 +43,36 @@ impl Sub for Color {
     /// assert_eq!(result, expected);
     /// ```
     fn sub(self, other: Self) -> Self::Output {
-        match (self, other) {
-            (Color::HSL(left), Color::HSL(right)) => Self::HSL(left - right),
-            (Color::HSL(left), Color::HSV(right)) => Self::HSL(left - right),
-            (Color::HSL(left), Color::RGB(right)) => Self::HSL(left - right),
-
-            (Color::HSV(left), Color::HSL(right)) => Self::HSV(left - right),
-            (Color::HSV(left), Color::HSV(right)) => Self::HSV(left - right),
-            (Color::HSV(left), Color::RGB(right)) => Self::HSV(left - right),
-
-            (Color::RGB(left), Color::HSL(right)) => Self::RGB(left - right),
-            (Color::RGB(left), Color::HSV(right)) => Self::RGB(left - right),
-            (Color::RGB(left), Color::RGB(right)) => Self::RGB(left - right),
+        match self {
+            Color::CMYK(left) => Self::CMYK(left - other),
+            Color::HSL(left) => Self::HSL(left - other),
+            Color::HSLA(left) => Self::HSLA(left - other),
+            Color::HSV(left) => Self::HSV(left - other),
+            Color::HSVA(left) => Self::HSVA(left - other),
+            Color::HWB(left) => Self::HWB(left - other),
+            Color::Lab(left) => Self::Lab(left - other),
+            Color::LCh(left) => Self::LCh(left - other),
+            Color::Oklab(left) => Self::Oklab(left - other),
+            Color::Oklch(left) => Self::Oklch(left - other),
+            Color::RGB(left) => Self::RGB(left - other),
+            Color::RGBA(left) => Self::RGBA(left - other),
+            Color::XYZ(left) => Self::XYZ(left - other),
+            Color::YCbCr(left) => Self::YCbCr(left - other),
         }
     }
 }
 
 
+/// Converts right side from `CMYK` to `Color` prior to arithmetic
+impl Sub<CMYK> for Color {
+    type Output = Self;
+
+    fn sub(self, other: CMYK) -> Self::Output {
+        self - Self::from(other)
+    }
+}
+
+
 /// Converts right side from `HSL` to `Color` prior to arithmetic
 impl Sub<HSL> for Color {
     type Output = Self;
@@ -69,11 +93,17 @@ impl Sub<HSL> for Color {
     /// assert_eq!(result, expected);
     /// ```
     fn sub(self, other: HSL) -> Self::Output {
-        match self {
-            Color::HSL(v) => Self::HSL(v - other),
-            Color::HSV(v) => Self::HSV(v - other),
-            Color::RGB(v) => Self::RGB(v - other),
-        }
+        self - Self::from(other)
+    }
+}
+
+
+/// Converts right side from `HSLA` to `Color` prior to arithmetic
+impl Sub<HSLA> for Color {
+    type Output = Self;
+
+    fn sub(self, other: HSLA) -> Self::Output {
+        self - Self::from(other)
     }
 }
 
@@ -98,11 +128,67 @@ impl Sub<HSV> for Color {
     /// assert_eq!(result, expected);
     /// ```
     fn sub(self, other: HSV) -> Self::Output {
-        match self {
-            Color::HSL(v) => Self::HSL(v - other),
-            Color::HSV(v) => Self::HSV(v - other),
-            Color::RGB(v) => Self::RGB(v - other),
-        }
+        self - Self::from(other)
+    }
+}
+
+
+/// Converts right side from `HSVA` to `Color` prior to arithmetic
+impl Sub<HSVA> for Color {
+    type Output = Self;
+
+    fn sub(self, other: HSVA) -> Self::Output {
+        self - Self::from(other)
+    }
+}
+
+
+/// Converts right side from `HWB` to `Color` prior to arithmetic
+impl Sub<HWB> for Color {
+    type Output = Self;
+
+    fn sub(self, other: HWB) -> Self::Output {
+        self - Self::from(other)
+    }
+}
+
+
+/// Converts right side from `Lab` to `Color` prior to arithmetic
+impl Sub<Lab> for Color {
+    type Output = Self;
+
+    fn sub(self, other: Lab) -> Self::Output {
+        self - Self::from(other)
+    }
+}
+
+
+/// Converts right side from `LCh` to `Color` prior to arithmetic
+impl Sub<LCh> for Color {
+    type Output = Self;
+
+    fn sub(self, other: LCh) -> Self::Output {
+        self - Self::from(other)
+    }
+}
+
+
+/// Converts right side from `Oklab` to `Color` prior to arithmetic
+impl Sub<Oklab> for Color {
+    type Output = Self;
+
+    fn sub(self, other: Oklab) -> Self::Output {
+        self - Self::from(other)
+    }
+}
+
+
+/// Converts right side from `Oklch` to `Color` prior to arithmetic
+impl Sub<Oklch> for Color {
+    type Output = Self;
+
+    fn sub(self, other: Oklch) -> Self::Output {
+        self - Self::from(other)
     }
 }
 
@@ -126,11 +212,36 @@ impl Sub<RGB> for Color {
     /// assert_eq!(result, expected);
     /// ```
     fn sub(self, other: RGB) -> Self::Output {
-        match self {
-            Color::HSL(v) => Self::HSL(v - other),
-            Color::HSV(v) => Self::HSV(v - other),
-            Color::RGB(v) => Self::RGB(v - other),
-        }
+        self - Self::from(other)
+    }
+}
+
+
+/// Converts right side from `RGBA` to `Color` prior to arithmetic
+impl Sub<RGBA> for Color {
+    type Output = Self;
+
+    fn sub(self, other: RGBA) -> Self::Output {
+        self - Self::from(other)
+    }
+}
+
+
+/// Converts right side from `XYZ` to `Color` prior to arithmetic
+impl Sub<XYZ> for Color {
+    type Output = Self;
+
+    fn sub(self, other: XYZ) -> Self::Output {
+        self - Self::from(other)
     }
 }
 
+
+/// Converts right side from `YCbCr` to `Color` prior to arithmetic
+impl Sub<YCbCr> for Color {
+    type Output = Self;
+
+    fn sub(self, other: YCbCr) -> Self::Output {
+        self - Self::from(other)
+    }
+}
@@ -0,0 +1,247 @@
+#!/usr/bin/env rust
+
+
+use crate::rgb_space::RgbSpace;
+use crate::rgba::RGBA;
+
+
+/// Porter-Duff compositing operators, where source is layered onto destination
+///
+/// **Note** each operator is defined by the fractions of source and destination that contribute
+/// to result, see `Operator::fractions`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operator {
+    /// Neither source nor destination, result is fully transparent
+    Clear,
+
+    /// Source only, a.k.a. copy
+    Source,
+
+    /// Destination only
+    Destination,
+
+    /// Source placed over destination
+    SourceOver,
+
+    /// Destination placed over source
+    DestinationOver,
+
+    /// Source where destination is present
+    SourceIn,
+
+    /// Destination where source is present
+    DestinationIn,
+
+    /// Source where destination is absent
+    SourceOut,
+
+    /// Destination where source is absent
+    DestinationOut,
+
+    /// Source over destination, only where destination is present
+    SourceAtop,
+
+    /// Destination over source, only where source is present
+    DestinationAtop,
+
+    /// Source and destination where they do not overlap
+    Xor,
+
+    /// Sum of source and destination, a.k.a. lighter
+    Plus,
+}
+
+
+/// How red, green, blue values of alpha carrying colors relate to alpha
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AlphaMode {
+    /// Color values are independent of alpha
+    #[default]
+    Straight,
+
+    /// Color values are already multiplied by alpha
+    Premultiplied,
+}
+
+
+/// Color space compositing arithmetic is preformed within
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Space {
+    /// sRGB gamma is removed prior to compositing and applied to result
+    #[default]
+    Linear,
+
+    /// Gamma encoded values are composited directly, matching most legacy tools and browsers
+    Gamma,
+}
+
+
+/// Settings for compositing alpha carrying colors
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Compositor {
+    alpha_mode: AlphaMode,
+    space: Space,
+}
+
+
+impl Operator {
+    /// Returns fractions of source and destination that contribute to result, for given source and
+    /// destination alpha
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::composite::Operator;
+    ///
+    /// assert_eq!(Operator::SourceOver.fractions(0.25, 1.0), (1.0, 0.75));
+    /// assert_eq!(Operator::DestinationIn.fractions(0.25, 1.0), (0.0, 0.25));
+    /// ```
+    pub fn fractions(&self, source_alpha: f64, destination_alpha: f64) -> (f64, f64) {
+        match self {
+            Self::Clear => (0.0, 0.0),
+            Self::Source => (1.0, 0.0),
+            Self::Destination => (0.0, 1.0),
+            Self::SourceOver => (1.0, 1.0 - source_alpha),
+            Self::DestinationOver => (1.0 - destination_alpha, 1.0),
+            Self::SourceIn => (destination_alpha, 0.0),
+            Self::DestinationIn => (0.0, source_alpha),
+            Self::SourceOut => (1.0 - destination_alpha, 0.0),
+            Self::DestinationOut => (0.0, 1.0 - source_alpha),
+            Self::SourceAtop => (destination_alpha, 1.0 - source_alpha),
+            Self::DestinationAtop => (1.0 - destination_alpha, source_alpha),
+            Self::Xor => (1.0 - destination_alpha, 1.0 - source_alpha),
+            Self::Plus => (1.0, 1.0),
+        }
+    }
+
+    /// Composites source onto destination with straight alpha in linear light
+    ///
+    /// **Note** this method uses `Compositor::default` internally
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::composite::Operator;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let source = RGBA::new(255, 0, 0, 128);
+    /// let destination = RGBA::new(0, 0, 255, 255);
+    ///
+    /// let result = Operator::SourceOver.composite(source, destination);
+    ///
+    /// assert_eq!(result, RGBA::new(188, 0, 187, 255));
+    /// ```
+    pub fn composite<S, D>(&self, source: S, destination: D) -> RGBA
+    where
+        S: Into<RGBA>,
+        D: Into<RGBA>
+    {
+        Compositor::default().composite(*self, source, destination)
+    }
+}
+
+
+impl Compositor {
+    /// Returns new compositing settings
+    pub fn new(alpha_mode: AlphaMode, space: Space) -> Self {
+        Self { alpha_mode, space }
+    }
+
+    /// Straight alpha composited in gamma encoded space, for parity with legacy tools
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::composite::{ Compositor, Operator };
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let source = RGBA::new(255, 0, 0, 128);
+    /// let destination = RGBA::new(0, 0, 255, 255);
+    ///
+    /// let result = Compositor::legacy().composite(Operator::SourceOver, source, destination);
+    ///
+    /// assert_eq!(result, RGBA::new(128, 0, 127, 255));
+    /// ```
+    pub fn legacy() -> Self {
+        Self::new(AlphaMode::Straight, Space::Gamma)
+    }
+
+    /// Composites source onto destination with given operator
+    ///
+    /// **Note** result uses the same alpha mode as inputs, and values exceeding `1.0`, e.g. from
+    /// `Operator::Plus`, are clamped
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::composite::{ AlphaMode, Compositor, Operator, Space };
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let compositor = Compositor::new(AlphaMode::Premultiplied, Space::Gamma);
+    ///
+    /// let source = RGBA::new(128, 0, 0, 128);
+    /// let destination = RGBA::new(0, 0, 255, 255);
+    ///
+    /// let over = compositor.composite(Operator::SourceOver, source.clone(), destination.clone());
+    /// assert_eq!(over, RGBA::new(128, 0, 127, 255));
+    ///
+    /// let xor = compositor.composite(Operator::Xor, source, destination);
+    /// assert_eq!(xor, RGBA::new(0, 0, 127, 127));
+    /// ```
+    pub fn composite<S, D>(&self, operator: Operator, source: S, destination: D) -> RGBA
+    where
+        S: Into<RGBA>,
+        D: Into<RGBA>
+    {
+        let source = self.premultiply(source.into());
+        let destination = self.premultiply(destination.into());
+
+        let ( source_fraction, destination_fraction ) = operator.fractions(source[3], destination[3]);
+
+        let mut result = [0.0; 4];
+        for (value, (s, d)) in result.iter_mut().zip(source.iter().zip(destination.iter())) {
+            *value = (s * source_fraction + d * destination_fraction).min(1.0);
+        }
+
+        self.unpremultiply(result)
+    }
+
+    /// Returns red, green, blue within working space multiplied by alpha, followed by alpha
+    fn premultiply(&self, rgba: RGBA) -> [f64; 4] {
+        let alpha = rgba.opacity();
+        let ( red, green, blue, _alpha ) = rgba.into();
+
+        let transfer = RgbSpace::srgb().transfer();
+        let working = |v: u8| -> f64 {
+            let mut v = v as f64 / 255.0;
+            if self.alpha_mode == AlphaMode::Premultiplied {
+                v = if alpha > 0.0 { (v / alpha).min(1.0) } else { 0.0 };
+            }
+            if self.space == Space::Linear {
+                v = transfer.decode(v);
+            }
+            v * alpha
+        };
+
+        [ working(red), working(green), working(blue), alpha ]
+    }
+
+    /// Returns `RGBA` from premultiplied working space values, converted to configured alpha mode
+    fn unpremultiply(&self, values: [f64; 4]) -> RGBA {
+        let alpha = values[3];
+
+        let transfer = RgbSpace::srgb().transfer();
+        let channel = |v: f64| -> u8 {
+            let mut v = if alpha > 0.0 { (v / alpha).min(1.0).max(0.0) } else { 0.0 };
+            if self.space == Space::Linear {
+                v = transfer.encode(v);
+            }
+            if self.alpha_mode == AlphaMode::Premultiplied {
+                v *= alpha;
+            }
+            (v * 255.0).round() as u8
+        };
+
+        RGBA::new(channel(values[0]), channel(values[1]), channel(values[2]), (alpha * 255.0).round() as u8)
+    }
+}
@@ -0,0 +1,44 @@
+#!/usr/bin/env rust
+
+
+/// Font weights, i.e. columns of `FONT_SIZES`
+pub(crate) const FONT_WEIGHTS: [u16; 9] = [ 100, 200, 300, 400, 500, 600, 700, 800, 900 ];
+
+
+/// Size in pixels for non-text elements only, e.g. dividers and disabled controls
+pub(crate) const NON_TEXT: f64 = 777.0;
+
+
+/// Size in pixels for contrast that is not usable
+pub(crate) const PROHIBITED: f64 = 999.0;
+
+
+/// Minimum font size in pixels by ascending absolute Lc, and font weight, from
+/// APCA-W3 font lookup tables
+pub(crate) const FONT_SIZES: [(f64, [f64; 9]); 25] = [
+    ( 0.0, [ 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0 ] ),
+    ( 10.0, [ 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0 ] ),
+    ( 15.0, [ 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0 ] ),
+    ( 20.0, [ 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0 ] ),
+    ( 25.0, [ 777.0, 777.0, 777.0, 120.0, 120.0, 108.0, 96.0, 96.0, 96.0 ] ),
+    ( 30.0, [ 777.0, 777.0, 120.0, 108.0, 108.0, 96.0, 72.0, 72.0, 72.0 ] ),
+    ( 35.0, [ 777.0, 120.0, 108.0, 96.0, 72.0, 60.0, 48.0, 48.0, 48.0 ] ),
+    ( 40.0, [ 120.0, 108.0, 96.0, 60.0, 48.0, 42.0, 32.0, 32.0, 32.0 ] ),
+    ( 45.0, [ 108.0, 96.0, 72.0, 42.0, 32.0, 28.0, 24.0, 24.0, 24.0 ] ),
+    ( 50.0, [ 96.0, 72.0, 60.0, 32.0, 28.0, 24.0, 21.0, 21.0, 21.0 ] ),
+    ( 55.0, [ 80.0, 60.0, 48.0, 28.0, 24.0, 21.0, 18.0, 18.0, 18.0 ] ),
+    ( 60.0, [ 72.0, 48.0, 42.0, 24.0, 21.0, 18.0, 16.0, 16.0, 18.0 ] ),
+    ( 65.0, [ 68.0, 46.0, 32.0, 21.75, 19.0, 17.0, 15.0, 16.0, 18.0 ] ),
+    ( 70.0, [ 64.0, 44.0, 28.0, 19.5, 18.0, 16.0, 14.5, 16.0, 18.0 ] ),
+    ( 75.0, [ 60.0, 42.0, 24.0, 18.0, 16.0, 15.0, 14.0, 16.0, 18.0 ] ),
+    ( 80.0, [ 56.0, 38.25, 23.0, 17.25, 15.81, 14.81, 14.0, 16.0, 18.0 ] ),
+    ( 85.0, [ 52.0, 34.5, 22.0, 16.5, 15.625, 14.625, 14.0, 16.0, 18.0 ] ),
+    ( 90.0, [ 48.0, 32.0, 21.0, 16.0, 15.5, 14.5, 14.0, 16.0, 18.0 ] ),
+    ( 95.0, [ 45.0, 28.0, 19.5, 15.5, 15.0, 14.0, 13.5, 16.0, 18.0 ] ),
+    ( 100.0, [ 42.0, 26.5, 18.5, 15.0, 14.5, 13.5, 13.0, 16.0, 18.0 ] ),
+    ( 105.0, [ 39.0, 25.0, 18.0, 14.5, 14.0, 13.0, 12.0, 16.0, 18.0 ] ),
+    ( 110.0, [ 36.0, 24.0, 18.0, 14.0, 13.0, 12.0, 11.0, 16.0, 18.0 ] ),
+    ( 115.0, [ 34.0, 22.5, 17.5, 13.5, 12.5, 11.5, 10.0, 16.0, 18.0 ] ),
+    ( 120.0, [ 32.0, 21.0, 17.0, 13.0, 12.0, 11.0, 9.0, 16.0, 18.0 ] ),
+    ( 125.0, [ 30.0, 20.0, 16.5, 12.5, 11.5, 10.5, 8.0, 16.0, 18.0 ] ),
+];
@@ -0,0 +1,433 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::contrast::font::{ FONT_SIZES, FONT_WEIGHTS, NON_TEXT, PROHIBITED };
+use crate::gamut;
+use crate::oklch::Oklch;
+use crate::rgb::RGB;
+use crate::rgb_space::RgbSpace;
+
+
+/// Minimum font sizes for APCA lightness contrast
+mod font;
+
+
+/// WCAG 2 conformance levels for contrast of text
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Level {
+    /// Success criterion 1.4.3, contrast (minimum)
+    AA,
+
+    /// Success criterion 1.4.6, contrast (enhanced)
+    AAA,
+}
+
+
+/// WCAG 2 text size categories
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextSize {
+    /// Text smaller than large text
+    #[default]
+    Normal,
+
+    /// At least 18 point, or 14 point bold, text
+    Large,
+}
+
+
+/// Pass/fail report of WCAG 2 contrast between two colors
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::contrast::{ Level, Report, TextSize };
+///
+/// let report = Report::between(Color::new_rgb(119, 119, 119), Color::new_rgb(255, 255, 255));
+///
+/// assert_eq!((report.ratio() * 100.0).round() / 100.0, 4.48);
+/// assert!(!report.passes(Level::AA, TextSize::Normal));
+/// assert!(report.passes(Level::AA, TextSize::Large));
+/// assert!(!report.passes(Level::AAA, TextSize::Large));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Report {
+    ratio: f64,
+}
+
+
+/// Which of text and background is lighter, APCA contrast is not symmetric between the two
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Polarity {
+    /// Dark text on light background, positive Lc
+    DarkOnLight,
+
+    /// Light text on dark background, negative Lc
+    LightOnDark,
+}
+
+
+/// Accessible Perceptual Contrast Algorithm lightness contrast, Lc, of text on background
+///
+/// **Note** implements APCA-W3 0.0.98G-4g constants, where Lc is about `-108.0` through `106.0`
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::contrast::{ Apca, Polarity };
+/// use color_operators::hsl::HSL;
+///
+/// let grey = Color::new_rgb(136, 136, 136);
+/// let white = HSL::new(0.0, 0.0, 1.0);
+///
+/// let dark_on_light = Apca::between(grey.clone(), white.clone());
+/// let light_on_dark = Apca::between(white, grey);
+///
+/// assert_eq!((dark_on_light.lc() * 1000.0).round() / 1000.0, 63.056);
+/// assert_eq!((light_on_dark.lc() * 1000.0).round() / 1000.0, -68.541);
+/// assert_eq!(light_on_dark.polarity(), Polarity::LightOnDark);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Apca {
+    lc: f64,
+}
+
+
+/// Minimum contrast a foreground color must reach against a background color
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::contrast::Target;
+///
+/// let foreground = Color::new_rgb(120, 150, 220);
+/// let background = Color::new_rgb(255, 255, 255);
+///
+/// assert!(!Target::Wcag(4.5).is_met(foreground.clone(), background.clone()));
+///
+/// let adjusted = Target::Wcag(4.5).adjust(foreground, background.clone()).unwrap();
+///
+/// assert!(Target::Wcag(4.5).is_met(adjusted, background));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Target {
+    /// WCAG 2 contrast ratio, e.g. `4.5` for `Level::AA` normal text
+    Wcag(f64),
+
+    /// Absolute APCA lightness contrast, e.g. `60.0` for content text, regardless of polarity
+    Apca(f64),
+}
+
+
+impl Level {
+    /// Returns minimum contrast ratio required for text size
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::contrast::{ Level, TextSize };
+    ///
+    /// assert_eq!(Level::AA.minimum_ratio(TextSize::Normal), 4.5);
+    /// assert_eq!(Level::AAA.minimum_ratio(TextSize::Large), 4.5);
+    /// ```
+    pub fn minimum_ratio(&self, size: TextSize) -> f64 {
+        match ( self, size ) {
+            ( Self::AA, TextSize::Normal ) => 4.5,
+            ( Self::AA, TextSize::Large ) => 3.0,
+            ( Self::AAA, TextSize::Normal ) => 7.0,
+            ( Self::AAA, TextSize::Large ) => 4.5,
+        }
+    }
+}
+
+
+impl Report {
+    /// Returns report for contrast ratio, e.g. from `Color::contrast_ratio`
+    pub fn new(ratio: f64) -> Self {
+        Self { ratio }
+    }
+
+    /// Returns report for text color on background color
+    ///
+    /// **Note** WCAG 2 contrast is symmetric, and alpha is ignored
+    pub fn between<F, B>(foreground: F, background: B) -> Self
+    where
+        F: Into<Color>,
+        B: Into<Color>
+    {
+        Self::new(foreground.into().contrast_ratio(&background.into()))
+    }
+
+    /// Returns contrast ratio between `1.0` and `21.0`
+    pub fn ratio(&self) -> f64 {
+        self.ratio
+    }
+
+    /// Returns `true` if contrast ratio meets level for text size
+    pub fn passes(&self, level: Level, size: TextSize) -> bool {
+        self.ratio >= level.minimum_ratio(size)
+    }
+}
+
+
+impl Apca {
+    /// Returns lightness contrast of text color on background color
+    ///
+    /// **Note** alpha is ignored
+    pub fn between<T, B>(text: T, background: B) -> Self
+    where
+        T: Into<Color>,
+        B: Into<Color>
+    {
+        let text = screen_luminance(RGB::from(text.into()));
+        let background = screen_luminance(RGB::from(background.into()));
+
+        if (background - text).abs() < 0.0005 {
+            return Self { lc: 0.0 };
+        }
+
+        let lc = if background > text {
+            let contrast = (background.powf(0.56) - text.powf(0.57)) * 1.14;
+            if contrast < 0.1 { 0.0 } else { contrast - 0.027 }
+        } else {
+            let contrast = (background.powf(0.65) - text.powf(0.62)) * 1.14;
+            if contrast > -0.1 { 0.0 } else { contrast + 0.027 }
+        };
+
+        Self { lc: lc * 100.0 }
+    }
+
+    /// Returns signed lightness contrast, positive for dark text on light background
+    pub fn lc(&self) -> f64 {
+        self.lc
+    }
+
+    /// Returns whether text is darker or lighter than background
+    pub fn polarity(&self) -> Polarity {
+        if self.lc < 0.0 {
+            return Polarity::LightOnDark;
+        }
+        Polarity::DarkOnLight
+    }
+
+    /// Returns minimum font size in pixels for font weight, or `None` if contrast is too low for
+    /// text of that weight
+    ///
+    /// **Note** weights are rounded down to nearest hundred, and Lc is rounded down to nearest
+    /// row of lookup table
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::contrast::Apca;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let apca = Apca::between(RGB::new(136, 136, 136), RGB::new(255, 255, 255));
+    ///
+    /// assert_eq!(apca.minimum_font_size(400), Some(24.0));
+    /// assert_eq!(apca.minimum_font_size(700), Some(16.0));
+    /// assert_eq!(apca.minimum_font_size(100), Some(72.0));
+    /// ```
+    pub fn minimum_font_size(&self, weight: u16) -> Option<f64> {
+        let column = FONT_WEIGHTS.iter().rposition(|w| *w <= weight).unwrap_or(0);
+        let size = self.sizes()[column];
+        if size >= NON_TEXT {
+            return None;
+        }
+        Some(size)
+    }
+
+    /// Returns lightest font weight usable at font size in pixels, or `None` if no weight is
+    /// sufficient
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::contrast::Apca;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let apca = Apca::between(RGB::new(136, 136, 136), RGB::new(255, 255, 255));
+    ///
+    /// assert_eq!(apca.minimum_weight(16.0), Some(700));
+    /// assert_eq!(apca.minimum_weight(12.0), None);
+    /// ```
+    pub fn minimum_weight(&self, size: f64) -> Option<u16> {
+        FONT_WEIGHTS.iter().zip(self.sizes().iter()).find(|( _weight, minimum )| {
+            **minimum < NON_TEXT && **minimum <= size
+        }).map(|( weight, _minimum )| *weight)
+    }
+
+    /// Returns `true` if contrast is sufficient for non-text elements, e.g. borders and icons
+    pub fn is_non_text_visible(&self) -> bool {
+        self.sizes()[0] < PROHIBITED
+    }
+
+    /// Returns row of minimum font sizes for absolute Lc
+    fn sizes(&self) -> [f64; 9] {
+        let lc = self.lc.abs();
+        let index = FONT_SIZES.iter().rposition(|( minimum, _sizes )| *minimum <= lc).unwrap_or(0);
+        FONT_SIZES[index].1
+    }
+}
+
+
+impl Target {
+    /// Returns contrast of foreground on background measured as target is, i.e. WCAG 2 ratio or
+    /// absolute APCA Lc
+    pub fn contrast<F, B>(&self, foreground: F, background: B) -> f64
+    where
+        F: Into<Color>,
+        B: Into<Color>
+    {
+        match self {
+            Self::Wcag(_) => foreground.into().contrast_ratio(&background.into()),
+            Self::Apca(_) => Apca::between(foreground, background).lc().abs(),
+        }
+    }
+
+    /// Returns `true` if contrast of foreground on background reaches target
+    pub fn is_met<F, B>(&self, foreground: F, background: B) -> bool
+    where
+        F: Into<Color>,
+        B: Into<Color>
+    {
+        let minimum = match self {
+            Self::Wcag(minimum) | Self::Apca(minimum) => *minimum,
+        };
+        self.contrast(foreground, background) >= minimum
+    }
+
+    /// Returns foreground with Oklch lightness changed as little as possible to reach target, while
+    /// keeping chroma and hue, or `None` if neither darkening nor lightening is sufficient
+    ///
+    /// **Note** foreground already reaching target is returned unchanged, otherwise result is
+    /// `Color::Oklch` mapped within sRGB by `gamut::Method::Css` before contrast is measured, so
+    /// very high chroma may be reduced
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::contrast::Target;
+    /// use color_operators::rgb_space::RgbSpace;
+    ///
+    /// let foreground = Color::new_oklch(0.6, 0.15, 250.0);
+    /// let background = Color::new_rgb(0, 0, 0);
+    ///
+    /// let adjusted = Target::Apca(75.0).adjust(foreground, background).unwrap();
+    ///
+    /// assert!(adjusted.is_oklch());
+    /// assert!(adjusted.in_gamut(&RgbSpace::srgb()));
+    ///
+    /// let impossible = Target::Wcag(21.0).adjust(Color::new_rgb(255, 0, 0), Color::new_rgb(128, 128, 128));
+    ///
+    /// assert_eq!(impossible, None);
+    /// ```
+    pub fn adjust<F, B>(&self, foreground: F, background: B) -> Option<Color>
+    where
+        F: Into<Color>,
+        B: Into<Color>
+    {
+        let foreground: Color = foreground.into();
+        let background: Color = background.into();
+        if self.is_met(foreground.clone(), background.clone()) {
+            return Some(foreground);
+        }
+
+        let ( lightness, chroma, hue ) = Oklch::from(foreground).into();
+        let srgb = RgbSpace::srgb();
+        let candidate = |l: f64| -> Color {
+            let color = Color::Oklch(Oklch::new(l, chroma, hue));
+            if color.in_gamut(&srgb) {
+                return color;
+            }
+            color.to_gamut(&srgb, gamut::Method::Css)
+        };
+        let meets = |l: f64| -> bool {
+            self.is_met(candidate(l), background.clone())
+        };
+
+        let darker = bisect(lightness, 0.0, &meets);
+        let lighter = bisect(lightness, 1.0, &meets);
+
+        let closest = match ( darker, lighter ) {
+            ( Some(d), Some(l) ) => if lightness - d <= l - lightness { d } else { l },
+            ( Some(d), None ) => d,
+            ( None, Some(l) ) => l,
+            ( None, None ) => return None,
+        };
+        Some(candidate(closest))
+    }
+
+    /// Returns black or white, whichever has greater contrast on background
+    ///
+    /// **Note** only the kind of target, WCAG 2 or APCA, is used, and the two may disagree for mid
+    /// tone backgrounds such as orange
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::contrast::Target;
+    ///
+    /// let background = Color::new_rgb(255, 120, 0);
+    ///
+    /// assert_eq!(Target::Wcag(4.5).black_or_white(background.clone()), Color::new_rgb(0, 0, 0));
+    /// assert_eq!(Target::Apca(60.0).black_or_white(background), Color::new_rgb(255, 255, 255));
+    /// ```
+    pub fn black_or_white<B>(&self, background: B) -> Color
+    where
+        B: Into<Color>
+    {
+        let background: Color = background.into();
+        let black = Color::new_rgb(0, 0, 0);
+        let white = Color::new_rgb(255, 255, 255);
+        if self.contrast(black.clone(), background.clone()) >= self.contrast(white.clone(), background) {
+            return black;
+        }
+        white
+    }
+}
+
+
+/// Returns lightness closest to start, towards end, that meets target, found by bisection
+///
+/// **Note** contrast only grows when moving away from start once past background, so meeting
+/// target is monotonic along the searched range
+fn bisect<F>(start: f64, end: f64, meets: &F) -> Option<f64>
+where
+    F: Fn(f64) -> bool
+{
+    if !meets(end) {
+        return None;
+    }
+
+    let ( mut failing, mut passing ) = ( start, end );
+    while (passing - failing).abs() > 0.0001 {
+        let middle = (failing + passing) / 2.0;
+        if meets(middle) {
+            passing = middle;
+        } else {
+            failing = middle;
+        }
+    }
+    Some(passing)
+}
+
+
+/// Returns APCA estimated screen luminance, with soft clamp of near black
+fn screen_luminance(rgb: RGB) -> f64 {
+    let ( red, green, blue ) = rgb.into();
+    let linear = |v: u8| -> f64 {
+        (v as f64 / 255.0).powf(2.4)
+    };
+
+    let luminance = 0.2126729 * linear(red) + 0.7151522 * linear(green) + 0.0721750 * linear(blue);
+    if luminance < 0.022 {
+        return luminance + (0.022 - luminance).powf(1.414);
+    }
+    luminance
+}
@@ -0,0 +1,172 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::error::Error;
+
+
+/// Named colors defined by CSS Color 4
+pub(crate) mod named;
+
+/// Parses CSS color syntax into `Color`
+mod parse;
+
+/// Formats color components as CSS color syntax
+pub(crate) mod serialize;
+
+
+/// Argument separators of CSS color functions
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Syntax {
+    /// Space separated components with `/` before alpha, e.g. `rgb(255 0 0 / 0.5)`
+    #[default]
+    Modern,
+
+    /// Comma separated values with `a` suffixed function for alpha, e.g. `rgba(255, 0, 0, 0.5)`
+    Legacy,
+}
+
+
+/// Form of CSS color output
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Notation {
+    /// Color function, e.g. `rgb()` or `hsl()`
+    #[default]
+    Functional,
+
+    /// Hexadecimal with leading `#`, e.g. `#FF0000`
+    Hex,
+}
+
+
+/// How functional notation writes components that may be percentages
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Units {
+    /// Plain numbers, e.g. `rgb(255 128 0 / 0.5)`
+    #[default]
+    Number,
+
+    /// Percentages, e.g. `rgb(100% 50.2% 0% / 50%)`
+    Percent,
+}
+
+
+/// Settings for serializing colors as CSS
+///
+/// **Note** legacy syntax requires percentages for saturation and lightness, so `Units` only
+/// affects `hsl()` output with modern syntax
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::css::{ Notation, Options, Syntax, Units };
+/// use color_operators::rgb::RGB;
+///
+/// let rgb = RGB::new(255, 128, 0);
+///
+/// assert_eq!(rgb.to_css_string(Options::new()), "rgb(255 128 0)");
+/// assert_eq!(rgb.to_css_string(Options::new().syntax(Syntax::Legacy)), "rgb(255, 128, 0)");
+/// assert_eq!(rgb.to_css_string(Options::new().notation(Notation::Hex)), "#FF8000");
+/// assert_eq!(rgb.to_css_string(Options::new().units(Units::Percent).precision(1)), "rgb(100% 50.2% 0%)");
+/// assert_eq!(RGB::new(255, 165, 0).to_css_string(Options::new().named(true)), "orange");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Options {
+    syntax: Syntax,
+    notation: Notation,
+    units: Units,
+    precision: usize,
+    named: bool,
+}
+
+
+/// Parses any CSS Color 4 color syntax into `Color`
+///
+/// Supported syntax is `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`, `rgb()`, `rgba()`, `hsl()`,
+/// `hsla()`, `hwb()`, `lab()`, `lch()`, `oklab()`, `oklch()`, `color(srgb ...)`, the 148 named
+/// colors, and `transparent`. Functions accept legacy comma separated or modern space separated
+/// arguments, percentages, angle units for hues, and `none` for missing components.
+///
+/// Results keep the data structure of the syntax, e.g. `hsl()` returns `Color::HSL`, and alpha
+/// returns `Color::RGBA` for `rgb()`, or `Color::HSLA` for `hsl()`
+///
+/// **Note** `hwb()`, `lab()`, `lch()`, `oklab()`, and `oklch()` have no data structure with alpha,
+/// so translucent alpha converts them to `Color::RGBA` where fully opaque alpha keeps their space
+///
+/// **Note** CSS defines `lab()` and `lch()` relative to `WhitePoint::D50`, so their values are
+/// adapted by `Method::Bradford` to the `WhitePoint::D65` of `Lab` and `LCh`
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::css;
+/// use color_operators::error::Error;
+/// use color_operators::rgb::RGB;
+///
+/// assert_eq!(css::parse("#F80"), Ok(Color::new_rgb(255, 136, 0)));
+/// assert_eq!(css::parse("rgb(100% 0% 50% / 50%)"), Ok(Color::new_rgba(255, 0, 128, 128)));
+/// assert_eq!(css::parse("hsl(0.5turn, 100%, 50%)"), Ok(Color::new_hsl(180.0, 1.0, 0.5)));
+/// assert_eq!(css::parse("RebeccaPurple"), Ok(Color::new_rgb(102, 51, 153)));
+/// assert_eq!(css::parse("oklab(0.5 0.1 -0.1 / 1)"), Ok(Color::new_oklab(0.5, 0.1, -0.1)));
+/// assert_eq!(css::parse("lab(54.29 80.8 69.89)").map(RGB::from), Ok(RGB::new(255, 0, 0)));
+/// assert_eq!(css::parse("hwb(0 0% 0% / 0.5)"), Ok(Color::new_rgba(255, 0, 0, 128)));
+///
+/// assert_eq!(css::parse("rgb(255 0 nope)"), Err(Error::Parse {
+///     position: 10,
+///     message: "expected number, percentage, or `none`".to_string(),
+/// }));
+/// ```
+pub fn parse(input: &str) -> Result<Color, Error> {
+    parse::Parser::new(input).parse()
+}
+
+
+/// Defaults to modern functional syntax with numbers, two decimal places, and no named colors
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            syntax: Syntax::default(),
+            notation: Notation::default(),
+            units: Units::default(),
+            precision: 2,
+            named: false,
+        }
+    }
+}
+
+
+impl Options {
+    /// Returns default settings, see `Options::default`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns settings with given argument separators
+    pub fn syntax(self, syntax: Syntax) -> Self {
+        Self { syntax, ..self }
+    }
+
+    /// Returns settings with given form of output
+    pub fn notation(self, notation: Notation) -> Self {
+        Self { notation, ..self }
+    }
+
+    /// Returns settings with given units for components that may be percentages
+    pub fn units(self, units: Units) -> Self {
+        Self { units, ..self }
+    }
+
+    /// Returns settings with given maximum number of decimal places, trailing zeros are dropped
+    ///
+    /// **Note** alpha keeps at least three decimal places, or one for percentages, and numbers of
+    /// `oklab()` and `oklch()` other than hue keep two more
+    pub fn precision(self, precision: usize) -> Self {
+        Self { precision, ..self }
+    }
+
+    /// Returns settings that prefer named colors, e.g. `red`, when one matches exactly
+    pub fn named(self, named: bool) -> Self {
+        Self { named, ..self }
+    }
+}
@@ -0,0 +1,379 @@
+#!/usr/bin/env rust
+
+
+use crate::adaptation::Method;
+use crate::color::Color;
+use crate::css::named;
+use crate::error::Error;
+use crate::hsl::HSL;
+use crate::hsla::HSLA;
+use crate::hwb::HWB;
+use crate::lab::Lab;
+use crate::lch::LCh;
+use crate::oklab::Oklab;
+use crate::oklch::Oklch;
+use crate::rgb::RGB;
+use crate::rgba::RGBA;
+use crate::white_point::WhitePoint;
+
+
+/// Single color component as written within CSS color function
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Component {
+    /// Plain number, e.g. `42` or `0.5`
+    Number(f64),
+
+    /// Number followed by `%`
+    Percentage(f64),
+
+    /// Number followed by angle unit, converted to degrees
+    Angle(f64),
+
+    /// The `none` keyword, i.e. missing component
+    None,
+}
+
+
+/// Component paired with byte offset it was parsed from
+type Positioned = (Component, usize);
+
+
+/// Color function arguments, with optional alpha
+struct Arguments {
+    values: [Positioned; 3],
+    alpha: Option<Positioned>,
+}
+
+
+/// Cursor over CSS color string
+pub(crate) struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+
+impl<'a> Parser<'a> {
+    /// Returns parser positioned at start of input
+    pub(crate) fn new(input: &'a str) -> Self {
+        Self { input, position: 0 }
+    }
+
+    /// Parses whole input as single color, surrounding whitespace is ignored
+    pub(crate) fn parse(mut self) -> Result<Color, Error> {
+        self.skip_whitespace();
+        let start = self.position;
+
+        let color = if self.eat('#') {
+            self.hex(start)?
+        } else {
+            let name = self.identifier();
+            if name.is_empty() {
+                return Err(self.error("expected `#`, color name or color function"));
+            }
+            if self.eat('(') {
+                self.function(&name.to_ascii_lowercase(), start)?
+            } else {
+                keyword(name, start)?
+            }
+        };
+
+        self.skip_whitespace();
+        if self.position < self.input.len() {
+            return Err(self.error("unexpected input after color"));
+        }
+        Ok(color)
+    }
+
+    /// Returns `Error::Parse` at current position
+    fn error(&self, message: &str) -> Error {
+        Error::Parse { position: self.position, message: message.to_string() }
+    }
+
+    /// Returns next character without consuming it
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    /// Consumes next character if it matches expected
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.position += expected.len_utf8();
+            return true;
+        }
+        false
+    }
+
+    /// Consumes characters while predicate holds, returning consumed slice
+    fn take_while<F>(&mut self, predicate: F) -> &'a str
+    where
+        F: Fn(char) -> bool
+    {
+        let input: &'a str = self.input;
+        let start = self.position;
+        while let Some(c) = self.peek() {
+            if !predicate(c) {
+                break;
+            }
+            self.position += c.len_utf8();
+        }
+        &input[start..self.position]
+    }
+
+    /// Consumes optional `+` or `-`
+    fn sign(&mut self) {
+        if !self.eat('+') {
+            self.eat('-');
+        }
+    }
+
+    /// Consumes any whitespace
+    fn skip_whitespace(&mut self) {
+        self.take_while(|c| c.is_ascii_whitespace());
+    }
+
+    /// Consumes function name, color name, keyword, or unit
+    fn identifier(&mut self) -> &'a str {
+        self.take_while(|c| c.is_ascii_alphanumeric() || c == '-')
+    }
+
+    /// Parses `#rgb`, `#rgba`, `#rrggbb`, or `#rrggbbaa` where leading `#` is already consumed
+    fn hex(&mut self, start: usize) -> Result<Color, Error> {
+        let digits = self.take_while(|c| c.is_ascii_hexdigit());
+        let expanded = match digits.len() {
+            3 | 4 => digits.chars().flat_map(|c| vec![c, c]).collect::<String>(),
+            6 | 8 => digits.to_string(),
+            _ => {
+                let message = "expected 3, 4, 6, or 8 hexadecimal digits".to_string();
+                return Err(Error::Parse { position: start, message });
+            },
+        };
+
+        let values = hex::decode(expanded)?;
+        if values.len() == 4 {
+            return Ok(Color::RGBA(RGBA::new(values[0], values[1], values[2], values[3])));
+        }
+        Ok(Color::RGB(RGB::new(values[0], values[1], values[2])))
+    }
+
+    /// Parses color function where name and opening parenthesis are already consumed
+    fn function(&mut self, name: &str, start: usize) -> Result<Color, Error> {
+        match name {
+            "rgb" | "rgba" => {
+                let Arguments { values: [ red, green, blue ], alpha } = self.arguments()?;
+                let rgb = RGB::new(
+                    channel(number(red, 255.0)?),
+                    channel(number(green, 255.0)?),
+                    channel(number(blue, 255.0)?),
+                );
+                with_alpha(Color::RGB(rgb), alpha)
+            },
+            "hsl" | "hsla" => {
+                let Arguments { values: [ h, s, l ], alpha } = self.arguments()?;
+                let hsl = HSL::new(hue(h)?, number(s, 100.0)? / 100.0, number(l, 100.0)? / 100.0);
+                match alpha {
+                    Some(alpha) => Ok(Color::HSLA(HSLA::from_hsl(hsl, opacity(alpha)?))),
+                    None => Ok(Color::HSL(hsl)),
+                }
+            },
+            "hwb" => {
+                let Arguments { values: [ h, w, b ], alpha } = self.arguments()?;
+                let hwb = HWB::new(hue(h)?, number(w, 100.0)? / 100.0, number(b, 100.0)? / 100.0);
+                with_alpha(Color::HWB(hwb), alpha)
+            },
+            "lab" => {
+                let Arguments { values: [ l, a, b ], alpha } = self.arguments()?;
+                let lab = Lab::new(number(l, 100.0)?, number(a, 125.0)?, number(b, 125.0)?);
+                let lab = lab.adapt(WhitePoint::D50, WhitePoint::D65, Method::Bradford);
+                with_alpha(Color::Lab(lab), alpha)
+            },
+            "lch" => {
+                let Arguments { values: [ l, c, h ], alpha } = self.arguments()?;
+                let lab = Lab::from(LCh::new(number(l, 100.0)?, number(c, 150.0)?, hue(h)?));
+                let lch = LCh::from(lab.adapt(WhitePoint::D50, WhitePoint::D65, Method::Bradford));
+                with_alpha(Color::LCh(lch), alpha)
+            },
+            "oklab" => {
+                let Arguments { values: [ l, a, b ], alpha } = self.arguments()?;
+                let oklab = Oklab::new(number(l, 1.0)?, number(a, 0.4)?, number(b, 0.4)?);
+                with_alpha(Color::Oklab(oklab), alpha)
+            },
+            "oklch" => {
+                let Arguments { values: [ l, c, h ], alpha } = self.arguments()?;
+                let oklch = Oklch::new(number(l, 1.0)?, number(c, 0.4)?, hue(h)?);
+                with_alpha(Color::Oklch(oklch), alpha)
+            },
+            "color" => {
+                self.skip_whitespace();
+                let space_start = self.position;
+                let space = self.identifier();
+                if !space.eq_ignore_ascii_case("srgb") {
+                    let message = format!("unsupported color space `{}`, expected `srgb`", space);
+                    return Err(Error::Parse { position: space_start, message });
+                }
+                let Arguments { values: [ red, green, blue ], alpha } = self.arguments()?;
+                let rgb = RGB::new(
+                    channel(number(red, 1.0)? * 255.0),
+                    channel(number(green, 1.0)? * 255.0),
+                    channel(number(blue, 1.0)? * 255.0),
+                );
+                with_alpha(Color::RGB(rgb), alpha)
+            },
+            _ => Err(Error::Parse { position: start, message: format!("unknown color function `{}`", name) }),
+        }
+    }
+
+    /// Parses three components and optional alpha, followed by closing parenthesis
+    ///
+    /// **Note** legacy syntax separates every value by `,` where modern syntax separates components
+    /// by whitespace and alpha by `/`
+    fn arguments(&mut self) -> Result<Arguments, Error> {
+        self.skip_whitespace();
+        let first = self.component()?;
+        self.skip_whitespace();
+        let legacy = self.peek() == Some(',');
+
+        let mut rest = [first; 2];
+        for value in rest.iter_mut() {
+            self.skip_whitespace();
+            if legacy && !self.eat(',') {
+                return Err(self.error("expected `,`"));
+            }
+            self.skip_whitespace();
+            *value = self.component()?;
+        }
+
+        self.skip_whitespace();
+        let mut alpha = None;
+        if self.eat(if legacy { ',' } else { '/' }) {
+            self.skip_whitespace();
+            alpha = Some(self.component()?);
+            self.skip_whitespace();
+        }
+
+        if !self.eat(')') {
+            return Err(self.error(if legacy { "expected `,` or `)`" } else { "expected `/` or `)`" }));
+        }
+        Ok(Arguments { values: [ first, rest[0], rest[1] ], alpha })
+    }
+
+    /// Parses number, percentage, angle, or `none`
+    fn component(&mut self) -> Result<Positioned, Error> {
+        let start = self.position;
+
+        let word = self.identifier();
+        if word.eq_ignore_ascii_case("none") {
+            return Ok((Component::None, start));
+        }
+        self.position = start;
+
+        self.sign();
+        let mut digits = self.take_while(|c| c.is_ascii_digit()).len();
+        if self.eat('.') {
+            digits += self.take_while(|c| c.is_ascii_digit()).len();
+        }
+        if digits == 0 {
+            self.position = start;
+            return Err(self.error("expected number, percentage, or `none`"));
+        }
+
+        let mantissa_end = self.position;
+        if self.eat('e') || self.eat('E') {
+            self.sign();
+            if self.take_while(|c| c.is_ascii_digit()).is_empty() {
+                self.position = mantissa_end;
+            }
+        }
+
+        let value: f64 = match self.input[start..self.position].parse() {
+            Ok(value) => value,
+            Err(_) => return Err(Error::Parse { position: start, message: "invalid number".to_string() }),
+        };
+
+        if self.eat('%') {
+            return Ok((Component::Percentage(value), start));
+        }
+
+        let unit_start = self.position;
+        let unit = self.identifier().to_ascii_lowercase();
+        let degrees = match unit.as_str() {
+            "" => return Ok((Component::Number(value), start)),
+            "deg" => value,
+            "grad" => value * 0.9,
+            "rad" => value.to_degrees(),
+            "turn" => value * 360.0,
+            _ => {
+                let message = format!("unknown unit `{}`", unit);
+                return Err(Error::Parse { position: unit_start, message });
+            },
+        };
+        Ok((Component::Angle(degrees), start))
+    }
+}
+
+
+/// Returns named color, or transparent black for `transparent`
+fn keyword(name: &str, start: usize) -> Result<Color, Error> {
+    if name.eq_ignore_ascii_case("transparent") {
+        return Ok(Color::RGBA(RGBA::new(0, 0, 0, 0)));
+    }
+    match named::lookup(name) {
+        Some(rgb) => Ok(Color::RGB(rgb)),
+        None => Err(Error::Parse { position: start, message: format!("unknown color name `{}`", name) }),
+    }
+}
+
+
+/// Returns number where percentage is scaled such that `100%` equals reference, and `none` is zero
+fn number(component: Positioned, reference: f64) -> Result<f64, Error> {
+    match component {
+        ( Component::Number(value), _ ) => Ok(value),
+        ( Component::Percentage(value), _ ) => Ok(value / 100.0 * reference),
+        ( Component::None, _ ) => Ok(0.0),
+        ( Component::Angle(_), position ) => {
+            Err(Error::Parse { position, message: "expected number or percentage".to_string() })
+        },
+    }
+}
+
+
+/// Returns hue in degrees wrapped between `0.0` and `360.0`
+fn hue(component: Positioned) -> Result<f64, Error> {
+    match component {
+        ( Component::Number(value), _ ) | ( Component::Angle(value), _ ) => Ok(value.rem_euclid(360.0)),
+        ( Component::None, _ ) => Ok(0.0),
+        ( Component::Percentage(_), position ) => {
+            Err(Error::Parse { position, message: "expected number or angle".to_string() })
+        },
+    }
+}
+
+
+/// Returns alpha between `0.0` and `1.0`
+fn opacity(component: Positioned) -> Result<f64, Error> {
+    Ok(number(component, 1.0)?.min(1.0).max(0.0))
+}
+
+
+/// Returns rounded and clamped unsigned 8-bit integer
+fn channel(value: f64) -> u8 {
+    value.round().min(255.0).max(0.0) as u8
+}
+
+
+/// Returns color unchanged without alpha or when fully opaque, `Color::RGB` with alpha becomes
+/// `Color::RGBA`
+///
+/// **Note** data structures without alpha variant are converted to `Color::RGBA` for translucent
+/// alpha
+fn with_alpha(color: Color, alpha: Option<Positioned>) -> Result<Color, Error> {
+    let alpha = match alpha {
+        Some(alpha) => opacity(alpha)?,
+        None => return Ok(color),
+    };
+
+    match color {
+        Color::RGB(rgb) => Ok(Color::RGBA(RGBA::from_rgb(rgb, channel(alpha * 255.0)))),
+        color if alpha >= 1.0 => Ok(color),
+        color => Ok(Color::RGBA(RGBA::from_rgb(RGB::from(color), channel(alpha * 255.0)))),
+    }
+}
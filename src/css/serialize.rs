@@ -0,0 +1,164 @@
+#!/usr/bin/env rust
+
+
+use crate::css::named;
+use crate::css::{ Notation, Options, Syntax, Units };
+
+
+/// Returns value rounded to configured precision, without trailing zeros
+pub(crate) fn number(value: f64, options: Options) -> String {
+    let formatted = format!("{:.*}", options.precision, value);
+    let trimmed = if formatted.contains('.') {
+        formatted.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        formatted.as_str()
+    };
+    match trimmed {
+        "-0" => "0".to_string(),
+        _ => trimmed.to_string(),
+    }
+}
+
+
+/// Returns percentage, from value where `1.0` is `100%`
+pub(crate) fn percent(value: f64, options: Options) -> String {
+    format!("{}%", number(value * 100.0, options))
+}
+
+
+/// Returns alpha between `0.0` and `1.0` as number or percentage
+///
+/// **Note** at least three decimal places, or one for percentages, are kept so that unsigned 8-bit
+/// alpha survives parsing back
+fn alpha(value: f64, options: Options) -> String {
+    match options.units {
+        Units::Number => number(value, options.precision(options.precision.max(3))),
+        Units::Percent => percent(value, options.precision(options.precision.max(1))),
+    }
+}
+
+
+/// Returns color function with arguments separated per configured syntax
+fn function(name: &str, components: [String; 3], opacity: Option<f64>, options: Options) -> String {
+    let [ first, second, third ] = components;
+    match ( options.syntax, opacity ) {
+        ( Syntax::Legacy, Some(v) ) => {
+            format!("{}a({}, {}, {}, {})", name, first, second, third, alpha(v, options))
+        },
+        ( Syntax::Legacy, None ) => format!("{}({}, {}, {})", name, first, second, third),
+        ( Syntax::Modern, Some(v) ) => {
+            format!("{}({} {} {} / {})", name, first, second, third, alpha(v, options))
+        },
+        ( Syntax::Modern, None ) => format!("{}({} {} {})", name, first, second, third),
+    }
+}
+
+
+/// Returns named or hexadecimal notation when configured, where opacity is between `0.0` and `1.0`
+pub(crate) fn keyword_or_hex(red: u8, green: u8, blue: u8, opacity: Option<f64>, options: Options) -> Option<String> {
+    if options.named {
+        if opacity == Some(0.0) && ( red, green, blue ) == ( 0, 0, 0 ) {
+            return Some("transparent".to_string());
+        }
+        if opacity.unwrap_or(1.0) >= 1.0 {
+            if let Some(name) = named::name_of(red, green, blue) {
+                return Some(name.to_string());
+            }
+        }
+    }
+
+    if options.notation == Notation::Hex {
+        let mut hex = format!("#{:02X}{:02X}{:02X}", red, green, blue);
+        if let Some(v) = opacity {
+            hex.push_str(&format!("{:02X}", (v * 255.0).round() as u8));
+        }
+        return Some(hex);
+    }
+
+    None
+}
+
+
+/// Returns named, hexadecimal, or `rgb()` notation, where opacity is between `0.0` and `1.0`
+pub(crate) fn rgb(red: u8, green: u8, blue: u8, opacity: Option<f64>, options: Options) -> String {
+    if let Some(css) = keyword_or_hex(red, green, blue, opacity, options) {
+        return css;
+    }
+
+    let channel = |v: u8| -> String {
+        match options.units {
+            Units::Number => v.to_string(),
+            Units::Percent => percent(v as f64 / 255.0, options),
+        }
+    };
+    function("rgb", [ channel(red), channel(green), channel(blue) ], opacity, options)
+}
+
+
+/// Returns `hsl()` notation, where saturation, lightness, and opacity are between `0.0` and `1.0`
+pub(crate) fn hsl(hue: f64, saturation: f64, lightness: f64, opacity: Option<f64>, options: Options) -> String {
+    let fraction = |v: f64| -> String {
+        match ( options.syntax, options.units ) {
+            ( Syntax::Modern, Units::Number ) => number(v * 100.0, options),
+            _ => percent(v, options),
+        }
+    };
+    function("hsl", [ number(hue, options), fraction(saturation), fraction(lightness) ], opacity, options)
+}
+
+
+/// Returns `hwb()` notation for modern syntax, or `hsl()` for legacy syntax, where saturation
+/// and value are between `0.0` and `1.0`
+pub(crate) fn hsv(hue: f64, saturation: f64, value: f64, options: Options) -> String {
+    if options.syntax == Syntax::Legacy {
+        let lightness = value * (1.0 - saturation / 2.0);
+        let divisor = lightness.min(1.0 - lightness);
+        let saturation = if divisor > 0.0 { (value - lightness) / divisor } else { 0.0 };
+        return hsl(hue, saturation, lightness, None, options);
+    }
+
+    let fraction = |v: f64| -> String {
+        match options.units {
+            Units::Number => number(v * 100.0, options),
+            Units::Percent => percent(v, options),
+        }
+    };
+    let whiteness = (1.0 - saturation) * value;
+    let blackness = 1.0 - value;
+    function("hwb", [ number(hue, options), fraction(whiteness), fraction(blackness) ], None, options)
+}
+
+
+/// Returns named, hexadecimal, `lab()`, `lch()`, `oklab()`, or `oklch()` notation, where
+/// references are values written as `100%` and are `None` for hue
+///
+/// **Note** these functions have no legacy syntax, so legacy syntax is written as `rgb()`, and
+/// numbers with reference of at most `1.0`, i.e. of `oklab()` and `oklch()`, keep two more decimal
+/// places so they are as precise as percentages
+pub(crate) fn lab(name: &str, components: [f64; 3], references: [Option<f64>; 3], rgb: (u8, u8, u8), options: Options) -> String {
+    let ( red, green, blue ) = rgb;
+    if options.syntax == Syntax::Legacy {
+        return self::rgb(red, green, blue, None, options);
+    }
+    if let Some(css) = keyword_or_hex(red, green, blue, None, options) {
+        return css;
+    }
+
+    let component = |v: f64, reference: Option<f64>| -> String {
+        match ( options.units, reference ) {
+            ( Units::Percent, Some(reference) ) => percent(v / reference, options),
+            ( Units::Number, Some(reference) ) if reference <= 1.0 => {
+                number(v, options.precision(options.precision + 2))
+            },
+            _ => number(v, options),
+        }
+    };
+    let [ first, second, third ] = components;
+    let [ first_reference, second_reference, third_reference ] = references;
+    let components = [
+        component(first, first_reference),
+        component(second, second_reference),
+        component(third, third_reference),
+    ];
+    function(name, components, None, options)
+}
@@ -0,0 +1,348 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::difference::DeltaE;
+use crate::rgb::RGB;
+
+
+/// Simulation matrices for supported methods
+mod matrices;
+
+
+use matrices::{ HalfPlanes, Matrix };
+
+
+/// Color vision deficiencies, where severity `1.0` is complete absence of affected cone or, for
+/// achromatopsia, of all color perception
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Deficiency {
+    /// Missing or anomalous long wavelength, red, cones
+    Protanopia,
+
+    /// Missing or anomalous medium wavelength, green, cones
+    Deuteranopia,
+
+    /// Missing or anomalous short wavelength, blue, cones
+    Tritanopia,
+
+    /// Monochromacy, only luminance is perceived
+    Achromatopsia,
+}
+
+
+/// Published models used to simulate dichromacy and anomalous trichromacy
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Method {
+    /// Machado, Oliveira, and Fernandes 2009, interpolated between published severities in steps
+    /// of `0.1`
+    #[default]
+    Machado,
+
+    /// Brettel, Viénot, and Mollon 1997, projection onto two half-planes, most accurate for
+    /// tritanopia
+    Brettel,
+
+    /// Viénot, Brettel, and Mollon 1999, projection onto single plane
+    Vienot,
+}
+
+
+/// Pair of palette colors that are indistinguishable with a color vision deficiency
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Conflict {
+    first: usize,
+    second: usize,
+    deficiency: Deficiency,
+    difference: f64,
+}
+
+
+/// Settings for checking that palette colors remain distinguishable with color vision deficiencies
+///
+/// **Note** defaults to simulating protanopia, deuteranopia, and tritanopia at full severity
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::cvd::{ Checker, Deficiency };
+/// use color_operators::difference::DeltaE;
+/// use color_operators::rgb::RGB;
+///
+/// let palette = [ RGB::new(255, 0, 0), RGB::new(0, 128, 0), RGB::new(0, 0, 255) ];
+///
+/// let conflicts = Checker::new(DeltaE::CIEDE2000, 10.0).check(&palette);
+///
+/// assert!(conflicts.iter().any(|c| c.pair() == (0, 1) && c.deficiency() == Deficiency::Protanopia));
+/// assert!(conflicts.iter().all(|c| c.pair() != (0, 2)));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Checker {
+    formula: DeltaE,
+    threshold: f64,
+    severity: f64,
+    deficiencies: Vec<Deficiency>,
+}
+
+
+/// Settings for simulating color vision deficiencies
+///
+/// **Note** simulation is preformed on linear light, i.e. with sRGB gamma removed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Simulator {
+    method: Method,
+}
+
+
+impl Deficiency {
+    /// Returns color as perceived with deficiency at severity between `0.0` and `1.0`
+    ///
+    /// **Note** this method uses `Simulator::default` internally
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cvd::Deficiency;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let red = RGB::new(255, 0, 0);
+    ///
+    /// assert_eq!(Deficiency::Protanopia.simulate(red.clone(), 0.0), red);
+    /// assert_eq!(Deficiency::Protanopia.simulate(red.clone(), 1.0), RGB::new(109, 95, 0));
+    /// assert_eq!(Deficiency::Achromatopsia.simulate(red, 1.0), RGB::new(127, 127, 127));
+    /// ```
+    pub fn simulate<C>(&self, color: C, severity: f64) -> RGB
+    where
+        C: Into<RGB>
+    {
+        Simulator::default().simulate(*self, color, severity)
+    }
+
+    /// Returns color adjusted such that contrast lost with deficiency, at severity between `0.0`
+    /// and `1.0`, is shifted into channels viewer is still able to distinguish
+    ///
+    /// **Note** deficiency is simulated with `Method::Machado`, and error between original and
+    /// simulated linear light values is redistributed following Fidaner, Lin, and Ozguven
+    ///
+    /// **Warning** achromatopsia leaves no channel to shift contrast into, so colors are returned
+    /// unchanged
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cvd::Deficiency;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let red = RGB::new(255, 0, 0);
+    /// let gray = RGB::new(128, 128, 128);
+    ///
+    /// assert_eq!(Deficiency::Protanopia.daltonize(red, 1.0), RGB::new(255, 184, 203));
+    /// assert_eq!(Deficiency::Protanopia.daltonize(gray.clone(), 1.0), gray);
+    /// ```
+    pub fn daltonize<C>(&self, color: C, severity: f64) -> RGB
+    where
+        C: Into<RGB>
+    {
+        let shift = match self {
+            Self::Protanopia | Self::Deuteranopia => &matrices::SHIFT_PROTAN_DEUTAN,
+            Self::Tritanopia => &matrices::SHIFT_TRITAN,
+            Self::Achromatopsia => return color.into(),
+        };
+
+        let ( red, green, blue ) = color.into().to_linear();
+        let simulated = Simulator::default().simulate_linear(*self, [ red, green, blue ], severity);
+
+        let error = [ red - simulated[0], green - simulated[1], blue - simulated[2] ];
+        let correction = multiply(shift, error);
+
+        RGB::from_linear(red + correction[0], green + correction[1], blue + correction[2])
+    }
+}
+
+
+impl Conflict {
+    /// Returns indices of both colors within palette, lowest first
+    pub fn pair(&self) -> (usize, usize) {
+        (self.first, self.second)
+    }
+
+    /// Returns deficiency colors are indistinguishable with
+    pub fn deficiency(&self) -> Deficiency {
+        self.deficiency
+    }
+
+    /// Returns perceptual difference, ΔE, between simulated colors
+    pub fn difference(&self) -> f64 {
+        self.difference
+    }
+}
+
+
+impl Checker {
+    /// Returns new palette checker reporting pairs of colors whose simulated difference, by given
+    /// formula, is below threshold
+    pub fn new(formula: DeltaE, threshold: f64) -> Self {
+        Self {
+            formula,
+            threshold,
+            severity: 1.0,
+            deficiencies: vec![ Deficiency::Protanopia, Deficiency::Deuteranopia, Deficiency::Tritanopia ],
+        }
+    }
+
+    /// Severity between `0.0` and `1.0` deficiencies are simulated at
+    pub fn severity(self, severity: f64) -> Self {
+        Self { severity, ..self }
+    }
+
+    /// Deficiencies palette is checked against
+    pub fn deficiencies(self, deficiencies: &[Deficiency]) -> Self {
+        Self { deficiencies: deficiencies.to_vec(), ..self }
+    }
+
+    /// Returns conflicting pairs of palette colors, ordered by deficiency then by pair indices
+    ///
+    /// **Note** colors are simulated with `Method::Machado` and alpha is ignored, an empty result
+    /// means every pair remains distinguishable
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::cvd::{ Checker, Deficiency };
+    /// use color_operators::difference::DeltaE;
+    ///
+    /// let palette = [ Color::new_rgb(0, 114, 178), Color::new_rgb(230, 159, 0) ];
+    ///
+    /// let checker = Checker::new(DeltaE::CIEDE2000, 10.0)
+    ///     .deficiencies(&[ Deficiency::Protanopia, Deficiency::Deuteranopia, Deficiency::Tritanopia ]);
+    ///
+    /// assert!(checker.check(&palette).is_empty());
+    /// ```
+    pub fn check<C>(&self, palette: &[C]) -> Vec<Conflict>
+    where
+        C: Clone + Into<Color>
+    {
+        let palette: Vec<RGB> = palette.iter().map(|c| RGB::from(c.clone().into())).collect();
+
+        let mut conflicts = Vec::new();
+        for deficiency in self.deficiencies.iter() {
+            let simulated: Vec<RGB> = palette.iter().map(|c| deficiency.simulate(c.clone(), self.severity)).collect();
+            for (first, left) in simulated.iter().enumerate() {
+                for (second, right) in simulated.iter().enumerate().skip(first + 1) {
+                    let difference = self.formula.between(left.clone(), right.clone());
+                    if difference < self.threshold {
+                        conflicts.push(Conflict { first, second, deficiency: *deficiency, difference });
+                    }
+                }
+            }
+        }
+        conflicts
+    }
+}
+
+
+impl Simulator {
+    /// Returns new simulation settings
+    pub fn new(method: Method) -> Self {
+        Self { method }
+    }
+
+    /// Returns color as perceived with deficiency at severity between `0.0` and `1.0`
+    ///
+    /// **Note** Brettel and Viénot only model dichromacy, for lower severities result is
+    /// interpolated between input and dichromat simulation within linear light, and achromatopsia
+    /// is modeled identically by each method
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cvd::{ Deficiency, Method, Simulator };
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let red = RGB::new(255, 0, 0);
+    ///
+    /// let brettel = Simulator::new(Method::Brettel);
+    /// let vienot = Simulator::new(Method::Vienot);
+    ///
+    /// assert_eq!(brettel.simulate(Deficiency::Protanopia, red.clone(), 1.0), RGB::new(106, 91, 14));
+    /// assert_eq!(vienot.simulate(Deficiency::Protanopia, red, 1.0), RGB::new(94, 94, 13));
+    /// ```
+    pub fn simulate<C>(&self, deficiency: Deficiency, color: C, severity: f64) -> RGB
+    where
+        C: Into<RGB>
+    {
+        let ( red, green, blue ) = color.into().to_linear();
+        let result = self.simulate_linear(deficiency, [ red, green, blue ], severity);
+        RGB::from_linear(result[0], result[1], result[2])
+    }
+
+    /// Returns simulated linear light values, which may be out of range prior to being clamped by
+    /// `RGB::from_linear`
+    fn simulate_linear(&self, deficiency: Deficiency, linear: [f64; 3], severity: f64) -> [f64; 3] {
+        let severity = severity.min(1.0).max(0.0);
+        let [ red, green, blue ] = linear;
+
+        match (deficiency, self.method) {
+            (Deficiency::Achromatopsia, _) => {
+                let luminance = 0.2126 * red + 0.7152 * green + 0.0722 * blue;
+                mix(linear, [ luminance; 3 ], severity)
+            },
+            (Deficiency::Protanopia, Method::Machado) => multiply(&machado(&matrices::MACHADO_PROTAN, severity), linear),
+            (Deficiency::Deuteranopia, Method::Machado) => multiply(&machado(&matrices::MACHADO_DEUTAN, severity), linear),
+            (Deficiency::Tritanopia, Method::Machado) => multiply(&machado(&matrices::MACHADO_TRITAN, severity), linear),
+            (Deficiency::Protanopia, Method::Brettel) => mix(linear, brettel(&matrices::BRETTEL_PROTAN, linear), severity),
+            (Deficiency::Deuteranopia, Method::Brettel) => mix(linear, brettel(&matrices::BRETTEL_DEUTAN, linear), severity),
+            (Deficiency::Tritanopia, Method::Brettel) => mix(linear, brettel(&matrices::BRETTEL_TRITAN, linear), severity),
+            (Deficiency::Protanopia, Method::Vienot) => mix(linear, multiply(&matrices::VIENOT_PROTAN, linear), severity),
+            (Deficiency::Deuteranopia, Method::Vienot) => mix(linear, multiply(&matrices::VIENOT_DEUTAN, linear), severity),
+            (Deficiency::Tritanopia, Method::Vienot) => mix(linear, multiply(&matrices::VIENOT_TRITAN, linear), severity),
+        }
+    }
+}
+
+
+/// Returns product of matrix and linear light values
+fn multiply(matrix: &Matrix, values: [f64; 3]) -> [f64; 3] {
+    let mut result = [0.0; 3];
+    for (value, row) in result.iter_mut().zip(matrix.iter()) {
+        *value = row[0] * values[0] + row[1] * values[1] + row[2] * values[2];
+    }
+    result
+}
+
+
+/// Returns values interpolated from start toward end by amount between `0.0` and `1.0`
+fn mix(start: [f64; 3], end: [f64; 3], amount: f64) -> [f64; 3] {
+    let mut result = [0.0; 3];
+    for (value, (s, e)) in result.iter_mut().zip(start.iter().zip(end.iter())) {
+        *value = s + (e - s) * amount;
+    }
+    result
+}
+
+
+/// Returns Machado matrix for severity, interpolated between neighboring published severities
+fn machado(table: &[Matrix; 11], severity: f64) -> Matrix {
+    let scaled = severity * 10.0;
+    let index = (scaled.floor() as usize).min(9);
+    let amount = scaled - index as f64;
+
+    let mut result = [[0.0; 3]; 3];
+    for (row, (lower, upper)) in result.iter_mut().zip(table[index].iter().zip(table[index + 1].iter())) {
+        *row = mix(*lower, *upper, amount);
+    }
+    result
+}
+
+
+/// Returns values projected onto half-plane on the same side of separating plane
+fn brettel(half_planes: &HalfPlanes, values: [f64; 3]) -> [f64; 3] {
+    let ( normal, first, second ) = half_planes;
+    let side = normal[0] * values[0] + normal[1] * values[1] + normal[2] * values[2];
+    if side >= 0.0 {
+        multiply(first, values)
+    } else {
+        multiply(second, values)
+    }
+}
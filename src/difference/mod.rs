@@ -0,0 +1,221 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::lab::Lab;
+use crate::oklab::Oklab;
+
+
+/// Weighting of CIE94 for intended application
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Application {
+    /// `kL = 1`, `K1 = 0.045`, `K2 = 0.015`
+    #[default]
+    GraphicArts,
+
+    /// `kL = 2`, `K1 = 0.048`, `K2 = 0.014`
+    Textiles,
+}
+
+
+/// Color difference formulas, each returning ΔE where roughly `1.0` is a just noticeable difference
+///
+/// **Note** differences are computed on `Lab` values, or `Oklab` for `DeltaE::Ok`, alpha is ignored
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DeltaE {
+    /// Euclidean distance within `Lab`, fast but overstates differences of saturated colors
+    CIE76,
+
+    /// Chroma and hue weighted distance, not symmetric as first color is reference
+    CIE94(Application),
+
+    /// Current CIE recommendation, corrects CIE94 for blues and neutral colors
+    CIEDE2000,
+
+    /// Colour Measurement Committee l:c formula, commonly `2:1` for acceptability and `1:1` for
+    /// perceptibility, not symmetric as first color is reference
+    CMC {
+        /// Lightness weight, `l`
+        lightness: f64,
+
+        /// Chroma weight, `c`
+        chroma: f64,
+    },
+
+    /// Euclidean distance within `Oklab`, as used by CSS Color 4 gamut mapping, where roughly
+    /// `0.02` is a just noticeable difference
+    Ok,
+}
+
+
+impl DeltaE {
+    /// Returns difference between reference and sample colors
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::difference::{ Application, DeltaE };
+    /// use color_operators::lab::Lab;
+    ///
+    /// let reference = Lab::new(50.0, 2.6772, -79.7751);
+    /// let sample = Lab::new(50.0, 0.0, -82.7485);
+    ///
+    /// let round = |v: f64| (v * 10_000.0).round() / 10_000.0;
+    ///
+    /// assert_eq!(round(DeltaE::CIE76.between(reference.clone(), sample.clone())), 4.0011);
+    /// assert_eq!(round(DeltaE::CIE94(Application::GraphicArts).between(reference.clone(), sample.clone())), 1.3950);
+    /// assert_eq!(round(DeltaE::CIEDE2000.between(reference.clone(), sample.clone())), 2.0425);
+    /// assert_eq!(round(DeltaE::Ok.between(reference, sample)), 0.0344);
+    /// ```
+    pub fn between<R, S>(&self, reference: R, sample: S) -> f64
+    where
+        R: Into<Color>,
+        S: Into<Color>
+    {
+        let lab = |color: Color| -> (f64, f64, f64) { Lab::from(color).into() };
+        let oklab = |color: Color| -> (f64, f64, f64) { Oklab::from(color).into() };
+        let ( reference, sample ) = ( reference.into(), sample.into() );
+
+        match self {
+            Self::CIE76 => euclidean(lab(reference), lab(sample)),
+            Self::CIE94(application) => cie94(lab(reference), lab(sample), *application),
+            Self::CIEDE2000 => ciede2000(lab(reference), lab(sample)),
+            Self::CMC { lightness, chroma } => cmc(lab(reference), lab(sample), *lightness, *chroma),
+            Self::Ok => euclidean(oklab(reference), oklab(sample)),
+        }
+    }
+}
+
+
+/// Returns chroma of `Lab` values
+fn chroma(a: f64, b: f64) -> f64 {
+    (a * a + b * b).sqrt()
+}
+
+
+/// Returns hue angle of `Lab` values in degrees between `0.0` and `360.0`
+fn hue(a: f64, b: f64) -> f64 {
+    if a == 0.0 && b == 0.0 {
+        return 0.0;
+    }
+    b.atan2(a).to_degrees().rem_euclid(360.0)
+}
+
+
+/// Returns Euclidean distance, i.e. CIE76 within `Lab`
+fn euclidean(reference: (f64, f64, f64), sample: (f64, f64, f64)) -> f64 {
+    let ( l1, a1, b1 ) = reference;
+    let ( l2, a2, b2 ) = sample;
+    ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt()
+}
+
+
+fn cie94(reference: (f64, f64, f64), sample: (f64, f64, f64), application: Application) -> f64 {
+    let ( l1, a1, b1 ) = reference;
+    let ( l2, a2, b2 ) = sample;
+
+    let ( k_l, k_1, k_2 ) = match application {
+        Application::GraphicArts => (1.0, 0.045, 0.015),
+        Application::Textiles => (2.0, 0.048, 0.014),
+    };
+
+    let c1 = chroma(a1, b1);
+    let c2 = chroma(a2, b2);
+
+    let delta_l = l1 - l2;
+    let delta_c = c1 - c2;
+    let delta_h_squared = ((a1 - a2).powi(2) + (b1 - b2).powi(2) - delta_c.powi(2)).max(0.0);
+
+    let s_c = 1.0 + k_1 * c1;
+    let s_h = 1.0 + k_2 * c1;
+
+    ((delta_l / k_l).powi(2) + (delta_c / s_c).powi(2) + delta_h_squared / s_h.powi(2)).sqrt()
+}
+
+
+/// Implementation follows Sharma, Wu, and Dalal, "The CIEDE2000 Color-Difference Formula"
+fn ciede2000(reference: (f64, f64, f64), sample: (f64, f64, f64)) -> f64 {
+    let ( l1, a1, b1 ) = reference;
+    let ( l2, a2, b2 ) = sample;
+
+    let c_bar = (chroma(a1, b1) + chroma(a2, b2)) / 2.0;
+    let g = 0.5 * (1.0 - (c_bar.powi(7) / (c_bar.powi(7) + 25_f64.powi(7))).sqrt());
+
+    let a1 = a1 * (1.0 + g);
+    let a2 = a2 * (1.0 + g);
+    let c1 = chroma(a1, b1);
+    let c2 = chroma(a2, b2);
+    let h1 = hue(a1, b1);
+    let h2 = hue(a2, b2);
+
+    let delta_l = l2 - l1;
+    let delta_c = c2 - c1;
+    let delta_h = if c1 * c2 == 0.0 {
+        0.0
+    } else if (h2 - h1).abs() <= 180.0 {
+        h2 - h1
+    } else if h2 - h1 > 180.0 {
+        h2 - h1 - 360.0
+    } else {
+        h2 - h1 + 360.0
+    };
+    let delta_h = 2.0 * (c1 * c2).sqrt() * (delta_h / 2.0).to_radians().sin();
+
+    let l_bar = (l1 + l2) / 2.0;
+    let c_bar = (c1 + c2) / 2.0;
+    let h_bar = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+
+    let t = 1.0
+        - 0.17 * (h_bar - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_bar).to_radians().cos()
+        + 0.32 * (3.0 * h_bar + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_bar - 63.0).to_radians().cos();
+
+    let delta_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+    let r_c = 2.0 * (c_bar.powi(7) / (c_bar.powi(7) + 25_f64.powi(7))).sqrt();
+    let s_l = 1.0 + (0.015 * (l_bar - 50.0).powi(2)) / (20.0 + (l_bar - 50.0).powi(2)).sqrt();
+    let s_c = 1.0 + 0.045 * c_bar;
+    let s_h = 1.0 + 0.015 * c_bar * t;
+    let r_t = -(2.0 * delta_theta).to_radians().sin() * r_c;
+
+    let l = delta_l / s_l;
+    let c = delta_c / s_c;
+    let h = delta_h / s_h;
+
+    (l * l + c * c + h * h + r_t * c * h).sqrt()
+}
+
+
+fn cmc(reference: (f64, f64, f64), sample: (f64, f64, f64), lightness: f64, chroma_weight: f64) -> f64 {
+    let ( l1, a1, b1 ) = reference;
+    let ( l2, a2, b2 ) = sample;
+
+    let c1 = chroma(a1, b1);
+    let c2 = chroma(a2, b2);
+    let h1 = hue(a1, b1);
+
+    let delta_l = l1 - l2;
+    let delta_c = c1 - c2;
+    let delta_h_squared = ((a1 - a2).powi(2) + (b1 - b2).powi(2) - delta_c.powi(2)).max(0.0);
+
+    let f = (c1.powi(4) / (c1.powi(4) + 1900.0)).sqrt();
+    let t = if (164.0..=345.0).contains(&h1) {
+        0.56 + (0.2 * (h1 + 168.0).to_radians().cos()).abs()
+    } else {
+        0.36 + (0.4 * (h1 + 35.0).to_radians().cos()).abs()
+    };
+
+    let s_l = if l1 < 16.0 { 0.511 } else { 0.040975 * l1 / (1.0 + 0.01765 * l1) };
+    let s_c = 0.0638 * c1 / (1.0 + 0.0131 * c1) + 0.638;
+    let s_h = s_c * (f * t + 1.0 - f);
+
+    ((delta_l / (lightness * s_l)).powi(2) + (delta_c / (chroma_weight * s_c)).powi(2) + delta_h_squared / s_h.powi(2)).sqrt()
+}
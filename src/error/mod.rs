@@ -0,0 +1,106 @@
+#!/usr/bin/env rust
+
+
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+
+extern crate json;
+use json::JsonValue;
+
+
+/// Errors returned by fallible parsing and conversion methods
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// Input is not valid hexadecimal
+    Hex(String),
+
+    /// Input is not valid JSON
+    Json(String),
+
+    /// Named color component is missing
+    MissingComponent(String),
+
+    /// Named color component is not a number, or is outside of representable range
+    InvalidComponent(String),
+
+    /// Number of values does not match number of color components
+    Length {
+        /// Number of values required
+        expected: usize,
+
+        /// Number of values provided
+        found: usize,
+    },
+
+    /// Input is not valid CSS color syntax
+    Parse {
+        /// Byte offset within input where parsing failed
+        position: usize,
+
+        /// Description of what was expected
+        message: String,
+    },
+}
+
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Hex(message) => write!(f, "Invalid hexadecimal -> {}", message),
+            Self::Json(message) => write!(f, "Invalid JSON -> {}", message),
+            Self::MissingComponent(name) => write!(f, "Missing color component -> {}", name),
+            Self::InvalidComponent(name) => write!(f, "Invalid color component -> {}", name),
+            Self::Length { expected, found } => write!(f, "Expected {} values but found {}", expected, found),
+            Self::Parse { position, message } => write!(f, "Invalid CSS color at position {} -> {}", position, message),
+        }
+    }
+}
+
+
+impl std::error::Error for Error {}
+
+
+/// Converts from errors of `json::parse`
+impl From<json::Error> for Error {
+    fn from(error: json::Error) -> Self {
+        Self::Json(error.to_string())
+    }
+}
+
+
+/// Converts from errors of `hex::decode`
+impl From<hex::FromHexError> for Error {
+    fn from(error: hex::FromHexError) -> Self {
+        Self::Hex(error.to_string())
+    }
+}
+
+
+/// Returns named component of JSON object as 64-bit precision floating point number
+pub(crate) fn json_f64(data: &JsonValue, name: &str) -> Result<f64, Error> {
+    let value = &data[name];
+    if value.is_null() {
+        return Err(Error::MissingComponent(name.to_string()));
+    }
+    value.as_f64().ok_or_else(|| Error::InvalidComponent(name.to_string()))
+}
+
+
+/// Returns named component of JSON object as unsigned 8-bit integer
+pub(crate) fn json_u8(data: &JsonValue, name: &str) -> Result<u8, Error> {
+    let value = &data[name];
+    if value.is_null() {
+        return Err(Error::MissingComponent(name.to_string()));
+    }
+    value.as_u8().ok_or_else(|| Error::InvalidComponent(name.to_string()))
+}
+
+
+/// Returns `Error::Length` unless number of values found is within expected range
+pub(crate) fn check_length(found: usize, minimum: usize, maximum: usize) -> Result<(), Error> {
+    if found < minimum || found > maximum {
+        return Err(Error::Length { expected: maximum, found });
+    }
+    Ok(())
+}
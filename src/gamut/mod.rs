@@ -0,0 +1,157 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::oklab::Oklab;
+use crate::oklch::Oklch;
+use crate::rgb_space::RgbSpace;
+use crate::xyz::XYZ;
+
+
+/// Allowed distance outside of `0.0` through `1.0` for linear light within gamut
+const EPSILON: f64 = 0.000001;
+
+
+/// Just noticeable difference within `Oklab` used by CSS Color 4 gamut mapping
+const JND: f64 = 0.02;
+
+
+/// Chroma resolution at which CSS Color 4 gamut mapping stops searching
+const CHROMA_EPSILON: f64 = 0.0001;
+
+
+/// How colors outside of gamut are brought within it
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Method {
+    /// CSS Color 4 mapping, reduces `Oklch` chroma until clipping changes color by less than just
+    /// noticeable difference, keeping lightness and hue
+    #[default]
+    Css,
+
+    /// Clamps each of red, green, and blue, fast but may shift hue and lightness
+    Clip,
+}
+
+
+/// Returns `true` when color may be shown within space without clamping
+///
+/// **Note** `RGB` based data structures are sRGB, so they are always within gamut of sRGB and wider
+/// spaces
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::gamut;
+/// use color_operators::rgb_space::RgbSpace;
+///
+/// let green = Color::new_oklch(0.85, 0.3, 145.0);
+///
+/// assert!(!gamut::in_gamut(green.clone(), &RgbSpace::srgb()));
+/// assert!(gamut::in_gamut(green, &RgbSpace::display_p3()));
+/// assert!(gamut::in_gamut(Color::new_rgb(0, 255, 0), &RgbSpace::display_p3()));
+/// ```
+pub fn in_gamut<C>(color: C, space: &RgbSpace) -> bool
+where
+    C: Into<Color>
+{
+    linear_in_gamut(space.xyz_to_linear(XYZ::from(color.into())))
+}
+
+
+/// Returns red, green, and blue encoded within space, between `0.0` and `1.0`, with colors
+/// outside of gamut brought within it by method
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::gamut::{ self, Method };
+/// use color_operators::rgb_space::RgbSpace;
+///
+/// let green = Color::new_oklch(0.9, 0.35, 145.0);
+/// let round = |( r, g, b ): (f64, f64, f64)| ((r * 1000.0).round(), (g * 1000.0).round(), (b * 1000.0).round());
+///
+/// assert_eq!(round(gamut::map(green.clone(), &RgbSpace::srgb(), Method::Clip)), (0.0, 1000.0, 0.0));
+/// assert_eq!(round(gamut::map(green, &RgbSpace::srgb(), Method::Css)), (327.0, 1000.0, 412.0));
+/// ```
+pub fn map<C>(color: C, space: &RgbSpace, method: Method) -> (f64, f64, f64)
+where
+    C: Into<Color>
+{
+    let xyz = XYZ::from(color.into());
+    let linear = space.xyz_to_linear(xyz.clone());
+    if linear_in_gamut(linear) || method == Method::Clip {
+        return clip(space, linear);
+    }
+
+    let ( lightness, chroma, hue ) = Oklch::from(xyz).into();
+    if lightness >= 1.0 - EPSILON {
+        return ( 1.0, 1.0, 1.0 );
+    }
+    if lightness <= EPSILON {
+        return ( 0.0, 0.0, 0.0 );
+    }
+
+    let linear_of = |chroma: f64| space.xyz_to_linear(XYZ::from(Oklch::new(lightness, chroma, hue)));
+    let distance = |chroma: f64, encoded: (f64, f64, f64)| -> f64 {
+        let ( l, a, b ) = Oklab::from(Oklch::new(lightness, chroma, hue)).into();
+        let ( clipped_l, clipped_a, clipped_b ) = Oklab::from(to_xyz(space, encoded)).into();
+        ((l - clipped_l).powi(2) + (a - clipped_a).powi(2) + (b - clipped_b).powi(2)).sqrt()
+    };
+
+    let mut clipped = clip(space, linear);
+    if distance(chroma, clipped) < JND {
+        return clipped;
+    }
+
+    let mut low = 0.0;
+    let mut high = chroma;
+    let mut low_in_gamut = true;
+    while high - low > CHROMA_EPSILON {
+        let middle = (low + high) / 2.0;
+        let linear = linear_of(middle);
+
+        if low_in_gamut && linear_in_gamut(linear) {
+            low = middle;
+            continue;
+        }
+
+        clipped = clip(space, linear);
+        let difference = distance(middle, clipped);
+        if difference < JND {
+            if JND - difference < CHROMA_EPSILON {
+                break;
+            }
+            low_in_gamut = false;
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    clipped
+}
+
+
+/// Returns `true` when linear light values are within `0.0` through `1.0`
+fn linear_in_gamut(linear: (f64, f64, f64)) -> bool {
+    let ( red, green, blue ) = linear;
+    [ red, green, blue ].iter().all(|v| *v >= -EPSILON && *v <= 1.0 + EPSILON)
+}
+
+
+/// Returns linear light values encoded within space and clamped to `0.0` through `1.0`
+fn clip(space: &RgbSpace, linear: (f64, f64, f64)) -> (f64, f64, f64) {
+    let ( red, green, blue ) = linear;
+    let encode = |v: f64| space.transfer().encode(v).min(1.0).max(0.0);
+    ( encode(red), encode(green), encode(blue) )
+}
+
+
+/// Returns `XYZ` of values encoded within space
+pub(crate) fn to_xyz(space: &RgbSpace, encoded: (f64, f64, f64)) -> XYZ {
+    let ( red, green, blue ) = encoded;
+    let decode = |v: f64| space.transfer().decode(v);
+    space.linear_to_xyz(decode(red), decode(green), decode(blue))
+}
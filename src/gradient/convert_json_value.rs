@@ -0,0 +1,170 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+extern crate json;
+use json::JsonValue;
+
+
+use crate::color::Color;
+use crate::error::{ json_f64, Error };
+use crate::gradient::{ Easing, Gradient, Stop };
+use crate::mix::{ HueMethod, Space };
+
+
+/// Easing keywords, cubic Bézier curves are written as array of four numbers
+const EASING_KEYWORDS: [(Easing, &str); 5] = [
+    (Easing::Linear, "linear"),
+    (Easing::Ease, "ease"),
+    (Easing::EaseIn, "ease-in"),
+    (Easing::EaseOut, "ease-out"),
+    (Easing::EaseInOut, "ease-in-out"),
+];
+
+
+/// Returns easing from keyword or array, or error naming invalid value
+fn easing(data: &JsonValue) -> Result<Easing, Error> {
+    let invalid = || Error::InvalidComponent("easing".to_string());
+
+    if data.is_null() {
+        return Ok(Easing::default());
+    }
+
+    if data.is_array() {
+        let values: Option<Vec<f64>> = data.members().map(|v| v.as_f64()).collect();
+        return match values.as_deref() {
+            Some([ x1, y1, x2, y2 ]) => Ok(Easing::CubicBezier(*x1, *y1, *x2, *y2)),
+            _ => Err(invalid()),
+        };
+    }
+
+    let keyword = data.as_str().ok_or_else(invalid)?;
+    EASING_KEYWORDS.iter().find(|(_, k)| k.eq_ignore_ascii_case(keyword)).map(|(e, _)| *e).ok_or_else(invalid)
+}
+
+
+/// Returns keyword or array for easing
+fn easing_value(easing: Easing) -> JsonValue {
+    if let Easing::CubicBezier(x1, y1, x2, y2) = easing {
+        return json::array![ x1, y1, x2, y2 ];
+    }
+    let keyword = EASING_KEYWORDS.iter().find(|(e, _)| *e == easing).map(|(_, k)| *k).unwrap_or("linear");
+    keyword.into()
+}
+
+
+/// Converts from `JsonValue` key value pares, or error for missing or invalid values
+impl TryFrom<&JsonValue> for Gradient {
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::gradient::Gradient;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{ "space": "nope", "stops": [] }"#).unwrap();
+    ///
+    /// assert_eq!(Gradient::try_from(&data), Err(Error::InvalidComponent("space".to_string())));
+    /// ```
+    fn try_from(data: &JsonValue) -> Result<Self, Self::Error> {
+        let mut gradient = Self::default();
+
+        if !data["space"].is_null() {
+            let space = data["space"].as_str().and_then(Space::from_keyword);
+            gradient.space = space.ok_or_else(|| Error::InvalidComponent("space".to_string()))?;
+        }
+
+        if !data["hue_method"].is_null() {
+            let hue_method = data["hue_method"].as_str().and_then(HueMethod::from_keyword);
+            gradient.hue_method = hue_method.ok_or_else(|| Error::InvalidComponent("hue_method".to_string()))?;
+        }
+
+        if !data["angle"].is_null() {
+            gradient = gradient.angle(json_f64(data, "angle")?);
+        }
+
+        let stops = &data["stops"];
+        if stops.is_null() {
+            return Err(Error::MissingComponent("stops".to_string()));
+        }
+        if !stops.is_array() {
+            return Err(Error::InvalidComponent("stops".to_string()));
+        }
+
+        for stop in stops.members() {
+            if stop["color"].is_null() {
+                return Err(Error::MissingComponent("color".to_string()));
+            }
+            let color = Color::try_from(&stop["color"])?;
+            gradient = gradient.stop(color, json_f64(stop, "position")?).easing(easing(&stop["easing"])?);
+            if !stop["hint"].is_null() {
+                gradient = gradient.hint(json_f64(stop, "hint")?);
+            }
+        }
+
+        Ok(gradient)
+    }
+}
+
+
+/// Converts from `JsonValue` key value pares
+///
+/// **Note** this uses `Gradient::try_from` internally, and returns default values for missing or
+/// invalid values
+impl From<JsonValue> for Gradient {
+    fn from(data: JsonValue) -> Self {
+        Self::try_from(&data).unwrap_or_default()
+    }
+}
+
+
+/// Converts to `JsonValue`
+impl Into<JsonValue> for Gradient {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::gradient::Gradient;
+    /// extern crate json;
+    /// use json::JsonValue;
+    ///
+    /// let gradient = Gradient::new().stop(Color::new_rgb(255, 42, 90), 0.0).hint(0.5);
+    /// let data: JsonValue = gradient.into();
+    ///
+    /// let expected = json::parse(r#"{
+    ///     "space": "oklab",
+    ///     "hue_method": "shorter",
+    ///     "angle": 180,
+    ///     "stops": [
+    ///         { "color": { "red": 255, "green": 42, "blue": 90 }, "position": 0, "hint": 0.5, "easing": "linear" }
+    ///     ]
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(data, expected);
+    /// ```
+    fn into(self) -> JsonValue {
+        let stops: Vec<JsonValue> = self.stops.into_iter().map(|stop: Stop| {
+            let mut data = json::object!{
+                "color" => stop.color,
+                "position" => stop.position,
+            };
+            if let Some(hint) = stop.hint {
+                data["hint"] = hint.into();
+            }
+            data["easing"] = easing_value(stop.easing);
+            data
+        }).collect();
+
+        json::object!{
+            "space" => self.space.keyword(),
+            "hue_method" => self.hue_method.keyword(),
+            "angle" => self.angle,
+            "stops" => stops,
+        }
+    }
+}
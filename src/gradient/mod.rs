@@ -0,0 +1,472 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+use crate::color::Color;
+use crate::css::Options;
+use crate::css::serialize;
+use crate::error::Error;
+use crate::mix::{ HueMethod, Space };
+use crate::rgb::RGB;
+
+
+/// Converts from/to `JsonValue` for `Gradient` data structures
+mod convert_json_value;
+
+
+/// Number of intervals each segment is split into when CSS cannot express it exactly
+const APPROXIMATION_STEPS: usize = 8;
+
+
+/// Timing of progress between two stops, matching CSS easing functions
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Easing {
+    /// Constant rate of change
+    #[default]
+    Linear,
+
+    /// CSS `ease`, i.e. `cubic-bezier(0.25, 0.1, 0.25, 1)`
+    Ease,
+
+    /// CSS `ease-in`, i.e. `cubic-bezier(0.42, 0, 1, 1)`
+    EaseIn,
+
+    /// CSS `ease-out`, i.e. `cubic-bezier(0, 0, 0.58, 1)`
+    EaseOut,
+
+    /// CSS `ease-in-out`, i.e. `cubic-bezier(0.42, 0, 0.58, 1)`
+    EaseInOut,
+
+    /// CSS `cubic-bezier(x1, y1, x2, y2)`, where `x1` and `x2` are clamped between `0.0` and `1.0`
+    CubicBezier(f64, f64, f64, f64),
+}
+
+
+/// Color at position within `Gradient`, with optional hint and easing toward next stop
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stop {
+    color: Color,
+    position: f64,
+    hint: Option<f64>,
+    easing: Easing,
+}
+
+
+/// Ordered color stops interpolated within a color space
+///
+/// **Note** positions are conventionally between `0.0` and `1.0`, and a position before that of
+/// previous stop is moved to previous position, as defined by CSS Images 3
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::gradient::Gradient;
+/// use color_operators::mix::Space;
+///
+/// let gradient = Gradient::new()
+///     .space(Space::Srgb)
+///     .stop(Color::new_rgb(255, 0, 0), 0.0)
+///     .stop(Color::new_rgb(0, 0, 255), 1.0);
+///
+/// assert_eq!(gradient.sample(0.5), Color::new_rgb(128, 0, 128));
+/// assert_eq!(gradient.colors(3)[2], Color::new_rgb(0, 0, 255));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient {
+    stops: Vec<Stop>,
+    space: Space,
+    hue_method: HueMethod,
+    angle: f64,
+}
+
+
+impl Easing {
+    /// Returns eased progress for linear progress between `0.0` and `1.0`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::gradient::Easing;
+    ///
+    /// assert_eq!(Easing::Linear.apply(0.25), 0.25);
+    /// assert_eq!((Easing::EaseInOut.apply(0.5) * 1_000.0).round(), 500.0);
+    /// assert!(Easing::EaseIn.apply(0.25) < 0.25);
+    /// assert!(Easing::EaseOut.apply(0.25) > 0.25);
+    /// ```
+    pub fn apply(&self, progress: f64) -> f64 {
+        let progress = progress.min(1.0).max(0.0);
+        match self {
+            Self::Linear => progress,
+            Self::Ease => cubic_bezier((0.25, 0.1, 0.25, 1.0), progress),
+            Self::EaseIn => cubic_bezier((0.42, 0.0, 1.0, 1.0), progress),
+            Self::EaseOut => cubic_bezier((0.0, 0.0, 0.58, 1.0), progress),
+            Self::EaseInOut => cubic_bezier((0.42, 0.0, 0.58, 1.0), progress),
+            Self::CubicBezier(x1, y1, x2, y2) => cubic_bezier((*x1, *y1, *x2, *y2), progress),
+        }
+    }
+}
+
+
+impl Stop {
+    /// Returns color of stop
+    pub fn color(&self) -> &Color {
+        &self.color
+    }
+
+    /// Returns position of stop
+    pub fn position(&self) -> f64 {
+        self.position
+    }
+
+    /// Returns position where colors of this and next stop are evenly mixed, if any
+    pub fn hint(&self) -> Option<f64> {
+        self.hint
+    }
+
+    /// Returns easing toward next stop
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+
+    /// Returns amount of next color for linear progress toward next stop at given position
+    fn progress(&self, progress: f64, next_position: f64) -> f64 {
+        let progress = match self.hint {
+            Some(hint) => {
+                let midpoint = (hint - self.position) / (next_position - self.position);
+                if midpoint <= 0.0 {
+                    1.0
+                } else if midpoint >= 1.0 {
+                    0.0
+                } else {
+                    progress.powf(0.5_f64.ln() / midpoint.ln())
+                }
+            },
+            None => progress,
+        };
+        self.easing.apply(progress)
+    }
+}
+
+
+/// Defaults to no stops, interpolated within `Space::Oklab` along shorter hue, top to bottom
+impl Default for Gradient {
+    fn default() -> Self {
+        Self {
+            stops: Vec::new(),
+            space: Space::default(),
+            hue_method: HueMethod::default(),
+            angle: 180.0,
+        }
+    }
+}
+
+
+impl Gradient {
+    /// Returns gradient without stops, see `Gradient::default`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends color stop at position
+    pub fn stop<C>(mut self, color: C, position: f64) -> Self
+    where
+        C: Into<Color>
+    {
+        let position = match self.stops.last() {
+            Some(previous) => position.max(previous.position),
+            None => position,
+        };
+        self.stops.push(Stop { color: color.into(), position, hint: None, easing: Easing::default() });
+        self
+    }
+
+    /// Sets position, between last appended stop and next, where both colors are evenly mixed
+    ///
+    /// **Note** has no effect prior to appending a stop
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::gradient::Gradient;
+    /// use color_operators::mix::Space;
+    ///
+    /// let gradient = Gradient::new()
+    ///     .space(Space::Srgb)
+    ///     .stop(Color::new_rgb(0, 0, 0), 0.0)
+    ///     .hint(0.25)
+    ///     .stop(Color::new_rgb(255, 255, 255), 1.0);
+    ///
+    /// assert_eq!(gradient.sample(0.25), Color::new_rgb(128, 128, 128));
+    /// ```
+    pub fn hint(mut self, position: f64) -> Self {
+        if let Some(stop) = self.stops.last_mut() {
+            stop.hint = Some(position);
+        }
+        self
+    }
+
+    /// Sets easing between last appended stop and next
+    ///
+    /// **Note** has no effect prior to appending a stop
+    pub fn easing(mut self, easing: Easing) -> Self {
+        if let Some(stop) = self.stops.last_mut() {
+            stop.easing = easing;
+        }
+        self
+    }
+
+    /// Color space stops are interpolated within
+    pub fn space(self, space: Space) -> Self {
+        Self { space, ..self }
+    }
+
+    /// How hue is interpolated within cylindrical spaces
+    pub fn hue_method(self, hue_method: HueMethod) -> Self {
+        Self { hue_method, ..self }
+    }
+
+    /// Direction of CSS linear gradient in degrees, where `0.0` points up and `90.0` points right
+    pub fn angle(self, angle: f64) -> Self {
+        Self { angle: angle.rem_euclid(360.0), ..self }
+    }
+
+    /// Returns stops in order
+    pub fn stops(&self) -> &[Stop] {
+        &self.stops
+    }
+
+    /// Returns color at position, where positions outside of stops return nearest stop color
+    ///
+    /// **Note** result has type of the color of stop preceding position, see `Color::mix`, and an
+    /// empty gradient returns `Color::RGB` black
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::gradient::Gradient;
+    ///
+    /// let gradient = Gradient::new()
+    ///     .stop(Color::new_oklch(0.5, 0.1, 0.0), 0.0)
+    ///     .stop(Color::new_oklch(0.7, 0.1, 90.0), 1.0);
+    ///
+    /// assert_eq!(gradient.sample(-1.0), Color::new_oklch(0.5, 0.1, 0.0));
+    /// assert!(gradient.sample(0.5).is_oklch());
+    /// ```
+    pub fn sample(&self, position: f64) -> Color {
+        let ( first, last ) = match ( self.stops.first(), self.stops.last() ) {
+            ( Some(first), Some(last) ) => (first, last),
+            _ => return Color::RGB(RGB::default()),
+        };
+
+        if position <= first.position {
+            return first.color.clone();
+        }
+
+        for pair in self.stops.windows(2) {
+            let ( start, end ) = (&pair[0], &pair[1]);
+            if position > end.position {
+                continue;
+            }
+            let progress = (position - start.position) / (end.position - start.position);
+            let amount = start.progress(progress, end.position);
+            return start.color.mix(&end.color, amount, self.space, self.hue_method);
+        }
+
+        last.color.clone()
+    }
+
+    /// Returns number of colors evenly spaced from first to last stop, inclusive
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::gradient::Gradient;
+    /// use color_operators::mix::Space;
+    ///
+    /// let gradient = Gradient::new()
+    ///     .space(Space::Srgb)
+    ///     .stop(Color::new_rgb(0, 0, 0), 0.0)
+    ///     .stop(Color::new_rgb(200, 100, 0), 1.0);
+    ///
+    /// assert_eq!(gradient.colors(5), vec![
+    ///     Color::new_rgb(0, 0, 0),
+    ///     Color::new_rgb(50, 25, 0),
+    ///     Color::new_rgb(100, 50, 0),
+    ///     Color::new_rgb(150, 75, 0),
+    ///     Color::new_rgb(200, 100, 0),
+    /// ]);
+    /// ```
+    pub fn colors(&self, count: usize) -> Vec<Color> {
+        let ( start, end ) = match ( self.stops.first(), self.stops.last() ) {
+            ( Some(first), Some(last) ) => (first.position, last.position),
+            _ => return Vec::new(),
+        };
+
+        if count == 1 {
+            return vec![ self.sample(start) ];
+        }
+
+        (0..count).map(|index| {
+            self.sample(start + (end - start) * index as f64 / (count - 1) as f64)
+        }).collect()
+    }
+
+    /// Returns CSS `linear-gradient()` with colors serialized per options
+    ///
+    /// **Note** easing, and interpolation within `Space::HSV`, are not expressible by CSS, so
+    /// affected segments are approximated by intermediate stops
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::css::{ Notation, Options };
+    /// use color_operators::gradient::Gradient;
+    /// use color_operators::mix::{ HueMethod, Space };
+    ///
+    /// let gradient = Gradient::new()
+    ///     .angle(90.0)
+    ///     .space(Space::Oklch)
+    ///     .hue_method(HueMethod::Longer)
+    ///     .stop(Color::new_rgb(255, 0, 0), 0.0)
+    ///     .hint(0.25)
+    ///     .stop(Color::new_rgb(0, 0, 255), 1.0);
+    ///
+    /// assert_eq!(
+    ///     gradient.to_css_string(Options::new().notation(Notation::Hex)),
+    ///     "linear-gradient(90deg in oklch longer hue, #FF0000 0%, 25%, #0000FF 100%)"
+    /// );
+    /// ```
+    pub fn to_css_string(&self, options: Options) -> String {
+        let exact = self.space != Space::HSV;
+
+        let mut method = format!("in {}", if exact { self.space.keyword() } else { "srgb" });
+        if exact && self.space.is_cylindrical() && self.hue_method != HueMethod::Shorter {
+            method.push_str(&format!(" {} hue", self.hue_method.keyword()));
+        }
+
+        let mut arguments = vec![];
+        if self.angle != 180.0 {
+            arguments.push(format!("{}deg {}", serialize::number(self.angle, options), method));
+        } else {
+            arguments.push(method);
+        }
+
+        let stop = |color: &Color, position: f64| -> String {
+            format!("{} {}", color.to_css_string(options), serialize::percent(position, options))
+        };
+
+        for (index, current) in self.stops.iter().enumerate() {
+            arguments.push(stop(&current.color, current.position));
+
+            let next = match self.stops.get(index + 1) {
+                Some(next) if next.position > current.position => next,
+                _ => continue,
+            };
+
+            if !exact || current.easing != Easing::Linear {
+                for step in 1..APPROXIMATION_STEPS {
+                    let position = current.position + (next.position - current.position) * step as f64 / APPROXIMATION_STEPS as f64;
+                    arguments.push(stop(&self.sample(position), position));
+                }
+            } else if let Some(hint) = current.hint {
+                arguments.push(serialize::percent(hint, options));
+            }
+        }
+
+        format!("linear-gradient({})", arguments.join(", "))
+    }
+
+    /// Returns parsed JSON string for gradient, or default values
+    ///
+    /// **Note** this method uses `Gradient::try_from_json_str` internally
+    pub fn from_json_string<S>(string: S) -> Self
+    where
+        S: Into<String>
+    {
+        Self::try_from_json_str(&string.into()).unwrap_or_default()
+    }
+
+    /// Returns parsed JSON string for gradient, or error for invalid JSON and missing or invalid
+    /// values
+    ///
+    /// **Note** `space`, `hue_method`, `angle`, and per stop `hint` and `easing`, are optional
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::error::Error;
+    /// use color_operators::gradient::{ Easing, Gradient };
+    ///
+    /// let gradient = Gradient::try_from_json_str(r#"{
+    ///     "space": "srgb",
+    ///     "stops": [
+    ///         { "color": { "red": 0, "green": 0, "blue": 0 }, "position": 0, "easing": "ease-in" },
+    ///         { "color": { "red": 255, "green": 255, "blue": 255 }, "position": 1 }
+    ///     ]
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(gradient.stops()[0].easing(), Easing::EaseIn);
+    /// assert_eq!(gradient.sample(1.0), Color::new_rgb(255, 255, 255));
+    ///
+    /// let result = Gradient::try_from_json_str(r#"{ "space": "srgb" }"#);
+    ///
+    /// assert_eq!(result, Err(Error::MissingComponent("stops".to_string())));
+    /// ```
+    pub fn try_from_json_str(string: &str) -> Result<Self, Error> {
+        let data = json::parse(string)?;
+        Self::try_from(&data)
+    }
+
+    /// Serializes gradient as JSON string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::gradient::{ Easing, Gradient };
+    ///
+    /// let gradient = Gradient::new()
+    ///     .stop(Color::new_rgb(255, 42, 90), 0.0)
+    ///     .easing(Easing::CubicBezier(0.5, 0.0, 0.5, 1.0))
+    ///     .stop(Color::new_hsl(120.0, 1.0, 0.5), 1.0);
+    ///
+    /// assert_eq!(Gradient::from_json_string(gradient.to_json_string()), gradient);
+    /// ```
+    pub fn to_json_string(&self) -> String {
+        json::stringify(self.clone())
+    }
+}
+
+
+/// Returns y of CSS cubic Bézier timing curve where x equals progress
+fn cubic_bezier(points: (f64, f64, f64, f64), progress: f64) -> f64 {
+    let ( x1, y1, x2, y2 ) = points;
+    let x1 = x1.min(1.0).max(0.0);
+    let x2 = x2.min(1.0).max(0.0);
+
+    let curve = |p1: f64, p2: f64, t: f64| -> f64 {
+        let u = 1.0 - t;
+        3.0 * u * u * t * p1 + 3.0 * u * t * t * p2 + t * t * t
+    };
+
+    let mut low = 0.0;
+    let mut high = 1.0;
+    for _ in 0..64 {
+        let middle = (low + high) / 2.0;
+        if curve(x1, x2, middle) < progress {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    curve(y1, y2, (low + high) / 2.0)
+}
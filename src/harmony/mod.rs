@@ -0,0 +1,122 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::hsl::HSL;
+use crate::mix;
+use crate::oklch::Oklch;
+
+
+/// Color wheel hues are rotated around
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Wheel {
+    /// Hue of `HSL`, the traditional digital color wheel
+    #[default]
+    HSL,
+
+    /// Hue of `Oklch`, perceptually even spacing that keeps lightness and chroma
+    Oklch,
+}
+
+
+/// Classic color harmonies, defined by hue offsets from base color
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Harmony {
+    /// Base and opposite hue
+    Complementary,
+
+    /// Base and both neighbors of opposite hue, commonly `30` degrees from it
+    SplitComplementary {
+        /// Degrees between opposite hue and each neighbor
+        angle: f64,
+    },
+
+    /// Base and hues either side of it, commonly `30` degrees away
+    Analogous {
+        /// Degrees between base and each neighbor
+        angle: f64,
+    },
+
+    /// Three hues evenly spaced
+    Triadic,
+
+    /// Two complementary pairs, a.k.a. rectangle, commonly `60` degrees apart
+    Tetradic {
+        /// Degrees between base and second pair
+        angle: f64,
+    },
+
+    /// Four hues evenly spaced
+    Square,
+}
+
+
+impl Harmony {
+    /// Returns hue offsets in degrees, beginning with `0.0` for base color
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::harmony::Harmony;
+    ///
+    /// assert_eq!(Harmony::SplitComplementary { angle: 30.0 }.offsets(), vec![0.0, 150.0, 210.0]);
+    /// assert_eq!(Harmony::Analogous { angle: 30.0 }.offsets(), vec![0.0, -30.0, 30.0]);
+    /// assert_eq!(Harmony::Tetradic { angle: 60.0 }.offsets(), vec![0.0, 60.0, 180.0, 240.0]);
+    /// ```
+    pub fn offsets(&self) -> Vec<f64> {
+        match self {
+            Self::Complementary => vec![ 0.0, 180.0 ],
+            Self::SplitComplementary { angle } => vec![ 0.0, 180.0 - angle, 180.0 + angle ],
+            Self::Analogous { angle } => vec![ 0.0, -angle, *angle ],
+            Self::Triadic => vec![ 0.0, 120.0, 240.0 ],
+            Self::Tetradic { angle } => vec![ 0.0, *angle, 180.0, 180.0 + angle ],
+            Self::Square => vec![ 0.0, 90.0, 180.0, 270.0 ],
+        }
+    }
+
+    /// Returns base color followed by colors with hue rotated around wheel
+    ///
+    /// **Note** results are converted to type of base color and keep its alpha, colors rotated on
+    /// `Wheel::Oklch` that fall outside of sRGB are clamped when converted to `RGB` based types
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::harmony::{ Harmony, Wheel };
+    ///
+    /// let red = Color::new_rgb(255, 0, 0);
+    ///
+    /// assert_eq!(Harmony::Triadic.colors(red.clone(), Wheel::HSL), vec![
+    ///     Color::new_rgb(255, 0, 0),
+    ///     Color::new_rgb(0, 255, 0),
+    ///     Color::new_rgb(0, 0, 255),
+    /// ]);
+    ///
+    /// let oklch = Color::new_oklch(0.7, 0.1, 20.0);
+    ///
+    /// assert_eq!(Harmony::Complementary.colors(oklch, Wheel::Oklch)[1], Color::new_oklch(0.7, 0.1, 200.0));
+    /// ```
+    pub fn colors<C>(&self, base: C, wheel: Wheel) -> Vec<Color>
+    where
+        C: Into<Color>
+    {
+        let base: Color = base.into();
+        let alpha = mix::opacity(&base);
+
+        let rotate = |offset: f64| -> Color {
+            let rotated = match wheel {
+                Wheel::HSL => Color::HSL(HSL::from(base.clone()).rotate_hue(offset)),
+                Wheel::Oklch => Color::Oklch(Oklch::from(base.clone()).rotate_hue(offset)),
+            };
+            base.convert_like(rotated, alpha)
+        };
+
+        self.offsets().into_iter().map(|offset| {
+            if offset == 0.0 {
+                return base.clone();
+            }
+            rotate(offset)
+        }).collect()
+    }
+}
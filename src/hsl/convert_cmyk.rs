@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::cmyk::CMYK;
+use crate::hsl::HSL;
+use crate::rgb::RGB;
+
+
+/// Convert from `CMYK` to `HSL`
+///
+/// **Note** uses `RGB::from(cmyk)` internally
+impl From<CMYK> for HSL {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::CMYK;
+    /// use color_operators::hsl::HSL;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let cmyk = CMYK::from(rgb.clone());
+    /// let hsl = HSL::from(cmyk);
+    ///
+    /// assert_eq!(RGB::from(hsl), rgb);
+    /// ```
+    fn from(cmyk: CMYK) -> Self {
+        Self::from(RGB::from(cmyk))
+    }
+}
@@ -0,0 +1,26 @@
+#!/usr/bin/env rust
+
+
+use crate::hsl::HSL;
+use crate::hsla::HSLA;
+
+
+/// Convert from `HSLA` to `HSL`
+///
+/// **Note** alpha is discarded
+impl From<HSLA> for HSL {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsl::HSL;
+    /// use color_operators::hsla::HSLA;
+    ///
+    /// let hsl = HSL::from(HSLA::new(60.0, 1.0, 0.5, 0.25));
+    ///
+    /// assert_eq!(hsl, HSL::new(60.0, 1.0, 0.5));
+    /// ```
+    fn from(hsla: HSLA) -> Self {
+        let ( hue, saturation, lightness, _alpha ) = hsla.into();
+        Self::new(hue, saturation, lightness)
+    }
+}
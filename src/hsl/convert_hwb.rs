@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::hsl::HSL;
+use crate::hsv::HSV;
+use crate::hwb::HWB;
+
+
+/// Convert from `HWB` to `HSL`
+///
+/// **Note** uses `HSV::from(hwb)` internally
+impl From<HWB> for HSL {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsl::HSL;
+    /// use color_operators::hwb::HWB;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let hwb = HWB::from(rgb.clone());
+    /// let hsl = HSL::from(hwb);
+    ///
+    /// assert_eq!(RGB::from(hsl), rgb);
+    /// ```
+    fn from(hwb: HWB) -> Self {
+        Self::from(HSV::from(hwb))
+    }
+}
@@ -1,14 +1,46 @@
 #!/usr/bin/env rust
 
 
+use std::convert::TryFrom;
+
+
 extern crate json;
 use json::JsonValue;
 
 
+use crate::error::{ json_f64, Error };
 use crate::hsl::HSL;
 
 
+/// Converts from `JsonValue` key value pares, or error for missing or invalid components
+impl TryFrom<&JsonValue> for HSL {
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::hsl::HSL;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{ "hue": "nope" }"#).unwrap();
+    ///
+    /// assert_eq!(HSL::try_from(&data), Err(Error::InvalidComponent("hue".to_string())));
+    /// ```
+    fn try_from(data: &JsonValue) -> Result<Self, Self::Error> {
+        let hue = json_f64(data, "hue")?;
+        let saturation = json_f64(data, "saturation")?;
+        let lightness = json_f64(data, "lightness")?;
+        Ok(Self::new(hue, saturation, lightness))
+    }
+}
+
+
 /// Converts from `JsonValue` key value pares
+///
+/// **Note** missing or invalid components default individually, where `HSL::try_from` returns
+/// error
 impl From<JsonValue> for HSL {
     /// # Example
     ///
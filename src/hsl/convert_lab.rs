@@ -0,0 +1,30 @@
+#!/usr/bin/env rust
+
+
+use crate::hsl::HSL;
+use crate::lab::Lab;
+use crate::rgb_space::RgbSpace;
+use crate::xyz::XYZ;
+
+
+/// Convert from `Lab` to `HSL`
+///
+/// **Note** uses `RgbSpace::srgb().from_xyz` internally
+impl From<Lab> for HSL {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsl::HSL;
+    /// use color_operators::lab::Lab;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let lab = Lab::from(rgb.clone());
+    /// let hsl = HSL::from(lab);
+    ///
+    /// assert_eq!(hsl, HSL::from(rgb));
+    /// ```
+    fn from(lab: Lab) -> Self {
+        Self::from(RgbSpace::srgb().from_xyz(XYZ::from(lab)))
+    }
+}
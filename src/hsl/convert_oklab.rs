@@ -0,0 +1,30 @@
+#!/usr/bin/env rust
+
+
+use crate::hsl::HSL;
+use crate::oklab::Oklab;
+use crate::rgb_space::RgbSpace;
+use crate::xyz::XYZ;
+
+
+/// Convert from `Oklab` to `HSL`
+///
+/// **Note** uses `RgbSpace::srgb().from_xyz` internally
+impl From<Oklab> for HSL {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsl::HSL;
+    /// use color_operators::oklab::Oklab;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let oklab = Oklab::from(rgb.clone());
+    /// let hsl = HSL::from(oklab);
+    ///
+    /// assert_eq!(RGB::from(hsl), rgb);
+    /// ```
+    fn from(oklab: Oklab) -> Self {
+        Self::from(RgbSpace::srgb().from_xyz(XYZ::from(oklab)))
+    }
+}
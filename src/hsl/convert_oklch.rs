@@ -0,0 +1,30 @@
+#!/usr/bin/env rust
+
+
+use crate::hsl::HSL;
+use crate::oklch::Oklch;
+use crate::rgb_space::RgbSpace;
+use crate::xyz::XYZ;
+
+
+/// Convert from `Oklch` to `HSL`
+///
+/// **Note** uses `RgbSpace::srgb().from_xyz` internally
+impl From<Oklch> for HSL {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsl::HSL;
+    /// use color_operators::oklch::Oklch;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let oklch = Oklch::from(rgb.clone());
+    /// let hsl = HSL::from(oklch);
+    ///
+    /// assert_eq!(RGB::from(hsl), rgb);
+    /// ```
+    fn from(oklch: Oklch) -> Self {
+        Self::from(RgbSpace::srgb().from_xyz(XYZ::from(oklch)))
+    }
+}
@@ -1,10 +1,47 @@
 #!/usr/bin/env rust
 
 
+use std::convert::TryFrom;
+
+
+use crate::error::{ check_length, Error };
 use crate::hsl::HSL;
 
 
+/// Convert from slice of 64-bit precision floating point numbers, or error for invalid length
+impl<T> TryFrom<&[T]> for HSL
+where
+    T: Into<f64> + Copy
+{
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::hsl::HSL;
+    ///
+    /// let hsl = HSL::try_from(&[0.0, 1.0, 0.5823529411764706][..]).unwrap();
+    /// assert_eq!(hsl, HSL::from(vec![0.0, 1.0, 0.5823529411764706]));
+    ///
+    /// let result = HSL::try_from(&[0.0, 1.0][..]);
+    /// assert_eq!(result, Err(Error::Length { expected: 3, found: 2 }));
+    /// ```
+    fn try_from(values: &[T]) -> Result<Self, Self::Error> {
+        check_length(values.len(), 3, 3)?;
+        let hue = values[0].into();
+        let saturation = values[1].into();
+        let lightness = values[2].into();
+        Ok(Self::new(hue, saturation, lightness))
+    }
+}
+
+
 /// Convert from vector of 64-bit precision floating point numbers
+///
+/// **Note** this uses `HSL::try_from` internally, values past the third are ignored, and too few
+/// values return default values
 impl<T> From<Vec<T>> for HSL
 where
     T: Into<f64> + Copy
@@ -22,10 +59,8 @@ where
     /// assert_eq!(hsl.get("lightness"), Ok(0.5823529411764706));
     /// ```
     fn from(vector: Vec<T>) -> Self {
-        let hue = vector[0];
-        let saturation = vector[1];
-        let lightness = vector[2];
-        Self::new(hue, saturation, lightness)
+        let length = vector.len().min(3);
+        Self::try_from(&vector[..length]).unwrap_or_default()
     }
 }
 
@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::hsl::HSL;
+use crate::rgb_space::RgbSpace;
+use crate::xyz::XYZ;
+
+
+/// Convert from `XYZ` to `HSL`
+///
+/// **Note** uses `RgbSpace::srgb().from_xyz` internally
+impl From<XYZ> for HSL {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsl::HSL;
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let xyz = XYZ::from(rgb.clone());
+    /// let hsl = HSL::from(xyz);
+    ///
+    /// assert_eq!(hsl, HSL::from(rgb));
+    /// ```
+    fn from(xyz: XYZ) -> Self {
+        Self::from(RgbSpace::srgb().from_xyz(xyz))
+    }
+}
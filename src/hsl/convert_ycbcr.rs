@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::hsl::HSL;
+use crate::rgb::RGB;
+use crate::ycbcr::YCbCr;
+
+
+/// Convert from `YCbCr` to `HSL`
+///
+/// **Note** uses `RGB::from(ycbcr)` internally
+impl From<YCbCr> for HSL {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsl::HSL;
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::ycbcr::YCbCr;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let ycbcr = YCbCr::from(rgb.clone());
+    /// let hsl = HSL::from(ycbcr);
+    ///
+    /// assert_eq!(RGB::from(hsl), rgb);
+    /// ```
+    fn from(ycbcr: YCbCr) -> Self {
+        Self::from(RGB::from(ycbcr))
+    }
+}
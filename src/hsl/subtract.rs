@@ -41,11 +41,8 @@ impl Sub<Color> for HSL {
     type Output = Self;
 
     fn sub(self, other: Color) -> Self::Output {
-        match other {
-            Color::HSL(v) => self - v,
-            Color::HSV(v) => self - Self::from(v),
-            Color::RGB(v) => self - Self::from(v),
-        }
+        let other: HSL = other.into();
+        self - other
     }
 }
 
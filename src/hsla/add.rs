@@ -0,0 +1,60 @@
+#!/usr/bin/env rust
+
+
+use std::ops::Add;
+
+
+use crate::color::Color;
+use crate::hsl::HSL;
+use crate::hsla::HSLA;
+use crate::rgb::RGB;
+
+
+/// Adds color components
+///
+/// **Note** this uses `RGB::add` internally, and alpha of left side is kept
+impl Add for HSLA {
+    type Output = Self;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsla::HSLA;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let left = HSLA::from(RGBA::new(25, 0, 0, 128));
+    /// let right = HSLA::from(RGBA::new(25, 0, 0, 255));
+    /// let result = left + right;
+    ///
+    /// assert_eq!(RGBA::from(result), RGBA::new(50, 0, 0, 128));
+    /// ```
+    fn add(self, other: Self) -> Self::Output {
+        let alpha = self.alpha;
+        let left = RGB::from(HSL::from(self));
+        let right = RGB::from(HSL::from(other));
+        Self::from_hsl(HSL::from(left + right), alpha)
+    }
+}
+
+
+/// Converts right side from `Color` to `HSLA` prior to arithmetic
+impl Add<Color> for HSLA {
+    type Output = Self;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::hsla::HSLA;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let left = HSLA::from(RGBA::new(25, 0, 0, 128));
+    /// let right = Color::new_rgb(25, 0, 0);
+    ///
+    /// assert_eq!(RGBA::from(left + right), RGBA::new(50, 0, 0, 128));
+    /// ```
+    fn add(self, other: Color) -> Self::Output {
+        let other: HSLA = other.into();
+        self + other
+    }
+}
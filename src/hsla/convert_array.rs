@@ -0,0 +1,53 @@
+#!/usr/bin/env rust
+
+
+use crate::hsla::HSLA;
+
+
+/// Convert from array of 64-bit precision floating point numbers
+impl<T> From<[T; 4]> for HSLA
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsla::HSLA;
+    ///
+    /// let array = [ 60.0, 1.0, 0.5, 0.25 ];
+    /// let hsla = HSLA::from(array);
+    ///
+    /// assert_eq!(hsla.get("hue"), Ok(60.0));
+    /// assert_eq!(hsla.get("saturation"), Ok(1.0));
+    /// assert_eq!(hsla.get("lightness"), Ok(0.5));
+    /// assert_eq!(hsla.get("alpha"), Ok(0.25));
+    /// ```
+    fn from(array: [T; 4]) -> Self {
+        let hue = array[0];
+        let saturation = array[1];
+        let lightness = array[2];
+        let alpha = array[3];
+        Self::new(hue, saturation, lightness, alpha)
+    }
+}
+
+
+/// Convert into array of 64-bit precision floating point numbers
+impl Into<[f64; 4]> for HSLA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsla::HSLA;
+    ///
+    /// let hsla = HSLA::new(60.0, 1.0, 0.5, 0.25);
+    /// let array: [f64; 4] = hsla.into();
+    ///
+    /// assert_eq!(array[0], 60.0);
+    /// assert_eq!(array[1], 1.0);
+    /// assert_eq!(array[2], 0.5);
+    /// assert_eq!(array[3], 0.25);
+    /// ```
+    fn into(self) -> [f64; 4] {
+        [ self.hue, self.saturation, self.lightness, self.alpha ]
+    }
+}
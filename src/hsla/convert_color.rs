@@ -0,0 +1,28 @@
+#!/usr/bin/env rust
+
+
+use crate::hsla::HSLA;
+use crate::color::Color;
+use crate::hsl::HSL;
+
+
+impl From<Color> for HSLA {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::CMYK(v) => Self::from(HSL::from(v)),
+            Color::HSL(v) => Self::from(v),
+            Color::HSLA(v) => v,
+            Color::HSV(v) => Self::from(HSL::from(v)),
+            Color::HSVA(v) => Self::from(v),
+            Color::HWB(v) => Self::from(HSL::from(v)),
+            Color::Lab(v) => Self::from(HSL::from(v)),
+            Color::LCh(v) => Self::from(HSL::from(v)),
+            Color::Oklab(v) => Self::from(HSL::from(v)),
+            Color::Oklch(v) => Self::from(HSL::from(v)),
+            Color::RGB(v) => Self::from(HSL::from(v)),
+            Color::RGBA(v) => Self::from(v),
+            Color::XYZ(v) => Self::from(HSL::from(v)),
+            Color::YCbCr(v) => Self::from(HSL::from(v)),
+        }
+    }
+}
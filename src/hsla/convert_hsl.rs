@@ -0,0 +1,23 @@
+#!/usr/bin/env rust
+
+
+use crate::hsl::HSL;
+use crate::hsla::HSLA;
+
+
+/// Convert from `HSL` to fully opaque `HSLA`
+impl From<HSL> for HSLA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsl::HSL;
+    /// use color_operators::hsla::HSLA;
+    ///
+    /// let hsla = HSLA::from(HSL::new(60.0, 1.0, 0.5));
+    ///
+    /// assert_eq!(hsla, HSLA::new(60.0, 1.0, 0.5, 1.0));
+    /// ```
+    fn from(hsl: HSL) -> Self {
+        Self::from_hsl(hsl, 1.0)
+    }
+}
@@ -0,0 +1,32 @@
+#!/usr/bin/env rust
+
+
+use crate::hsl::HSL;
+use crate::hsla::HSLA;
+use crate::hsv::HSV;
+use crate::hsva::HSVA;
+
+
+/// Convert from `HSVA` to `HSLA`
+///
+/// **Note** uses `HSL::from(hsv)` internally, and alpha is kept
+impl From<HSVA> for HSLA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsla::HSLA;
+    /// use color_operators::hsva::HSVA;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let rgba = RGBA::new(255, 42, 90, 128);
+    /// let hsva = HSVA::from(rgba.clone());
+    /// let hsla = HSLA::from(hsva);
+    ///
+    /// assert_eq!(RGBA::from(hsla), rgba);
+    /// ```
+    fn from(hsva: HSVA) -> Self {
+        let ( hue, saturation, value, alpha ) = hsva.into();
+        let hsl = HSL::from(HSV::new(hue, saturation, value));
+        Self::from_hsl(hsl, alpha)
+    }
+}
@@ -0,0 +1,107 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+extern crate json;
+use json::JsonValue;
+
+
+use crate::error::{ json_f64, Error };
+use crate::hsla::HSLA;
+
+
+/// Converts from `JsonValue` key value pares, or error for missing or invalid components
+///
+/// **Note** missing alpha defaults to fully opaque
+impl TryFrom<&JsonValue> for HSLA {
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::hsla::HSLA;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{ "hue": "nope" }"#).unwrap();
+    ///
+    /// assert_eq!(HSLA::try_from(&data), Err(Error::InvalidComponent("hue".to_string())));
+    /// ```
+    fn try_from(data: &JsonValue) -> Result<Self, Self::Error> {
+        let hue = json_f64(data, "hue")?;
+        let saturation = json_f64(data, "saturation")?;
+        let lightness = json_f64(data, "lightness")?;
+        let alpha = if data["alpha"].is_null() { 1.0 } else { json_f64(data, "alpha")? };
+        Ok(Self::new(hue, saturation, lightness, alpha))
+    }
+}
+
+
+/// Converts from `JsonValue` key value pares
+///
+/// **Note** missing or invalid components default individually, where `HSLA::try_from` returns
+/// error
+impl From<JsonValue> for HSLA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsla::HSLA;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{
+    ///     "hue": 60.0,
+    ///     "saturation": 1.0,
+    ///     "lightness": 0.5,
+    ///     "alpha": 0.25
+    /// }"#).unwrap();
+    ///
+    /// let hsla = HSLA::from(data);
+    ///
+    /// assert_eq!(hsla.get("hue"), Ok(60.0));
+    /// assert_eq!(hsla.get("saturation"), Ok(1.0));
+    /// assert_eq!(hsla.get("lightness"), Ok(0.5));
+    /// assert_eq!(hsla.get("alpha"), Ok(0.25));
+    /// ```
+    fn from(data: JsonValue) -> Self {
+        let hue = data["hue"].as_f64().unwrap_or_default();
+        let saturation = data["saturation"].as_f64().unwrap_or_default();
+        let lightness = data["lightness"].as_f64().unwrap_or_default();
+        let alpha = data["alpha"].as_f64().unwrap_or(1.0);
+        Self::new(hue, saturation, lightness, alpha)
+    }
+}
+
+
+/// Converts to `JsonValue`
+impl Into<JsonValue> for HSLA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsla::HSLA;
+    /// extern crate json;
+    /// use json::JsonValue;
+    ///
+    /// let hsla = HSLA::new(60.0, 1.0, 0.5, 0.25);
+    /// let data: JsonValue = hsla.into();
+    ///
+    /// let expected = json::parse(r#"{
+    ///     "hue": 60.0,
+    ///     "saturation": 1.0,
+    ///     "lightness": 0.5,
+    ///     "alpha": 0.25
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(data, expected);
+    /// ```
+    fn into(self) -> JsonValue {
+        json::object!{
+            "hue" => self.hue,
+            "saturation" => self.saturation,
+            "lightness" => self.lightness,
+            "alpha" => self.alpha,
+        }
+    }
+}
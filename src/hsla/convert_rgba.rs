@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::hsl::HSL;
+use crate::hsla::HSLA;
+use crate::rgb::RGB;
+use crate::rgba::RGBA;
+
+
+/// Convert from `RGBA` to `HSLA`
+///
+/// **Note** uses `HSL::from(rgb)` internally, and alpha is scaled between `0.0` and `1.0`
+impl From<RGBA> for HSLA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsla::HSLA;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let hsla = HSLA::from(RGBA::new(255, 0, 0, 51));
+    ///
+    /// assert_eq!(hsla.get("hue"), Ok(0.0));
+    /// assert_eq!(hsla.get("alpha"), Ok(0.2));
+    /// ```
+    fn from(rgba: RGBA) -> Self {
+        let alpha = rgba.opacity();
+        Self::from_hsl(HSL::from(RGB::from(rgba)), alpha)
+    }
+}
@@ -0,0 +1,50 @@
+#!/usr/bin/env rust
+
+
+use crate::hsla::HSLA;
+
+
+/// Convert from tuple of 64-bit precision floating point numbers
+impl<T> From<(T, T, T, T)> for HSLA
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsla::HSLA;
+    ///
+    /// let tuple = ( 60.0, 1.0, 0.5, 0.25 );
+    /// let hsla = HSLA::from(tuple);
+    ///
+    /// assert_eq!(hsla.get("hue"), Ok(60.0));
+    /// assert_eq!(hsla.get("saturation"), Ok(1.0));
+    /// assert_eq!(hsla.get("lightness"), Ok(0.5));
+    /// assert_eq!(hsla.get("alpha"), Ok(0.25));
+    /// ```
+    fn from(tuple: (T, T, T, T)) -> Self {
+        let ( hue, saturation, lightness, alpha ) = tuple;
+        Self::new(hue, saturation, lightness, alpha)
+    }
+}
+
+
+/// Convert into tuple of 64-bit precision floating point numbers
+impl Into<(f64, f64, f64, f64)> for HSLA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsla::HSLA;
+    ///
+    /// let hsla = HSLA::new(60.0, 1.0, 0.5, 0.25);
+    /// let ( hue, saturation, lightness, alpha ) = hsla.into();
+    ///
+    /// assert_eq!(hue, 60.0);
+    /// assert_eq!(saturation, 1.0);
+    /// assert_eq!(lightness, 0.5);
+    /// assert_eq!(alpha, 0.25);
+    /// ```
+    fn into(self) -> (f64, f64, f64, f64) {
+        ( self.hue, self.saturation, self.lightness, self.alpha )
+    }
+}
@@ -0,0 +1,93 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+use crate::error::{ check_length, Error };
+use crate::hsla::HSLA;
+
+
+/// Convert from slice of 64-bit precision floating point numbers, or error for invalid length
+///
+/// **Note** missing alpha defaults to fully opaque
+impl<T> TryFrom<&[T]> for HSLA
+where
+    T: Into<f64> + Copy
+{
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::hsla::HSLA;
+    ///
+    /// let hsla = HSLA::try_from(&[60.0, 1.0, 0.5, 0.25][..]).unwrap();
+    /// assert_eq!(hsla, HSLA::from(vec![60.0, 1.0, 0.5, 0.25]));
+    ///
+    /// let result = HSLA::try_from(&[60.0, 1.0][..]);
+    /// assert_eq!(result, Err(Error::Length { expected: 4, found: 2 }));
+    /// ```
+    fn try_from(values: &[T]) -> Result<Self, Self::Error> {
+        check_length(values.len(), 3, 4)?;
+        let hue = values[0].into();
+        let saturation = values[1].into();
+        let lightness = values[2].into();
+        let alpha = match values.get(3) {
+            Some(v) => (*v).into(),
+            None => 1.0,
+        };
+        Ok(Self::new(hue, saturation, lightness, alpha))
+    }
+}
+
+
+/// Convert from vector of 64-bit precision floating point numbers
+///
+/// **Note** this uses `HSLA::try_from` internally, values past the fourth are ignored, and too few
+/// values return default values
+impl<T> From<Vec<T>> for HSLA
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsla::HSLA;
+    ///
+    /// let vector = vec![60.0, 1.0, 0.5, 0.25];
+    /// let hsla = HSLA::from(vector);
+    ///
+    /// assert_eq!(hsla.get("hue"), Ok(60.0));
+    /// assert_eq!(hsla.get("saturation"), Ok(1.0));
+    /// assert_eq!(hsla.get("lightness"), Ok(0.5));
+    /// assert_eq!(hsla.get("alpha"), Ok(0.25));
+    /// ```
+    fn from(vector: Vec<T>) -> Self {
+        let length = vector.len().min(4);
+        Self::try_from(&vector[..length]).unwrap_or_default()
+    }
+}
+
+
+/// Convert into vector of 64-bit precision floating point numbers
+impl Into<Vec<f64>> for HSLA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsla::HSLA;
+    ///
+    /// let hsla = HSLA::new(60.0, 1.0, 0.5, 0.25);
+    /// let vector: Vec<f64> = hsla.into();
+    ///
+    /// assert_eq!(vector[0], 60.0);
+    /// assert_eq!(vector[1], 1.0);
+    /// assert_eq!(vector[2], 0.5);
+    /// assert_eq!(vector[3], 0.25);
+    /// ```
+    fn into(self) -> Vec<f64> {
+        vec![ self.hue, self.saturation, self.lightness, self.alpha ]
+    }
+}
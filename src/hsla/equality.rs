@@ -0,0 +1,55 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::color::equality::alpha_eq;
+use crate::hsla::HSLA;
+
+
+impl Eq for HSLA {}
+impl PartialEq for HSLA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsla::HSLA;
+    ///
+    /// let left = HSLA::new(60.0, 1.0, 0.5, 0.25);
+    /// let right = HSLA::new(60.0, 1.0, 0.5, 0.25);
+    ///
+    /// assert_eq!(left, right);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        if self.hue != other.hue || self.saturation != other.saturation || self.lightness != other.lightness || self.alpha != other.alpha {
+            return false;
+        }
+        true
+    }
+}
+
+
+/// Converts right side from `Color` to `HSLA` prior to checking equality
+///
+/// **Note** colors without alpha are only equal to fully opaque colors
+impl PartialEq<Color> for HSLA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::hsla::HSLA;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let rgba = RGBA::new(255, 42, 90, 128);
+    /// let hsla = HSLA::from(rgba.clone());
+    ///
+    /// assert!(hsla == Color::from(rgba));
+    /// assert!(hsla != Color::new_rgb(255, 42, 90));
+    /// ```
+    fn eq(&self, other: &Color) -> bool {
+        if let Some(equal) = alpha_eq(&Color::from(self.clone()), other) {
+            return equal;
+        }
+
+        let other: HSLA = other.clone().into();
+        self == &other
+    }
+}
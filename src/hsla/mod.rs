@@ -0,0 +1,309 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+
+use crate::blend::BlendMode;
+use crate::css::Options;
+use crate::css::serialize;
+use crate::error::Error;
+use crate::hsl::HSL;
+use crate::rgba::RGBA;
+
+
+/// Adds color components for `HSLA` data structures
+mod add;
+
+/// Subtracts color components for `HSLA` data structures
+mod subtract;
+
+/// Equal and not-equal checks for `HSLA` data structures
+mod equality;
+
+/// Converts from/to array for `HSLA` data structures
+mod convert_array;
+
+/// Converts from/to `Color` enum
+mod convert_color;
+
+/// Converts from `HSL` to `HSLA` data structure
+mod convert_hsl;
+
+/// Converts from `HSVA` to `HSLA` data structure
+mod convert_hsva;
+
+/// Converts from/to `JsonValue` for `HSLA` data structures
+mod convert_json_value;
+
+/// Converts from `RGBA` to `HSLA` data structure
+mod convert_rgba;
+
+/// Converts from/to tuple for `HSLA` data structures
+mod convert_tuple;
+
+/// Converts from/to vector for `HSLA` data structures
+mod convert_vector;
+
+
+/// Data structure for Hue, Saturation, Lightness, Alpha encoded colors
+///
+/// **Note** alpha of `0.0` is fully transparent and `1.0` is fully opaque
+#[derive(Clone, Debug)]
+pub struct HSLA {
+    hue: f64,
+    saturation: f64,
+    lightness: f64,
+    alpha: f64,
+}
+
+
+/// Defaults to fully opaque black
+impl Default for HSLA {
+    fn default() -> Self {
+        Self { hue: 0.0, saturation: 0.0, lightness: 0.0, alpha: 1.0 }
+    }
+}
+
+
+impl HSLA {
+    /// Returns new instance of `HSLA` data structure
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsla::HSLA;
+    ///
+    /// let color = HSLA::new(60.0, 1.0, 0.5, 0.25);
+    ///
+    /// assert_eq!(color.get("hue"), Ok(60.0));
+    /// assert_eq!(color.get("saturation"), Ok(1.0));
+    /// assert_eq!(color.get("lightness"), Ok(0.5));
+    /// assert_eq!(color.get("alpha"), Ok(0.25));
+    /// ```
+    pub fn new<T>(hue: T, saturation: T, lightness: T, alpha: T) -> Self
+    where
+        T: Into<f64>
+    {
+        let hue: f64 = hue.into().min(360.0).max(0.0);
+        let saturation: f64 = saturation.into().min(1.0).max(0.0);
+        let lightness: f64 = lightness.into().min(1.0).max(0.0);
+        let alpha: f64 = alpha.into().min(1.0).max(0.0);
+        Self { hue, saturation, lightness, alpha }
+    }
+
+    /// Returns new instance of `HSLA` from `HSL` values and alpha
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsl::HSL;
+    /// use color_operators::hsla::HSLA;
+    ///
+    /// let hsla = HSLA::from_hsl(HSL::new(60.0, 1.0, 0.5), 0.25);
+    ///
+    /// assert_eq!(hsla, HSLA::new(60.0, 1.0, 0.5, 0.25));
+    /// ```
+    pub fn from_hsl<T>(hsl: HSL, alpha: T) -> Self
+    where
+        T: Into<f64>
+    {
+        let ( hue, saturation, lightness ) = hsl.into();
+        Self::new(hue, saturation, lightness, alpha.into())
+    }
+
+    /// Returns named component value or error
+    ///
+    /// ```rust
+    /// use color_operators::hsla::HSLA;
+    ///
+    /// let color = HSLA::new(60.0, 1.0, 0.5, 0.25);
+    ///
+    /// assert_eq!(color.get("nothing"), Err("No color component named -> nothing".to_string()));
+    /// ```
+    pub fn get<S>(&self, component: S) -> Result<f64, String>
+    where
+        S: Into<String>
+    {
+        let component: String = component.into();
+        match component.as_str() {
+            "hue" => Ok(self.hue),
+            "saturation" => Ok(self.saturation),
+            "lightness" => Ok(self.lightness),
+            "alpha" => Ok(self.alpha),
+            _ => Err(format!("No color component named -> {}", component)),
+        }
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or default values
+    ///
+    /// **Note** missing or invalid components default individually, see `From<JsonValue>`
+    ///
+    /// **Note** missing alpha defaults to fully opaque
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsla::HSLA;
+    ///
+    /// let hsla = HSLA::from_json_string(r#"{ "hue": 60.0, "saturation": 1.0, "lightness": 0.5, "alpha": 0.25 }"#);
+    ///
+    /// assert_eq!(hsla.get("hue"), Ok(60.0));
+    /// assert_eq!(hsla.get("saturation"), Ok(1.0));
+    /// assert_eq!(hsla.get("lightness"), Ok(0.5));
+    /// assert_eq!(hsla.get("alpha"), Ok(0.25));
+    /// ```
+    pub fn from_json_string<S>(string: S) -> Self
+    where
+        S: Into<String>
+    {
+        json::parse(&string.into()).map(Self::from).unwrap_or_default()
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or error for invalid JSON and missing
+    /// or invalid components
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::error::Error;
+    /// use color_operators::hsla::HSLA;
+    ///
+    /// let result = HSLA::try_from_json_str(r#"{ "hue": 1 }"#);
+    ///
+    /// assert_eq!(result, Err(Error::MissingComponent("saturation".to_string())));
+    /// ```
+    pub fn try_from_json_str(string: &str) -> Result<Self, Error> {
+        let data = json::parse(string)?;
+        Self::try_from(&data)
+    }
+
+    /// Serializes data structure as JSON string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsla::HSLA;
+    ///
+    /// let hsla = HSLA::new(60.0, 1.0, 0.5, 0.25);
+    /// let data = hsla.to_json_string();
+    ///
+    /// let object = json::object!{
+    ///     "hue" => 60.0,
+    ///     "saturation" => 1.0,
+    ///     "lightness" => 0.5,
+    ///     "alpha" => 0.25
+    /// };
+    ///
+    /// let expected = json::stringify(object);
+    ///
+    /// assert_eq!(data, expected);
+    /// ```
+    pub fn to_json_string(&self) -> String {
+        json::stringify(self.clone())
+    }
+
+    /// Converts hexadecimal string, `RRGGBBAA` or `RRGGBB`, into `HSLA`
+    ///
+    /// **Note** this method uses `RGBA::from_hex_string` internally, which returns default values
+    /// for invalid input
+    pub fn from_hex_string<S>(input: S) -> Self
+    where
+        S: Into<String>
+    {
+        Self::from(RGBA::from_hex_string(input))
+    }
+
+    /// Converts hexadecimal string into `HSLA`, or returns error for invalid input
+    ///
+    /// **Note** this method uses `RGBA::try_from_hex_string` internally
+    pub fn try_from_hex_string<S>(input: S) -> Result<Self, Error>
+    where
+        S: Into<String>
+    {
+        RGBA::try_from_hex_string(input).map(Self::from)
+    }
+
+    /// Returns hexadecimal string representation of `HSLA` values
+    ///
+    /// **Note** this method uses `RGBA::to_hex_string` internally
+    pub fn to_hex_string(&self) -> String {
+        RGBA::from(self.clone()).to_hex_string()
+    }
+
+    /// Returns CSS representation of `HSLA` values
+    ///
+    /// **Note** hexadecimal and named colors are converted via `RGBA`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::css::{ Options, Syntax };
+    /// use color_operators::hsla::HSLA;
+    ///
+    /// let color = HSLA::new(120.0, 1.0, 0.25, 0.5);
+    ///
+    /// assert_eq!(color.to_css_string(Options::new()), "hsl(120 100 25 / 0.5)");
+    /// assert_eq!(color.to_css_string(Options::new().syntax(Syntax::Legacy)), "hsla(120, 100%, 25%, 0.5)");
+    /// ```
+    pub fn to_css_string(&self, options: Options) -> String {
+        let ( red, green, blue, _alpha ) = RGBA::from(self.clone()).into();
+        if let Some(css) = serialize::keyword_or_hex(red, green, blue, Some(self.alpha), options) {
+            return css;
+        }
+        serialize::hsl(self.hue, self.saturation, self.lightness, Some(self.alpha), options)
+    }
+
+    /// Attempts to rotate hue by some amount of degrees
+    ///
+    /// **Note** this method uses `HSL::rotate_hue` internally, and alpha is kept
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsla::HSLA;
+    ///
+    /// let color = HSLA::new(120.0, 0.5, 1.0, 0.25);
+    /// let rotated = color.rotate_hue(90.0);
+    ///
+    /// assert_eq!(rotated, HSLA::new(210.0, 0.5, 1.0, 0.25));
+    /// ```
+    pub fn rotate_hue<T>(&self, amount: T) -> Self
+    where
+        T: Into<f64>
+    {
+        let hsl = HSL::from(self.clone());
+        Self::from_hsl(hsl.rotate_hue(amount), self.alpha)
+    }
+
+    /// Attempts to rotate this color with another `HSLA` value
+    ///
+    /// **Note** this method uses `RGBA::rotate_rgb` internally, and alpha of left side is kept
+    pub fn rotate_rgb(&self, other: Self) -> Self {
+        let left = RGBA::from(self.clone());
+        let right = RGBA::from(other);
+        Self::from(left.rotate_rgb(right))
+    }
+
+    /// Blends source color into this backdrop color, then composites result over backdrop by alpha
+    /// of source
+    ///
+    /// **Note** this method uses `RGBA::blend` internally
+    pub fn blend(&self, source: Self, mode: BlendMode) -> Self {
+        let alpha = source.alpha + self.alpha * (1.0 - source.alpha);
+        let backdrop = RGBA::from(self.clone());
+        let source = RGBA::from(source);
+        let mut result = Self::from(backdrop.blend(source, mode));
+        result.alpha = alpha;
+        result
+    }
+}
+
+
+impl Display for HSLA {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "hue: {}, saturation: {}, lightness: {}, alpha: {}", self.hue, self.saturation, self.lightness, self.alpha)
+    }
+}
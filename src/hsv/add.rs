@@ -41,11 +41,8 @@ impl Add<Color> for HSV {
     type Output = Self;
 
     fn add(self, other: Color) -> Self::Output {
-        match other {
-            Color::HSL(v) => self + Self::from(v),
-            Color::HSV(v) => self + v,
-            Color::RGB(v) => self + Self::from(v),
-        }
+        let other: HSV = other.into();
+        self + other
     }
 }
 
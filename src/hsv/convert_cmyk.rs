@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::cmyk::CMYK;
+use crate::hsv::HSV;
+use crate::rgb::RGB;
+
+
+/// Convert from `CMYK` to `HSV`
+///
+/// **Note** uses `RGB::from(cmyk)` internally
+impl From<CMYK> for HSV {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::CMYK;
+    /// use color_operators::hsv::HSV;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let cmyk = CMYK::from(rgb.clone());
+    /// let hsv = HSV::from(cmyk);
+    ///
+    /// assert_eq!(RGB::from(hsv), rgb);
+    /// ```
+    fn from(cmyk: CMYK) -> Self {
+        Self::from(RGB::from(cmyk))
+    }
+}
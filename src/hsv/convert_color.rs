@@ -3,14 +3,27 @@
 
 use crate::hsv::HSV;
 use crate::color::Color;
+use crate::hsl::HSL;
+use crate::rgb::RGB;
 
 
 impl From<Color> for HSV {
     fn from(color: Color) -> Self {
         match color {
+            Color::CMYK(v) => Self::from(v),
             Color::HSL(v) => Self::from(v),
+            Color::HSLA(v) => Self::from(HSL::from(v)),
             Color::HSV(v) => v,
+            Color::HSVA(v) => Self::from(v),
+            Color::HWB(v) => Self::from(v),
+            Color::Lab(v) => Self::from(v),
+            Color::LCh(v) => Self::from(v),
+            Color::Oklab(v) => Self::from(v),
+            Color::Oklch(v) => Self::from(v),
             Color::RGB(v) => Self::from(v),
+            Color::RGBA(v) => Self::from(RGB::from(v)),
+            Color::XYZ(v) => Self::from(v),
+            Color::YCbCr(v) => Self::from(v),
         }
     }
 }
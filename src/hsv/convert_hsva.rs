@@ -0,0 +1,26 @@
+#!/usr/bin/env rust
+
+
+use crate::hsv::HSV;
+use crate::hsva::HSVA;
+
+
+/// Convert from `HSVA` to `HSV`
+///
+/// **Note** alpha is discarded
+impl From<HSVA> for HSV {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsv::HSV;
+    /// use color_operators::hsva::HSVA;
+    ///
+    /// let hsv = HSV::from(HSVA::new(60.0, 1.0, 1.0, 0.25));
+    ///
+    /// assert_eq!(hsv, HSV::new(60.0, 1.0, 1.0));
+    /// ```
+    fn from(hsva: HSVA) -> Self {
+        let ( hue, saturation, value, _alpha ) = hsva.into();
+        Self::new(hue, saturation, value)
+    }
+}
@@ -0,0 +1,37 @@
+#!/usr/bin/env rust
+
+
+use crate::hsv::HSV;
+use crate::hwb::HWB;
+
+
+/// Convert from `HWB` to `HSV`
+///
+/// **Note** floating point to unsigned integer conversions may be inaccurate due to binary to/from
+/// decimal conversions
+impl From<HWB> for HSV {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsv::HSV;
+    /// use color_operators::hwb::HWB;
+    ///
+    /// let hwb = HWB::new(120.0, 0.25, 0.5);
+    /// let hsv = HSV::from(hwb);
+    ///
+    /// assert_eq!(hsv.get("hue"), Ok(120.0));
+    /// assert_eq!(hsv.get("saturation"), Ok(0.5));
+    /// assert_eq!(hsv.get("value"), Ok(0.5));
+    /// ```
+    fn from(hwb: HWB) -> Self {
+        let ( hue, whiteness, blackness ) = hwb.into();
+
+        let value = 1.0 - blackness;
+
+        let mut saturation = 0.0;
+        if value != 0.0 {
+            saturation = 1.0 - (whiteness / value);
+        }
+        Self { hue, saturation, value }
+    }
+}
@@ -0,0 +1,30 @@
+#!/usr/bin/env rust
+
+
+use crate::hsv::HSV;
+use crate::lab::Lab;
+use crate::rgb_space::RgbSpace;
+use crate::xyz::XYZ;
+
+
+/// Convert from `Lab` to `HSV`
+///
+/// **Note** uses `RgbSpace::srgb().from_xyz` internally
+impl From<Lab> for HSV {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsv::HSV;
+    /// use color_operators::lab::Lab;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let lab = Lab::from(rgb.clone());
+    /// let hsv = HSV::from(lab);
+    ///
+    /// assert_eq!(hsv, HSV::from(rgb));
+    /// ```
+    fn from(lab: Lab) -> Self {
+        Self::from(RgbSpace::srgb().from_xyz(XYZ::from(lab)))
+    }
+}
@@ -0,0 +1,30 @@
+#!/usr/bin/env rust
+
+
+use crate::hsv::HSV;
+use crate::lch::LCh;
+use crate::rgb_space::RgbSpace;
+use crate::xyz::XYZ;
+
+
+/// Convert from `LCh` to `HSV`
+///
+/// **Note** uses `RgbSpace::srgb().from_xyz` internally
+impl From<LCh> for HSV {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsv::HSV;
+    /// use color_operators::lch::LCh;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let lch = LCh::from(rgb.clone());
+    /// let hsv = HSV::from(lch);
+    ///
+    /// assert_eq!(hsv, HSV::from(rgb));
+    /// ```
+    fn from(lch: LCh) -> Self {
+        Self::from(RgbSpace::srgb().from_xyz(XYZ::from(lch)))
+    }
+}
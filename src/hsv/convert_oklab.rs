@@ -0,0 +1,30 @@
+#!/usr/bin/env rust
+
+
+use crate::hsv::HSV;
+use crate::oklab::Oklab;
+use crate::rgb_space::RgbSpace;
+use crate::xyz::XYZ;
+
+
+/// Convert from `Oklab` to `HSV`
+///
+/// **Note** uses `RgbSpace::srgb().from_xyz` internally
+impl From<Oklab> for HSV {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsv::HSV;
+    /// use color_operators::oklab::Oklab;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let oklab = Oklab::from(rgb.clone());
+    /// let hsv = HSV::from(oklab);
+    ///
+    /// assert_eq!(RGB::from(hsv), rgb);
+    /// ```
+    fn from(oklab: Oklab) -> Self {
+        Self::from(RgbSpace::srgb().from_xyz(XYZ::from(oklab)))
+    }
+}
@@ -0,0 +1,30 @@
+#!/usr/bin/env rust
+
+
+use crate::hsv::HSV;
+use crate::oklch::Oklch;
+use crate::rgb_space::RgbSpace;
+use crate::xyz::XYZ;
+
+
+/// Convert from `Oklch` to `HSV`
+///
+/// **Note** uses `RgbSpace::srgb().from_xyz` internally
+impl From<Oklch> for HSV {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsv::HSV;
+    /// use color_operators::oklch::Oklch;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let oklch = Oklch::from(rgb.clone());
+    /// let hsv = HSV::from(oklch);
+    ///
+    /// assert_eq!(RGB::from(hsv), rgb);
+    /// ```
+    fn from(oklch: Oklch) -> Self {
+        Self::from(RgbSpace::srgb().from_xyz(XYZ::from(oklch)))
+    }
+}
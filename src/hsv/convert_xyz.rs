@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::hsv::HSV;
+use crate::rgb_space::RgbSpace;
+use crate::xyz::XYZ;
+
+
+/// Convert from `XYZ` to `HSV`
+///
+/// **Note** uses `RgbSpace::srgb().from_xyz` internally
+impl From<XYZ> for HSV {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsv::HSV;
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let xyz = XYZ::from(rgb.clone());
+    /// let hsv = HSV::from(xyz);
+    ///
+    /// assert_eq!(hsv, HSV::from(rgb));
+    /// ```
+    fn from(xyz: XYZ) -> Self {
+        Self::from(RgbSpace::srgb().from_xyz(xyz))
+    }
+}
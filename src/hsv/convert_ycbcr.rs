@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::hsv::HSV;
+use crate::rgb::RGB;
+use crate::ycbcr::YCbCr;
+
+
+/// Convert from `YCbCr` to `HSV`
+///
+/// **Note** uses `RGB::from(ycbcr)` internally
+impl From<YCbCr> for HSV {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsv::HSV;
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::ycbcr::YCbCr;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let ycbcr = YCbCr::from(rgb.clone());
+    /// let hsv = HSV::from(ycbcr);
+    ///
+    /// assert_eq!(RGB::from(hsv), rgb);
+    /// ```
+    fn from(ycbcr: YCbCr) -> Self {
+        Self::from(RGB::from(ycbcr))
+    }
+}
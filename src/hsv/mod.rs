@@ -1,10 +1,15 @@
 #!/usr/bin/env rust
 
 
+use std::convert::TryFrom;
 use std::fmt;
 use std::fmt::{Display, Formatter};
 
 
+use crate::blend::BlendMode;
+use crate::css::Options;
+use crate::css::serialize;
+use crate::error::Error;
 use crate::rgb::RGB;
 
 
@@ -20,6 +25,9 @@ mod equality;
 /// Converts from/to array for `HSV` data structures
 mod convert_array;
 
+/// Converts from `CMYK` to `HSV` data structure
+mod convert_cmyk;
+
 /// Converts from/to `Color` enum
 mod convert_color;
 
@@ -29,6 +37,24 @@ mod convert_json_value;
 /// Converts from `HSL` to `HSV` data structure
 mod convert_hsl;
 
+/// Converts from `HSVA` to `HSV` data structure
+mod convert_hsva;
+
+/// Converts from `HWB` to `HSV` data structure
+mod convert_hwb;
+
+/// Converts from `Lab` to `HSV` data structure
+mod convert_lab;
+
+/// Converts from `LCh` to `HSV` data structure
+mod convert_lch;
+
+/// Converts from `Oklab` to `HSV` data structure
+mod convert_oklab;
+
+/// Converts from `Oklch` to `HSV` data structure
+mod convert_oklch;
+
 /// Converts from `RGB` to `HSV` data structure
 mod convert_rgb;
 
@@ -38,6 +64,12 @@ mod convert_tuple;
 /// Converts from/to vector for `HSV` data structures
 mod convert_vector;
 
+/// Converts from `XYZ` to `HSV` data structure
+mod convert_xyz;
+
+/// Converts from `YCbCr` to `HSV` data structure
+mod convert_ycbcr;
+
 
 /// Data structure for Hue, Saturation, Value encoded colors
 #[derive(Clone, Debug, Default)]
@@ -51,6 +83,8 @@ pub struct HSV {
 impl HSV {
     /// Returns new instance of `HSV` data structure
     ///
+    /// **Note** out of range values are clamped, see `HSV::try_new` to detect them instead
+    ///
     /// # Example
     ///
     /// ```rust
@@ -72,6 +106,32 @@ impl HSV {
         Self { hue, saturation, value }
     }
 
+    /// Returns new instance of `HSV` data structure, or error naming component outside of hue
+    /// `0.0` to `360.0`, or value and saturation `0.0` to `1.0`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::error::Error;
+    /// use color_operators::hsv::HSV;
+    ///
+    /// assert_eq!(HSV::try_new(60.0, 1.0, 0.5), Ok(HSV::new(60.0, 1.0, 0.5)));
+    /// assert_eq!(HSV::try_new(60.0, 1.2, 0.5), Err(Error::InvalidComponent("saturation".to_string())));
+    /// ```
+    pub fn try_new<T>(hue: T, saturation: T, value: T) -> Result<Self, Error>
+    where
+        T: Into<f64>
+    {
+        let components = [ ("hue", hue.into(), 360.0), ("saturation", saturation.into(), 1.0), ("value", value.into(), 1.0) ];
+        for (name, value, max) in components.iter() {
+            if !(0.0..=*max).contains(value) {
+                return Err(Error::InvalidComponent(name.to_string()));
+            }
+        }
+        let [ (_, hue, _), (_, saturation, _), (_, value, _) ] = components;
+        Ok(Self { hue, saturation, value })
+    }
+
     /// Returns named component value or error
     ///
     /// ```rust
@@ -94,7 +154,9 @@ impl HSV {
         }
     }
 
-    /// Returns parsed JSON string for color key/value pares, or defaults values
+    /// Returns parsed JSON string for color key/value pares, or default values
+    ///
+    /// **Note** missing or invalid components default individually, see `From<JsonValue>`
     ///
     /// # Example
     ///
@@ -111,13 +173,25 @@ impl HSV {
     where
         S: Into<String>
     {
-        match json::parse(&string.into()) {
-            Ok(data) => Self::from(data),
-            Err(e) => {
-                println!("Warning: ignoring error -> {:?}", e);
-                Self { hue: 0.0, saturation: 0.0, value: 0.0 }
-            }
-        }
+        json::parse(&string.into()).map(Self::from).unwrap_or_default()
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or error for invalid JSON and missing
+    /// or invalid components
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::error::Error;
+    /// use color_operators::hsv::HSV;
+    ///
+    /// let result = HSV::try_from_json_str(r#"{ "hue": 1 }"#);
+    ///
+    /// assert_eq!(result, Err(Error::MissingComponent("saturation".to_string())));
+    /// ```
+    pub fn try_from_json_str(string: &str) -> Result<Self, Error> {
+        let data = json::parse(string)?;
+        Self::try_from(&data)
     }
 
     /// Serializes data structure as JSON string
@@ -146,7 +220,8 @@ impl HSV {
 
     /// Converts hexadecimal string into `HSV`
     ///
-    /// **Warning** this method uses `RGB::from_hex_string` and may panic
+    /// **Note** this method uses `RGB::from_hex_string` internally, which returns default values
+    /// for invalid input
     pub fn from_hex_string<S>(input: S) -> Self
     where
         S: Into<String>
@@ -154,6 +229,16 @@ impl HSV {
         Self::from(RGB::from_hex_string(input))
     }
 
+    /// Converts hexadecimal string into `HSV`, or returns error for invalid input
+    ///
+    /// **Note** this method uses `RGB::try_from_hex_string` internally
+    pub fn try_from_hex_string<S>(input: S) -> Result<Self, Error>
+    where
+        S: Into<String>
+    {
+        RGB::try_from_hex_string(input).map(Self::from)
+    }
+
     /// Returns hexadecimal string representation of `HSV` values
     ///
     /// **Note** this method uses `RGB::to_hex_string` internally
@@ -161,6 +246,30 @@ impl HSV {
         RGB::from(self.clone()).to_hex_string()
     }
 
+    /// Returns CSS representation of `HSV` values
+    ///
+    /// **Note** CSS has no `hsv()` function, so modern syntax is written as `hwb()` and legacy syntax
+    /// as `hsl()`, while hexadecimal and named colors are converted via `RGB`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::css::{ Options, Syntax };
+    /// use color_operators::hsv::HSV;
+    ///
+    /// let color = HSV::new(120.0, 0.5, 1.0);
+    ///
+    /// assert_eq!(color.to_css_string(Options::new()), "hwb(120 50 0)");
+    /// assert_eq!(color.to_css_string(Options::new().syntax(Syntax::Legacy)), "hsl(120, 100%, 75%)");
+    /// ```
+    pub fn to_css_string(&self, options: Options) -> String {
+        let ( red, green, blue ) = RGB::from(self.clone()).into();
+        if let Some(css) = serialize::keyword_or_hex(red, green, blue, None, options) {
+            return css;
+        }
+        serialize::hsv(self.hue, self.saturation, self.value, options)
+    }
+
     /// Attempts to rotate hue by some amount of degrees
     ///
     /// # Examples
@@ -221,6 +330,59 @@ impl HSV {
         let sum = rgb_left.rotate_rgb(rgb_right);
         Self::from(sum)
     }
+
+    /// Blends source color into this backdrop color
+    ///
+    /// **Note** this method uses `BlendMode::apply` internally
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::blend::BlendMode;
+    /// use color_operators::hsv::HSV;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let backdrop = HSV::from(RGB::new(255, 0, 0));
+    /// let source = HSV::from(RGB::new(0, 0, 255));
+    ///
+    /// assert_eq!(backdrop.blend(source, BlendMode::Lighten), HSV::from(RGB::new(255, 0, 255)));
+    /// ```
+    pub fn blend(&self, source: Self, mode: BlendMode) -> Self {
+        let backdrop: RGB = self.clone().into();
+        let source: RGB = source.into();
+        Self::from(mode.apply(backdrop, source))
+    }
+
+    /// Returns WCAG 2 relative luminance between `0.0` and `1.0`
+    ///
+    /// **Note** this method uses `RGB::relative_luminance` internally
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsv::HSV;
+    ///
+    /// assert_eq!(HSV::new(0.0, 0.0, 1.0).relative_luminance(), 1.0);
+    /// ```
+    pub fn relative_luminance(&self) -> f64 {
+        RGB::from(self.clone()).relative_luminance()
+    }
+
+    /// Returns WCAG 2 contrast ratio, between `1.0` and `21.0`, with other color
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsv::HSV;
+    ///
+    /// assert_eq!(HSV::new(0.0, 0.0, 1.0).contrast_ratio(&HSV::new(0.0, 0.0, 0.0)), 21.0);
+    /// ```
+    pub fn contrast_ratio<T>(&self, other: &T) -> f64
+    where
+        T: Clone + Into<RGB>
+    {
+        RGB::from(self.clone()).contrast_ratio(other)
+    }
 }
 
 
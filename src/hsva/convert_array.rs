@@ -0,0 +1,53 @@
+#!/usr/bin/env rust
+
+
+use crate::hsva::HSVA;
+
+
+/// Convert from array of 64-bit precision floating point numbers
+impl<T> From<[T; 4]> for HSVA
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsva::HSVA;
+    ///
+    /// let array = [ 60.0, 1.0, 0.5, 0.25 ];
+    /// let hsva = HSVA::from(array);
+    ///
+    /// assert_eq!(hsva.get("hue"), Ok(60.0));
+    /// assert_eq!(hsva.get("saturation"), Ok(1.0));
+    /// assert_eq!(hsva.get("value"), Ok(0.5));
+    /// assert_eq!(hsva.get("alpha"), Ok(0.25));
+    /// ```
+    fn from(array: [T; 4]) -> Self {
+        let hue = array[0];
+        let saturation = array[1];
+        let value = array[2];
+        let alpha = array[3];
+        Self::new(hue, saturation, value, alpha)
+    }
+}
+
+
+/// Convert into array of 64-bit precision floating point numbers
+impl Into<[f64; 4]> for HSVA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsva::HSVA;
+    ///
+    /// let hsva = HSVA::new(60.0, 1.0, 0.5, 0.25);
+    /// let array: [f64; 4] = hsva.into();
+    ///
+    /// assert_eq!(array[0], 60.0);
+    /// assert_eq!(array[1], 1.0);
+    /// assert_eq!(array[2], 0.5);
+    /// assert_eq!(array[3], 0.25);
+    /// ```
+    fn into(self) -> [f64; 4] {
+        [ self.hue, self.saturation, self.value, self.alpha ]
+    }
+}
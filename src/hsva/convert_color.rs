@@ -0,0 +1,28 @@
+#!/usr/bin/env rust
+
+
+use crate::hsva::HSVA;
+use crate::color::Color;
+use crate::hsv::HSV;
+
+
+impl From<Color> for HSVA {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::CMYK(v) => Self::from(HSV::from(v)),
+            Color::HSL(v) => Self::from(HSV::from(v)),
+            Color::HSLA(v) => Self::from(v),
+            Color::HSV(v) => Self::from(v),
+            Color::HSVA(v) => v,
+            Color::HWB(v) => Self::from(HSV::from(v)),
+            Color::Lab(v) => Self::from(HSV::from(v)),
+            Color::LCh(v) => Self::from(HSV::from(v)),
+            Color::Oklab(v) => Self::from(HSV::from(v)),
+            Color::Oklch(v) => Self::from(HSV::from(v)),
+            Color::RGB(v) => Self::from(HSV::from(v)),
+            Color::RGBA(v) => Self::from(v),
+            Color::XYZ(v) => Self::from(HSV::from(v)),
+            Color::YCbCr(v) => Self::from(HSV::from(v)),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+#!/usr/bin/env rust
+
+
+use crate::hsv::HSV;
+use crate::hsva::HSVA;
+use crate::hsl::HSL;
+use crate::hsla::HSLA;
+
+
+/// Convert from `HSLA` to `HSVA`
+///
+/// **Note** uses `HSV::from(hsl)` internally, and alpha is kept
+impl From<HSLA> for HSVA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsva::HSVA;
+    /// use color_operators::hsla::HSLA;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let rgba = RGBA::new(255, 42, 90, 128);
+    /// let hsla = HSLA::from(rgba.clone());
+    /// let hsva = HSVA::from(hsla);
+    ///
+    /// assert_eq!(RGBA::from(hsva), rgba);
+    /// ```
+    fn from(hsla: HSLA) -> Self {
+        let ( hue, saturation, lightness, alpha ) = hsla.into();
+        let hsv = HSV::from(HSL::new(hue, saturation, lightness));
+        Self::from_hsv(hsv, alpha)
+    }
+}
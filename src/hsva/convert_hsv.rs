@@ -0,0 +1,23 @@
+#!/usr/bin/env rust
+
+
+use crate::hsv::HSV;
+use crate::hsva::HSVA;
+
+
+/// Convert from `HSV` to fully opaque `HSVA`
+impl From<HSV> for HSVA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsv::HSV;
+    /// use color_operators::hsva::HSVA;
+    ///
+    /// let hsva = HSVA::from(HSV::new(60.0, 1.0, 1.0));
+    ///
+    /// assert_eq!(hsva, HSVA::new(60.0, 1.0, 1.0, 1.0));
+    /// ```
+    fn from(hsv: HSV) -> Self {
+        Self::from_hsv(hsv, 1.0)
+    }
+}
@@ -0,0 +1,107 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+extern crate json;
+use json::JsonValue;
+
+
+use crate::error::{ json_f64, Error };
+use crate::hsva::HSVA;
+
+
+/// Converts from `JsonValue` key value pares, or error for missing or invalid components
+///
+/// **Note** missing alpha defaults to fully opaque
+impl TryFrom<&JsonValue> for HSVA {
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::hsva::HSVA;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{ "hue": "nope" }"#).unwrap();
+    ///
+    /// assert_eq!(HSVA::try_from(&data), Err(Error::InvalidComponent("hue".to_string())));
+    /// ```
+    fn try_from(data: &JsonValue) -> Result<Self, Self::Error> {
+        let hue = json_f64(data, "hue")?;
+        let saturation = json_f64(data, "saturation")?;
+        let value = json_f64(data, "value")?;
+        let alpha = if data["alpha"].is_null() { 1.0 } else { json_f64(data, "alpha")? };
+        Ok(Self::new(hue, saturation, value, alpha))
+    }
+}
+
+
+/// Converts from `JsonValue` key value pares
+///
+/// **Note** missing or invalid components default individually, where `HSVA::try_from` returns
+/// error
+impl From<JsonValue> for HSVA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsva::HSVA;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{
+    ///     "hue": 60.0,
+    ///     "saturation": 1.0,
+    ///     "value": 0.5,
+    ///     "alpha": 0.25
+    /// }"#).unwrap();
+    ///
+    /// let hsva = HSVA::from(data);
+    ///
+    /// assert_eq!(hsva.get("hue"), Ok(60.0));
+    /// assert_eq!(hsva.get("saturation"), Ok(1.0));
+    /// assert_eq!(hsva.get("value"), Ok(0.5));
+    /// assert_eq!(hsva.get("alpha"), Ok(0.25));
+    /// ```
+    fn from(data: JsonValue) -> Self {
+        let hue = data["hue"].as_f64().unwrap_or_default();
+        let saturation = data["saturation"].as_f64().unwrap_or_default();
+        let value = data["value"].as_f64().unwrap_or_default();
+        let alpha = data["alpha"].as_f64().unwrap_or(1.0);
+        Self::new(hue, saturation, value, alpha)
+    }
+}
+
+
+/// Converts to `JsonValue`
+impl Into<JsonValue> for HSVA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsva::HSVA;
+    /// extern crate json;
+    /// use json::JsonValue;
+    ///
+    /// let hsva = HSVA::new(60.0, 1.0, 0.5, 0.25);
+    /// let data: JsonValue = hsva.into();
+    ///
+    /// let expected = json::parse(r#"{
+    ///     "hue": 60.0,
+    ///     "saturation": 1.0,
+    ///     "value": 0.5,
+    ///     "alpha": 0.25
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(data, expected);
+    /// ```
+    fn into(self) -> JsonValue {
+        json::object!{
+            "hue" => self.hue,
+            "saturation" => self.saturation,
+            "value" => self.value,
+            "alpha" => self.alpha,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::hsv::HSV;
+use crate::hsva::HSVA;
+use crate::rgb::RGB;
+use crate::rgba::RGBA;
+
+
+/// Convert from `RGBA` to `HSVA`
+///
+/// **Note** uses `HSV::from(rgb)` internally, and alpha is scaled between `0.0` and `1.0`
+impl From<RGBA> for HSVA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsva::HSVA;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let hsva = HSVA::from(RGBA::new(255, 0, 0, 51));
+    ///
+    /// assert_eq!(hsva.get("hue"), Ok(0.0));
+    /// assert_eq!(hsva.get("alpha"), Ok(0.2));
+    /// ```
+    fn from(rgba: RGBA) -> Self {
+        let alpha = rgba.opacity();
+        Self::from_hsv(HSV::from(RGB::from(rgba)), alpha)
+    }
+}
@@ -0,0 +1,50 @@
+#!/usr/bin/env rust
+
+
+use crate::hsva::HSVA;
+
+
+/// Convert from tuple of 64-bit precision floating point numbers
+impl<T> From<(T, T, T, T)> for HSVA
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsva::HSVA;
+    ///
+    /// let tuple = ( 60.0, 1.0, 0.5, 0.25 );
+    /// let hsva = HSVA::from(tuple);
+    ///
+    /// assert_eq!(hsva.get("hue"), Ok(60.0));
+    /// assert_eq!(hsva.get("saturation"), Ok(1.0));
+    /// assert_eq!(hsva.get("value"), Ok(0.5));
+    /// assert_eq!(hsva.get("alpha"), Ok(0.25));
+    /// ```
+    fn from(tuple: (T, T, T, T)) -> Self {
+        let ( hue, saturation, value, alpha ) = tuple;
+        Self::new(hue, saturation, value, alpha)
+    }
+}
+
+
+/// Convert into tuple of 64-bit precision floating point numbers
+impl Into<(f64, f64, f64, f64)> for HSVA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsva::HSVA;
+    ///
+    /// let hsva = HSVA::new(60.0, 1.0, 0.5, 0.25);
+    /// let ( hue, saturation, value, alpha ) = hsva.into();
+    ///
+    /// assert_eq!(hue, 60.0);
+    /// assert_eq!(saturation, 1.0);
+    /// assert_eq!(value, 0.5);
+    /// assert_eq!(alpha, 0.25);
+    /// ```
+    fn into(self) -> (f64, f64, f64, f64) {
+        ( self.hue, self.saturation, self.value, self.alpha )
+    }
+}
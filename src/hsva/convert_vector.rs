@@ -0,0 +1,93 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+use crate::error::{ check_length, Error };
+use crate::hsva::HSVA;
+
+
+/// Convert from slice of 64-bit precision floating point numbers, or error for invalid length
+///
+/// **Note** missing alpha defaults to fully opaque
+impl<T> TryFrom<&[T]> for HSVA
+where
+    T: Into<f64> + Copy
+{
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::hsva::HSVA;
+    ///
+    /// let hsva = HSVA::try_from(&[60.0, 1.0, 0.5, 0.25][..]).unwrap();
+    /// assert_eq!(hsva, HSVA::from(vec![60.0, 1.0, 0.5, 0.25]));
+    ///
+    /// let result = HSVA::try_from(&[60.0, 1.0][..]);
+    /// assert_eq!(result, Err(Error::Length { expected: 4, found: 2 }));
+    /// ```
+    fn try_from(values: &[T]) -> Result<Self, Self::Error> {
+        check_length(values.len(), 3, 4)?;
+        let hue = values[0].into();
+        let saturation = values[1].into();
+        let value = values[2].into();
+        let alpha = match values.get(3) {
+            Some(v) => (*v).into(),
+            None => 1.0,
+        };
+        Ok(Self::new(hue, saturation, value, alpha))
+    }
+}
+
+
+/// Convert from vector of 64-bit precision floating point numbers
+///
+/// **Note** this uses `HSVA::try_from` internally, values past the fourth are ignored, and too few
+/// values return default values
+impl<T> From<Vec<T>> for HSVA
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsva::HSVA;
+    ///
+    /// let vector = vec![60.0, 1.0, 0.5, 0.25];
+    /// let hsva = HSVA::from(vector);
+    ///
+    /// assert_eq!(hsva.get("hue"), Ok(60.0));
+    /// assert_eq!(hsva.get("saturation"), Ok(1.0));
+    /// assert_eq!(hsva.get("value"), Ok(0.5));
+    /// assert_eq!(hsva.get("alpha"), Ok(0.25));
+    /// ```
+    fn from(vector: Vec<T>) -> Self {
+        let length = vector.len().min(4);
+        Self::try_from(&vector[..length]).unwrap_or_default()
+    }
+}
+
+
+/// Convert into vector of 64-bit precision floating point numbers
+impl Into<Vec<f64>> for HSVA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsva::HSVA;
+    ///
+    /// let hsva = HSVA::new(60.0, 1.0, 0.5, 0.25);
+    /// let vector: Vec<f64> = hsva.into();
+    ///
+    /// assert_eq!(vector[0], 60.0);
+    /// assert_eq!(vector[1], 1.0);
+    /// assert_eq!(vector[2], 0.5);
+    /// assert_eq!(vector[3], 0.25);
+    /// ```
+    fn into(self) -> Vec<f64> {
+        vec![ self.hue, self.saturation, self.value, self.alpha ]
+    }
+}
@@ -0,0 +1,55 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::color::equality::alpha_eq;
+use crate::hsva::HSVA;
+
+
+impl Eq for HSVA {}
+impl PartialEq for HSVA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsva::HSVA;
+    ///
+    /// let left = HSVA::new(60.0, 1.0, 0.5, 0.25);
+    /// let right = HSVA::new(60.0, 1.0, 0.5, 0.25);
+    ///
+    /// assert_eq!(left, right);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        if self.hue != other.hue || self.saturation != other.saturation || self.value != other.value || self.alpha != other.alpha {
+            return false;
+        }
+        true
+    }
+}
+
+
+/// Converts right side from `Color` to `HSVA` prior to checking equality
+///
+/// **Note** colors without alpha are only equal to fully opaque colors
+impl PartialEq<Color> for HSVA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::hsva::HSVA;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let rgba = RGBA::new(255, 42, 90, 128);
+    /// let hsva = HSVA::from(rgba.clone());
+    ///
+    /// assert!(hsva == Color::from(rgba));
+    /// assert!(hsva != Color::new_rgb(255, 42, 90));
+    /// ```
+    fn eq(&self, other: &Color) -> bool {
+        if let Some(equal) = alpha_eq(&Color::from(self.clone()), other) {
+            return equal;
+        }
+
+        let other: HSVA = other.clone().into();
+        self == &other
+    }
+}
@@ -0,0 +1,284 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+
+use crate::blend::BlendMode;
+use crate::error::Error;
+use crate::hsv::HSV;
+use crate::rgba::RGBA;
+
+
+/// Adds color components for `HSVA` data structures
+mod add;
+
+/// Subtracts color components for `HSVA` data structures
+mod subtract;
+
+/// Equal and not-equal checks for `HSVA` data structures
+mod equality;
+
+/// Converts from/to array for `HSVA` data structures
+mod convert_array;
+
+/// Converts from/to `Color` enum
+mod convert_color;
+
+/// Converts from `HSV` to `HSVA` data structure
+mod convert_hsv;
+
+/// Converts from `HSLA` to `HSVA` data structure
+mod convert_hsla;
+
+/// Converts from/to `JsonValue` for `HSVA` data structures
+mod convert_json_value;
+
+/// Converts from `RGBA` to `HSVA` data structure
+mod convert_rgba;
+
+/// Converts from/to tuple for `HSVA` data structures
+mod convert_tuple;
+
+/// Converts from/to vector for `HSVA` data structures
+mod convert_vector;
+
+
+/// Data structure for Hue, Saturation, Value, Alpha encoded colors
+///
+/// **Note** alpha of `0.0` is fully transparent and `1.0` is fully opaque
+#[derive(Clone, Debug)]
+pub struct HSVA {
+    hue: f64,
+    saturation: f64,
+    value: f64,
+    alpha: f64,
+}
+
+
+/// Defaults to fully opaque black
+impl Default for HSVA {
+    fn default() -> Self {
+        Self { hue: 0.0, saturation: 0.0, value: 0.0, alpha: 1.0 }
+    }
+}
+
+
+impl HSVA {
+    /// Returns new instance of `HSVA` data structure
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsva::HSVA;
+    ///
+    /// let color = HSVA::new(60.0, 1.0, 0.5, 0.25);
+    ///
+    /// assert_eq!(color.get("hue"), Ok(60.0));
+    /// assert_eq!(color.get("saturation"), Ok(1.0));
+    /// assert_eq!(color.get("value"), Ok(0.5));
+    /// assert_eq!(color.get("alpha"), Ok(0.25));
+    /// ```
+    pub fn new<T>(hue: T, saturation: T, value: T, alpha: T) -> Self
+    where
+        T: Into<f64>
+    {
+        let hue: f64 = hue.into().min(360.0).max(0.0);
+        let saturation: f64 = saturation.into().min(1.0).max(0.0);
+        let value: f64 = value.into().min(1.0).max(0.0);
+        let alpha: f64 = alpha.into().min(1.0).max(0.0);
+        Self { hue, saturation, value, alpha }
+    }
+
+    /// Returns new instance of `HSVA` from `HSV` values and alpha
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsv::HSV;
+    /// use color_operators::hsva::HSVA;
+    ///
+    /// let hsva = HSVA::from_hsv(HSV::new(60.0, 1.0, 0.5), 0.25);
+    ///
+    /// assert_eq!(hsva, HSVA::new(60.0, 1.0, 0.5, 0.25));
+    /// ```
+    pub fn from_hsv<T>(hsv: HSV, alpha: T) -> Self
+    where
+        T: Into<f64>
+    {
+        let ( hue, saturation, value ) = hsv.into();
+        Self::new(hue, saturation, value, alpha.into())
+    }
+
+    /// Returns named component value or error
+    ///
+    /// ```rust
+    /// use color_operators::hsva::HSVA;
+    ///
+    /// let color = HSVA::new(60.0, 1.0, 0.5, 0.25);
+    ///
+    /// assert_eq!(color.get("nothing"), Err("No color component named -> nothing".to_string()));
+    /// ```
+    pub fn get<S>(&self, component: S) -> Result<f64, String>
+    where
+        S: Into<String>
+    {
+        let component: String = component.into();
+        match component.as_str() {
+            "hue" => Ok(self.hue),
+            "saturation" => Ok(self.saturation),
+            "value" => Ok(self.value),
+            "alpha" => Ok(self.alpha),
+            _ => Err(format!("No color component named -> {}", component)),
+        }
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or default values
+    ///
+    /// **Note** missing or invalid components default individually, see `From<JsonValue>`
+    ///
+    /// **Note** missing alpha defaults to fully opaque
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsva::HSVA;
+    ///
+    /// let hsva = HSVA::from_json_string(r#"{ "hue": 60.0, "saturation": 1.0, "value": 0.5, "alpha": 0.25 }"#);
+    ///
+    /// assert_eq!(hsva.get("hue"), Ok(60.0));
+    /// assert_eq!(hsva.get("saturation"), Ok(1.0));
+    /// assert_eq!(hsva.get("value"), Ok(0.5));
+    /// assert_eq!(hsva.get("alpha"), Ok(0.25));
+    /// ```
+    pub fn from_json_string<S>(string: S) -> Self
+    where
+        S: Into<String>
+    {
+        json::parse(&string.into()).map(Self::from).unwrap_or_default()
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or error for invalid JSON and missing
+    /// or invalid components
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::error::Error;
+    /// use color_operators::hsva::HSVA;
+    ///
+    /// let result = HSVA::try_from_json_str(r#"{ "hue": 1 }"#);
+    ///
+    /// assert_eq!(result, Err(Error::MissingComponent("saturation".to_string())));
+    /// ```
+    pub fn try_from_json_str(string: &str) -> Result<Self, Error> {
+        let data = json::parse(string)?;
+        Self::try_from(&data)
+    }
+
+    /// Serializes data structure as JSON string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsva::HSVA;
+    ///
+    /// let hsva = HSVA::new(60.0, 1.0, 0.5, 0.25);
+    /// let data = hsva.to_json_string();
+    ///
+    /// let object = json::object!{
+    ///     "hue" => 60.0,
+    ///     "saturation" => 1.0,
+    ///     "value" => 0.5,
+    ///     "alpha" => 0.25
+    /// };
+    ///
+    /// let expected = json::stringify(object);
+    ///
+    /// assert_eq!(data, expected);
+    /// ```
+    pub fn to_json_string(&self) -> String {
+        json::stringify(self.clone())
+    }
+
+    /// Converts hexadecimal string, `RRGGBBAA` or `RRGGBB`, into `HSVA`
+    ///
+    /// **Note** this method uses `RGBA::from_hex_string` internally, which returns default values
+    /// for invalid input
+    pub fn from_hex_string<S>(input: S) -> Self
+    where
+        S: Into<String>
+    {
+        Self::from(RGBA::from_hex_string(input))
+    }
+
+    /// Converts hexadecimal string into `HSVA`, or returns error for invalid input
+    ///
+    /// **Note** this method uses `RGBA::try_from_hex_string` internally
+    pub fn try_from_hex_string<S>(input: S) -> Result<Self, Error>
+    where
+        S: Into<String>
+    {
+        RGBA::try_from_hex_string(input).map(Self::from)
+    }
+
+    /// Returns hexadecimal string representation of `HSVA` values
+    ///
+    /// **Note** this method uses `RGBA::to_hex_string` internally
+    pub fn to_hex_string(&self) -> String {
+        RGBA::from(self.clone()).to_hex_string()
+    }
+
+    /// Attempts to rotate hue by some amount of degrees
+    ///
+    /// **Note** this method uses `HSV::rotate_hue` internally, and alpha is kept
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsva::HSVA;
+    ///
+    /// let color = HSVA::new(120.0, 0.5, 1.0, 0.25);
+    /// let rotated = color.rotate_hue(90.0);
+    ///
+    /// assert_eq!(rotated, HSVA::new(210.0, 0.5, 1.0, 0.25));
+    /// ```
+    pub fn rotate_hue<T>(&self, amount: T) -> Self
+    where
+        T: Into<f64>
+    {
+        let hsv = HSV::from(self.clone());
+        Self::from_hsv(hsv.rotate_hue(amount), self.alpha)
+    }
+
+    /// Attempts to rotate this color with another `HSVA` value
+    ///
+    /// **Note** this method uses `RGBA::rotate_rgb` internally, and alpha of left side is kept
+    pub fn rotate_rgb(&self, other: Self) -> Self {
+        let left = RGBA::from(self.clone());
+        let right = RGBA::from(other);
+        Self::from(left.rotate_rgb(right))
+    }
+
+    /// Blends source color into this backdrop color, then composites result over backdrop by alpha
+    /// of source
+    ///
+    /// **Note** this method uses `RGBA::blend` internally
+    pub fn blend(&self, source: Self, mode: BlendMode) -> Self {
+        let alpha = source.alpha + self.alpha * (1.0 - source.alpha);
+        let backdrop = RGBA::from(self.clone());
+        let source = RGBA::from(source);
+        let mut result = Self::from(backdrop.blend(source, mode));
+        result.alpha = alpha;
+        result
+    }
+}
+
+
+impl Display for HSVA {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "hue: {}, saturation: {}, value: {}, alpha: {}", self.hue, self.saturation, self.value, self.alpha)
+    }
+}
@@ -0,0 +1,60 @@
+#!/usr/bin/env rust
+
+
+use std::ops::Sub;
+
+
+use crate::color::Color;
+use crate::hsv::HSV;
+use crate::hsva::HSVA;
+use crate::rgb::RGB;
+
+
+/// Subtracts color components
+///
+/// **Note** this uses `RGB::sub` internally, and alpha of left side is kept
+impl Sub for HSVA {
+    type Output = Self;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsva::HSVA;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let left = HSVA::from(RGBA::new(50, 0, 0, 128));
+    /// let right = HSVA::from(RGBA::new(25, 0, 0, 255));
+    /// let result = left - right;
+    ///
+    /// assert_eq!(RGBA::from(result), RGBA::new(25, 0, 0, 128));
+    /// ```
+    fn sub(self, other: Self) -> Self::Output {
+        let alpha = self.alpha;
+        let left = RGB::from(HSV::from(self));
+        let right = RGB::from(HSV::from(other));
+        Self::from_hsv(HSV::from(left - right), alpha)
+    }
+}
+
+
+/// Converts right side from `Color` to `HSVA` prior to arithmetic
+impl Sub<Color> for HSVA {
+    type Output = Self;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::hsva::HSVA;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let left = HSVA::from(RGBA::new(50, 0, 0, 128));
+    /// let right = Color::new_rgb(25, 0, 0);
+    ///
+    /// assert_eq!(RGBA::from(left - right), RGBA::new(25, 0, 0, 128));
+    /// ```
+    fn sub(self, other: Color) -> Self::Output {
+        let other: HSVA = other.into();
+        self - other
+    }
+}
@@ -0,0 +1,50 @@
+#!/usr/bin/env rust
+
+
+use crate::hwb::HWB;
+
+
+/// Convert from array of 64-bit precision floating point numbers
+impl<T> From<[T; 3]> for HWB
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hwb::HWB;
+    ///
+    /// let array = [ 120.0, 0.25, 0.5 ];
+    /// let hwb = HWB::from(array);
+    ///
+    /// assert_eq!(hwb.get("hue"), Ok(120.0));
+    /// assert_eq!(hwb.get("whiteness"), Ok(0.25));
+    /// assert_eq!(hwb.get("blackness"), Ok(0.5));
+    /// ```
+    fn from(array: [T; 3]) -> Self {
+        let hue = array[0];
+        let whiteness = array[1];
+        let blackness = array[2];
+        Self::new(hue, whiteness, blackness)
+    }
+}
+
+
+/// Convert into array of 64-bit precision floating point numbers
+impl Into<[f64; 3]> for HWB {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hwb::HWB;
+    ///
+    /// let hwb = HWB::new(120.0, 0.25, 0.5);
+    /// let array: [f64; 3] = hwb.into();
+    ///
+    /// assert_eq!(array[0], 120.0);
+    /// assert_eq!(array[1], 0.25);
+    /// assert_eq!(array[2], 0.5);
+    /// ```
+    fn into(self) -> [f64; 3] {
+        [ self.hue, self.whiteness, self.blackness ]
+    }
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::hsl::HSL;
+use crate::hsv::HSV;
+use crate::hwb::HWB;
+
+
+/// Convert from `HSL` to `HWB`
+///
+/// **Note** uses `HSV::from(hsl)` internally
+impl From<HSL> for HWB {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsl::HSL;
+    /// use color_operators::hwb::HWB;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let hsl = HSL::from(rgb.clone());
+    /// let hwb = HWB::from(hsl);
+    ///
+    /// assert_eq!(RGB::from(hwb), rgb);
+    /// ```
+    fn from(hsl: HSL) -> Self {
+        Self::from(HSV::from(hsl))
+    }
+}
@@ -0,0 +1,34 @@
+#!/usr/bin/env rust
+
+
+use crate::hsv::HSV;
+use crate::hwb::HWB;
+
+
+/// Convert from `HSV` to `HWB`
+///
+/// **Note** floating point to unsigned integer conversions may be inaccurate due to binary to/from
+/// decimal conversions
+impl From<HSV> for HWB {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsv::HSV;
+    /// use color_operators::hwb::HWB;
+    ///
+    /// let hsv = HSV::new(120.0, 0.5, 0.5);
+    /// let hwb = HWB::from(hsv);
+    ///
+    /// assert_eq!(hwb.get("hue"), Ok(120.0));
+    /// assert_eq!(hwb.get("whiteness"), Ok(0.25));
+    /// assert_eq!(hwb.get("blackness"), Ok(0.5));
+    /// ```
+    fn from(hsv: HSV) -> Self {
+        let ( hue, saturation, value ) = hsv.into();
+
+        let whiteness = (1.0 - saturation) * value;
+        let blackness = 1.0 - value;
+
+        Self::new(hue, whiteness, blackness)
+    }
+}
@@ -0,0 +1,99 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+extern crate json;
+use json::JsonValue;
+
+
+use crate::error::{ json_f64, Error };
+use crate::hwb::HWB;
+
+
+/// Converts from `JsonValue` key value pares, or error for missing or invalid components
+impl TryFrom<&JsonValue> for HWB {
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::hwb::HWB;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{ "hue": "nope" }"#).unwrap();
+    ///
+    /// assert_eq!(HWB::try_from(&data), Err(Error::InvalidComponent("hue".to_string())));
+    /// ```
+    fn try_from(data: &JsonValue) -> Result<Self, Self::Error> {
+        let hue = json_f64(data, "hue")?;
+        let whiteness = json_f64(data, "whiteness")?;
+        let blackness = json_f64(data, "blackness")?;
+        Ok(Self::new(hue, whiteness, blackness))
+    }
+}
+
+
+/// Converts from `JsonValue` key value pares
+///
+/// **Note** missing or invalid components default individually, where `HWB::try_from` returns
+/// error
+impl From<JsonValue> for HWB {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hwb::HWB;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{
+    ///     "hue": 120.0,
+    ///     "whiteness": 0.25,
+    ///     "blackness": 0.5
+    /// }"#).unwrap();
+    ///
+    /// let hwb = HWB::from(data);
+    ///
+    /// assert_eq!(hwb.get("hue"), Ok(120.0));
+    /// assert_eq!(hwb.get("whiteness"), Ok(0.25));
+    /// assert_eq!(hwb.get("blackness"), Ok(0.5));
+    /// ```
+    fn from(data: JsonValue) -> Self {
+        let hue = data["hue"].as_f64().unwrap_or_default();
+        let whiteness = data["whiteness"].as_f64().unwrap_or_default();
+        let blackness = data["blackness"].as_f64().unwrap_or_default();
+        Self::new(hue, whiteness, blackness)
+    }
+}
+
+
+/// Converts to `JsonValue`
+impl Into<JsonValue> for HWB {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hwb::HWB;
+    /// extern crate json;
+    /// use json::JsonValue;
+    ///
+    /// let hwb = HWB::new(120.0, 0.25, 0.5);
+    /// let data: JsonValue = hwb.into();
+    ///
+    /// let expected = json::parse(r#"{
+    ///     "hue": 120.0,
+    ///     "whiteness": 0.25,
+    ///     "blackness": 0.5
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(data, expected);
+    /// ```
+    fn into(self) -> JsonValue {
+        json::object!{
+            "hue" => self.hue,
+            "whiteness" => self.whiteness,
+            "blackness" => self.blackness,
+        }
+    }
+}
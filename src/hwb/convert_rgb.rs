@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::hsv::HSV;
+use crate::hwb::HWB;
+use crate::rgb::RGB;
+
+
+/// Convert from `RGB` to `HWB`
+///
+/// **Note** uses `HSV::from(rgb)` internally
+impl From<RGB> for HWB {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hwb::HWB;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 0, 0);
+    /// let hwb = HWB::from(rgb);
+    ///
+    /// assert_eq!(hwb.get("hue"), Ok(0.0));
+    /// assert_eq!(hwb.get("whiteness"), Ok(0.0));
+    /// assert_eq!(hwb.get("blackness"), Ok(0.0));
+    /// ```
+    fn from(rgb: RGB) -> Self {
+        Self::from(HSV::from(rgb))
+    }
+}
@@ -0,0 +1,48 @@
+#!/usr/bin/env rust
+
+
+use crate::hwb::HWB;
+
+
+/// Convert from tuple of 64-bit precision floating point numbers
+impl<T> From<(T, T, T)> for HWB
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hwb::HWB;
+    ///
+    /// let tuple = ( 120.0, 0.25, 0.5 );
+    /// let hwb = HWB::from(tuple);
+    ///
+    /// assert_eq!(hwb.get("hue"), Ok(120.0));
+    /// assert_eq!(hwb.get("whiteness"), Ok(0.25));
+    /// assert_eq!(hwb.get("blackness"), Ok(0.5));
+    /// ```
+    fn from(tuple: (T, T, T)) -> Self {
+        let ( hue, whiteness, blackness ) = tuple;
+        Self::new(hue, whiteness, blackness)
+    }
+}
+
+
+/// Convert into tuple of 64-bit precision floating point numbers
+impl Into<(f64, f64, f64)> for HWB {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hwb::HWB;
+    ///
+    /// let hwb = HWB::new(120.0, 0.25, 0.5);
+    /// let ( hue, whiteness, blackness ) = hwb.into();
+    ///
+    /// assert_eq!(hue, 120.0);
+    /// assert_eq!(whiteness, 0.25);
+    /// assert_eq!(blackness, 0.5);
+    /// ```
+    fn into(self) -> (f64, f64, f64) {
+        ( self.hue, self.whiteness, self.blackness )
+    }
+}
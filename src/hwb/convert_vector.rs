@@ -0,0 +1,85 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+use crate::error::{ check_length, Error };
+use crate::hwb::HWB;
+
+
+/// Convert from slice of 64-bit precision floating point numbers, or error for invalid length
+impl<T> TryFrom<&[T]> for HWB
+where
+    T: Into<f64> + Copy
+{
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::hwb::HWB;
+    ///
+    /// let hwb = HWB::try_from(&[120.0, 0.25, 0.5][..]).unwrap();
+    /// assert_eq!(hwb, HWB::from(vec![120.0, 0.25, 0.5]));
+    ///
+    /// let result = HWB::try_from(&[120.0, 0.25][..]);
+    /// assert_eq!(result, Err(Error::Length { expected: 3, found: 2 }));
+    /// ```
+    fn try_from(values: &[T]) -> Result<Self, Self::Error> {
+        check_length(values.len(), 3, 3)?;
+        let hue = values[0].into();
+        let whiteness = values[1].into();
+        let blackness = values[2].into();
+        Ok(Self::new(hue, whiteness, blackness))
+    }
+}
+
+
+/// Convert from vector of 64-bit precision floating point numbers
+///
+/// **Note** this uses `HWB::try_from` internally, values past the third are ignored, and too few
+/// values return default values
+impl<T> From<Vec<T>> for HWB
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hwb::HWB;
+    ///
+    /// let vector = vec![120.0, 0.25, 0.5];
+    /// let hwb = HWB::from(vector);
+    ///
+    /// assert_eq!(hwb.get("hue"), Ok(120.0));
+    /// assert_eq!(hwb.get("whiteness"), Ok(0.25));
+    /// assert_eq!(hwb.get("blackness"), Ok(0.5));
+    /// ```
+    fn from(vector: Vec<T>) -> Self {
+        let length = vector.len().min(3);
+        Self::try_from(&vector[..length]).unwrap_or_default()
+    }
+}
+
+
+/// Convert into vector of 64-bit precision floating point numbers
+impl Into<Vec<f64>> for HWB {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hwb::HWB;
+    ///
+    /// let hwb = HWB::new(120.0, 0.25, 0.5);
+    /// let vector: Vec<f64> = hwb.into();
+    ///
+    /// assert_eq!(vector[0], 120.0);
+    /// assert_eq!(vector[1], 0.25);
+    /// assert_eq!(vector[2], 0.5);
+    /// ```
+    fn into(self) -> Vec<f64> {
+        vec![ self.hue, self.whiteness, self.blackness ]
+    }
+}
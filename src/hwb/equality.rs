@@ -0,0 +1,54 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::color::equality::alpha_eq;
+use crate::hwb::HWB;
+
+
+impl Eq for HWB {}
+impl PartialEq for HWB {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hwb::HWB;
+    ///
+    /// let left = HWB::new(120.0, 0.25, 0.5);
+    /// let right = HWB::new(120.0, 0.25, 0.5);
+    ///
+    /// assert_eq!(left, right);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        if self.hue != other.hue || self.whiteness != other.whiteness || self.blackness != other.blackness {
+            return false;
+        }
+        true
+    }
+}
+
+
+/// Converts right side from `Color` to `HWB` prior to checking equality
+///
+/// **Note** colors with alpha are compared as `RGBA`, so are only equal when fully opaque
+impl PartialEq<Color> for HWB {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::hwb::HWB;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let hwb = HWB::from(rgb.clone());
+    ///
+    /// assert!(hwb == Color::from(rgb));
+    /// ```
+    fn eq(&self, other: &Color) -> bool {
+        if let Some(equal) = alpha_eq(&Color::from(self.clone()), other) {
+            return equal;
+        }
+
+        let other: HWB = other.clone().into();
+        self == &other
+    }
+}
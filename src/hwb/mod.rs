@@ -0,0 +1,249 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+
+use crate::error::Error;
+use crate::rgb::RGB;
+
+
+/// Adds color components for `HWB` data structures
+mod add;
+
+/// Subtracts color components for `HWB` data structures
+mod subtract;
+
+/// Equal and not-equal checks for `HWB` data structures
+mod equality;
+
+/// Converts from/to array for `HWB` data structures
+mod convert_array;
+
+/// Converts from/to `Color` enum
+mod convert_color;
+
+/// Converts from `HSL` to `HWB` data structure
+mod convert_hsl;
+
+/// Converts from `HSV` to `HWB` data structure
+mod convert_hsv;
+
+/// Converts from/to `JsonValue` for `HWB` data structures
+mod convert_json_value;
+
+/// Converts from `RGB` to `HWB` data structure
+mod convert_rgb;
+
+/// Converts from/to tuple for `HWB` data structures
+mod convert_tuple;
+
+/// Converts from/to vector for `HWB` data structures
+mod convert_vector;
+
+
+/// Data structure for Hue, Whiteness, Blackness encoded colors
+///
+/// **Note** follows the CSS Color Module Level 4 definition, where whiteness and blackness are the
+/// amounts of white and black mixed into a pure hue
+#[derive(Clone, Debug, Default)]
+pub struct HWB {
+    hue: f64,
+    whiteness: f64,
+    blackness: f64,
+}
+
+
+impl HWB {
+    /// Returns new instance of `HWB` data structure
+    ///
+    /// **Note** hue wraps around to stay within `0.0` through `360.0` degrees, and whiteness plus
+    /// blackness greater than `1.0` are normalized to sum to `1.0`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hwb::HWB;
+    ///
+    /// let color = HWB::new(120.0, 0.25, 0.5);
+    ///
+    /// assert_eq!(color.get("hue"), Ok(120.0));
+    /// assert_eq!(color.get("whiteness"), Ok(0.25));
+    /// assert_eq!(color.get("blackness"), Ok(0.5));
+    /// ```
+    pub fn new<T>(hue: T, whiteness: T, blackness: T) -> Self
+    where
+        T: Into<f64>
+    {
+        let hue: f64 = hue.into().rem_euclid(360.0);
+        let mut whiteness: f64 = whiteness.into().min(1.0).max(0.0);
+        let mut blackness: f64 = blackness.into().min(1.0).max(0.0);
+
+        let sum = whiteness + blackness;
+        if sum > 1.0 {
+            whiteness /= sum;
+            blackness /= sum;
+        }
+
+        Self { hue, whiteness, blackness }
+    }
+
+    /// Returns named component value or error
+    ///
+    /// ```rust
+    /// use color_operators::hwb::HWB;
+    ///
+    /// let color = HWB::new(120.0, 0.25, 0.5);
+    ///
+    /// assert_eq!(color.get("nothing"), Err("No color component named -> nothing".to_string()));
+    /// ```
+    pub fn get<S>(&self, component: S) -> Result<f64, String>
+    where
+        S: Into<String>
+    {
+        let component: String = component.into();
+        match component.as_str() {
+            "hue" => Ok(self.hue),
+            "whiteness" => Ok(self.whiteness),
+            "blackness" => Ok(self.blackness),
+            _ => Err(format!("No color component named -> {}", component)),
+        }
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or default values
+    ///
+    /// **Note** missing or invalid components default individually, see `From<JsonValue>`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hwb::HWB;
+    ///
+    /// let hwb = HWB::from_json_string(r#"{ "hue": 120.0, "whiteness": 0.25, "blackness": 0.5 }"#);
+    ///
+    /// assert_eq!(hwb.get("hue"), Ok(120.0));
+    /// assert_eq!(hwb.get("whiteness"), Ok(0.25));
+    /// assert_eq!(hwb.get("blackness"), Ok(0.5));
+    /// ```
+    pub fn from_json_string<S>(string: S) -> Self
+    where
+        S: Into<String>
+    {
+        json::parse(&string.into()).map(Self::from).unwrap_or_default()
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or error for invalid JSON and missing
+    /// or invalid components
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::error::Error;
+    /// use color_operators::hwb::HWB;
+    ///
+    /// let result = HWB::try_from_json_str(r#"{ "hue": 1 }"#);
+    ///
+    /// assert_eq!(result, Err(Error::MissingComponent("whiteness".to_string())));
+    /// ```
+    pub fn try_from_json_str(string: &str) -> Result<Self, Error> {
+        let data = json::parse(string)?;
+        Self::try_from(&data)
+    }
+
+    /// Serializes data structure as JSON string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hwb::HWB;
+    ///
+    /// let hwb = HWB::new(120.0, 0.25, 0.5);
+    /// let data = hwb.to_json_string();
+    ///
+    /// let object = json::object!{
+    ///     "hue" => 120.0,
+    ///     "whiteness" => 0.25,
+    ///     "blackness" => 0.5
+    /// };
+    ///
+    /// let expected = json::stringify(object);
+    ///
+    /// assert_eq!(data, expected);
+    /// ```
+    pub fn to_json_string(&self) -> String {
+        json::stringify(self.clone())
+    }
+
+    /// Converts hexadecimal string into `HWB`
+    ///
+    /// **Note** this method uses `RGB::from_hex_string` internally, which returns default values
+    /// for invalid input
+    pub fn from_hex_string<S>(input: S) -> Self
+    where
+        S: Into<String>
+    {
+        Self::from(RGB::from_hex_string(input))
+    }
+
+    /// Converts hexadecimal string into `HWB`, or returns error for invalid input
+    ///
+    /// **Note** this method uses `RGB::try_from_hex_string` internally
+    pub fn try_from_hex_string<S>(input: S) -> Result<Self, Error>
+    where
+        S: Into<String>
+    {
+        RGB::try_from_hex_string(input).map(Self::from)
+    }
+
+    /// Returns hexadecimal string representation of `HWB` values
+    ///
+    /// **Note** this method uses `RGB::to_hex_string` internally
+    pub fn to_hex_string(&self) -> String {
+        RGB::from(self.clone()).to_hex_string()
+    }
+
+    /// Attempts to rotate hue by some amount of degrees
+    ///
+    /// # Examples
+    ///
+    /// Positive values rotates hue counterclockwise
+    ///
+    /// ```rust
+    /// use color_operators::hwb::HWB;
+    ///
+    /// let color = HWB::new(120.0, 0.25, 0.5);
+    /// let rotated = color.rotate_hue(300.0);
+    ///
+    /// let expected = HWB::new(60.0, 0.25, 0.5);
+    ///
+    /// assert_eq!(rotated, expected);
+    /// ```
+    ///
+    /// Negative values rotates hue clockwise
+    ///
+    /// ```rust
+    /// use color_operators::hwb::HWB;
+    ///
+    /// let color = HWB::new(120.0, 0.25, 0.5);
+    /// let rotated = color.rotate_hue(-180.0);
+    ///
+    /// let expected = HWB::new(300.0, 0.25, 0.5);
+    ///
+    /// assert_eq!(rotated, expected);
+    /// ```
+    pub fn rotate_hue<T>(&self, amount: T) -> Self
+    where
+        T: Into<f64>
+    {
+        Self::new(self.hue + amount.into(), self.whiteness, self.blackness)
+    }
+}
+
+
+impl Display for HWB {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "hue: {}, whiteness: {}, blackness: {}", self.hue, self.whiteness, self.blackness)
+    }
+}
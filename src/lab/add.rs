@@ -0,0 +1,61 @@
+#!/usr/bin/env rust
+
+
+use std::ops::Add;
+
+
+use crate::color::Color;
+use crate::lab::Lab;
+use crate::rgb::RGB;
+
+
+/// Adds color components
+///
+/// **Note** this uses `RGB::add` internally
+impl Add for Lab {
+    type Output = Self;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let left = Lab::from(RGB::new(25, 0, 0));
+    /// let right = Lab::from(RGB::new(25, 0, 0));
+    /// let result = left + right;
+    ///
+    /// let expected = Lab::from(RGB::new(50, 0, 0));
+    /// assert_eq!(result, expected);
+    /// ```
+    fn add(self, other: Self) -> Self::Output {
+        let left = RGB::from(self);
+        let right = RGB::from(other);
+        Lab::from(left + right)
+    }
+}
+
+
+/// Converts right side from `Color` to `Lab` prior to arithmetic
+impl Add<Color> for Lab {
+    type Output = Self;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::lab::Lab;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let left = Lab::from(RGB::new(25, 0, 0));
+    /// let right = Color::new_rgb(25, 0, 0);
+    ///
+    /// let expected = Lab::from(RGB::new(50, 0, 0));
+    ///
+    /// assert_eq!(left + right, expected);
+    /// ```
+    fn add(self, other: Color) -> Self::Output {
+        let other: Lab = other.into();
+        self + other
+    }
+}
@@ -0,0 +1,50 @@
+#!/usr/bin/env rust
+
+
+use crate::lab::Lab;
+
+
+/// Convert from array of 64-bit precision floating point numbers
+impl<T> From<[T; 3]> for Lab
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    ///
+    /// let array = [ 50.0, 25.0, -25.0 ];
+    /// let lab = Lab::from(array);
+    ///
+    /// assert_eq!(lab.get("lightness"), Ok(50.0));
+    /// assert_eq!(lab.get("a"), Ok(25.0));
+    /// assert_eq!(lab.get("b"), Ok(-25.0));
+    /// ```
+    fn from(array: [T; 3]) -> Self {
+        let lightness = array[0];
+        let a = array[1];
+        let b = array[2];
+        Self::new(lightness, a, b)
+    }
+}
+
+
+/// Convert into array of 64-bit precision floating point numbers
+impl Into<[f64; 3]> for Lab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    ///
+    /// let lab = Lab::new(50.0, 25.0, -25.0);
+    /// let array: [f64; 3] = lab.into();
+    ///
+    /// assert_eq!(array[0], 50.0);
+    /// assert_eq!(array[1], 25.0);
+    /// assert_eq!(array[2], -25.0);
+    /// ```
+    fn into(self) -> [f64; 3] {
+        [ self.lightness, self.a, self.b ]
+    }
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::hsl::HSL;
+use crate::lab::Lab;
+use crate::rgb_space::RgbSpace;
+
+
+/// Convert from `HSL` to `Lab`
+///
+/// **Note** uses `RgbSpace::srgb().to_xyz` internally
+impl From<HSL> for Lab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsl::HSL;
+    /// use color_operators::lab::Lab;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let hsl = HSL::from(rgb.clone());
+    /// let lab = Lab::from(hsl);
+    ///
+    /// assert_eq!(lab, Lab::from(rgb));
+    /// ```
+    fn from(hsl: HSL) -> Self {
+        Self::from(RgbSpace::srgb().to_xyz(hsl))
+    }
+}
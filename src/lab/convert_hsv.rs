@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::hsv::HSV;
+use crate::lab::Lab;
+use crate::rgb_space::RgbSpace;
+
+
+/// Convert from `HSV` to `Lab`
+///
+/// **Note** uses `RgbSpace::srgb().to_xyz` internally
+impl From<HSV> for Lab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsv::HSV;
+    /// use color_operators::lab::Lab;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let hsv = HSV::from(rgb.clone());
+    /// let lab = Lab::from(hsv);
+    ///
+    /// assert_eq!(lab, Lab::from(rgb));
+    /// ```
+    fn from(hsv: HSV) -> Self {
+        Self::from(RgbSpace::srgb().to_xyz(hsv))
+    }
+}
@@ -0,0 +1,99 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+extern crate json;
+use json::JsonValue;
+
+
+use crate::error::{ json_f64, Error };
+use crate::lab::Lab;
+
+
+/// Converts from `JsonValue` key value pares, or error for missing or invalid components
+impl TryFrom<&JsonValue> for Lab {
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::lab::Lab;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{ "lightness": "nope" }"#).unwrap();
+    ///
+    /// assert_eq!(Lab::try_from(&data), Err(Error::InvalidComponent("lightness".to_string())));
+    /// ```
+    fn try_from(data: &JsonValue) -> Result<Self, Self::Error> {
+        let lightness = json_f64(data, "lightness")?;
+        let a = json_f64(data, "a")?;
+        let b = json_f64(data, "b")?;
+        Ok(Self::new(lightness, a, b))
+    }
+}
+
+
+/// Converts from `JsonValue` key value pares
+///
+/// **Note** missing or invalid components default individually, where `Lab::try_from` returns
+/// error
+impl From<JsonValue> for Lab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{
+    ///     "lightness": 50.0,
+    ///     "a": 25.0,
+    ///     "b": -25.0
+    /// }"#).unwrap();
+    ///
+    /// let lab = Lab::from(data);
+    ///
+    /// assert_eq!(lab.get("lightness"), Ok(50.0));
+    /// assert_eq!(lab.get("a"), Ok(25.0));
+    /// assert_eq!(lab.get("b"), Ok(-25.0));
+    /// ```
+    fn from(data: JsonValue) -> Self {
+        let lightness = data["lightness"].as_f64().unwrap_or_default();
+        let a = data["a"].as_f64().unwrap_or_default();
+        let b = data["b"].as_f64().unwrap_or_default();
+        Self::new(lightness, a, b)
+    }
+}
+
+
+/// Converts to `JsonValue`
+impl Into<JsonValue> for Lab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    /// extern crate json;
+    /// use json::JsonValue;
+    ///
+    /// let lab = Lab::new(50.0, 25.0, -25.0);
+    /// let data: JsonValue = lab.into();
+    ///
+    /// let expected = json::parse(r#"{
+    ///     "lightness": 50.0,
+    ///     "a": 25.0,
+    ///     "b": -25.0
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(data, expected);
+    /// ```
+    fn into(self) -> JsonValue {
+        json::object!{
+            "lightness" => self.lightness,
+            "a" => self.a,
+            "b" => self.b,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::lab::Lab;
+use crate::lch::LCh;
+
+
+/// Convert from `LCh` to `Lab`
+impl From<LCh> for Lab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    /// use color_operators::lch::LCh;
+    ///
+    /// let lch = LCh::new(50.0, 25.0, 0.0);
+    /// let lab = Lab::from(lch);
+    ///
+    /// assert_eq!(lab.get("lightness"), Ok(50.0));
+    /// assert_eq!(lab.get("a"), Ok(25.0));
+    /// assert_eq!(lab.get("b"), Ok(0.0));
+    /// ```
+    fn from(lch: LCh) -> Self {
+        let ( lightness, chroma, hue ) = lch.into();
+        let a = chroma * hue.to_radians().cos();
+        let b = chroma * hue.to_radians().sin();
+        Self::new(lightness, a, b)
+    }
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::lab::Lab;
+use crate::oklab::Oklab;
+use crate::xyz::XYZ;
+
+
+/// Convert from `Oklab` to `Lab`
+///
+/// **Note** uses `XYZ::from(oklab)` internally
+impl From<Oklab> for Lab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    /// use color_operators::oklab::Oklab;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let oklab = Oklab::from(rgb.clone());
+    /// let lab = Lab::from(oklab);
+    ///
+    /// assert_eq!(RGB::from(lab), rgb);
+    /// ```
+    fn from(oklab: Oklab) -> Self {
+        Self::from(XYZ::from(oklab))
+    }
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::lab::Lab;
+use crate::oklch::Oklch;
+use crate::xyz::XYZ;
+
+
+/// Convert from `Oklch` to `Lab`
+///
+/// **Note** uses `XYZ::from(oklch)` internally
+impl From<Oklch> for Lab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    /// use color_operators::oklch::Oklch;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let oklch = Oklch::from(rgb.clone());
+    /// let lab = Lab::from(oklch);
+    ///
+    /// assert_eq!(RGB::from(lab), rgb);
+    /// ```
+    fn from(oklch: Oklch) -> Self {
+        Self::from(XYZ::from(oklch))
+    }
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::lab::Lab;
+use crate::rgb::RGB;
+use crate::xyz::XYZ;
+
+
+/// Convert from `RGB` to `Lab`
+///
+/// **Note** uses `XYZ::from(rgb)` internally
+impl From<RGB> for Lab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 255, 255);
+    /// let lab = Lab::from(rgb);
+    ///
+    /// assert_eq!(lab.get("lightness").unwrap().round(), 100.0);
+    /// assert_eq!(lab.get("a").unwrap().round(), 0.0);
+    /// assert_eq!(lab.get("b").unwrap().round(), 0.0);
+    /// ```
+    fn from(rgb: RGB) -> Self {
+        Self::from(XYZ::from(rgb))
+    }
+}
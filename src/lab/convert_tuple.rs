@@ -0,0 +1,48 @@
+#!/usr/bin/env rust
+
+
+use crate::lab::Lab;
+
+
+/// Convert from tuple of 64-bit precision floating point numbers
+impl<T> From<(T, T, T)> for Lab
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    ///
+    /// let tuple = ( 50.0, 25.0, -25.0 );
+    /// let lab = Lab::from(tuple);
+    ///
+    /// assert_eq!(lab.get("lightness"), Ok(50.0));
+    /// assert_eq!(lab.get("a"), Ok(25.0));
+    /// assert_eq!(lab.get("b"), Ok(-25.0));
+    /// ```
+    fn from(tuple: (T, T, T)) -> Self {
+        let ( lightness, a, b ) = tuple;
+        Self::new(lightness, a, b)
+    }
+}
+
+
+/// Convert into tuple of 64-bit precision floating point numbers
+impl Into<(f64, f64, f64)> for Lab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    ///
+    /// let lab = Lab::new(50.0, 25.0, -25.0);
+    /// let ( lightness, a, b ) = lab.into();
+    ///
+    /// assert_eq!(lightness, 50.0);
+    /// assert_eq!(a, 25.0);
+    /// assert_eq!(b, -25.0);
+    /// ```
+    fn into(self) -> (f64, f64, f64) {
+        ( self.lightness, self.a, self.b )
+    }
+}
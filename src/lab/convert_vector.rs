@@ -0,0 +1,85 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+use crate::error::{ check_length, Error };
+use crate::lab::Lab;
+
+
+/// Convert from slice of 64-bit precision floating point numbers, or error for invalid length
+impl<T> TryFrom<&[T]> for Lab
+where
+    T: Into<f64> + Copy
+{
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::lab::Lab;
+    ///
+    /// let lab = Lab::try_from(&[50.0, 25.0, -25.0][..]).unwrap();
+    /// assert_eq!(lab, Lab::from(vec![50.0, 25.0, -25.0]));
+    ///
+    /// let result = Lab::try_from(&[50.0, 25.0][..]);
+    /// assert_eq!(result, Err(Error::Length { expected: 3, found: 2 }));
+    /// ```
+    fn try_from(values: &[T]) -> Result<Self, Self::Error> {
+        check_length(values.len(), 3, 3)?;
+        let lightness = values[0].into();
+        let a = values[1].into();
+        let b = values[2].into();
+        Ok(Self::new(lightness, a, b))
+    }
+}
+
+
+/// Convert from vector of 64-bit precision floating point numbers
+///
+/// **Note** this uses `Lab::try_from` internally, values past the third are ignored, and too few
+/// values return default values
+impl<T> From<Vec<T>> for Lab
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    ///
+    /// let vector = vec![50.0, 25.0, -25.0];
+    /// let lab = Lab::from(vector);
+    ///
+    /// assert_eq!(lab.get("lightness"), Ok(50.0));
+    /// assert_eq!(lab.get("a"), Ok(25.0));
+    /// assert_eq!(lab.get("b"), Ok(-25.0));
+    /// ```
+    fn from(vector: Vec<T>) -> Self {
+        let length = vector.len().min(3);
+        Self::try_from(&vector[..length]).unwrap_or_default()
+    }
+}
+
+
+/// Convert into vector of 64-bit precision floating point numbers
+impl Into<Vec<f64>> for Lab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    ///
+    /// let lab = Lab::new(50.0, 25.0, -25.0);
+    /// let vector: Vec<f64> = lab.into();
+    ///
+    /// assert_eq!(vector[0], 50.0);
+    /// assert_eq!(vector[1], 25.0);
+    /// assert_eq!(vector[2], -25.0);
+    /// ```
+    fn into(self) -> Vec<f64> {
+        vec![ self.lightness, self.a, self.b ]
+    }
+}
@@ -0,0 +1,27 @@
+#!/usr/bin/env rust
+
+
+use crate::lab::Lab;
+use crate::white_point::WhitePoint;
+use crate::xyz::XYZ;
+
+
+/// Convert from `XYZ` to `Lab`
+///
+/// **Note** uses `WhitePoint::D65` as reference white, see `Lab::from_xyz` for alternatives
+impl From<XYZ> for Lab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let xyz = XYZ::new(0.0, 0.0, 0.0);
+    /// let lab = Lab::from(xyz);
+    ///
+    /// assert_eq!(lab, Lab::new(0.0, 0.0, 0.0));
+    /// ```
+    fn from(xyz: XYZ) -> Self {
+        Self::from_xyz(xyz, WhitePoint::D65)
+    }
+}
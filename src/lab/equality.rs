@@ -0,0 +1,54 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::color::equality::alpha_eq;
+use crate::lab::Lab;
+
+
+impl Eq for Lab {}
+impl PartialEq for Lab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    ///
+    /// let left = Lab::new(50.0, 25.0, -25.0);
+    /// let right = Lab::new(50.0, 25.0, -25.0);
+    ///
+    /// assert_eq!(left, right);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        if self.lightness != other.lightness || self.a != other.a || self.b != other.b {
+            return false;
+        }
+        true
+    }
+}
+
+
+/// Converts right side from `Color` to `Lab` prior to checking equality
+///
+/// **Note** colors with alpha are compared as `RGBA`, so are only equal when fully opaque
+impl PartialEq<Color> for Lab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::lab::Lab;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let lab = Lab::from(rgb.clone());
+    ///
+    /// assert!(lab == Color::from(rgb));
+    /// ```
+    fn eq(&self, other: &Color) -> bool {
+        if let Some(equal) = alpha_eq(&Color::from(self.clone()), other) {
+            return equal;
+        }
+
+        let other: Lab = other.clone().into();
+        self == &other
+    }
+}
@@ -0,0 +1,355 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+
+use crate::adaptation::Method;
+use crate::css::Options;
+use crate::css::serialize;
+use crate::error::Error;
+use crate::lch::LCh;
+use crate::rgb::RGB;
+use crate::white_point::WhitePoint;
+use crate::xyz::XYZ;
+
+
+/// Adds color components for `Lab` data structures
+mod add;
+
+/// Subtracts color components for `Lab` data structures
+mod subtract;
+
+/// Equal and not-equal checks for `Lab` data structures
+mod equality;
+
+/// Converts from/to array for `Lab` data structures
+mod convert_array;
+
+/// Converts from/to `Color` enum
+mod convert_color;
+
+/// Converts from `HSL` to `Lab` data structure
+mod convert_hsl;
+
+/// Converts from `HSV` to `Lab` data structure
+mod convert_hsv;
+
+/// Converts from/to `JsonValue` for `Lab` data structures
+mod convert_json_value;
+
+/// Converts from `LCh` to `Lab` data structure
+mod convert_lch;
+
+/// Converts from `Oklab` to `Lab` data structure
+mod convert_oklab;
+
+/// Converts from `Oklch` to `Lab` data structure
+mod convert_oklch;
+
+/// Converts from `RGB` to `Lab` data structure
+mod convert_rgb;
+
+/// Converts from/to tuple for `Lab` data structures
+mod convert_tuple;
+
+/// Converts from/to vector for `Lab` data structures
+mod convert_vector;
+
+/// Converts from `XYZ` to `Lab` data structure
+mod convert_xyz;
+
+
+/// CIE threshold between linear and cube root segments of L\*a\*b\* companding
+const EPSILON: f64 = 216.0 / 24389.0;
+
+/// CIE slope of linear segment of L\*a\*b\* companding
+const KAPPA: f64 = 24389.0 / 27.0;
+
+
+/// Data structure for CIE L\*a\*b\* encoded colors
+///
+/// **Note** `From` conversions assume `WhitePoint::D65`, use `Lab::from_xyz` and `Lab::to_xyz` for
+/// other reference whites
+#[derive(Clone, Debug, Default)]
+pub struct Lab {
+    lightness: f64,
+    a: f64,
+    b: f64,
+}
+
+
+impl Lab {
+    /// Returns new instance of `Lab` data structure
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    ///
+    /// let color = Lab::new(50.0, 25.0, -25.0);
+    ///
+    /// assert_eq!(color.get("lightness"), Ok(50.0));
+    /// assert_eq!(color.get("a"), Ok(25.0));
+    /// assert_eq!(color.get("b"), Ok(-25.0));
+    /// ```
+    pub fn new<T>(lightness: T, a: T, b: T) -> Self
+    where
+        T: Into<f64>
+    {
+        let lightness: f64 = lightness.into().min(100.0).max(0.0);
+        Self { lightness, a: a.into(), b: b.into() }
+    }
+
+    /// Returns named component value or error
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    ///
+    /// let color = Lab::new(50.0, 25.0, -25.0);
+    ///
+    /// assert_eq!(color.get("nothing"), Err("No color component named -> nothing".to_string()));
+    /// ```
+    pub fn get<S>(&self, component: S) -> Result<f64, String>
+    where
+        S: Into<String>
+    {
+        let component: String = component.into();
+        match component.as_str() {
+            "lightness" => Ok(self.lightness),
+            "a" => Ok(self.a),
+            "b" => Ok(self.b),
+            _ => Err(format!("No color component named -> {}", component)),
+        }
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or default values
+    ///
+    /// **Note** missing or invalid components default individually, see `From<JsonValue>`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    ///
+    /// let lab = Lab::from_json_string(r#"{ "lightness": 50.0, "a": 25.0, "b": -25.0 }"#);
+    ///
+    /// assert_eq!(lab.get("lightness"), Ok(50.0));
+    /// assert_eq!(lab.get("a"), Ok(25.0));
+    /// assert_eq!(lab.get("b"), Ok(-25.0));
+    /// ```
+    pub fn from_json_string<S>(string: S) -> Self
+    where
+        S: Into<String>
+    {
+        json::parse(&string.into()).map(Self::from).unwrap_or_default()
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or error for invalid JSON and missing
+    /// or invalid components
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::error::Error;
+    /// use color_operators::lab::Lab;
+    ///
+    /// let result = Lab::try_from_json_str(r#"{ "lightness": 1 }"#);
+    ///
+    /// assert_eq!(result, Err(Error::MissingComponent("a".to_string())));
+    /// ```
+    pub fn try_from_json_str(string: &str) -> Result<Self, Error> {
+        let data = json::parse(string)?;
+        Self::try_from(&data)
+    }
+
+    /// Serializes data structure as JSON string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    ///
+    /// let lab = Lab::new(50.0, 25.0, -25.0);
+    /// let data = lab.to_json_string();
+    ///
+    /// let object = json::object!{
+    ///     "lightness" => 50.0,
+    ///     "a" => 25.0,
+    ///     "b" => -25.0
+    /// };
+    ///
+    /// let expected = json::stringify(object);
+    ///
+    /// assert_eq!(data, expected);
+    /// ```
+    pub fn to_json_string(&self) -> String {
+        json::stringify(self.clone())
+    }
+
+    /// Converts hexadecimal string into `Lab`
+    ///
+    /// **Note** this method uses `RGB::from_hex_string` internally, which returns default values
+    /// for invalid input
+    pub fn from_hex_string<S>(input: S) -> Self
+    where
+        S: Into<String>
+    {
+        Self::from(RGB::from_hex_string(input))
+    }
+
+    /// Converts hexadecimal string into `Lab`, or returns error for invalid input
+    ///
+    /// **Note** this method uses `RGB::try_from_hex_string` internally
+    pub fn try_from_hex_string<S>(input: S) -> Result<Self, Error>
+    where
+        S: Into<String>
+    {
+        RGB::try_from_hex_string(input).map(Self::from)
+    }
+
+    /// Returns hexadecimal string representation of `Lab` values
+    ///
+    /// **Note** this method uses `RGB::to_hex_string` internally
+    pub fn to_hex_string(&self) -> String {
+        RGB::from(self.clone()).to_hex_string()
+    }
+
+    /// Returns CSS representation of `Lab` values
+    ///
+    /// **Note** `lab()` has no legacy syntax, so legacy syntax, hexadecimal, and named colors are
+    /// converted via `RGB`
+    ///
+    /// **Note** CSS defines `lab()` relative to `WhitePoint::D50`, so values are adapted by
+    /// `Method::Bradford` from `WhitePoint::D65`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::css::{ Options, Syntax, Units };
+    /// use color_operators::lab::Lab;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let color = Lab::from(RGB::new(255, 0, 0));
+    ///
+    /// assert_eq!(color.to_css_string(Options::new()), "lab(54.29 80.8 69.89)");
+    /// assert_eq!(color.to_css_string(Options::new().units(Units::Percent)), "lab(54.29% 64.64% 55.91%)");
+    /// assert!(color.to_css_string(Options::new().syntax(Syntax::Legacy)).starts_with("rgb("));
+    /// ```
+    pub fn to_css_string(&self, options: Options) -> String {
+        let rgb = RGB::from(self.clone()).into();
+        let lab = self.adapt(WhitePoint::D65, WhitePoint::D50, Method::Bradford);
+        serialize::lab("lab", [ lab.lightness, lab.a, lab.b ], [ Some(100.0), Some(125.0), Some(125.0) ], rgb, options)
+    }
+
+    /// Returns `Lab` from `XYZ` values relative to given reference white
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    /// use color_operators::white_point::WhitePoint;
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let ( x, y, z ) = WhitePoint::D50.tristimulus();
+    /// let lab = Lab::from_xyz(XYZ::new(x, y, z), WhitePoint::D50);
+    ///
+    /// assert_eq!(lab, Lab::new(100.0, 0.0, 0.0));
+    /// ```
+    pub fn from_xyz(xyz: XYZ, white_point: WhitePoint) -> Self {
+        let ( x, y, z ) = xyz.into();
+        let ( white_x, white_y, white_z ) = white_point.tristimulus();
+
+        let compand = |v: f64| -> f64 {
+            if v > EPSILON {
+                return v.cbrt();
+            }
+            (KAPPA * v + 16.0) / 116.0
+        };
+
+        let fx = compand(x / white_x);
+        let fy = compand(y / white_y);
+        let fz = compand(z / white_z);
+
+        let lightness = 116.0 * fy - 16.0;
+        let a = 500.0 * (fx - fy);
+        let b = 200.0 * (fy - fz);
+
+        Self::new(lightness, a, b)
+    }
+
+    /// Returns `XYZ` values relative to given reference white
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    /// use color_operators::white_point::WhitePoint;
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let lab = Lab::new(0.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(lab.to_xyz(WhitePoint::D50), XYZ::new(0.0, 0.0, 0.0));
+    /// ```
+    pub fn to_xyz(&self, white_point: WhitePoint) -> XYZ {
+        let ( white_x, white_y, white_z ) = white_point.tristimulus();
+
+        let fy = (self.lightness + 16.0) / 116.0;
+        let fx = self.a / 500.0 + fy;
+        let fz = fy - self.b / 200.0;
+
+        let expand = |v: f64| -> f64 {
+            let cubed = v.powi(3);
+            if cubed > EPSILON {
+                return cubed;
+            }
+            (116.0 * v - 16.0) / KAPPA
+        };
+
+        let mut y = self.lightness / KAPPA;
+        if self.lightness > KAPPA * EPSILON {
+            y = fy.powi(3);
+        }
+
+        XYZ::new(expand(fx) * white_x, y * white_y, expand(fz) * white_z)
+    }
+
+    /// Returns values relative to source white point adapted to be relative to destination white
+    /// point
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::adaptation::Method;
+    /// use color_operators::lab::Lab;
+    /// use color_operators::white_point::WhitePoint;
+    ///
+    /// let paper = Lab::new(95.0, 1.0, -4.0);
+    /// let ( l, a, b ) = paper.adapt(WhitePoint::D50, WhitePoint::D65, Method::Bradford).into();
+    ///
+    /// assert_eq!((l.round(), a.round(), b.round()), (95.0, 1.0, -4.0));
+    /// ```
+    pub fn adapt(&self, source: WhitePoint, destination: WhitePoint, method: Method) -> Self {
+        Self::from_xyz(method.adapt(self.to_xyz(source), source, destination), destination)
+    }
+
+    /// Attempts to rotate hue by some amount of degrees
+    ///
+    /// **Note** this method uses `LCh::rotate_hue` internally
+    pub fn rotate_hue<T>(&self, amount: T) -> Self
+    where
+        T: Into<f64>
+    {
+        let lch = LCh::from(self.clone());
+        Self::from(lch.rotate_hue(amount))
+    }
+}
+
+
+impl Display for Lab {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "lightness: {}, a: {}, b: {}", self.lightness, self.a, self.b)
+    }
+}
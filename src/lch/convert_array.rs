@@ -0,0 +1,50 @@
+#!/usr/bin/env rust
+
+
+use crate::lch::LCh;
+
+
+/// Convert from array of 64-bit precision floating point numbers
+impl<T> From<[T; 3]> for LCh
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    ///
+    /// let array = [ 50.0, 25.0, 120.0 ];
+    /// let lch = LCh::from(array);
+    ///
+    /// assert_eq!(lch.get("lightness"), Ok(50.0));
+    /// assert_eq!(lch.get("chroma"), Ok(25.0));
+    /// assert_eq!(lch.get("hue"), Ok(120.0));
+    /// ```
+    fn from(array: [T; 3]) -> Self {
+        let lightness = array[0];
+        let chroma = array[1];
+        let hue = array[2];
+        Self::new(lightness, chroma, hue)
+    }
+}
+
+
+/// Convert into array of 64-bit precision floating point numbers
+impl Into<[f64; 3]> for LCh {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    ///
+    /// let lch = LCh::new(50.0, 25.0, 120.0);
+    /// let array: [f64; 3] = lch.into();
+    ///
+    /// assert_eq!(array[0], 50.0);
+    /// assert_eq!(array[1], 25.0);
+    /// assert_eq!(array[2], 120.0);
+    /// ```
+    fn into(self) -> [f64; 3] {
+        [ self.lightness, self.chroma, self.hue ]
+    }
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::hsl::HSL;
+use crate::lab::Lab;
+use crate::lch::LCh;
+
+
+/// Convert from `HSL` to `LCh`
+///
+/// **Note** uses `Lab::from(hsl)` internally
+impl From<HSL> for LCh {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsl::HSL;
+    /// use color_operators::lch::LCh;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let hsl = HSL::from(rgb.clone());
+    /// let lch = LCh::from(hsl);
+    ///
+    /// assert_eq!(lch, LCh::from(rgb));
+    /// ```
+    fn from(hsl: HSL) -> Self {
+        Self::from(Lab::from(hsl))
+    }
+}
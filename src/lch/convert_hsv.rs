@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::hsv::HSV;
+use crate::lab::Lab;
+use crate::lch::LCh;
+
+
+/// Convert from `HSV` to `LCh`
+///
+/// **Note** uses `Lab::from(hsv)` internally
+impl From<HSV> for LCh {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsv::HSV;
+    /// use color_operators::lch::LCh;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let hsv = HSV::from(rgb.clone());
+    /// let lch = LCh::from(hsv);
+    ///
+    /// assert_eq!(lch, LCh::from(rgb));
+    /// ```
+    fn from(hsv: HSV) -> Self {
+        Self::from(Lab::from(hsv))
+    }
+}
@@ -0,0 +1,99 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+extern crate json;
+use json::JsonValue;
+
+
+use crate::error::{ json_f64, Error };
+use crate::lch::LCh;
+
+
+/// Converts from `JsonValue` key value pares, or error for missing or invalid components
+impl TryFrom<&JsonValue> for LCh {
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::lch::LCh;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{ "lightness": "nope" }"#).unwrap();
+    ///
+    /// assert_eq!(LCh::try_from(&data), Err(Error::InvalidComponent("lightness".to_string())));
+    /// ```
+    fn try_from(data: &JsonValue) -> Result<Self, Self::Error> {
+        let lightness = json_f64(data, "lightness")?;
+        let chroma = json_f64(data, "chroma")?;
+        let hue = json_f64(data, "hue")?;
+        Ok(Self::new(lightness, chroma, hue))
+    }
+}
+
+
+/// Converts from `JsonValue` key value pares
+///
+/// **Note** missing or invalid components default individually, where `LCh::try_from` returns
+/// error
+impl From<JsonValue> for LCh {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{
+    ///     "lightness": 50.0,
+    ///     "chroma": 25.0,
+    ///     "hue": 120.0
+    /// }"#).unwrap();
+    ///
+    /// let lch = LCh::from(data);
+    ///
+    /// assert_eq!(lch.get("lightness"), Ok(50.0));
+    /// assert_eq!(lch.get("chroma"), Ok(25.0));
+    /// assert_eq!(lch.get("hue"), Ok(120.0));
+    /// ```
+    fn from(data: JsonValue) -> Self {
+        let lightness = data["lightness"].as_f64().unwrap_or_default();
+        let chroma = data["chroma"].as_f64().unwrap_or_default();
+        let hue = data["hue"].as_f64().unwrap_or_default();
+        Self::new(lightness, chroma, hue)
+    }
+}
+
+
+/// Converts to `JsonValue`
+impl Into<JsonValue> for LCh {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    /// extern crate json;
+    /// use json::JsonValue;
+    ///
+    /// let lch = LCh::new(50.0, 25.0, 120.0);
+    /// let data: JsonValue = lch.into();
+    ///
+    /// let expected = json::parse(r#"{
+    ///     "lightness": 50.0,
+    ///     "chroma": 25.0,
+    ///     "hue": 120.0
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(data, expected);
+    /// ```
+    fn into(self) -> JsonValue {
+        json::object!{
+            "lightness" => self.lightness,
+            "chroma" => self.chroma,
+            "hue" => self.hue,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::lab::Lab;
+use crate::lch::LCh;
+
+
+/// Convert from `Lab` to `LCh`
+impl From<Lab> for LCh {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    /// use color_operators::lch::LCh;
+    ///
+    /// let lab = Lab::new(50.0, 0.0, 25.0);
+    /// let lch = LCh::from(lab);
+    ///
+    /// assert_eq!(lch.get("lightness"), Ok(50.0));
+    /// assert_eq!(lch.get("chroma"), Ok(25.0));
+    /// assert_eq!(lch.get("hue"), Ok(90.0));
+    /// ```
+    fn from(lab: Lab) -> Self {
+        let ( lightness, a, b ) = lab.into();
+        let chroma = a.hypot(b);
+        let hue = b.atan2(a).to_degrees();
+        Self::new(lightness, chroma, hue)
+    }
+}
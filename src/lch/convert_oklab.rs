@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::lab::Lab;
+use crate::lch::LCh;
+use crate::oklab::Oklab;
+
+
+/// Convert from `Oklab` to `LCh`
+///
+/// **Note** uses `Lab::from(oklab)` internally
+impl From<Oklab> for LCh {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    /// use color_operators::oklab::Oklab;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let oklab = Oklab::from(rgb.clone());
+    /// let lch = LCh::from(oklab);
+    ///
+    /// assert_eq!(RGB::from(lch), rgb);
+    /// ```
+    fn from(oklab: Oklab) -> Self {
+        Self::from(Lab::from(oklab))
+    }
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::lab::Lab;
+use crate::lch::LCh;
+use crate::oklch::Oklch;
+
+
+/// Convert from `Oklch` to `LCh`
+///
+/// **Note** uses `Lab::from(oklch)` internally
+impl From<Oklch> for LCh {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    /// use color_operators::oklch::Oklch;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let oklch = Oklch::from(rgb.clone());
+    /// let lch = LCh::from(oklch);
+    ///
+    /// assert_eq!(RGB::from(lch), rgb);
+    /// ```
+    fn from(oklch: Oklch) -> Self {
+        Self::from(Lab::from(oklch))
+    }
+}
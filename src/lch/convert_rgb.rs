@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::lab::Lab;
+use crate::lch::LCh;
+use crate::rgb::RGB;
+
+
+/// Convert from `RGB` to `LCh`
+///
+/// **Note** uses `Lab::from(rgb)` internally
+impl From<RGB> for LCh {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 0, 0);
+    /// let lch = LCh::from(rgb);
+    ///
+    /// assert_eq!(lch.get("lightness").unwrap().round(), 53.0);
+    /// assert_eq!(lch.get("chroma").unwrap().round(), 105.0);
+    /// assert_eq!(lch.get("hue").unwrap().round(), 40.0);
+    /// ```
+    fn from(rgb: RGB) -> Self {
+        Self::from(Lab::from(rgb))
+    }
+}
@@ -0,0 +1,48 @@
+#!/usr/bin/env rust
+
+
+use crate::lch::LCh;
+
+
+/// Convert from tuple of 64-bit precision floating point numbers
+impl<T> From<(T, T, T)> for LCh
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    ///
+    /// let tuple = ( 50.0, 25.0, 120.0 );
+    /// let lch = LCh::from(tuple);
+    ///
+    /// assert_eq!(lch.get("lightness"), Ok(50.0));
+    /// assert_eq!(lch.get("chroma"), Ok(25.0));
+    /// assert_eq!(lch.get("hue"), Ok(120.0));
+    /// ```
+    fn from(tuple: (T, T, T)) -> Self {
+        let ( lightness, chroma, hue ) = tuple;
+        Self::new(lightness, chroma, hue)
+    }
+}
+
+
+/// Convert into tuple of 64-bit precision floating point numbers
+impl Into<(f64, f64, f64)> for LCh {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    ///
+    /// let lch = LCh::new(50.0, 25.0, 120.0);
+    /// let ( lightness, chroma, hue ) = lch.into();
+    ///
+    /// assert_eq!(lightness, 50.0);
+    /// assert_eq!(chroma, 25.0);
+    /// assert_eq!(hue, 120.0);
+    /// ```
+    fn into(self) -> (f64, f64, f64) {
+        ( self.lightness, self.chroma, self.hue )
+    }
+}
@@ -0,0 +1,85 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+use crate::error::{ check_length, Error };
+use crate::lch::LCh;
+
+
+/// Convert from slice of 64-bit precision floating point numbers, or error for invalid length
+impl<T> TryFrom<&[T]> for LCh
+where
+    T: Into<f64> + Copy
+{
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::lch::LCh;
+    ///
+    /// let lch = LCh::try_from(&[50.0, 25.0, 120.0][..]).unwrap();
+    /// assert_eq!(lch, LCh::from(vec![50.0, 25.0, 120.0]));
+    ///
+    /// let result = LCh::try_from(&[50.0, 25.0][..]);
+    /// assert_eq!(result, Err(Error::Length { expected: 3, found: 2 }));
+    /// ```
+    fn try_from(values: &[T]) -> Result<Self, Self::Error> {
+        check_length(values.len(), 3, 3)?;
+        let lightness = values[0].into();
+        let chroma = values[1].into();
+        let hue = values[2].into();
+        Ok(Self::new(lightness, chroma, hue))
+    }
+}
+
+
+/// Convert from vector of 64-bit precision floating point numbers
+///
+/// **Note** this uses `LCh::try_from` internally, values past the third are ignored, and too few
+/// values return default values
+impl<T> From<Vec<T>> for LCh
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    ///
+    /// let vector = vec![50.0, 25.0, 120.0];
+    /// let lch = LCh::from(vector);
+    ///
+    /// assert_eq!(lch.get("lightness"), Ok(50.0));
+    /// assert_eq!(lch.get("chroma"), Ok(25.0));
+    /// assert_eq!(lch.get("hue"), Ok(120.0));
+    /// ```
+    fn from(vector: Vec<T>) -> Self {
+        let length = vector.len().min(3);
+        Self::try_from(&vector[..length]).unwrap_or_default()
+    }
+}
+
+
+/// Convert into vector of 64-bit precision floating point numbers
+impl Into<Vec<f64>> for LCh {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    ///
+    /// let lch = LCh::new(50.0, 25.0, 120.0);
+    /// let vector: Vec<f64> = lch.into();
+    ///
+    /// assert_eq!(vector[0], 50.0);
+    /// assert_eq!(vector[1], 25.0);
+    /// assert_eq!(vector[2], 120.0);
+    /// ```
+    fn into(self) -> Vec<f64> {
+        vec![ self.lightness, self.chroma, self.hue ]
+    }
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::lab::Lab;
+use crate::lch::LCh;
+use crate::xyz::XYZ;
+
+
+/// Convert from `XYZ` to `LCh`
+///
+/// **Note** uses `Lab::from(xyz)` internally
+impl From<XYZ> for LCh {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let xyz = XYZ::from(rgb.clone());
+    /// let lch = LCh::from(xyz);
+    ///
+    /// assert_eq!(lch, LCh::from(rgb));
+    /// ```
+    fn from(xyz: XYZ) -> Self {
+        Self::from(Lab::from(xyz))
+    }
+}
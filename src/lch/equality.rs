@@ -0,0 +1,54 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::color::equality::alpha_eq;
+use crate::lch::LCh;
+
+
+impl Eq for LCh {}
+impl PartialEq for LCh {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    ///
+    /// let left = LCh::new(50.0, 25.0, 120.0);
+    /// let right = LCh::new(50.0, 25.0, 120.0);
+    ///
+    /// assert_eq!(left, right);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        if self.lightness != other.lightness || self.chroma != other.chroma || self.hue != other.hue {
+            return false;
+        }
+        true
+    }
+}
+
+
+/// Converts right side from `Color` to `LCh` prior to checking equality
+///
+/// **Note** colors with alpha are compared as `RGBA`, so are only equal when fully opaque
+impl PartialEq<Color> for LCh {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::lch::LCh;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let lch = LCh::from(rgb.clone());
+    ///
+    /// assert!(lch == Color::from(rgb));
+    /// ```
+    fn eq(&self, other: &Color) -> bool {
+        if let Some(equal) = alpha_eq(&Color::from(self.clone()), other) {
+            return equal;
+        }
+
+        let other: LCh = other.clone().into();
+        self == &other
+    }
+}
@@ -0,0 +1,286 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+
+use crate::adaptation::Method;
+use crate::css::Options;
+use crate::css::serialize;
+use crate::error::Error;
+use crate::lab::Lab;
+use crate::rgb::RGB;
+use crate::white_point::WhitePoint;
+
+
+/// Adds color components for `LCh` data structures
+mod add;
+
+/// Subtracts color components for `LCh` data structures
+mod subtract;
+
+/// Equal and not-equal checks for `LCh` data structures
+mod equality;
+
+/// Converts from/to array for `LCh` data structures
+mod convert_array;
+
+/// Converts from/to `Color` enum
+mod convert_color;
+
+/// Converts from `HSL` to `LCh` data structure
+mod convert_hsl;
+
+/// Converts from `HSV` to `LCh` data structure
+mod convert_hsv;
+
+/// Converts from/to `JsonValue` for `LCh` data structures
+mod convert_json_value;
+
+/// Converts from `Lab` to `LCh` data structure
+mod convert_lab;
+
+/// Converts from `Oklab` to `LCh` data structure
+mod convert_oklab;
+
+/// Converts from `Oklch` to `LCh` data structure
+mod convert_oklch;
+
+/// Converts from `RGB` to `LCh` data structure
+mod convert_rgb;
+
+/// Converts from/to tuple for `LCh` data structures
+mod convert_tuple;
+
+/// Converts from/to vector for `LCh` data structures
+mod convert_vector;
+
+/// Converts from `XYZ` to `LCh` data structure
+mod convert_xyz;
+
+
+/// Data structure for CIE L\*C\*h(ab) encoded colors
+///
+/// **Note** hue is measured in degrees counterclockwise from the positive `a` axis of `Lab`
+#[derive(Clone, Debug, Default)]
+pub struct LCh {
+    lightness: f64,
+    chroma: f64,
+    hue: f64,
+}
+
+
+impl LCh {
+    /// Returns new instance of `LCh` data structure
+    ///
+    /// **Note** hue wraps around to stay within `0.0` through `360.0` degrees
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    ///
+    /// let color = LCh::new(50.0, 25.0, 120.0);
+    ///
+    /// assert_eq!(color.get("lightness"), Ok(50.0));
+    /// assert_eq!(color.get("chroma"), Ok(25.0));
+    /// assert_eq!(color.get("hue"), Ok(120.0));
+    /// ```
+    pub fn new<T>(lightness: T, chroma: T, hue: T) -> Self
+    where
+        T: Into<f64>
+    {
+        let lightness: f64 = lightness.into().min(100.0).max(0.0);
+        let chroma: f64 = chroma.into().max(0.0);
+        let hue: f64 = hue.into().rem_euclid(360.0);
+        Self { lightness, chroma, hue }
+    }
+
+    /// Returns named component value or error
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    ///
+    /// let color = LCh::new(50.0, 25.0, 120.0);
+    ///
+    /// assert_eq!(color.get("nothing"), Err("No color component named -> nothing".to_string()));
+    /// ```
+    pub fn get<S>(&self, component: S) -> Result<f64, String>
+    where
+        S: Into<String>
+    {
+        let component: String = component.into();
+        match component.as_str() {
+            "lightness" => Ok(self.lightness),
+            "chroma" => Ok(self.chroma),
+            "hue" => Ok(self.hue),
+            _ => Err(format!("No color component named -> {}", component)),
+        }
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or default values
+    ///
+    /// **Note** missing or invalid components default individually, see `From<JsonValue>`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    ///
+    /// let lch = LCh::from_json_string(r#"{ "lightness": 50.0, "chroma": 25.0, "hue": 120.0 }"#);
+    ///
+    /// assert_eq!(lch.get("lightness"), Ok(50.0));
+    /// assert_eq!(lch.get("chroma"), Ok(25.0));
+    /// assert_eq!(lch.get("hue"), Ok(120.0));
+    /// ```
+    pub fn from_json_string<S>(string: S) -> Self
+    where
+        S: Into<String>
+    {
+        json::parse(&string.into()).map(Self::from).unwrap_or_default()
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or error for invalid JSON and missing
+    /// or invalid components
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::error::Error;
+    /// use color_operators::lch::LCh;
+    ///
+    /// let result = LCh::try_from_json_str(r#"{ "lightness": 1 }"#);
+    ///
+    /// assert_eq!(result, Err(Error::MissingComponent("chroma".to_string())));
+    /// ```
+    pub fn try_from_json_str(string: &str) -> Result<Self, Error> {
+        let data = json::parse(string)?;
+        Self::try_from(&data)
+    }
+
+    /// Serializes data structure as JSON string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    ///
+    /// let lch = LCh::new(50.0, 25.0, 120.0);
+    /// let data = lch.to_json_string();
+    ///
+    /// let object = json::object!{
+    ///     "lightness" => 50.0,
+    ///     "chroma" => 25.0,
+    ///     "hue" => 120.0
+    /// };
+    ///
+    /// let expected = json::stringify(object);
+    ///
+    /// assert_eq!(data, expected);
+    /// ```
+    pub fn to_json_string(&self) -> String {
+        json::stringify(self.clone())
+    }
+
+    /// Converts hexadecimal string into `LCh`
+    ///
+    /// **Note** this method uses `RGB::from_hex_string` internally, which returns default values
+    /// for invalid input
+    pub fn from_hex_string<S>(input: S) -> Self
+    where
+        S: Into<String>
+    {
+        Self::from(RGB::from_hex_string(input))
+    }
+
+    /// Converts hexadecimal string into `LCh`, or returns error for invalid input
+    ///
+    /// **Note** this method uses `RGB::try_from_hex_string` internally
+    pub fn try_from_hex_string<S>(input: S) -> Result<Self, Error>
+    where
+        S: Into<String>
+    {
+        RGB::try_from_hex_string(input).map(Self::from)
+    }
+
+    /// Returns hexadecimal string representation of `LCh` values
+    ///
+    /// **Note** this method uses `RGB::to_hex_string` internally
+    pub fn to_hex_string(&self) -> String {
+        RGB::from(self.clone()).to_hex_string()
+    }
+
+    /// Returns CSS representation of `LCh` values
+    ///
+    /// **Note** `lch()` has no legacy syntax, so legacy syntax, hexadecimal, and named colors are
+    /// converted via `RGB`
+    ///
+    /// **Note** CSS defines `lch()` relative to `WhitePoint::D50`, so values are adapted by
+    /// `Method::Bradford` from `WhitePoint::D65`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::css::{ Options, Syntax, Units };
+    /// use color_operators::lch::LCh;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let color = LCh::from(RGB::new(255, 0, 0));
+    ///
+    /// assert_eq!(color.to_css_string(Options::new()), "lch(54.29 106.84 40.86)");
+    /// assert_eq!(color.to_css_string(Options::new().units(Units::Percent)), "lch(54.29% 71.22% 40.86)");
+    /// assert!(color.to_css_string(Options::new().syntax(Syntax::Legacy)).starts_with("rgb("));
+    /// ```
+    pub fn to_css_string(&self, options: Options) -> String {
+        let rgb = RGB::from(self.clone()).into();
+        let lch = Self::from(Lab::from(self.clone()).adapt(WhitePoint::D65, WhitePoint::D50, Method::Bradford));
+        serialize::lab("lch", [ lch.lightness, lch.chroma, lch.hue ], [ Some(100.0), Some(150.0), None ], rgb, options)
+    }
+
+    /// Attempts to rotate hue by some amount of degrees
+    ///
+    /// **Note** unlike `HSL::rotate_hue` perceived lightness and chroma are preserved
+    ///
+    /// # Examples
+    ///
+    /// Positive values rotates hue counterclockwise
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    ///
+    /// let color = LCh::new(50.0, 25.0, 120.0);
+    /// let rotated = color.rotate_hue(300.0);
+    ///
+    /// let expected = LCh::new(50.0, 25.0, 60.0);
+    ///
+    /// assert_eq!(rotated, expected);
+    /// ```
+    ///
+    /// Negative values rotates hue clockwise
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    ///
+    /// let color = LCh::new(50.0, 25.0, 120.0);
+    /// let rotated = color.rotate_hue(-180.0);
+    ///
+    /// let expected = LCh::new(50.0, 25.0, 300.0);
+    ///
+    /// assert_eq!(rotated, expected);
+    /// ```
+    pub fn rotate_hue<T>(&self, amount: T) -> Self
+    where
+        T: Into<f64>
+    {
+        Self::new(self.lightness, self.chroma, self.hue + amount.into())
+    }
+}
+
+
+impl Display for LCh {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "lightness: {}, chroma: {}, hue: {}", self.lightness, self.chroma, self.hue)
+    }
+}
@@ -0,0 +1,61 @@
+#!/usr/bin/env rust
+
+
+use std::ops::Sub;
+
+
+use crate::color::Color;
+use crate::lch::LCh;
+use crate::rgb::RGB;
+
+
+/// Subtracts color components
+///
+/// **Note** this uses `RGB::sub` internally
+impl Sub for LCh {
+    type Output = Self;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let left = LCh::from(RGB::new(50, 0, 0));
+    /// let right = LCh::from(RGB::new(25, 0, 0));
+    /// let result = left - right;
+    ///
+    /// let expected = LCh::from(RGB::new(25, 0, 0));
+    /// assert_eq!(result, expected);
+    /// ```
+    fn sub(self, other: Self) -> Self::Output {
+        let left = RGB::from(self);
+        let right = RGB::from(other);
+        LCh::from(left - right)
+    }
+}
+
+
+/// Converts right side from `Color` to `LCh` prior to arithmetic
+impl Sub<Color> for LCh {
+    type Output = Self;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::lch::LCh;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let left = LCh::from(RGB::new(50, 0, 0));
+    /// let right = Color::new_rgb(25, 0, 0);
+    ///
+    /// let expected = LCh::from(RGB::new(25, 0, 0));
+    ///
+    /// assert_eq!(left - right, expected);
+    /// ```
+    fn sub(self, other: Color) -> Self::Output {
+        let other: LCh = other.into();
+        self - other
+    }
+}
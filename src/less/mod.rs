@@ -0,0 +1,131 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::sass::SassColor;
+
+
+/// Returns color with alpha replaced by percentage, between `0.0` and `100.0`
+///
+/// **Note** translucent results of types without alpha become `HSLA` for `HSL`, `HSVA` for `HSV`,
+/// and `RGBA` otherwise
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::less;
+///
+/// assert_eq!(less::fade(Color::new_rgb(255, 0, 0), 50.0), Color::new_rgba(255, 0, 0, 128));
+/// assert_eq!(less::fade(Color::new_hsl(90.0, 0.9, 0.5), 10.0), Color::new_hsla(90.0, 0.9, 0.5, 0.1));
+/// ```
+pub fn fade<C>(color: C, amount: f64) -> Color
+where
+    C: Into<Color>
+{
+    let color: Color = color.into();
+    SassColor::from_color(&color).with_alpha(amount / 100.0).into_color(&color)
+}
+
+
+/// Returns color with alpha increased by percentage, between `0.0` and `100.0`
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::less;
+///
+/// assert_eq!(less::fadein(Color::new_hsla(90.0, 0.9, 0.5, 0.5), 10.0), Color::new_hsla(90.0, 0.9, 0.5, 0.6));
+/// ```
+pub fn fadein<C>(color: C, amount: f64) -> Color
+where
+    C: Into<Color>
+{
+    let color: Color = color.into();
+    let sass = SassColor::from_color(&color);
+    sass.with_alpha(sass.alpha() + amount / 100.0).into_color(&color)
+}
+
+
+/// Returns color with alpha decreased by percentage, between `0.0` and `100.0`
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::less;
+///
+/// assert_eq!(less::fadeout(Color::new_hsl(90.0, 0.9, 0.5), 10.0), Color::new_hsla(90.0, 0.9, 0.5, 0.9));
+/// ```
+pub fn fadeout<C>(color: C, amount: f64) -> Color
+where
+    C: Into<Color>
+{
+    let color: Color = color.into();
+    let sass = SassColor::from_color(&color);
+    sass.with_alpha(sass.alpha() - amount / 100.0).into_color(&color)
+}
+
+
+/// Returns color with hue rotated by degrees, in either direction
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::less;
+///
+/// assert_eq!(less::spin(Color::new_hsl(10.0, 0.9, 0.5), 30.0), Color::new_hsl(40.0, 0.9, 0.5));
+/// assert_eq!(less::spin(Color::new_hsl(10.0, 0.9, 0.5), -30.0), Color::new_hsl(340.0, 0.9, 0.5));
+/// ```
+pub fn spin<C>(color: C, degrees: f64) -> Color
+where
+    C: Into<Color>
+{
+    let color: Color = color.into();
+    let sass = SassColor::from_color(&color);
+    sass.with_hue(sass.hue() + degrees).into_color(&color)
+}
+
+
+/// Returns color mixed with white, where weight of white is a percentage between `0.0` and
+/// `100.0`, commonly `50.0`
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::less;
+///
+/// assert_eq!(less::tint(Color::new_rgb(0x00, 0x77, 0x00), 50.0), Color::new_rgb(0x80, 0xbb, 0x80));
+/// ```
+pub fn tint<C>(color: C, weight: f64) -> Color
+where
+    C: Into<Color>
+{
+    let color: Color = color.into();
+    let white = SassColor::from_rgb(255.0, 255.0, 255.0, 1.0);
+    white.mix(&SassColor::from_color(&color), weight).into_color(&color)
+}
+
+
+/// Returns color mixed with black, where weight of black is a percentage between `0.0` and
+/// `100.0`, commonly `50.0`
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::less;
+///
+/// assert_eq!(less::shade(Color::new_rgb(0x00, 0x77, 0x00), 50.0), Color::new_rgb(0x00, 0x3c, 0x00));
+/// ```
+pub fn shade<C>(color: C, weight: f64) -> Color
+where
+    C: Into<Color>
+{
+    let color: Color = color.into();
+    let black = SassColor::from_rgb(0.0, 0.0, 0.0, 1.0);
+    black.mix(&SassColor::from_color(&color), weight).into_color(&color)
+}
@@ -1,6 +1,6 @@
 #!/usr/bin/env rust
 #![deny(clippy::all, missing_docs, unsafe_code)]
-#![allow(clippy::upper_case_acronyms, clippy::from_over_into, clippy::match_like_matches_macro)]
+#![allow(clippy::upper_case_acronyms, clippy::from_over_into, clippy::match_like_matches_macro, clippy::manual_clamp)]
 
 
 //! A library for converting, comparing, and preforming arithmetic on colors
@@ -29,6 +29,56 @@
 //! ```
 
 
+/// Chromatic adaptation of device independent colors between reference white points
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::adaptation::Method;
+/// use color_operators::white_point::WhitePoint;
+/// use color_operators::xyz::XYZ;
+///
+/// let ( x, y, z ) = WhitePoint::D65.tristimulus();
+/// let ( x, y, z ) = XYZ::new(x, y, z).adapt(WhitePoint::D65, WhitePoint::D50, Method::Bradford).into();
+///
+/// assert_eq!(((x * 1e4).round(), (y * 1e4).round(), (z * 1e4).round()), (9643.0, 10000.0, 8251.0));
+/// ```
+pub mod adaptation;
+
+
+/// Separable and non-separable blend modes from W3C Compositing and Blending
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::blend::BlendMode;
+/// use color_operators::rgb::RGB;
+///
+/// let backdrop = RGB::new(255, 128, 0);
+/// let source = RGB::new(128, 128, 128);
+///
+/// assert_eq!(backdrop.blend(source, BlendMode::Multiply), RGB::new(128, 64, 0));
+/// ```
+pub mod blend;
+
+
+/// Data structure for Cyan, Magenta, Yellow, Key (black) encoded colors
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::cmyk::CMYK;
+///
+/// let cmyk = CMYK::new(0.0, 0.5, 0.25, 0.125);
+///
+/// assert_eq!(cmyk.get("cyan"), Ok(0.0));
+/// assert_eq!(cmyk.get("magenta"), Ok(0.5));
+/// assert_eq!(cmyk.get("yellow"), Ok(0.25));
+/// assert_eq!(cmyk.get("key"), Ok(0.125));
+/// ```
+pub mod cmyk;
+
+
 /// Enumerable for currently supported color data structures
 ///
 /// # Example
@@ -50,6 +100,147 @@
 pub mod color;
 
 
+/// Porter-Duff compositing of alpha carrying colors
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::composite::Operator;
+/// use color_operators::rgba::RGBA;
+///
+/// let source = RGBA::new(255, 0, 0, 255);
+/// let destination = RGBA::new(0, 0, 255, 255);
+///
+/// assert_eq!(Operator::SourceOver.composite(source, destination), RGBA::new(255, 0, 0, 255));
+/// ```
+pub mod composite;
+
+
+/// WCAG 2 contrast ratios and conformance levels, and APCA lightness contrast
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::contrast::{ Level, Report, TextSize };
+/// use color_operators::rgb::RGB;
+///
+/// let report = Report::between(RGB::new(0, 0, 0), RGB::new(255, 255, 255));
+///
+/// assert_eq!(report.ratio(), 21.0);
+/// assert!(report.passes(Level::AAA, TextSize::Normal));
+/// ```
+pub mod contrast;
+
+
+/// Parsing of CSS Color 4 color syntax
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::css;
+///
+/// assert_eq!(css::parse("hwb(0 0% 0%)"), Ok(Color::new_hwb(0.0, 0.0, 0.0)));
+/// assert_eq!(css::parse("transparent"), Ok(Color::new_rgba(0, 0, 0, 0)));
+/// ```
+pub mod css;
+
+
+/// Simulation of color vision deficiencies, a.k.a. color blindness
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::cvd::Deficiency;
+/// use color_operators::rgb::RGB;
+///
+/// let green = RGB::new(0, 255, 0);
+///
+/// assert_eq!(green.simulate_cvd(Deficiency::Achromatopsia, 1.0), RGB::new(220, 220, 220));
+/// ```
+pub mod cvd;
+
+
+/// Perceptual color difference, ΔE, formulas
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::difference::DeltaE;
+///
+/// let left = Color::new_rgb(255, 42, 90);
+/// let right = Color::new_rgb(254, 42, 91);
+///
+/// assert!(left.is_close(&right, DeltaE::CIEDE2000, 1.0));
+/// ```
+pub mod difference;
+
+
+/// Errors returned by fallible parsing and conversion methods
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::error::Error;
+/// use color_operators::rgb::RGB;
+///
+/// let result = RGB::try_from_json_str(r#"{ "red": 255, "green": 42 }"#);
+///
+/// assert_eq!(result, Err(Error::MissingComponent("blue".to_string())));
+/// ```
+pub mod error;
+
+
+/// Gamut checking and CSS Color 4 gamut mapping of colors into an `RgbSpace`
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::gamut::Method;
+/// use color_operators::rgb_space::RgbSpace;
+///
+/// let c_oklch = Color::new_oklch(0.9, 0.3, 140.0);
+///
+/// assert!(!c_oklch.in_gamut(&RgbSpace::srgb()));
+/// assert!(c_oklch.to_gamut(&RgbSpace::srgb(), Method::Css).in_gamut(&RgbSpace::srgb()));
+/// ```
+pub mod gamut;
+
+
+/// Ordered color stops with hints and easing, sampled or serialized as CSS `linear-gradient()`
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::gradient::Gradient;
+///
+/// let gradient = Gradient::new()
+///     .stop(Color::new_rgb(255, 0, 0), 0.0)
+///     .stop(Color::new_rgb(0, 0, 255), 1.0);
+///
+/// assert_eq!(gradient.colors(4).len(), 4);
+/// ```
+pub mod gradient;
+
+
+/// Color harmonies, e.g. complementary and triadic, generated by rotating hue of base color
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::harmony::{ Harmony, Wheel };
+///
+/// let colors = Harmony::Complementary.colors(Color::new_hsl(30.0, 1.0, 0.5), Wheel::HSL);
+///
+/// assert_eq!(colors, vec![ Color::new_hsl(30.0, 1.0, 0.5), Color::new_hsl(210.0, 1.0, 0.5) ]);
+/// ```
+pub mod harmony;
+
+
 /// Data structure for Hue, Saturation, Lightness encoded colors
 ///
 /// # Example
@@ -66,6 +257,20 @@ pub mod color;
 pub mod hsl;
 
 
+/// Data structure for Hue, Saturation, Lightness, Alpha encoded colors
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::hsla::HSLA;
+///
+/// let hsla = HSLA::new(60.0, 1.0, 0.5, 0.25);
+///
+/// assert_eq!(hsla.get("alpha"), Ok(0.25));
+/// ```
+pub mod hsla;
+
+
 /// Data structure for Hue, Saturation, Value encoded colors
 ///
 /// # Example
@@ -82,6 +287,145 @@ pub mod hsl;
 pub mod hsv;
 
 
+/// Data structure for Hue, Saturation, Value, Alpha encoded colors
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::hsva::HSVA;
+///
+/// let hsva = HSVA::new(60.0, 1.0, 0.5, 0.25);
+///
+/// assert_eq!(hsva.get("alpha"), Ok(0.25));
+/// ```
+pub mod hsva;
+
+
+/// Data structure for Hue, Whiteness, Blackness encoded colors
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::hwb::HWB;
+///
+/// let hwb = HWB::new(120.0, 0.25, 0.5);
+///
+/// assert_eq!(hwb.get("hue"), Ok(120.0));
+/// assert_eq!(hwb.get("whiteness"), Ok(0.25));
+/// assert_eq!(hwb.get("blackness"), Ok(0.5));
+/// ```
+pub mod hwb;
+
+
+/// Data structure for CIE L\*a\*b\* encoded colors
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::lab::Lab;
+///
+/// let lab = Lab::new(50.0, 25.0, -25.0);
+///
+/// assert_eq!(lab.get("lightness"), Ok(50.0));
+/// assert_eq!(lab.get("a"), Ok(25.0));
+/// assert_eq!(lab.get("b"), Ok(-25.0));
+/// ```
+pub mod lab;
+
+
+/// Data structure for CIE L\*C\*h(ab), cylindrical form of L\*a\*b\*, encoded colors
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::lch::LCh;
+///
+/// let lch = LCh::new(50.0, 25.0, 120.0);
+///
+/// assert_eq!(lch.get("lightness"), Ok(50.0));
+/// assert_eq!(lch.get("chroma"), Ok(25.0));
+/// assert_eq!(lch.get("hue"), Ok(120.0));
+/// ```
+pub mod lch;
+
+
+/// Less color functions that are not part of Sass, e.g. `fade`, `spin`, `tint`, and `shade`
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::less;
+///
+/// assert_eq!(less::tint(Color::new_rgb(0, 119, 0), 50.0), Color::new_rgb(128, 187, 128));
+/// ```
+pub mod less;
+
+
+/// Interpolation of colors within a chosen color space, following CSS Color 4
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::mix::{ HueMethod, Space };
+///
+/// let black = Color::new_rgb(0, 0, 0);
+/// let white = Color::new_rgb(255, 255, 255);
+///
+/// assert_eq!(black.mix(&white, 0.5, Space::SrgbLinear, HueMethod::Shorter), Color::new_rgb(188, 188, 188));
+/// ```
+pub mod mix;
+
+
+/// Data structure for Oklab encoded colors
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::oklab::Oklab;
+///
+/// let oklab = Oklab::new(0.5, 0.125, -0.125);
+///
+/// assert_eq!(oklab.get("l"), Ok(0.5));
+/// assert_eq!(oklab.get("a"), Ok(0.125));
+/// assert_eq!(oklab.get("b"), Ok(-0.125));
+/// ```
+pub mod oklab;
+
+
+/// Data structure for Oklch, cylindrical form of Oklab, encoded colors
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::oklch::Oklch;
+///
+/// let oklch = Oklch::new(0.5, 0.125, 120.0);
+///
+/// assert_eq!(oklch.get("l"), Ok(0.5));
+/// assert_eq!(oklch.get("c"), Ok(0.125));
+/// assert_eq!(oklch.get("h"), Ok(120.0));
+/// ```
+pub mod oklch;
+
+
+/// Lightness ramps, a.k.a. tints and shades, generated from one brand color
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::ramp::Ramp;
+///
+/// let scale = Ramp::new().generate(Color::new_rgb(255, 42, 90));
+///
+/// assert_eq!(scale.len(), 11);
+/// assert!(scale.get("50").is_some());
+/// ```
+pub mod ramp;
+
+
 /// Data structure for Red, Green, Blue encoded colors
 ///
 /// # Example
@@ -97,3 +441,90 @@ pub mod hsv;
 /// ```
 pub mod rgb;
 
+
+/// RGB color spaces defined by primaries, reference white, and transfer function, e.g. Display P3
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::rgb::RGB;
+/// use color_operators::rgb_space::RgbSpace;
+///
+/// let p3 = RgbSpace::display_p3().from_xyz(RgbSpace::srgb().to_xyz(RGB::new(0, 255, 0)));
+///
+/// assert_eq!(p3, RGB::new(117, 251, 76));
+/// ```
+pub mod rgb_space;
+
+
+/// Data structure for Red, Green, Blue, Alpha encoded colors
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::rgba::RGBA;
+///
+/// let rgba = RGBA::from_hex_string("FF2A5A80");
+///
+/// assert_eq!(rgba, RGBA::new(255, 42, 90, 128));
+/// ```
+pub mod rgba;
+
+
+/// Sass color functions, e.g. `lighten`, `mix`, and `adjust`, with results matching dart-sass for
+/// sRGB inputs
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::sass;
+///
+/// assert_eq!(sass::lighten(Color::new_rgb(107, 113, 127), 20.0), Ok(Color::new_rgb(161, 165, 175)));
+/// ```
+pub mod sass;
+
+
+/// Reference white points used by device independent color data structures
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::white_point::WhitePoint;
+///
+/// let ( x, y, z ) = WhitePoint::D65.tristimulus();
+///
+/// assert_eq!(y, 1.0);
+/// ```
+pub mod white_point;
+
+
+/// Data structure for CIE 1931 XYZ encoded colors
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::xyz::XYZ;
+///
+/// let xyz = XYZ::new(0.5, 0.25, 0.125);
+///
+/// assert_eq!(xyz.get("x"), Ok(0.5));
+/// assert_eq!(xyz.get("y"), Ok(0.25));
+/// assert_eq!(xyz.get("z"), Ok(0.125));
+/// ```
+pub mod xyz;
+
+
+/// Data structure for luma and blue/red difference chroma encoded colors, a.k.a. YUV
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::rgb::RGB;
+/// use color_operators::ycbcr::{ BitDepth, Matrix, Range, YCbCr };
+///
+/// let ycbcr = YCbCr::from_rgb(RGB::new(255, 0, 0), Matrix::BT709);
+///
+/// assert_eq!(ycbcr.to_digital(Range::Limited, BitDepth::Eight), (63, 102, 240));
+/// ```
+pub mod ycbcr;
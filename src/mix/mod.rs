@@ -0,0 +1,299 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::hsl::HSL;
+use crate::hsv::HSV;
+use crate::hwb::HWB;
+use crate::lab::Lab;
+use crate::lch::LCh;
+use crate::oklab::Oklab;
+use crate::oklch::Oklch;
+use crate::rgb::RGB;
+use crate::xyz::XYZ;
+
+
+/// Color spaces that colors may be interpolated within, named after CSS Color 4 keywords
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Space {
+    /// Gamma encoded red, green, blue, matching legacy tools and browsers
+    Srgb,
+
+    /// Red, green, blue with sRGB gamma removed, physically correct mixing of light
+    SrgbLinear,
+
+    /// Hue, saturation, lightness
+    HSL,
+
+    /// Hue, saturation, value
+    HSV,
+
+    /// Hue, whiteness, blackness
+    HWB,
+
+    /// CIE L\*a\*b\*
+    Lab,
+
+    /// CIE L\*C\*h(ab)
+    LCh,
+
+    /// Perceptually uniform without hue shifts, CSS default for mixing colors
+    #[default]
+    Oklab,
+
+    /// Cylindrical form of Oklab
+    Oklch,
+
+    /// CIE 1931 XYZ, relative to D65
+    XYZ,
+}
+
+
+/// How hue is interpolated within cylindrical spaces, as defined by CSS Color 4
+///
+/// **Note** hue methods are ignored for spaces without hue, e.g. `Space::Oklab`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HueMethod {
+    /// Travel the shortest way around hue circle, at most `180` degrees
+    #[default]
+    Shorter,
+
+    /// Travel the longest way around hue circle, at least `180` degrees
+    Longer,
+
+    /// Hue only increases, wrapping from `360` to `0`
+    Increasing,
+
+    /// Hue only decreases, wrapping from `0` to `360`
+    Decreasing,
+}
+
+
+/// Spaces paired with keywords used by CSS `in <space>` syntax, `hsv` is not part of CSS
+const SPACE_KEYWORDS: [(Space, &str); 10] = [
+    (Space::Srgb, "srgb"),
+    (Space::SrgbLinear, "srgb-linear"),
+    (Space::HSL, "hsl"),
+    (Space::HSV, "hsv"),
+    (Space::HWB, "hwb"),
+    (Space::Lab, "lab"),
+    (Space::LCh, "lch"),
+    (Space::Oklab, "oklab"),
+    (Space::Oklch, "oklch"),
+    (Space::XYZ, "xyz"),
+];
+
+
+/// Hue methods paired with keywords used by CSS `<method> hue` syntax
+const HUE_METHOD_KEYWORDS: [(HueMethod, &str); 4] = [
+    (HueMethod::Shorter, "shorter"),
+    (HueMethod::Longer, "longer"),
+    (HueMethod::Increasing, "increasing"),
+    (HueMethod::Decreasing, "decreasing"),
+];
+
+
+impl Space {
+    /// Returns lowercase keyword naming space
+    pub(crate) fn keyword(&self) -> &'static str {
+        SPACE_KEYWORDS.iter().find(|(space, _)| space == self).map(|(_, keyword)| *keyword).unwrap_or("oklab")
+    }
+
+    /// Returns space named by keyword, ignoring case
+    pub(crate) fn from_keyword(keyword: &str) -> Option<Self> {
+        SPACE_KEYWORDS.iter().find(|(_, k)| k.eq_ignore_ascii_case(keyword)).map(|(space, _)| *space)
+    }
+
+    /// Returns `true` for spaces with hue component
+    pub(crate) fn is_cylindrical(&self) -> bool {
+        self.hue_index().is_some()
+    }
+
+    /// Returns index of hue within components of cylindrical spaces
+    fn hue_index(&self) -> Option<usize> {
+        match self {
+            Self::HSL | Self::HSV | Self::HWB => Some(0),
+            Self::LCh | Self::Oklch => Some(2),
+            _ => None,
+        }
+    }
+
+    /// Returns components of color within space, followed by if hue is powerless, i.e. missing,
+    /// because color is achromatic
+    fn components(&self, color: Color) -> ([f64; 3], bool) {
+        match self {
+            Self::Srgb => {
+                let ( red, green, blue ) = RGB::from(color).into();
+                ([ red as f64 / 255.0, green as f64 / 255.0, blue as f64 / 255.0 ], false)
+            },
+            Self::SrgbLinear => {
+                let ( red, green, blue ) = RGB::from(color).to_linear();
+                ([ red, green, blue ], false)
+            },
+            Self::HSL => {
+                let ( hue, saturation, lightness ) = HSL::from(color).into();
+                ([ hue, saturation, lightness ], saturation <= 0.0)
+            },
+            Self::HSV => {
+                let ( hue, saturation, value ) = HSV::from(color).into();
+                ([ hue, saturation, value ], saturation <= 0.0)
+            },
+            Self::HWB => {
+                let ( hue, whiteness, blackness ) = HWB::from(color).into();
+                ([ hue, whiteness, blackness ], whiteness + blackness >= 1.0)
+            },
+            Self::Lab => {
+                let ( l, a, b ) = Lab::from(color).into();
+                ([ l, a, b ], false)
+            },
+            Self::LCh => {
+                let ( l, c, h ) = LCh::from(color).into();
+                ([ l, c, h ], c < 0.0015)
+            },
+            Self::Oklab => {
+                let ( l, a, b ) = Oklab::from(color).into();
+                ([ l, a, b ], false)
+            },
+            Self::Oklch => {
+                let ( l, c, h ) = Oklch::from(color).into();
+                ([ l, c, h ], c < 0.000004)
+            },
+            Self::XYZ => {
+                let ( x, y, z ) = XYZ::from(color).into();
+                ([ x, y, z ], false)
+            },
+        }
+    }
+
+    /// Returns color built from components within space
+    fn color(&self, components: [f64; 3]) -> Color {
+        let [ first, second, third ] = components;
+        match self {
+            Self::Srgb => {
+                let channel = |v: f64| -> u8 {
+                    (v * 255.0).round().min(255.0).max(0.0) as u8
+                };
+                Color::RGB(RGB::new(channel(first), channel(second), channel(third)))
+            },
+            Self::SrgbLinear => Color::RGB(RGB::from_linear(first, second, third)),
+            Self::HSL => Color::HSL(HSL::new(first, second, third)),
+            Self::HSV => Color::HSV(HSV::new(first, second, third)),
+            Self::HWB => Color::HWB(HWB::new(first, second, third)),
+            Self::Lab => Color::Lab(Lab::new(first, second, third)),
+            Self::LCh => Color::LCh(LCh::new(first, second, third)),
+            Self::Oklab => Color::Oklab(Oklab::new(first, second, third)),
+            Self::Oklch => Color::Oklch(Oklch::new(first, second, third)),
+            Self::XYZ => Color::XYZ(XYZ::new(first, second, third)),
+        }
+    }
+}
+
+
+impl HueMethod {
+    /// Returns lowercase keyword naming hue method
+    pub(crate) fn keyword(&self) -> &'static str {
+        HUE_METHOD_KEYWORDS.iter().find(|(method, _)| method == self).map(|(_, keyword)| *keyword).unwrap_or("shorter")
+    }
+
+    /// Returns hue method named by keyword, ignoring case
+    pub(crate) fn from_keyword(keyword: &str) -> Option<Self> {
+        HUE_METHOD_KEYWORDS.iter().find(|(_, k)| k.eq_ignore_ascii_case(keyword)).map(|(method, _)| *method)
+    }
+
+    /// Returns hues, in degrees, adjusted such that linear interpolation between them travels
+    /// around hue circle as method requires
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::mix::HueMethod;
+    ///
+    /// assert_eq!(HueMethod::Shorter.fixup(350.0, 10.0), (350.0, 370.0));
+    /// assert_eq!(HueMethod::Longer.fixup(350.0, 10.0), (350.0, 10.0));
+    /// assert_eq!(HueMethod::Increasing.fixup(10.0, 350.0), (10.0, 350.0));
+    /// assert_eq!(HueMethod::Decreasing.fixup(10.0, 350.0), (370.0, 350.0));
+    /// ```
+    pub fn fixup(&self, first: f64, second: f64) -> (f64, f64) {
+        let first = first.rem_euclid(360.0);
+        let second = second.rem_euclid(360.0);
+        let delta = second - first;
+
+        match self {
+            Self::Shorter if delta > 180.0 => (first + 360.0, second),
+            Self::Shorter if delta < -180.0 => (first, second + 360.0),
+            Self::Longer if delta > 0.0 && delta < 180.0 => (first + 360.0, second),
+            Self::Longer if delta > -180.0 && delta <= 0.0 => (first, second + 360.0),
+            Self::Increasing if delta < 0.0 => (first, second + 360.0),
+            Self::Decreasing if delta > 0.0 => (first + 360.0, second),
+            _ => (first, second),
+        }
+    }
+}
+
+
+/// Returns alpha between `0.0` and `1.0`, or `1.0` for data structures without alpha
+pub(crate) fn opacity(color: &Color) -> f64 {
+    match color {
+        Color::HSLA(v) => {
+            let ( _hue, _saturation, _lightness, alpha ) = v.clone().into();
+            alpha
+        },
+        Color::HSVA(v) => {
+            let ( _hue, _saturation, _value, alpha ) = v.clone().into();
+            alpha
+        },
+        Color::RGBA(v) => v.opacity(),
+        _ => 1.0,
+    }
+}
+
+
+/// Returns color interpolated within space, from first color at `0.0` to second at `1.0`, followed
+/// by interpolated alpha
+///
+/// Implementation follows CSS Color 4, i.e. powerless hue takes hue of other color and components
+/// other than hue are interpolated with alpha premultiplied
+pub(crate) fn interpolate(first: Color, second: Color, amount: f64, space: Space, hue_method: HueMethod) -> (Color, f64) {
+    let amount = amount.min(1.0).max(0.0);
+
+    let first_alpha = opacity(&first);
+    let second_alpha = opacity(&second);
+    let alpha = first_alpha + (second_alpha - first_alpha) * amount;
+
+    let ( mut first, first_powerless ) = space.components(first);
+    let ( mut second, second_powerless ) = space.components(second);
+
+    let hue_index = space.hue_index();
+    if let Some(index) = hue_index {
+        match (first_powerless, second_powerless) {
+            (true, true) => {
+                first[index] = 0.0;
+                second[index] = 0.0;
+            },
+            (true, false) => first[index] = second[index],
+            (false, true) => second[index] = first[index],
+            (false, false) => {
+                let ( first_hue, second_hue ) = hue_method.fixup(first[index], second[index]);
+                first[index] = first_hue;
+                second[index] = second_hue;
+            },
+        }
+    }
+
+    let mut result = [0.0; 3];
+    for (index, value) in result.iter_mut().enumerate() {
+        if Some(index) == hue_index {
+            *value = (first[index] + (second[index] - first[index]) * amount).rem_euclid(360.0);
+            continue;
+        }
+
+        let start = first[index] * first_alpha;
+        let end = second[index] * second_alpha;
+        *value = start + (end - start) * amount;
+        if alpha > 0.0 {
+            *value /= alpha;
+        }
+    }
+
+    (space.color(result), alpha)
+}
@@ -0,0 +1,50 @@
+#!/usr/bin/env rust
+
+
+use crate::oklab::Oklab;
+
+
+/// Convert from array of 64-bit precision floating point numbers
+impl<T> From<[T; 3]> for Oklab
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklab::Oklab;
+    ///
+    /// let array = [ 0.5, 0.125, -0.125 ];
+    /// let oklab = Oklab::from(array);
+    ///
+    /// assert_eq!(oklab.get("l"), Ok(0.5));
+    /// assert_eq!(oklab.get("a"), Ok(0.125));
+    /// assert_eq!(oklab.get("b"), Ok(-0.125));
+    /// ```
+    fn from(array: [T; 3]) -> Self {
+        let l = array[0];
+        let a = array[1];
+        let b = array[2];
+        Self::new(l, a, b)
+    }
+}
+
+
+/// Convert into array of 64-bit precision floating point numbers
+impl Into<[f64; 3]> for Oklab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklab::Oklab;
+    ///
+    /// let oklab = Oklab::new(0.5, 0.125, -0.125);
+    /// let array: [f64; 3] = oklab.into();
+    ///
+    /// assert_eq!(array[0], 0.5);
+    /// assert_eq!(array[1], 0.125);
+    /// assert_eq!(array[2], -0.125);
+    /// ```
+    fn into(self) -> [f64; 3] {
+        [ self.l, self.a, self.b ]
+    }
+}
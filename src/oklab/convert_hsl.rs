@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::hsl::HSL;
+use crate::oklab::Oklab;
+use crate::rgb_space::RgbSpace;
+
+
+/// Convert from `HSL` to `Oklab`
+///
+/// **Note** uses `RgbSpace::srgb().to_xyz` internally
+impl From<HSL> for Oklab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsl::HSL;
+    /// use color_operators::oklab::Oklab;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let hsl = HSL::from(rgb.clone());
+    /// let oklab = Oklab::from(hsl);
+    ///
+    /// assert_eq!(RGB::from(oklab), rgb);
+    /// ```
+    fn from(hsl: HSL) -> Self {
+        Self::from(RgbSpace::srgb().to_xyz(hsl))
+    }
+}
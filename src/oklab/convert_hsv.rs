@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::hsv::HSV;
+use crate::oklab::Oklab;
+use crate::rgb_space::RgbSpace;
+
+
+/// Convert from `HSV` to `Oklab`
+///
+/// **Note** uses `RgbSpace::srgb().to_xyz` internally
+impl From<HSV> for Oklab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsv::HSV;
+    /// use color_operators::oklab::Oklab;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let hsv = HSV::from(rgb.clone());
+    /// let oklab = Oklab::from(hsv);
+    ///
+    /// assert_eq!(RGB::from(oklab), rgb);
+    /// ```
+    fn from(hsv: HSV) -> Self {
+        Self::from(RgbSpace::srgb().to_xyz(hsv))
+    }
+}
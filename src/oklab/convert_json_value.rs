@@ -0,0 +1,99 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+extern crate json;
+use json::JsonValue;
+
+
+use crate::error::{ json_f64, Error };
+use crate::oklab::Oklab;
+
+
+/// Converts from `JsonValue` key value pares, or error for missing or invalid components
+impl TryFrom<&JsonValue> for Oklab {
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::oklab::Oklab;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{ "l": "nope" }"#).unwrap();
+    ///
+    /// assert_eq!(Oklab::try_from(&data), Err(Error::InvalidComponent("l".to_string())));
+    /// ```
+    fn try_from(data: &JsonValue) -> Result<Self, Self::Error> {
+        let l = json_f64(data, "l")?;
+        let a = json_f64(data, "a")?;
+        let b = json_f64(data, "b")?;
+        Ok(Self::new(l, a, b))
+    }
+}
+
+
+/// Converts from `JsonValue` key value pares
+///
+/// **Note** missing or invalid components default individually, where `Oklab::try_from` returns
+/// error
+impl From<JsonValue> for Oklab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklab::Oklab;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{
+    ///     "l": 0.5,
+    ///     "a": 0.125,
+    ///     "b": -0.125
+    /// }"#).unwrap();
+    ///
+    /// let oklab = Oklab::from(data);
+    ///
+    /// assert_eq!(oklab.get("l"), Ok(0.5));
+    /// assert_eq!(oklab.get("a"), Ok(0.125));
+    /// assert_eq!(oklab.get("b"), Ok(-0.125));
+    /// ```
+    fn from(data: JsonValue) -> Self {
+        let l = data["l"].as_f64().unwrap_or_default();
+        let a = data["a"].as_f64().unwrap_or_default();
+        let b = data["b"].as_f64().unwrap_or_default();
+        Self::new(l, a, b)
+    }
+}
+
+
+/// Converts to `JsonValue`
+impl Into<JsonValue> for Oklab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklab::Oklab;
+    /// extern crate json;
+    /// use json::JsonValue;
+    ///
+    /// let oklab = Oklab::new(0.5, 0.125, -0.125);
+    /// let data: JsonValue = oklab.into();
+    ///
+    /// let expected = json::parse(r#"{
+    ///     "l": 0.5,
+    ///     "a": 0.125,
+    ///     "b": -0.125
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(data, expected);
+    /// ```
+    fn into(self) -> JsonValue {
+        json::object!{
+            "l" => self.l,
+            "a" => self.a,
+            "b" => self.b,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::lab::Lab;
+use crate::oklab::Oklab;
+use crate::xyz::XYZ;
+
+
+/// Convert from `Lab` to `Oklab`
+///
+/// **Note** uses `XYZ::from(lab)` internally
+impl From<Lab> for Oklab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    /// use color_operators::oklab::Oklab;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let lab = Lab::from(rgb.clone());
+    /// let oklab = Oklab::from(lab);
+    ///
+    /// assert_eq!(RGB::from(oklab), rgb);
+    /// ```
+    fn from(lab: Lab) -> Self {
+        Self::from(XYZ::from(lab))
+    }
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::lch::LCh;
+use crate::oklab::Oklab;
+use crate::xyz::XYZ;
+
+
+/// Convert from `LCh` to `Oklab`
+///
+/// **Note** uses `XYZ::from(lch)` internally
+impl From<LCh> for Oklab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    /// use color_operators::oklab::Oklab;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let lch = LCh::from(rgb.clone());
+    /// let oklab = Oklab::from(lch);
+    ///
+    /// assert_eq!(RGB::from(oklab), rgb);
+    /// ```
+    fn from(lch: LCh) -> Self {
+        Self::from(XYZ::from(lch))
+    }
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::oklab::Oklab;
+use crate::oklch::Oklch;
+
+
+/// Convert from `Oklch` to `Oklab`
+impl From<Oklch> for Oklab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklab::Oklab;
+    /// use color_operators::oklch::Oklch;
+    ///
+    /// let oklch = Oklch::new(0.5, 0.125, 0.0);
+    /// let oklab = Oklab::from(oklch);
+    ///
+    /// assert_eq!(oklab.get("l"), Ok(0.5));
+    /// assert_eq!(oklab.get("a"), Ok(0.125));
+    /// assert_eq!(oklab.get("b"), Ok(0.0));
+    /// ```
+    fn from(oklch: Oklch) -> Self {
+        let ( l, c, h ) = oklch.into();
+        let a = c * h.to_radians().cos();
+        let b = c * h.to_radians().sin();
+        Self::new(l, a, b)
+    }
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::oklab::Oklab;
+use crate::rgb::RGB;
+use crate::rgb_space::RgbSpace;
+
+
+/// Convert from `RGB` to `Oklab`
+///
+/// **Note** this uses `RgbSpace::srgb` to convert to `XYZ` prior to `Oklab`
+impl From<RGB> for Oklab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklab::Oklab;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 255, 255);
+    /// let oklab = Oklab::from(rgb);
+    ///
+    /// assert_eq!(oklab.get("l").unwrap().round(), 1.0);
+    /// assert_eq!(oklab.get("a").unwrap().round(), 0.0);
+    /// assert_eq!(oklab.get("b").unwrap().round(), 0.0);
+    /// ```
+    fn from(rgb: RGB) -> Self {
+        Self::from(RgbSpace::srgb().to_xyz(rgb))
+    }
+}
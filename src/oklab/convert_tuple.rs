@@ -0,0 +1,48 @@
+#!/usr/bin/env rust
+
+
+use crate::oklab::Oklab;
+
+
+/// Convert from tuple of 64-bit precision floating point numbers
+impl<T> From<(T, T, T)> for Oklab
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklab::Oklab;
+    ///
+    /// let tuple = ( 0.5, 0.125, -0.125 );
+    /// let oklab = Oklab::from(tuple);
+    ///
+    /// assert_eq!(oklab.get("l"), Ok(0.5));
+    /// assert_eq!(oklab.get("a"), Ok(0.125));
+    /// assert_eq!(oklab.get("b"), Ok(-0.125));
+    /// ```
+    fn from(tuple: (T, T, T)) -> Self {
+        let ( l, a, b ) = tuple;
+        Self::new(l, a, b)
+    }
+}
+
+
+/// Convert into tuple of 64-bit precision floating point numbers
+impl Into<(f64, f64, f64)> for Oklab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklab::Oklab;
+    ///
+    /// let oklab = Oklab::new(0.5, 0.125, -0.125);
+    /// let ( l, a, b ) = oklab.into();
+    ///
+    /// assert_eq!(l, 0.5);
+    /// assert_eq!(a, 0.125);
+    /// assert_eq!(b, -0.125);
+    /// ```
+    fn into(self) -> (f64, f64, f64) {
+        ( self.l, self.a, self.b )
+    }
+}
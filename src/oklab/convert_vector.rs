@@ -0,0 +1,85 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+use crate::error::{ check_length, Error };
+use crate::oklab::Oklab;
+
+
+/// Convert from slice of 64-bit precision floating point numbers, or error for invalid length
+impl<T> TryFrom<&[T]> for Oklab
+where
+    T: Into<f64> + Copy
+{
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::oklab::Oklab;
+    ///
+    /// let oklab = Oklab::try_from(&[0.5, 0.125, -0.125][..]).unwrap();
+    /// assert_eq!(oklab, Oklab::from(vec![0.5, 0.125, -0.125]));
+    ///
+    /// let result = Oklab::try_from(&[0.5, 0.125][..]);
+    /// assert_eq!(result, Err(Error::Length { expected: 3, found: 2 }));
+    /// ```
+    fn try_from(values: &[T]) -> Result<Self, Self::Error> {
+        check_length(values.len(), 3, 3)?;
+        let l = values[0].into();
+        let a = values[1].into();
+        let b = values[2].into();
+        Ok(Self::new(l, a, b))
+    }
+}
+
+
+/// Convert from vector of 64-bit precision floating point numbers
+///
+/// **Note** this uses `Oklab::try_from` internally, values past the third are ignored, and too few
+/// values return default values
+impl<T> From<Vec<T>> for Oklab
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklab::Oklab;
+    ///
+    /// let vector = vec![0.5, 0.125, -0.125];
+    /// let oklab = Oklab::from(vector);
+    ///
+    /// assert_eq!(oklab.get("l"), Ok(0.5));
+    /// assert_eq!(oklab.get("a"), Ok(0.125));
+    /// assert_eq!(oklab.get("b"), Ok(-0.125));
+    /// ```
+    fn from(vector: Vec<T>) -> Self {
+        let length = vector.len().min(3);
+        Self::try_from(&vector[..length]).unwrap_or_default()
+    }
+}
+
+
+/// Convert into vector of 64-bit precision floating point numbers
+impl Into<Vec<f64>> for Oklab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklab::Oklab;
+    ///
+    /// let oklab = Oklab::new(0.5, 0.125, -0.125);
+    /// let vector: Vec<f64> = oklab.into();
+    ///
+    /// assert_eq!(vector[0], 0.5);
+    /// assert_eq!(vector[1], 0.125);
+    /// assert_eq!(vector[2], -0.125);
+    /// ```
+    fn into(self) -> Vec<f64> {
+        vec![ self.l, self.a, self.b ]
+    }
+}
@@ -0,0 +1,32 @@
+#!/usr/bin/env rust
+
+
+use crate::oklab::Oklab;
+use crate::xyz::XYZ;
+
+
+/// Convert from `XYZ` to `Oklab`
+impl From<XYZ> for Oklab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklab::Oklab;
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let xyz = XYZ::from(rgb.clone());
+    /// let oklab = Oklab::from(xyz);
+    ///
+    /// assert_eq!(RGB::from(oklab), rgb);
+    /// ```
+    fn from(xyz: XYZ) -> Self {
+        let ( x, y, z ) = xyz.into();
+
+        let l = 0.8189330101 * x + 0.3618667424 * y - 0.1288597137 * z;
+        let m = 0.0329845436 * x + 0.9293118715 * y + 0.0361456387 * z;
+        let s = 0.0482003018 * x + 0.2643662691 * y + 0.6338517070 * z;
+
+        Self::from_lms(l, m, s)
+    }
+}
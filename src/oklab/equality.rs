@@ -0,0 +1,54 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::color::equality::alpha_eq;
+use crate::oklab::Oklab;
+
+
+impl Eq for Oklab {}
+impl PartialEq for Oklab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklab::Oklab;
+    ///
+    /// let left = Oklab::new(0.5, 0.125, -0.125);
+    /// let right = Oklab::new(0.5, 0.125, -0.125);
+    ///
+    /// assert_eq!(left, right);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        if self.l != other.l || self.a != other.a || self.b != other.b {
+            return false;
+        }
+        true
+    }
+}
+
+
+/// Converts right side from `Color` to `Oklab` prior to checking equality
+///
+/// **Note** colors with alpha are compared as `RGBA`, so are only equal when fully opaque
+impl PartialEq<Color> for Oklab {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::oklab::Oklab;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let oklab = Oklab::from(rgb.clone());
+    ///
+    /// assert!(oklab == Color::from(rgb));
+    /// ```
+    fn eq(&self, other: &Color) -> bool {
+        if let Some(equal) = alpha_eq(&Color::from(self.clone()), other) {
+            return equal;
+        }
+
+        let other: Oklab = other.clone().into();
+        self == &other
+    }
+}
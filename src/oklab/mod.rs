@@ -0,0 +1,309 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+
+use crate::css::Options;
+use crate::css::serialize;
+use crate::error::Error;
+use crate::oklch::Oklch;
+use crate::rgb::RGB;
+
+
+/// Adds color components for `Oklab` data structures
+mod add;
+
+/// Subtracts color components for `Oklab` data structures
+mod subtract;
+
+/// Equal and not-equal checks for `Oklab` data structures
+mod equality;
+
+/// Converts from/to array for `Oklab` data structures
+mod convert_array;
+
+/// Converts from/to `Color` enum
+mod convert_color;
+
+/// Converts from `HSL` to `Oklab` data structure
+mod convert_hsl;
+
+/// Converts from `HSV` to `Oklab` data structure
+mod convert_hsv;
+
+/// Converts from/to `JsonValue` for `Oklab` data structures
+mod convert_json_value;
+
+/// Converts from `Lab` to `Oklab` data structure
+mod convert_lab;
+
+/// Converts from `LCh` to `Oklab` data structure
+mod convert_lch;
+
+/// Converts from `Oklch` to `Oklab` data structure
+mod convert_oklch;
+
+/// Converts from `RGB` to `Oklab` data structure
+mod convert_rgb;
+
+/// Converts from/to tuple for `Oklab` data structures
+mod convert_tuple;
+
+/// Converts from/to vector for `Oklab` data structures
+mod convert_vector;
+
+/// Converts from `XYZ` to `Oklab` data structure
+mod convert_xyz;
+
+
+/// Data structure for Oklab encoded colors
+///
+/// **Note** perceived lightness `l` ranges from `0.0` to `1.0`, and `a`, `b` are typically within
+/// `-0.4` to `0.4` for displayable colors
+#[derive(Clone, Debug, Default)]
+pub struct Oklab {
+    l: f64,
+    a: f64,
+    b: f64,
+}
+
+
+impl Oklab {
+    /// Returns new instance of `Oklab` data structure
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklab::Oklab;
+    ///
+    /// let color = Oklab::new(0.5, 0.125, -0.125);
+    ///
+    /// assert_eq!(color.get("l"), Ok(0.5));
+    /// assert_eq!(color.get("a"), Ok(0.125));
+    /// assert_eq!(color.get("b"), Ok(-0.125));
+    /// ```
+    pub fn new<T>(l: T, a: T, b: T) -> Self
+    where
+        T: Into<f64>
+    {
+        let l: f64 = l.into().min(1.0).max(0.0);
+        Self { l, a: a.into(), b: b.into() }
+    }
+
+    /// Returns named component value or error
+    ///
+    /// ```rust
+    /// use color_operators::oklab::Oklab;
+    ///
+    /// let color = Oklab::new(0.5, 0.125, -0.125);
+    ///
+    /// assert_eq!(color.get("nothing"), Err("No color component named -> nothing".to_string()));
+    /// ```
+    pub fn get<S>(&self, component: S) -> Result<f64, String>
+    where
+        S: Into<String>
+    {
+        let component: String = component.into();
+        match component.as_str() {
+            "l" => Ok(self.l),
+            "a" => Ok(self.a),
+            "b" => Ok(self.b),
+            _ => Err(format!("No color component named -> {}", component)),
+        }
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or default values
+    ///
+    /// **Note** missing or invalid components default individually, see `From<JsonValue>`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklab::Oklab;
+    ///
+    /// let oklab = Oklab::from_json_string(r#"{ "l": 0.5, "a": 0.125, "b": -0.125 }"#);
+    ///
+    /// assert_eq!(oklab.get("l"), Ok(0.5));
+    /// assert_eq!(oklab.get("a"), Ok(0.125));
+    /// assert_eq!(oklab.get("b"), Ok(-0.125));
+    /// ```
+    pub fn from_json_string<S>(string: S) -> Self
+    where
+        S: Into<String>
+    {
+        json::parse(&string.into()).map(Self::from).unwrap_or_default()
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or error for invalid JSON and missing
+    /// or invalid components
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::error::Error;
+    /// use color_operators::oklab::Oklab;
+    ///
+    /// let result = Oklab::try_from_json_str(r#"{ "l": 1 }"#);
+    ///
+    /// assert_eq!(result, Err(Error::MissingComponent("a".to_string())));
+    /// ```
+    pub fn try_from_json_str(string: &str) -> Result<Self, Error> {
+        let data = json::parse(string)?;
+        Self::try_from(&data)
+    }
+
+    /// Serializes data structure as JSON string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklab::Oklab;
+    ///
+    /// let oklab = Oklab::new(0.5, 0.125, -0.125);
+    /// let data = oklab.to_json_string();
+    ///
+    /// let object = json::object!{
+    ///     "l" => 0.5,
+    ///     "a" => 0.125,
+    ///     "b" => -0.125
+    /// };
+    ///
+    /// let expected = json::stringify(object);
+    ///
+    /// assert_eq!(data, expected);
+    /// ```
+    pub fn to_json_string(&self) -> String {
+        json::stringify(self.clone())
+    }
+
+    /// Converts hexadecimal string into `Oklab`
+    ///
+    /// **Note** this method uses `RGB::from_hex_string` internally, which returns default values
+    /// for invalid input
+    pub fn from_hex_string<S>(input: S) -> Self
+    where
+        S: Into<String>
+    {
+        Self::from(RGB::from_hex_string(input))
+    }
+
+    /// Converts hexadecimal string into `Oklab`, or returns error for invalid input
+    ///
+    /// **Note** this method uses `RGB::try_from_hex_string` internally
+    pub fn try_from_hex_string<S>(input: S) -> Result<Self, Error>
+    where
+        S: Into<String>
+    {
+        RGB::try_from_hex_string(input).map(Self::from)
+    }
+
+    /// Returns hexadecimal string representation of `Oklab` values
+    ///
+    /// **Note** this method uses `RGB::to_hex_string` internally
+    pub fn to_hex_string(&self) -> String {
+        RGB::from(self.clone()).to_hex_string()
+    }
+
+    /// Returns CSS representation of `Oklab` values
+    ///
+    /// **Note** `oklab()` has no legacy syntax, so legacy syntax, hexadecimal, and named colors are
+    /// converted via `RGB`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::css::{ Options, Syntax, Units };
+    /// use color_operators::oklab::Oklab;
+    ///
+    /// let color = Oklab::new(0.5, 0.1, -0.2);
+    ///
+    /// assert_eq!(color.to_css_string(Options::new()), "oklab(0.5 0.1 -0.2)");
+    /// assert_eq!(color.to_css_string(Options::new().units(Units::Percent)), "oklab(50% 25% -50%)");
+    /// assert!(color.to_css_string(Options::new().syntax(Syntax::Legacy)).starts_with("rgb("));
+    /// ```
+    pub fn to_css_string(&self, options: Options) -> String {
+        let rgb = RGB::from(self.clone()).into();
+        serialize::lab("oklab", [ self.l, self.a, self.b ], [ Some(1.0), Some(0.4), Some(0.4) ], rgb, options)
+    }
+
+    /// Returns `Oklab` from cone responses of the Oklab LMS space
+    pub(crate) fn from_lms(l: f64, m: f64, s: f64) -> Self {
+        let l = l.cbrt();
+        let m = m.cbrt();
+        let s = s.cbrt();
+
+        Self {
+            l: 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            a: 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            b: 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+        }
+    }
+
+    /// Returns cone responses of the Oklab LMS space
+    pub(crate) fn to_lms(&self) -> (f64, f64, f64) {
+        let l = self.l + 0.3963377774 * self.a + 0.2158037573 * self.b;
+        let m = self.l - 0.1055613458 * self.a - 0.0638541728 * self.b;
+        let s = self.l - 0.0894841775 * self.a - 1.2914855480 * self.b;
+
+        ( l.powi(3), m.powi(3), s.powi(3) )
+    }
+
+    /// Attempts to rotate hue by some amount of degrees
+    ///
+    /// **Note** this method uses `Oklch::rotate_hue` internally
+    pub fn rotate_hue<T>(&self, amount: T) -> Self
+    where
+        T: Into<f64>
+    {
+        let oklch = Oklch::from(self.clone());
+        Self::from(oklch.rotate_hue(amount))
+    }
+
+    /// Increases perceived lightness by some amount
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklab::Oklab;
+    ///
+    /// let color = Oklab::new(0.5, 0.125, -0.125);
+    /// let lighter = color.lighten(0.25);
+    ///
+    /// assert_eq!(lighter, Oklab::new(0.75, 0.125, -0.125));
+    /// ```
+    pub fn lighten<T>(&self, amount: T) -> Self
+    where
+        T: Into<f64>
+    {
+        Self::new(self.l + amount.into(), self.a, self.b)
+    }
+
+    /// Decreases perceived lightness by some amount
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklab::Oklab;
+    ///
+    /// let color = Oklab::new(0.5, 0.125, -0.125);
+    /// let darker = color.darken(0.25);
+    ///
+    /// assert_eq!(darker, Oklab::new(0.25, 0.125, -0.125));
+    /// ```
+    pub fn darken<T>(&self, amount: T) -> Self
+    where
+        T: Into<f64>
+    {
+        Self::new(self.l - amount.into(), self.a, self.b)
+    }
+}
+
+
+impl Display for Oklab {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "l: {}, a: {}, b: {}", self.l, self.a, self.b)
+    }
+}
@@ -0,0 +1,61 @@
+#!/usr/bin/env rust
+
+
+use std::ops::Add;
+
+
+use crate::color::Color;
+use crate::oklch::Oklch;
+use crate::rgb::RGB;
+
+
+/// Adds color components
+///
+/// **Note** this uses `RGB::add` internally
+impl Add for Oklch {
+    type Output = Self;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklch::Oklch;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let left = Oklch::from(RGB::new(25, 0, 0));
+    /// let right = Oklch::from(RGB::new(25, 0, 0));
+    /// let result = left + right;
+    ///
+    /// let expected = Oklch::from(RGB::new(50, 0, 0));
+    /// assert_eq!(result, expected);
+    /// ```
+    fn add(self, other: Self) -> Self::Output {
+        let left = RGB::from(self);
+        let right = RGB::from(other);
+        Oklch::from(left + right)
+    }
+}
+
+
+/// Converts right side from `Color` to `Oklch` prior to arithmetic
+impl Add<Color> for Oklch {
+    type Output = Self;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::oklch::Oklch;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let left = Oklch::from(RGB::new(25, 0, 0));
+    /// let right = Color::new_rgb(25, 0, 0);
+    ///
+    /// let expected = Oklch::from(RGB::new(50, 0, 0));
+    ///
+    /// assert_eq!(left + right, expected);
+    /// ```
+    fn add(self, other: Color) -> Self::Output {
+        let other: Oklch = other.into();
+        self + other
+    }
+}
@@ -0,0 +1,50 @@
+#!/usr/bin/env rust
+
+
+use crate::oklch::Oklch;
+
+
+/// Convert from array of 64-bit precision floating point numbers
+impl<T> From<[T; 3]> for Oklch
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklch::Oklch;
+    ///
+    /// let array = [ 0.5, 0.125, 120.0 ];
+    /// let oklch = Oklch::from(array);
+    ///
+    /// assert_eq!(oklch.get("l"), Ok(0.5));
+    /// assert_eq!(oklch.get("c"), Ok(0.125));
+    /// assert_eq!(oklch.get("h"), Ok(120.0));
+    /// ```
+    fn from(array: [T; 3]) -> Self {
+        let l = array[0];
+        let c = array[1];
+        let h = array[2];
+        Self::new(l, c, h)
+    }
+}
+
+
+/// Convert into array of 64-bit precision floating point numbers
+impl Into<[f64; 3]> for Oklch {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklch::Oklch;
+    ///
+    /// let oklch = Oklch::new(0.5, 0.125, 120.0);
+    /// let array: [f64; 3] = oklch.into();
+    ///
+    /// assert_eq!(array[0], 0.5);
+    /// assert_eq!(array[1], 0.125);
+    /// assert_eq!(array[2], 120.0);
+    /// ```
+    fn into(self) -> [f64; 3] {
+        [ self.l, self.c, self.h ]
+    }
+}
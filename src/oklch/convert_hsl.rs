@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::hsl::HSL;
+use crate::oklab::Oklab;
+use crate::oklch::Oklch;
+
+
+/// Convert from `HSL` to `Oklch`
+///
+/// **Note** uses `Oklab::from(hsl)` internally
+impl From<HSL> for Oklch {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsl::HSL;
+    /// use color_operators::oklch::Oklch;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let hsl = HSL::from(rgb.clone());
+    /// let oklch = Oklch::from(hsl);
+    ///
+    /// assert_eq!(RGB::from(oklch), rgb);
+    /// ```
+    fn from(hsl: HSL) -> Self {
+        Self::from(Oklab::from(hsl))
+    }
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::hsv::HSV;
+use crate::oklab::Oklab;
+use crate::oklch::Oklch;
+
+
+/// Convert from `HSV` to `Oklch`
+///
+/// **Note** uses `Oklab::from(hsv)` internally
+impl From<HSV> for Oklch {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsv::HSV;
+    /// use color_operators::oklch::Oklch;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let hsv = HSV::from(rgb.clone());
+    /// let oklch = Oklch::from(hsv);
+    ///
+    /// assert_eq!(RGB::from(oklch), rgb);
+    /// ```
+    fn from(hsv: HSV) -> Self {
+        Self::from(Oklab::from(hsv))
+    }
+}
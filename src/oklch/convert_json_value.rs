@@ -0,0 +1,99 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+extern crate json;
+use json::JsonValue;
+
+
+use crate::error::{ json_f64, Error };
+use crate::oklch::Oklch;
+
+
+/// Converts from `JsonValue` key value pares, or error for missing or invalid components
+impl TryFrom<&JsonValue> for Oklch {
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::oklch::Oklch;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{ "l": "nope" }"#).unwrap();
+    ///
+    /// assert_eq!(Oklch::try_from(&data), Err(Error::InvalidComponent("l".to_string())));
+    /// ```
+    fn try_from(data: &JsonValue) -> Result<Self, Self::Error> {
+        let l = json_f64(data, "l")?;
+        let c = json_f64(data, "c")?;
+        let h = json_f64(data, "h")?;
+        Ok(Self::new(l, c, h))
+    }
+}
+
+
+/// Converts from `JsonValue` key value pares
+///
+/// **Note** missing or invalid components default individually, where `Oklch::try_from` returns
+/// error
+impl From<JsonValue> for Oklch {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklch::Oklch;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{
+    ///     "l": 0.5,
+    ///     "c": 0.125,
+    ///     "h": 120.0
+    /// }"#).unwrap();
+    ///
+    /// let oklch = Oklch::from(data);
+    ///
+    /// assert_eq!(oklch.get("l"), Ok(0.5));
+    /// assert_eq!(oklch.get("c"), Ok(0.125));
+    /// assert_eq!(oklch.get("h"), Ok(120.0));
+    /// ```
+    fn from(data: JsonValue) -> Self {
+        let l = data["l"].as_f64().unwrap_or_default();
+        let c = data["c"].as_f64().unwrap_or_default();
+        let h = data["h"].as_f64().unwrap_or_default();
+        Self::new(l, c, h)
+    }
+}
+
+
+/// Converts to `JsonValue`
+impl Into<JsonValue> for Oklch {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklch::Oklch;
+    /// extern crate json;
+    /// use json::JsonValue;
+    ///
+    /// let oklch = Oklch::new(0.5, 0.125, 120.0);
+    /// let data: JsonValue = oklch.into();
+    ///
+    /// let expected = json::parse(r#"{
+    ///     "l": 0.5,
+    ///     "c": 0.125,
+    ///     "h": 120.0
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(data, expected);
+    /// ```
+    fn into(self) -> JsonValue {
+        json::object!{
+            "l" => self.l,
+            "c" => self.c,
+            "h" => self.h,
+        }
+    }
+}
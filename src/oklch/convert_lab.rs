@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::lab::Lab;
+use crate::oklab::Oklab;
+use crate::oklch::Oklch;
+
+
+/// Convert from `Lab` to `Oklch`
+///
+/// **Note** uses `Oklab::from(lab)` internally
+impl From<Lab> for Oklch {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    /// use color_operators::oklch::Oklch;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let lab = Lab::from(rgb.clone());
+    /// let oklch = Oklch::from(lab);
+    ///
+    /// assert_eq!(RGB::from(oklch), rgb);
+    /// ```
+    fn from(lab: Lab) -> Self {
+        Self::from(Oklab::from(lab))
+    }
+}
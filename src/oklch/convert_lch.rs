@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::lch::LCh;
+use crate::oklab::Oklab;
+use crate::oklch::Oklch;
+
+
+/// Convert from `LCh` to `Oklch`
+///
+/// **Note** uses `Oklab::from(lch)` internally
+impl From<LCh> for Oklch {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    /// use color_operators::oklch::Oklch;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let lch = LCh::from(rgb.clone());
+    /// let oklch = Oklch::from(lch);
+    ///
+    /// assert_eq!(RGB::from(oklch), rgb);
+    /// ```
+    fn from(lch: LCh) -> Self {
+        Self::from(Oklab::from(lch))
+    }
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::oklab::Oklab;
+use crate::oklch::Oklch;
+
+
+/// Convert from `Oklab` to `Oklch`
+impl From<Oklab> for Oklch {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklab::Oklab;
+    /// use color_operators::oklch::Oklch;
+    ///
+    /// let oklab = Oklab::new(0.5, 0.0, 0.125);
+    /// let oklch = Oklch::from(oklab);
+    ///
+    /// assert_eq!(oklch.get("l"), Ok(0.5));
+    /// assert_eq!(oklch.get("c"), Ok(0.125));
+    /// assert_eq!(oklch.get("h"), Ok(90.0));
+    /// ```
+    fn from(oklab: Oklab) -> Self {
+        let ( l, a, b ) = oklab.into();
+        let c = a.hypot(b);
+        let h = b.atan2(a).to_degrees();
+        Self::new(l, c, h)
+    }
+}
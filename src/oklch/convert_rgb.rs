@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::oklab::Oklab;
+use crate::oklch::Oklch;
+use crate::rgb::RGB;
+
+
+/// Convert from `RGB` to `Oklch`
+///
+/// **Note** uses `Oklab::from(rgb)` internally
+impl From<RGB> for Oklch {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklch::Oklch;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 0, 0);
+    /// let oklch = Oklch::from(rgb);
+    ///
+    /// assert_eq!((oklch.get("l").unwrap() * 100.0).round(), 63.0);
+    /// assert_eq!((oklch.get("c").unwrap() * 100.0).round(), 26.0);
+    /// assert_eq!(oklch.get("h").unwrap().round(), 29.0);
+    /// ```
+    fn from(rgb: RGB) -> Self {
+        Self::from(Oklab::from(rgb))
+    }
+}
@@ -0,0 +1,48 @@
+#!/usr/bin/env rust
+
+
+use crate::oklch::Oklch;
+
+
+/// Convert from tuple of 64-bit precision floating point numbers
+impl<T> From<(T, T, T)> for Oklch
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklch::Oklch;
+    ///
+    /// let tuple = ( 0.5, 0.125, 120.0 );
+    /// let oklch = Oklch::from(tuple);
+    ///
+    /// assert_eq!(oklch.get("l"), Ok(0.5));
+    /// assert_eq!(oklch.get("c"), Ok(0.125));
+    /// assert_eq!(oklch.get("h"), Ok(120.0));
+    /// ```
+    fn from(tuple: (T, T, T)) -> Self {
+        let ( l, c, h ) = tuple;
+        Self::new(l, c, h)
+    }
+}
+
+
+/// Convert into tuple of 64-bit precision floating point numbers
+impl Into<(f64, f64, f64)> for Oklch {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklch::Oklch;
+    ///
+    /// let oklch = Oklch::new(0.5, 0.125, 120.0);
+    /// let ( l, c, h ) = oklch.into();
+    ///
+    /// assert_eq!(l, 0.5);
+    /// assert_eq!(c, 0.125);
+    /// assert_eq!(h, 120.0);
+    /// ```
+    fn into(self) -> (f64, f64, f64) {
+        ( self.l, self.c, self.h )
+    }
+}
@@ -0,0 +1,85 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+use crate::error::{ check_length, Error };
+use crate::oklch::Oklch;
+
+
+/// Convert from slice of 64-bit precision floating point numbers, or error for invalid length
+impl<T> TryFrom<&[T]> for Oklch
+where
+    T: Into<f64> + Copy
+{
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::oklch::Oklch;
+    ///
+    /// let oklch = Oklch::try_from(&[0.5, 0.125, 120.0][..]).unwrap();
+    /// assert_eq!(oklch, Oklch::from(vec![0.5, 0.125, 120.0]));
+    ///
+    /// let result = Oklch::try_from(&[0.5, 0.125][..]);
+    /// assert_eq!(result, Err(Error::Length { expected: 3, found: 2 }));
+    /// ```
+    fn try_from(values: &[T]) -> Result<Self, Self::Error> {
+        check_length(values.len(), 3, 3)?;
+        let l = values[0].into();
+        let c = values[1].into();
+        let h = values[2].into();
+        Ok(Self::new(l, c, h))
+    }
+}
+
+
+/// Convert from vector of 64-bit precision floating point numbers
+///
+/// **Note** this uses `Oklch::try_from` internally, values past the third are ignored, and too few
+/// values return default values
+impl<T> From<Vec<T>> for Oklch
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklch::Oklch;
+    ///
+    /// let vector = vec![0.5, 0.125, 120.0];
+    /// let oklch = Oklch::from(vector);
+    ///
+    /// assert_eq!(oklch.get("l"), Ok(0.5));
+    /// assert_eq!(oklch.get("c"), Ok(0.125));
+    /// assert_eq!(oklch.get("h"), Ok(120.0));
+    /// ```
+    fn from(vector: Vec<T>) -> Self {
+        let length = vector.len().min(3);
+        Self::try_from(&vector[..length]).unwrap_or_default()
+    }
+}
+
+
+/// Convert into vector of 64-bit precision floating point numbers
+impl Into<Vec<f64>> for Oklch {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklch::Oklch;
+    ///
+    /// let oklch = Oklch::new(0.5, 0.125, 120.0);
+    /// let vector: Vec<f64> = oklch.into();
+    ///
+    /// assert_eq!(vector[0], 0.5);
+    /// assert_eq!(vector[1], 0.125);
+    /// assert_eq!(vector[2], 120.0);
+    /// ```
+    fn into(self) -> Vec<f64> {
+        vec![ self.l, self.c, self.h ]
+    }
+}
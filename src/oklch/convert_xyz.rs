@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::oklab::Oklab;
+use crate::oklch::Oklch;
+use crate::xyz::XYZ;
+
+
+/// Convert from `XYZ` to `Oklch`
+///
+/// **Note** uses `Oklab::from(xyz)` internally
+impl From<XYZ> for Oklch {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklch::Oklch;
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let xyz = XYZ::from(rgb.clone());
+    /// let oklch = Oklch::from(xyz);
+    ///
+    /// assert_eq!(RGB::from(oklch), rgb);
+    /// ```
+    fn from(xyz: XYZ) -> Self {
+        Self::from(Oklab::from(xyz))
+    }
+}
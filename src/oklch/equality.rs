@@ -0,0 +1,54 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::color::equality::alpha_eq;
+use crate::oklch::Oklch;
+
+
+impl Eq for Oklch {}
+impl PartialEq for Oklch {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklch::Oklch;
+    ///
+    /// let left = Oklch::new(0.5, 0.125, 120.0);
+    /// let right = Oklch::new(0.5, 0.125, 120.0);
+    ///
+    /// assert_eq!(left, right);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        if self.l != other.l || self.c != other.c || self.h != other.h {
+            return false;
+        }
+        true
+    }
+}
+
+
+/// Converts right side from `Color` to `Oklch` prior to checking equality
+///
+/// **Note** colors with alpha are compared as `RGBA`, so are only equal when fully opaque
+impl PartialEq<Color> for Oklch {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::oklch::Oklch;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let oklch = Oklch::from(rgb.clone());
+    ///
+    /// assert!(oklch == Color::from(rgb));
+    /// ```
+    fn eq(&self, other: &Color) -> bool {
+        if let Some(equal) = alpha_eq(&Color::from(self.clone()), other) {
+            return equal;
+        }
+
+        let other: Oklch = other.clone().into();
+        self == &other
+    }
+}
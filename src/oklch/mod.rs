@@ -0,0 +1,320 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+
+use crate::css::Options;
+use crate::css::serialize;
+use crate::error::Error;
+use crate::rgb::RGB;
+
+
+/// Adds color components for `Oklch` data structures
+mod add;
+
+/// Subtracts color components for `Oklch` data structures
+mod subtract;
+
+/// Equal and not-equal checks for `Oklch` data structures
+mod equality;
+
+/// Converts from/to array for `Oklch` data structures
+mod convert_array;
+
+/// Converts from/to `Color` enum
+mod convert_color;
+
+/// Converts from `HSL` to `Oklch` data structure
+mod convert_hsl;
+
+/// Converts from `HSV` to `Oklch` data structure
+mod convert_hsv;
+
+/// Converts from/to `JsonValue` for `Oklch` data structures
+mod convert_json_value;
+
+/// Converts from `Lab` to `Oklch` data structure
+mod convert_lab;
+
+/// Converts from `LCh` to `Oklch` data structure
+mod convert_lch;
+
+/// Converts from `Oklab` to `Oklch` data structure
+mod convert_oklab;
+
+/// Converts from `RGB` to `Oklch` data structure
+mod convert_rgb;
+
+/// Converts from/to tuple for `Oklch` data structures
+mod convert_tuple;
+
+/// Converts from/to vector for `Oklch` data structures
+mod convert_vector;
+
+/// Converts from `XYZ` to `Oklch` data structure
+mod convert_xyz;
+
+
+/// Data structure for Oklch, cylindrical form of Oklab, encoded colors
+///
+/// **Note** hue is measured in degrees counterclockwise from the positive `a` axis of `Oklab`
+#[derive(Clone, Debug, Default)]
+pub struct Oklch {
+    l: f64,
+    c: f64,
+    h: f64,
+}
+
+
+impl Oklch {
+    /// Returns new instance of `Oklch` data structure
+    ///
+    /// **Note** hue wraps around to stay within `0.0` through `360.0` degrees
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklch::Oklch;
+    ///
+    /// let color = Oklch::new(0.5, 0.125, 120.0);
+    ///
+    /// assert_eq!(color.get("l"), Ok(0.5));
+    /// assert_eq!(color.get("c"), Ok(0.125));
+    /// assert_eq!(color.get("h"), Ok(120.0));
+    /// ```
+    pub fn new<T>(l: T, c: T, h: T) -> Self
+    where
+        T: Into<f64>
+    {
+        let l: f64 = l.into().min(1.0).max(0.0);
+        let c: f64 = c.into().max(0.0);
+        let h: f64 = h.into().rem_euclid(360.0);
+        Self { l, c, h }
+    }
+
+    /// Returns named component value or error
+    ///
+    /// ```rust
+    /// use color_operators::oklch::Oklch;
+    ///
+    /// let color = Oklch::new(0.5, 0.125, 120.0);
+    ///
+    /// assert_eq!(color.get("nothing"), Err("No color component named -> nothing".to_string()));
+    /// ```
+    pub fn get<S>(&self, component: S) -> Result<f64, String>
+    where
+        S: Into<String>
+    {
+        let component: String = component.into();
+        match component.as_str() {
+            "l" => Ok(self.l),
+            "c" => Ok(self.c),
+            "h" => Ok(self.h),
+            _ => Err(format!("No color component named -> {}", component)),
+        }
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or default values
+    ///
+    /// **Note** missing or invalid components default individually, see `From<JsonValue>`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklch::Oklch;
+    ///
+    /// let oklch = Oklch::from_json_string(r#"{ "l": 0.5, "c": 0.125, "h": 120.0 }"#);
+    ///
+    /// assert_eq!(oklch.get("l"), Ok(0.5));
+    /// assert_eq!(oklch.get("c"), Ok(0.125));
+    /// assert_eq!(oklch.get("h"), Ok(120.0));
+    /// ```
+    pub fn from_json_string<S>(string: S) -> Self
+    where
+        S: Into<String>
+    {
+        json::parse(&string.into()).map(Self::from).unwrap_or_default()
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or error for invalid JSON and missing
+    /// or invalid components
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::error::Error;
+    /// use color_operators::oklch::Oklch;
+    ///
+    /// let result = Oklch::try_from_json_str(r#"{ "l": 1 }"#);
+    ///
+    /// assert_eq!(result, Err(Error::MissingComponent("c".to_string())));
+    /// ```
+    pub fn try_from_json_str(string: &str) -> Result<Self, Error> {
+        let data = json::parse(string)?;
+        Self::try_from(&data)
+    }
+
+    /// Serializes data structure as JSON string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklch::Oklch;
+    ///
+    /// let oklch = Oklch::new(0.5, 0.125, 120.0);
+    /// let data = oklch.to_json_string();
+    ///
+    /// let object = json::object!{
+    ///     "l" => 0.5,
+    ///     "c" => 0.125,
+    ///     "h" => 120.0
+    /// };
+    ///
+    /// let expected = json::stringify(object);
+    ///
+    /// assert_eq!(data, expected);
+    /// ```
+    pub fn to_json_string(&self) -> String {
+        json::stringify(self.clone())
+    }
+
+    /// Converts hexadecimal string into `Oklch`
+    ///
+    /// **Note** this method uses `RGB::from_hex_string` internally, which returns default values
+    /// for invalid input
+    pub fn from_hex_string<S>(input: S) -> Self
+    where
+        S: Into<String>
+    {
+        Self::from(RGB::from_hex_string(input))
+    }
+
+    /// Converts hexadecimal string into `Oklch`, or returns error for invalid input
+    ///
+    /// **Note** this method uses `RGB::try_from_hex_string` internally
+    pub fn try_from_hex_string<S>(input: S) -> Result<Self, Error>
+    where
+        S: Into<String>
+    {
+        RGB::try_from_hex_string(input).map(Self::from)
+    }
+
+    /// Returns hexadecimal string representation of `Oklch` values
+    ///
+    /// **Note** this method uses `RGB::to_hex_string` internally
+    pub fn to_hex_string(&self) -> String {
+        RGB::from(self.clone()).to_hex_string()
+    }
+
+    /// Returns CSS representation of `Oklch` values
+    ///
+    /// **Note** `oklch()` has no legacy syntax, so legacy syntax, hexadecimal, and named colors are
+    /// converted via `RGB`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::css::{ Options, Syntax, Units };
+    /// use color_operators::oklch::Oklch;
+    ///
+    /// let color = Oklch::new(0.5, 0.2, 120.0);
+    ///
+    /// assert_eq!(color.to_css_string(Options::new()), "oklch(0.5 0.2 120)");
+    /// assert_eq!(color.to_css_string(Options::new().units(Units::Percent)), "oklch(50% 50% 120)");
+    /// assert!(color.to_css_string(Options::new().syntax(Syntax::Legacy)).starts_with("rgb("));
+    /// ```
+    pub fn to_css_string(&self, options: Options) -> String {
+        let rgb = RGB::from(self.clone()).into();
+        serialize::lab("oklch", [ self.l, self.c, self.h ], [ Some(1.0), Some(0.4), None ], rgb, options)
+    }
+
+    /// Attempts to rotate hue by some amount of degrees
+    ///
+    /// **Note** unlike `HSL::rotate_hue` perceived lightness and chroma are preserved
+    ///
+    /// # Examples
+    ///
+    /// Positive values rotates hue counterclockwise
+    ///
+    /// ```rust
+    /// use color_operators::oklch::Oklch;
+    ///
+    /// let color = Oklch::new(0.5, 0.125, 120.0);
+    /// let rotated = color.rotate_hue(300.0);
+    ///
+    /// let expected = Oklch::new(0.5, 0.125, 60.0);
+    ///
+    /// assert_eq!(rotated, expected);
+    /// ```
+    ///
+    /// Negative values rotates hue clockwise
+    ///
+    /// ```rust
+    /// use color_operators::oklch::Oklch;
+    ///
+    /// let color = Oklch::new(0.5, 0.125, 120.0);
+    /// let rotated = color.rotate_hue(-180.0);
+    ///
+    /// let expected = Oklch::new(0.5, 0.125, 300.0);
+    ///
+    /// assert_eq!(rotated, expected);
+    /// ```
+    pub fn rotate_hue<T>(&self, amount: T) -> Self
+    where
+        T: Into<f64>
+    {
+        Self::new(self.l, self.c, self.h + amount.into())
+    }
+
+    /// Increases perceived lightness by some amount while preserving chroma and hue
+    ///
+    /// **Note** lightness is clamped between `0.0` and `1.0`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklch::Oklch;
+    ///
+    /// let color = Oklch::new(0.5, 0.125, 120.0);
+    /// let lighter = color.lighten(0.25);
+    ///
+    /// assert_eq!(lighter, Oklch::new(0.75, 0.125, 120.0));
+    /// ```
+    pub fn lighten<T>(&self, amount: T) -> Self
+    where
+        T: Into<f64>
+    {
+        Self::new(self.l + amount.into(), self.c, self.h)
+    }
+
+    /// Decreases perceived lightness by some amount while preserving chroma and hue
+    ///
+    /// **Note** lightness is clamped between `0.0` and `1.0`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklch::Oklch;
+    ///
+    /// let color = Oklch::new(0.5, 0.125, 120.0);
+    /// let darker = color.darken(0.25);
+    ///
+    /// assert_eq!(darker, Oklch::new(0.25, 0.125, 120.0));
+    /// ```
+    pub fn darken<T>(&self, amount: T) -> Self
+    where
+        T: Into<f64>
+    {
+        Self::new(self.l - amount.into(), self.c, self.h)
+    }
+}
+
+
+impl Display for Oklch {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "l: {}, c: {}, h: {}", self.l, self.c, self.h)
+    }
+}
@@ -0,0 +1,286 @@
+#!/usr/bin/env rust
+
+
+extern crate json;
+use json::JsonValue;
+
+
+use crate::color::Color;
+use crate::gamut;
+use crate::mix;
+use crate::oklch::Oklch;
+use crate::rgb_space::RgbSpace;
+
+
+/// Labels and `Oklch` lightness of Tailwind style `50` through `950` scales
+const DEFAULT_TARGETS: [(&str, f64); 11] = [
+    ("50", 0.97),
+    ("100", 0.93),
+    ("200", 0.87),
+    ("300", 0.80),
+    ("400", 0.71),
+    ("500", 0.62),
+    ("600", 0.54),
+    ("700", 0.46),
+    ("800", 0.38),
+    ("900", 0.31),
+    ("950", 0.23),
+];
+
+
+/// Lightness of lightest and darkest step for evenly spaced ramps
+const LIGHTEST: f64 = 0.97;
+const DARKEST: f64 = 0.23;
+
+
+/// How chroma of brand color is scaled at each step
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum ChromaCurve {
+    /// Chroma of brand color at every step, reduced only by mapping within sRGB gamut
+    Constant,
+
+    /// Chroma of brand color at its own lightness, tapering linearly toward `0.0` at white and black
+    #[default]
+    Taper,
+
+    /// Multiplier of brand chroma for each step in order, missing steps use `1.0`
+    Custom(Vec<f64>),
+}
+
+
+/// Settings for generating a lightness ramp, a.k.a. tints and shades, from one brand color
+///
+/// **Note** steps are generated within `Oklch`, and steps outside of sRGB gamut are brought within
+/// it by `gamut::Method::Css`
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::oklch::Oklch;
+/// use color_operators::ramp::Ramp;
+///
+/// let brand = Color::new_rgb(59, 130, 246);
+/// let scale = Ramp::new().anchor(true).generate(brand.clone());
+///
+/// assert_eq!(scale.labels(), vec!["50", "100", "200", "300", "400", "500", "600", "700", "800", "900", "950"]);
+/// assert_eq!(scale.get("500"), Some(&brand));
+///
+/// let lightness = |label: &str| Oklch::from(scale.get(label).unwrap().clone()).get("l").unwrap();
+///
+/// assert!(lightness("50") > 0.95);
+/// assert!(lightness("950") < 0.25);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ramp {
+    targets: Vec<(String, f64)>,
+    chroma: ChromaCurve,
+    hue_drift: f64,
+    anchor: bool,
+}
+
+
+/// Labeled colors of a ramp, from lightest to darkest
+#[derive(Clone, Debug, PartialEq)]
+pub struct Scale {
+    swatches: Vec<(String, Color)>,
+}
+
+
+impl ChromaCurve {
+    /// Returns multiplier of brand chroma for step at index with lightness
+    fn factor(&self, index: usize, lightness: f64, brand_lightness: f64) -> f64 {
+        match self {
+            Self::Constant => 1.0,
+            Self::Taper if lightness >= brand_lightness && brand_lightness < 1.0 => {
+                (1.0 - lightness) / (1.0 - brand_lightness)
+            },
+            Self::Taper if lightness < brand_lightness => lightness / brand_lightness,
+            Self::Taper => 1.0,
+            Self::Custom(factors) => factors.get(index).copied().unwrap_or(1.0),
+        }
+    }
+}
+
+
+/// Defaults to Tailwind style `50` through `950` labels, tapered chroma, no hue drift, and no anchor
+impl Default for Ramp {
+    fn default() -> Self {
+        Self {
+            targets: DEFAULT_TARGETS.iter().map(|(label, lightness)| (label.to_string(), *lightness)).collect(),
+            chroma: ChromaCurve::default(),
+            hue_drift: 0.0,
+            anchor: false,
+        }
+    }
+}
+
+
+impl Ramp {
+    /// Returns default settings, see `Ramp::default`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Radix style steps labeled `1` through count, with lightness evenly spaced from near-white to
+    /// near-black
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::ramp::Ramp;
+    ///
+    /// let scale = Ramp::new().steps(12).generate(Color::new_rgb(255, 42, 90));
+    ///
+    /// assert_eq!(scale.len(), 12);
+    /// assert_eq!(scale.labels()[11], "12");
+    /// ```
+    pub fn steps(self, count: usize) -> Self {
+        let targets = (0..count).map(|index| {
+            let lightness = match count {
+                1 => (LIGHTEST + DARKEST) / 2.0,
+                _ => LIGHTEST + (DARKEST - LIGHTEST) * index as f64 / (count - 1) as f64,
+            };
+            ((index + 1).to_string(), lightness)
+        }).collect();
+        Self { targets, ..self }
+    }
+
+    /// Labels and `Oklch` lightness, between `0.0` and `1.0`, of each step in order
+    pub fn targets(self, targets: &[(&str, f64)]) -> Self {
+        let targets = targets.iter().map(|(label, lightness)| (label.to_string(), *lightness)).collect();
+        Self { targets, ..self }
+    }
+
+    /// How chroma of brand color is scaled at each step
+    pub fn chroma(self, chroma: ChromaCurve) -> Self {
+        Self { chroma, ..self }
+    }
+
+    /// Degrees hue rotates per unit decrease of `Oklch` lightness, anchored at brand color, e.g.
+    /// `20.0` turns darker steps toward higher hue and lighter steps toward lower hue
+    pub fn hue_drift(self, hue_drift: f64) -> Self {
+        Self { hue_drift, ..self }
+    }
+
+    /// When `true` step with lightness closest to brand color is replaced by brand color itself
+    pub fn anchor(self, anchor: bool) -> Self {
+        Self { anchor, ..self }
+    }
+
+    /// Returns labeled steps generated from brand color
+    ///
+    /// **Note** steps are converted to type of brand color and keep its alpha
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::oklch::Oklch;
+    /// use color_operators::ramp::{ ChromaCurve, Ramp };
+    ///
+    /// let brand = Color::new_oklch(0.6, 0.05, 150.0);
+    /// let scale = Ramp::new()
+    ///     .targets(&[ ("light", 0.9), ("dark", 0.3) ])
+    ///     .chroma(ChromaCurve::Constant)
+    ///     .hue_drift(10.0)
+    ///     .generate(brand);
+    ///
+    /// let light = Oklch::from(scale.get("light").unwrap().clone());
+    /// let dark = Oklch::from(scale.get("dark").unwrap().clone());
+    ///
+    /// assert_eq!((light.get("h").unwrap() * 100.0).round(), 14_700.0);
+    /// assert_eq!((dark.get("h").unwrap() * 100.0).round(), 15_300.0);
+    /// ```
+    pub fn generate<C>(&self, brand: C) -> Scale
+    where
+        C: Into<Color>
+    {
+        let brand: Color = brand.into();
+        let alpha = mix::opacity(&brand);
+        let ( brand_lightness, brand_chroma, brand_hue ) = Oklch::from(brand.clone()).into();
+        let srgb = RgbSpace::srgb();
+
+        let distance = |target: &(String, f64)| (target.1 - brand_lightness).abs();
+        let anchor = if self.anchor {
+            self.targets.iter().enumerate().min_by(|(_, a), (_, b)| distance(a).total_cmp(&distance(b))).map(|(index, _)| index)
+        } else {
+            None
+        };
+
+        let swatches = self.targets.iter().enumerate().map(|(index, (label, lightness))| {
+            if Some(index) == anchor {
+                return (label.clone(), brand.clone());
+            }
+
+            let lightness = lightness.min(1.0).max(0.0);
+            let chroma = brand_chroma * self.chroma.factor(index, lightness, brand_lightness);
+            let hue = (brand_hue + self.hue_drift * (brand_lightness - lightness)).rem_euclid(360.0);
+
+            let mut step = Color::new_oklch(lightness, chroma, hue);
+            if !step.in_gamut(&srgb) {
+                step = step.to_gamut(&srgb, gamut::Method::Css);
+            }
+            (label.clone(), brand.convert_like(step, alpha))
+        }).collect();
+
+        Scale { swatches }
+    }
+}
+
+
+impl Scale {
+    /// Returns color of labeled step
+    pub fn get(&self, label: &str) -> Option<&Color> {
+        self.swatches.iter().find(|(l, _)| l == label).map(|(_, color)| color)
+    }
+
+    /// Returns labels from lightest to darkest
+    pub fn labels(&self) -> Vec<&str> {
+        self.swatches.iter().map(|(label, _)| label.as_str()).collect()
+    }
+
+    /// Returns colors from lightest to darkest
+    pub fn colors(&self) -> Vec<Color> {
+        self.swatches.iter().map(|(_, color)| color.clone()).collect()
+    }
+
+    /// Returns number of steps
+    pub fn len(&self) -> usize {
+        self.swatches.len()
+    }
+
+    /// Returns `true` for scale without steps
+    pub fn is_empty(&self) -> bool {
+        self.swatches.is_empty()
+    }
+
+    /// Serializes scale as JSON object of labels to colors, from lightest to darkest
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::ramp::Ramp;
+    ///
+    /// let scale = Ramp::new().targets(&[ ("white", 1.0) ]).generate(Color::new_rgb(255, 42, 90));
+    ///
+    /// assert_eq!(scale.to_json_string(), r#"{"white":{"red":255,"green":255,"blue":255}}"#);
+    /// ```
+    pub fn to_json_string(&self) -> String {
+        json::stringify(self.clone())
+    }
+}
+
+
+/// Converts to `JsonValue` object of labels to colors
+impl Into<JsonValue> for Scale {
+    fn into(self) -> JsonValue {
+        let mut data = JsonValue::new_object();
+        for (label, color) in self.swatches.into_iter() {
+            data[label.as_str()] = color.into();
+        }
+        data
+    }
+}
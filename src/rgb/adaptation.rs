@@ -0,0 +1,93 @@
+#!/usr/bin/env rust
+
+
+use crate::adaptation::Method;
+use crate::lab::Lab;
+use crate::rgb::RGB;
+use crate::white_point::WhitePoint;
+use crate::xyz::XYZ;
+
+
+impl RGB {
+    /// Returns `XYZ` values relative to given reference white, adapted from `WhitePoint::D65` of
+    /// sRGB by method
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::adaptation::Method;
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::white_point::WhitePoint;
+    ///
+    /// let ( x, y, z ) = RGB::new(255, 255, 255).to_xyz(WhitePoint::D50, Method::Bradford).into();
+    /// let ( white_x, white_y, white_z ) = WhitePoint::D50.tristimulus();
+    ///
+    /// assert!((x - white_x).abs() < 1e-9);
+    /// assert!((y - white_y).abs() < 1e-9);
+    /// assert!((z - white_z).abs() < 1e-9);
+    /// ```
+    pub fn to_xyz(&self, white_point: WhitePoint, method: Method) -> XYZ {
+        method.adapt(XYZ::from(self.clone()), WhitePoint::D65, white_point)
+    }
+
+    /// Returns `RGB` from `XYZ` values relative to given reference white, adapted to
+    /// `WhitePoint::D65` of sRGB by method
+    ///
+    /// **Note** colors outside of sRGB gamut are clamped by `RGB::from_linear`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::adaptation::Method;
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::white_point::WhitePoint;
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let ( x, y, z ) = WhitePoint::D50.tristimulus();
+    ///
+    /// assert_eq!(RGB::from_xyz(XYZ::new(x, y, z), WhitePoint::D50, Method::Bradford), RGB::new(255, 255, 255));
+    /// assert_ne!(RGB::from(XYZ::new(x, y, z)), RGB::new(255, 255, 255));
+    /// ```
+    pub fn from_xyz(xyz: XYZ, white_point: WhitePoint, method: Method) -> Self {
+        Self::from(method.adapt(xyz, white_point, WhitePoint::D65))
+    }
+
+    /// Returns `Lab` relative to given reference white, adapted from `WhitePoint::D65` of sRGB by
+    /// method
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::adaptation::Method;
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::white_point::WhitePoint;
+    ///
+    /// let ( l, a, b ) = RGB::new(255, 0, 0).to_lab(WhitePoint::D50, Method::Bradford).into();
+    ///
+    /// assert_eq!((l.round(), a.round(), b.round()), (54.0, 81.0, 70.0));
+    /// ```
+    pub fn to_lab(&self, white_point: WhitePoint, method: Method) -> Lab {
+        Lab::from_xyz(self.to_xyz(white_point, method), white_point)
+    }
+
+    /// Returns `RGB` from `Lab` relative to given reference white, adapted to `WhitePoint::D65` of
+    /// sRGB by method
+    ///
+    /// **Note** colors outside of sRGB gamut are clamped by `RGB::from_linear`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::adaptation::Method;
+    /// use color_operators::lab::Lab;
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::white_point::WhitePoint;
+    ///
+    /// let lab = Lab::new(54.29, 80.8, 69.89);
+    ///
+    /// assert_eq!(RGB::from_lab(lab, WhitePoint::D50, Method::Bradford), RGB::new(255, 0, 0));
+    /// ```
+    pub fn from_lab(lab: Lab, white_point: WhitePoint, method: Method) -> Self {
+        Self::from_xyz(lab.to_xyz(white_point), white_point, method)
+    }
+}
@@ -0,0 +1,35 @@
+#!/usr/bin/env rust
+
+
+use crate::cmyk::CMYK;
+use crate::rgb::RGB;
+
+
+/// Convert from `CMYK` to `RGB`
+///
+/// **Note** floating point to unsigned integer conversions may be inaccurate due to binary to/from
+/// decimal conversions
+impl From<CMYK> for RGB {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cmyk::CMYK;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let cmyk = CMYK::new(0.0, 1.0, 1.0, 0.0);
+    /// let rgb = RGB::from(cmyk);
+    ///
+    /// assert_eq!(rgb.get("red"), Ok(255));
+    /// assert_eq!(rgb.get("green"), Ok(0));
+    /// assert_eq!(rgb.get("blue"), Ok(0));
+    /// ```
+    fn from(cmyk: CMYK) -> Self {
+        let ( cyan, magenta, yellow, key ) = cmyk.into();
+
+        let red = 255.0 * (1.0 - cyan) * (1.0 - key);
+        let green = 255.0 * (1.0 - magenta) * (1.0 - key);
+        let blue = 255.0 * (1.0 - yellow) * (1.0 - key);
+
+        Self::new(red.round() as u8, green.round() as u8, blue.round() as u8)
+    }
+}
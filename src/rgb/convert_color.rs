@@ -3,14 +3,27 @@
 
 use crate::rgb::RGB;
 use crate::color::Color;
+use crate::hsl::HSL;
+use crate::hsv::HSV;
 
 
 impl From<Color> for RGB {
     fn from(color: Color) -> Self {
         match color {
+            Color::CMYK(v) => Self::from(v),
             Color::HSL(v) => Self::from(v),
+            Color::HSLA(v) => Self::from(HSL::from(v)),
             Color::HSV(v) => Self::from(v),
+            Color::HSVA(v) => Self::from(HSV::from(v)),
+            Color::HWB(v) => Self::from(v),
+            Color::Lab(v) => Self::from(v),
+            Color::LCh(v) => Self::from(v),
+            Color::Oklab(v) => Self::from(v),
+            Color::Oklch(v) => Self::from(v),
             Color::RGB(v) => v,
+            Color::RGBA(v) => Self::from(v),
+            Color::XYZ(v) => Self::from(v),
+            Color::YCbCr(v) => Self::from(v),
         }
     }
 }
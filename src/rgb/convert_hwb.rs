@@ -0,0 +1,27 @@
+#!/usr/bin/env rust
+
+
+use crate::hsv::HSV;
+use crate::hwb::HWB;
+use crate::rgb::RGB;
+
+
+/// Convert from `HWB` to `RGB`
+///
+/// **Note** uses `HSV::from(hwb)` internally
+impl From<HWB> for RGB {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hwb::HWB;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let hwb = HWB::from(rgb.clone());
+    ///
+    /// assert_eq!(RGB::from(hwb), rgb);
+    /// ```
+    fn from(hwb: HWB) -> Self {
+        Self::from(HSV::from(hwb))
+    }
+}
@@ -1,14 +1,46 @@
 #!/usr/bin/env rust
 
 
+use std::convert::TryFrom;
+
+
 extern crate json;
 use json::JsonValue;
 
 
+use crate::error::{ json_u8, Error };
 use crate::rgb::RGB;
 
 
+/// Converts from `JsonValue` key value pares, or error for missing or invalid components
+impl TryFrom<&JsonValue> for RGB {
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::rgb::RGB;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{ "red": "nope" }"#).unwrap();
+    ///
+    /// assert_eq!(RGB::try_from(&data), Err(Error::InvalidComponent("red".to_string())));
+    /// ```
+    fn try_from(data: &JsonValue) -> Result<Self, Self::Error> {
+        let red = json_u8(data, "red")?;
+        let green = json_u8(data, "green")?;
+        let blue = json_u8(data, "blue")?;
+        Ok(Self { red, green, blue })
+    }
+}
+
+
 /// Converts from `JsonValue` key value pares
+///
+/// **Note** missing or invalid components default individually, where `RGB::try_from` returns
+/// error
 impl From<JsonValue> for RGB {
     /// # Example
     ///
@@ -0,0 +1,27 @@
+#!/usr/bin/env rust
+
+
+use crate::lab::Lab;
+use crate::rgb::RGB;
+use crate::xyz::XYZ;
+
+
+/// Convert from `Lab` to `RGB`
+///
+/// **Note** uses `XYZ::from(lab)` internally
+impl From<Lab> for RGB {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let lab = Lab::from(rgb.clone());
+    ///
+    /// assert_eq!(RGB::from(lab), rgb);
+    /// ```
+    fn from(lab: Lab) -> Self {
+        Self::from(XYZ::from(lab))
+    }
+}
@@ -0,0 +1,27 @@
+#!/usr/bin/env rust
+
+
+use crate::lab::Lab;
+use crate::lch::LCh;
+use crate::rgb::RGB;
+
+
+/// Convert from `LCh` to `RGB`
+///
+/// **Note** uses `Lab::from(lch)` internally
+impl From<LCh> for RGB {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let lch = LCh::from(rgb.clone());
+    ///
+    /// assert_eq!(RGB::from(lch), rgb);
+    /// ```
+    fn from(lch: LCh) -> Self {
+        Self::from(Lab::from(lch))
+    }
+}
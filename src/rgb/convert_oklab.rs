@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::oklab::Oklab;
+use crate::rgb::RGB;
+use crate::rgb_space::RgbSpace;
+use crate::xyz::XYZ;
+
+
+/// Convert from `Oklab` to `RGB`
+///
+/// **Note** this uses `RgbSpace::srgb` to convert from `XYZ`, and colors outside of sRGB gamut are
+/// clamped
+impl From<Oklab> for RGB {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklab::Oklab;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let oklab = Oklab::from(rgb.clone());
+    ///
+    /// assert_eq!(RGB::from(oklab), rgb);
+    /// ```
+    fn from(oklab: Oklab) -> Self {
+        RgbSpace::srgb().from_xyz(XYZ::from(oklab))
+    }
+}
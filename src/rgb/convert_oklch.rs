@@ -0,0 +1,27 @@
+#!/usr/bin/env rust
+
+
+use crate::oklab::Oklab;
+use crate::oklch::Oklch;
+use crate::rgb::RGB;
+
+
+/// Convert from `Oklch` to `RGB`
+///
+/// **Note** uses `Oklab::from(oklch)` internally
+impl From<Oklch> for RGB {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklch::Oklch;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let oklch = Oklch::from(rgb.clone());
+    ///
+    /// assert_eq!(RGB::from(oklch), rgb);
+    /// ```
+    fn from(oklch: Oklch) -> Self {
+        Self::from(Oklab::from(oklch))
+    }
+}
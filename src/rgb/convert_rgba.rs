@@ -0,0 +1,26 @@
+#!/usr/bin/env rust
+
+
+use crate::rgb::RGB;
+use crate::rgba::RGBA;
+
+
+/// Convert from `RGBA` to `RGB`
+///
+/// **Note** alpha is discarded
+impl From<RGBA> for RGB {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let rgb = RGB::from(RGBA::new(255, 42, 90, 128));
+    ///
+    /// assert_eq!(rgb, RGB::new(255, 42, 90));
+    /// ```
+    fn from(rgba: RGBA) -> Self {
+        let ( red, green, blue, _alpha ) = rgba.into();
+        Self::new(red, green, blue)
+    }
+}
@@ -0,0 +1,28 @@
+#!/usr/bin/env rust
+
+
+use std::str::FromStr;
+
+
+use crate::error::Error;
+use crate::rgb::RGB;
+
+
+/// Parses hexadecimal string, with or without leading `#`, into `RGB`
+impl FromStr for RGB {
+    type Err = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb: RGB = "#FF2A5A".parse().unwrap();
+    ///
+    /// assert_eq!(rgb, RGB::new(255, 42, 90));
+    /// assert!("#NOPE".parse::<RGB>().is_err());
+    /// ```
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::try_from_hex_string(string.trim().trim_start_matches('#'))
+    }
+}
@@ -1,10 +1,47 @@
 #!/usr/bin/env rust
 
 
+use std::convert::TryFrom;
+
+
+use crate::error::{ check_length, Error };
 use crate::rgb::RGB;
 
 
+/// Convert from slice of unsigned 8-bit integers, or error for invalid length
+impl<T> TryFrom<&[T]> for RGB
+where
+    T: Into<u8> + Copy
+{
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::try_from(&[255, 42, 90][..]).unwrap();
+    /// assert_eq!(rgb, RGB::from(vec![255, 42, 90]));
+    ///
+    /// let result = RGB::try_from(&[255, 42][..]);
+    /// assert_eq!(result, Err(Error::Length { expected: 3, found: 2 }));
+    /// ```
+    fn try_from(values: &[T]) -> Result<Self, Self::Error> {
+        check_length(values.len(), 3, 3)?;
+        let red = values[0].into();
+        let green = values[1].into();
+        let blue = values[2].into();
+        Ok(Self { red, green, blue })
+    }
+}
+
+
 /// Convert from vector of unsigned 8-bit integers
+///
+/// **Note** this uses `RGB::try_from` internally, values past the third are ignored, and too few
+/// values return default values
 impl<T> From<Vec<T>> for RGB
 where
     T: Into<u8> + Copy
@@ -20,12 +57,11 @@ where
     /// assert_eq!(rgb.get("red"), Ok(255));
     /// assert_eq!(rgb.get("green"), Ok(42));
     /// assert_eq!(rgb.get("blue"), Ok(90));
+    /// assert_eq!(RGB::from(vec![1u8, 2, 3, 4]), RGB::new(1, 2, 3));
     /// ```
     fn from(vector: Vec<T>) -> Self {
-        let red = vector[0];
-        let green = vector[1];
-        let blue = vector[2];
-        Self::new(red, green, blue)
+        let length = vector.len().min(3);
+        Self::try_from(&vector[..length]).unwrap_or_default()
     }
 }
 
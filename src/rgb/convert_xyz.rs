@@ -0,0 +1,28 @@
+#!/usr/bin/env rust
+
+
+use crate::rgb::RGB;
+use crate::rgb_space::RgbSpace;
+use crate::xyz::XYZ;
+
+
+/// Convert from `XYZ` to `RGB`
+///
+/// **Note** uses `RgbSpace::srgb` internally, see `RgbSpace::from_xyz` for other spaces, and colors
+/// outside of sRGB gamut are clamped
+impl From<XYZ> for RGB {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let xyz = XYZ::from(rgb.clone());
+    ///
+    /// assert_eq!(RGB::from(xyz), rgb);
+    /// ```
+    fn from(xyz: XYZ) -> Self {
+        RgbSpace::srgb().from_xyz(xyz)
+    }
+}
@@ -0,0 +1,26 @@
+#!/usr/bin/env rust
+
+
+use crate::rgb::RGB;
+use crate::ycbcr::{ Matrix, YCbCr };
+
+
+/// Convert from `YCbCr` to `RGB`
+///
+/// **Note** assumes `Matrix::BT601`, use `YCbCr::to_rgb` for other matrices
+impl From<YCbCr> for RGB {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::ycbcr::YCbCr;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let ycbcr = YCbCr::from(rgb.clone());
+    ///
+    /// assert_eq!(RGB::from(ycbcr), rgb);
+    /// ```
+    fn from(ycbcr: YCbCr) -> Self {
+        ycbcr.to_rgb(Matrix::BT601)
+    }
+}
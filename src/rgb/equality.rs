@@ -2,6 +2,7 @@
 
 
 use crate::color::Color;
+use crate::color::equality::alpha_eq;
 use crate::hsl::HSL;
 use crate::hsv::HSV;
 use crate::rgb::RGB;
@@ -29,13 +30,16 @@ impl PartialEq for RGB {
 
 
 /// Converts right side from `Color` to `RGB` prior to checking equality
+///
+/// **Note** colors with alpha are compared as `RGBA`, so are only equal when fully opaque
 impl PartialEq<Color> for RGB {
     fn eq(&self, other: &Color) -> bool {
-        match other {
-            Color::HSL(v) => self == v,
-            Color::HSV(v) => self == v,
-            Color::RGB(v) => self == v,
+        if let Some(equal) = alpha_eq(&Color::from(self.clone()), other) {
+            return equal;
         }
+
+        let other: RGB = other.clone().into();
+        self == &other
     }
 }
 
@@ -0,0 +1,63 @@
+#!/usr/bin/env rust
+
+
+use crate::rgb::RGB;
+use crate::rgb_space::RgbSpace;
+
+
+impl RGB {
+    /// Returns red, green, blue values scaled to `0.0` through `1.0` with sRGB gamma removed
+    ///
+    /// **Note** this method uses `RgbSpace::srgb().to_linear` internally
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 0, 0);
+    ///
+    /// assert_eq!(rgb.to_linear(), (1.0, 0.0, 0.0));
+    /// ```
+    pub fn to_linear(&self) -> (f64, f64, f64) {
+        RgbSpace::srgb().to_linear(self.clone())
+    }
+
+    /// Returns new instance of `RGB` from linear light values between `0.0` and `1.0`
+    ///
+    /// **Note** this method uses `RgbSpace::srgb().from_linear` internally, so out of range values
+    /// are clamped prior to applying sRGB gamma
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let ( red, green, blue ) = rgb.to_linear();
+    ///
+    /// assert_eq!(RGB::from_linear(red, green, blue), rgb);
+    /// ```
+    pub fn from_linear<T>(red: T, green: T, blue: T) -> Self
+    where
+        T: Into<f64>
+    {
+        RgbSpace::srgb().from_linear(red.into(), green.into(), blue.into())
+    }
+
+    /// Returns WCAG 2 relative luminance between `0.0` and `1.0`, i.e. linear light weighted by
+    /// sensitivity of human vision to red, green, and blue
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgb::RGB;
+    ///
+    /// assert_eq!(RGB::new(255, 255, 255).relative_luminance(), 1.0);
+    /// assert_eq!(RGB::new(0, 0, 255).relative_luminance(), 0.0722);
+    /// ```
+    pub fn relative_luminance(&self) -> f64 {
+        let ( red, green, blue ) = self.to_linear();
+        0.2126 * red + 0.7152 * green + 0.0722 * blue
+    }
+}
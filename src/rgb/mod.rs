@@ -1,6 +1,7 @@
 #!/usr/bin/env rust
 
 
+use std::convert::TryFrom;
 use std::fmt;
 use std::fmt::{Display, Formatter};
 
@@ -8,6 +9,11 @@ use std::fmt::{Display, Formatter};
 extern crate hex;
 
 
+use crate::blend::BlendMode;
+use crate::css::Options;
+use crate::css::serialize;
+use crate::cvd::Deficiency;
+use crate::error::Error;
 use crate::hsl::HSL;
 
 
@@ -23,6 +29,9 @@ mod equality;
 /// Converts from/to array for `RGB` data structures
 mod convert_array;
 
+/// Converts from `CMYK` to `RGB` data structure
+mod convert_cmyk;
+
 /// Converts from/to `Color` enum
 mod convert_color;
 
@@ -32,15 +41,48 @@ mod convert_hsl;
 /// Converts from `HSV` to `RGB` data structure
 mod convert_hsv;
 
+/// Converts from `HWB` to `RGB` data structure
+mod convert_hwb;
+
 /// Converts from/to `JsonValue` for `RGB` data structures
 mod convert_json_value;
 
+/// Converts from `Lab` to `RGB` data structure
+mod convert_lab;
+
+/// Converts from `LCh` to `RGB` data structure
+mod convert_lch;
+
+/// Converts from `Oklab` to `RGB` data structure
+mod convert_oklab;
+
+/// Converts from `Oklch` to `RGB` data structure
+mod convert_oklch;
+
+/// Converts from `RGBA` to `RGB` data structure
+mod convert_rgba;
+
+/// Parses hexadecimal string into `RGB` data structure
+mod convert_string;
+
 /// Converts from/to tuple for `RGB` data structures
 mod convert_tuple;
 
 /// Converts from/to vector for `RGB` data structures
 mod convert_vector;
 
+/// Converts from `XYZ` to `RGB` data structure
+mod convert_xyz;
+
+/// Converts from `YCbCr` to `RGB` data structure
+mod convert_ycbcr;
+
+/// Converts between gamma encoded and linear light values for `RGB` data structures
+pub(crate) mod linear;
+
+/// Converts between `RGB` and device independent data structures relative to chosen reference white
+mod adaptation;
+
 
 /// Data structure for Red, Green, Blue encoded colors
 #[derive(Clone, Debug, Default)]
@@ -92,7 +134,9 @@ impl RGB {
         }
     }
 
-    /// Returns parsed JSON string for color key/value pares, or defaults values
+    /// Returns parsed JSON string for color key/value pares, or default values
+    ///
+    /// **Note** missing or invalid components default individually, see `From<JsonValue>`
     ///
     /// # Example
     ///
@@ -109,13 +153,25 @@ impl RGB {
     where
         S: Into<String>
     {
-        match json::parse(&string.into()) {
-            Ok(data) => Self::from(data),
-            Err(e) => {
-                println!("Warning: ignoring error -> {:?}", e);
-                Self { red: 0, green: 0, blue: 0 }
-            }
-        }
+        json::parse(&string.into()).map(Self::from).unwrap_or_default()
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or error for invalid JSON and missing
+    /// or invalid components
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::error::Error;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let result = RGB::try_from_json_str(r#"{ "red": 1 }"#);
+    ///
+    /// assert_eq!(result, Err(Error::MissingComponent("green".to_string())));
+    /// ```
+    pub fn try_from_json_str(string: &str) -> Result<Self, Error> {
+        let data = json::parse(string)?;
+        Self::try_from(&data)
     }
 
     /// Serializes data structure as JSON string
@@ -144,7 +200,8 @@ impl RGB {
 
     /// Converts hexadecimal string into `RGB`
     ///
-    /// **Warning** may panic due to unwrapping `hex::decode`
+    /// **Note** bytes past blue, e.g. alpha of `RRGGBBAA`, are ignored, and invalid input returns
+    /// default values
     ///
     /// # Example
     ///
@@ -155,14 +212,33 @@ impl RGB {
     /// let rgb = RGB::from_hex_string(hex);
     ///
     /// assert_eq!(rgb, RGB::new(171, 205, 239));
+    /// assert_eq!(RGB::from_hex_string("FF2A5A80"), RGB::new(255, 42, 90));
     /// ```
     pub fn from_hex_string<S>(input: S) -> Self
     where
         S: Into<String>
     {
-        let s: String = input.into();
-        let values = hex::decode(s).unwrap();
-        Self::from(values)
+        hex::decode(input.into()).map(Self::from).unwrap_or_default()
+    }
+
+    /// Converts hexadecimal string into `RGB`, or returns error for invalid input
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::error::Error;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// assert!(RGB::try_from_hex_string("ABCDEF").is_ok());
+    /// assert!(matches!(RGB::try_from_hex_string("NOPE"), Err(Error::Hex(_))));
+    /// assert_eq!(RGB::try_from_hex_string("ABCD"), Err(Error::Length { expected: 3, found: 2 }));
+    /// ```
+    pub fn try_from_hex_string<S>(input: S) -> Result<Self, Error>
+    where
+        S: Into<String>
+    {
+        let values = hex::decode(input.into())?;
+        Self::try_from(values.as_slice())
     }
 
     /// Returns hexadecimal string representation of `RGB` values
@@ -176,10 +252,29 @@ impl RGB {
     /// let hex = color.to_hex_string();
     ///
     /// assert_eq!(hex, "FFFFFF");
+    /// assert_eq!(RGB::new(5, 5, 5).to_hex_string(), "050505");
     /// ```
     pub fn to_hex_string(&self) -> String {
         let vector: Vec<u8> = self.clone().into();
-        vector.iter().map(|v| { format!("{:X?}", v) }).collect::<String>()
+        vector.iter().map(|v| { format!("{:02X}", v) }).collect::<String>()
+    }
+
+    /// Returns CSS representation of `RGB` values
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::css::{ Notation, Options, Syntax };
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let color = RGB::new(5, 255, 90);
+    ///
+    /// assert_eq!(color.to_css_string(Options::new()), "rgb(5 255 90)");
+    /// assert_eq!(color.to_css_string(Options::new().syntax(Syntax::Legacy)), "rgb(5, 255, 90)");
+    /// assert_eq!(color.to_css_string(Options::new().notation(Notation::Hex)), "#05FF5A");
+    /// ```
+    pub fn to_css_string(&self, options: Options) -> String {
+        serialize::rgb(self.red, self.green, self.blue, None, options)
     }
 
     /// Attempts to rotate hue by some amount of degrees
@@ -223,6 +318,81 @@ impl RGB {
 
         Self::from(result)
     }
+
+    /// Blends source color into this backdrop color
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::blend::BlendMode;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let backdrop = RGB::new(255, 128, 0);
+    /// let source = RGB::new(0, 128, 255);
+    ///
+    /// assert_eq!(backdrop.blend(source, BlendMode::Difference), RGB::new(255, 0, 255));
+    /// ```
+    pub fn blend(&self, source: Self, mode: BlendMode) -> Self {
+        mode.apply(self.clone(), source)
+    }
+
+    /// Returns color as perceived with color vision deficiency at severity between `0.0` and `1.0`
+    ///
+    /// **Note** this method uses `Deficiency::simulate` internally
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cvd::Deficiency;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    ///
+    /// assert_eq!(rgb.simulate_cvd(Deficiency::Deuteranopia, 0.0), rgb);
+    /// assert_eq!(rgb.simulate_cvd(Deficiency::Deuteranopia, 1.0), RGB::new(163, 149, 84));
+    /// ```
+    pub fn simulate_cvd(&self, deficiency: Deficiency, severity: f64) -> Self {
+        deficiency.simulate(self.clone(), severity)
+    }
+
+    /// Returns color adjusted to remain distinguishable with color vision deficiency at severity
+    /// between `0.0` and `1.0`
+    ///
+    /// **Note** this method uses `Deficiency::daltonize` internally
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::cvd::Deficiency;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(0, 0, 255);
+    ///
+    /// assert_eq!(rgb.daltonize(Deficiency::Deuteranopia, 0.0), rgb);
+    /// assert_eq!(rgb.daltonize(Deficiency::Tritanopia, 1.0), RGB::new(213, 158, 255));
+    /// ```
+    pub fn daltonize(&self, deficiency: Deficiency, severity: f64) -> Self {
+        deficiency.daltonize(self.clone(), severity)
+    }
+
+    /// Returns WCAG 2 contrast ratio, between `1.0` and `21.0`, with other color
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgb::RGB;
+    ///
+    /// assert_eq!(RGB::new(255, 255, 255).contrast_ratio(&RGB::new(0, 0, 0)), 21.0);
+    /// ```
+    pub fn contrast_ratio<T>(&self, other: &T) -> f64
+    where
+        T: Clone + Into<Self>
+    {
+        let other: Self = other.clone().into();
+        let lighter = self.relative_luminance().max(other.relative_luminance());
+        let darker = self.relative_luminance().min(other.relative_luminance());
+        (lighter + 0.05) / (darker + 0.05)
+    }
 }
 
 
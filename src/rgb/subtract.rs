@@ -40,11 +40,8 @@ impl Sub<Color> for RGB {
     type Output = Self;
 
     fn sub(self, other: Color) -> Self::Output {
-        match other {
-            Color::HSL(v) => self - Self::from(v),
-            Color::HSV(v) => self - Self::from(v),
-            Color::RGB(v) => self - v,
-        }
+        let other: RGB = other.into();
+        self - other
     }
 }
 
@@ -0,0 +1,332 @@
+#!/usr/bin/env rust
+
+
+use std::sync::OnceLock;
+
+
+use crate::adaptation;
+use crate::adaptation::{ Matrix, Method };
+use crate::rgb::RGB;
+use crate::white_point::WhitePoint;
+use crate::xyz::XYZ;
+
+
+/// Curves between encoded and linear light values of red, green, and blue
+///
+/// **Note** negative values are mirrored, i.e. encoded as `-f(-v)`, such that out of gamut values
+/// survive round trips
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TransferFunction {
+    /// Values are linear light already, e.g. ACEScg
+    Linear,
+
+    /// Piecewise sRGB curve, also used by Display P3
+    Srgb,
+
+    /// Pure power curve with given exponent, e.g. `563.0 / 256.0` for Adobe RGB (1998)
+    Gamma(f64),
+
+    /// Rec. ITU-R BT.709 camera curve
+    Rec709,
+
+    /// Rec. ITU-R BT.2020 camera curve, BT.709 with more precise constants
+    Rec2020,
+
+    /// ROMM RGB curve of ProPhoto RGB
+    ProPhoto,
+}
+
+
+/// RGB color space defined by chromaticities of primaries, reference white, and transfer function
+///
+/// **Note** `RGB` data structures are sRGB, this descriptor interprets their components within
+/// other spaces, and `XYZ` values are relative to `WhitePoint::D65` as everywhere else, adapted
+/// with `Method::Bradford` for spaces with other reference white
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::rgb::RGB;
+/// use color_operators::rgb_space::RgbSpace;
+///
+/// let srgb = RgbSpace::srgb();
+/// let display_p3 = RgbSpace::display_p3();
+///
+/// assert_eq!(srgb.convert(RGB::new(255, 0, 0), &display_p3), RGB::new(234, 51, 35));
+/// assert_eq!(display_p3.convert(RGB::new(255, 0, 0), &srgb), RGB::new(255, 0, 0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RgbSpace {
+    primaries: [(f64, f64); 3],
+    white_point: WhitePoint,
+    transfer: TransferFunction,
+    to_xyz: Matrix,
+    from_xyz: Matrix,
+}
+
+
+impl TransferFunction {
+    /// Returns linear light from encoded value, where `0.0` to `1.0` is within gamut
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgb_space::TransferFunction;
+    ///
+    /// assert_eq!(TransferFunction::Gamma(2.0).decode(0.5), 0.25);
+    /// assert_eq!(TransferFunction::Gamma(2.0).decode(-0.5), -0.25);
+    /// ```
+    pub fn decode(&self, value: f64) -> f64 {
+        let magnitude = value.abs();
+        let decoded = match self {
+            Self::Linear => magnitude,
+            Self::Srgb if magnitude <= 0.04045 => magnitude / 12.92,
+            Self::Srgb => ((magnitude + 0.055) / 1.055).powf(2.4),
+            Self::Gamma(gamma) => magnitude.powf(*gamma),
+            Self::Rec709 => rec_decode(magnitude, 1.099, 0.018),
+            Self::Rec2020 => rec_decode(magnitude, 1.09929682680944, 0.018053968510807),
+            Self::ProPhoto if magnitude <= 16.0 / 512.0 => magnitude / 16.0,
+            Self::ProPhoto => magnitude.powf(1.8),
+        };
+        decoded.copysign(value)
+    }
+
+    /// Returns encoded value from linear light, where `0.0` to `1.0` is within gamut
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgb_space::TransferFunction;
+    ///
+    /// let transfer = TransferFunction::Rec2020;
+    ///
+    /// assert_eq!((transfer.decode(transfer.encode(0.3)) * 1e12).round(), 0.3e12);
+    /// ```
+    pub fn encode(&self, value: f64) -> f64 {
+        let magnitude = value.abs();
+        let encoded = match self {
+            Self::Linear => magnitude,
+            Self::Srgb if magnitude <= 0.0031308 => magnitude * 12.92,
+            Self::Srgb => 1.055 * magnitude.powf(1.0 / 2.4) - 0.055,
+            Self::Gamma(gamma) => magnitude.powf(1.0 / gamma),
+            Self::Rec709 => rec_encode(magnitude, 1.099, 0.018),
+            Self::Rec2020 => rec_encode(magnitude, 1.09929682680944, 0.018053968510807),
+            Self::ProPhoto if magnitude < 1.0 / 512.0 => magnitude * 16.0,
+            Self::ProPhoto => magnitude.powf(1.0 / 1.8),
+        };
+        encoded.copysign(value)
+    }
+}
+
+
+impl RgbSpace {
+    /// Returns space from `x` and `y` chromaticities of red, green, and blue primaries, reference
+    /// white, and transfer function, computing matrices to and from `XYZ`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::rgb_space::{ RgbSpace, TransferFunction };
+    /// use color_operators::white_point::WhitePoint;
+    ///
+    /// let panel = RgbSpace::new((0.66, 0.33), (0.28, 0.65), (0.15, 0.07), WhitePoint::D65, TransferFunction::Gamma(2.2));
+    ///
+    /// assert_eq!(panel.convert(RGB::new(255, 255, 255), &RgbSpace::srgb()), RGB::new(255, 255, 255));
+    /// assert_eq!(panel.convert(RGB::new(0, 0, 0), &RgbSpace::srgb()), RGB::new(0, 0, 0));
+    /// ```
+    pub fn new(red: (f64, f64), green: (f64, f64), blue: (f64, f64), white_point: WhitePoint, transfer: TransferFunction) -> Self {
+        let primaries = [ red, green, blue ];
+
+        let mut columns = [[0.0; 3]; 3];
+        for (column, (x, y)) in primaries.iter().enumerate() {
+            columns[0][column] = x / y;
+            columns[1][column] = 1.0;
+            columns[2][column] = (1.0 - x - y) / y;
+        }
+
+        let ( white_x, white_y, white_z ) = white_point.tristimulus();
+        let scale = adaptation::transform(&adaptation::invert(&columns), [ white_x, white_y, white_z ]);
+
+        let mut to_xyz = columns;
+        for row in to_xyz.iter_mut() {
+            for (value, factor) in row.iter_mut().zip(scale.iter()) {
+                *value *= factor;
+            }
+        }
+
+        Self { primaries, white_point, transfer, to_xyz, from_xyz: adaptation::invert(&to_xyz) }
+    }
+
+    /// Returns sRGB, the space of `RGB` data structures and most displays
+    ///
+    /// **Note** matrices are computed once and reused, as every conversion between `RGB` and `XYZ`
+    /// goes through this space
+    pub fn srgb() -> Self {
+        static SRGB: OnceLock<RgbSpace> = OnceLock::new();
+        *SRGB.get_or_init(|| {
+            Self::new((0.64, 0.33), (0.3, 0.6), (0.15, 0.06), WhitePoint::D65, TransferFunction::Srgb)
+        })
+    }
+
+    /// Returns Display P3, wide gamut space of recent displays
+    pub fn display_p3() -> Self {
+        Self::new((0.68, 0.32), (0.265, 0.69), (0.15, 0.06), WhitePoint::D65, TransferFunction::Srgb)
+    }
+
+    /// Returns Adobe RGB (1998), wide gamut space of photography and print
+    pub fn adobe_rgb() -> Self {
+        Self::new((0.64, 0.33), (0.21, 0.71), (0.15, 0.06), WhitePoint::D65, TransferFunction::Gamma(563.0 / 256.0))
+    }
+
+    /// Returns ProPhoto RGB, a.k.a. ROMM RGB, very wide gamut space relative to `WhitePoint::D50`
+    pub fn prophoto_rgb() -> Self {
+        Self::new((0.734699, 0.265301), (0.159597, 0.840403), (0.036598, 0.000105), WhitePoint::D50, TransferFunction::ProPhoto)
+    }
+
+    /// Returns Rec. ITU-R BT.709, primaries of sRGB with camera transfer function of HDTV
+    pub fn rec709() -> Self {
+        Self::new((0.64, 0.33), (0.3, 0.6), (0.15, 0.06), WhitePoint::D65, TransferFunction::Rec709)
+    }
+
+    /// Returns Rec. ITU-R BT.2020, wide gamut space of UHDTV
+    pub fn rec2020() -> Self {
+        Self::new((0.708, 0.292), (0.17, 0.797), (0.131, 0.046), WhitePoint::D65, TransferFunction::Rec2020)
+    }
+
+    /// Returns ACEScg, linear light AP1 primaries used by rendering and compositing
+    pub fn acescg() -> Self {
+        Self::new((0.713, 0.293), (0.165, 0.83), (0.128, 0.044), WhitePoint::D60, TransferFunction::Linear)
+    }
+
+    /// Returns `x` and `y` chromaticities of red, green, and blue primaries
+    pub fn primaries(&self) -> [(f64, f64); 3] {
+        self.primaries
+    }
+
+    /// Returns reference white
+    pub fn white_point(&self) -> WhitePoint {
+        self.white_point
+    }
+
+    /// Returns transfer function
+    pub fn transfer(&self) -> TransferFunction {
+        self.transfer
+    }
+
+    /// Returns matrix from linear light to `XYZ` relative to reference white of space
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgb_space::RgbSpace;
+    ///
+    /// let [ _, [ red, green, blue ], _ ] = RgbSpace::srgb().to_xyz_matrix();
+    ///
+    /// assert_eq!(((red * 1e6).round(), (green * 1e6).round(), (blue * 1e6).round()), (212639.0, 715169.0, 72192.0));
+    /// ```
+    pub fn to_xyz_matrix(&self) -> Matrix {
+        self.to_xyz
+    }
+
+    /// Returns matrix from `XYZ` relative to reference white of space to linear light
+    pub fn from_xyz_matrix(&self) -> Matrix {
+        self.from_xyz
+    }
+
+    /// Returns `XYZ`, relative to `WhitePoint::D65`, from linear light values of space
+    pub fn linear_to_xyz(&self, red: f64, green: f64, blue: f64) -> XYZ {
+        let [ x, y, z ] = adaptation::transform(&self.to_xyz, [ red, green, blue ]);
+        Method::Bradford.adapt(XYZ::new(x, y, z), self.white_point, WhitePoint::D65)
+    }
+
+    /// Returns linear light values of space from `XYZ` relative to `WhitePoint::D65`
+    ///
+    /// **Note** values are not clamped, such that any outside of `0.0` to `1.0` reveal colors
+    /// outside of gamut
+    pub fn xyz_to_linear(&self, xyz: XYZ) -> (f64, f64, f64) {
+        let ( x, y, z ) = Method::Bradford.adapt(xyz, WhitePoint::D65, self.white_point).into();
+        let [ red, green, blue ] = adaptation::transform(&self.from_xyz, [ x, y, z ]);
+        ( red, green, blue )
+    }
+
+    /// Returns linear light values of color, with components interpreted as encoded within space
+    ///
+    /// **Note** `HSL` and `HSV` colors are converted to `RGB` components first, i.e. they are
+    /// interpreted as cylindrical forms of this space
+    pub fn to_linear<C>(&self, color: C) -> (f64, f64, f64)
+    where
+        C: Into<RGB>
+    {
+        let ( red, green, blue ) = color.into().into();
+        let decode = |v: u8| self.transfer.decode(v as f64 / 255.0);
+        ( decode(red), decode(green), decode(blue) )
+    }
+
+    /// Returns `RGB` components encoded within space from linear light values
+    ///
+    /// **Note** out of range values are clamped prior to encoding
+    pub fn from_linear(&self, red: f64, green: f64, blue: f64) -> RGB {
+        let encode = |v: f64| -> u8 {
+            (self.transfer.encode(v.min(1.0).max(0.0)) * 255.0).round() as u8
+        };
+        RGB::new(encode(red), encode(green), encode(blue))
+    }
+
+    /// Returns `XYZ`, relative to `WhitePoint::D65`, of color with components interpreted as
+    /// encoded within space
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsl::HSL;
+    /// use color_operators::rgb_space::RgbSpace;
+    ///
+    /// let ( _x, y, _z ) = RgbSpace::display_p3().to_xyz(HSL::new(120.0, 1.0, 0.5)).into();
+    ///
+    /// assert_eq!((y * 1e4).round(), 6917.0);
+    /// ```
+    pub fn to_xyz<C>(&self, color: C) -> XYZ
+    where
+        C: Into<RGB>
+    {
+        let ( red, green, blue ) = self.to_linear(color);
+        self.linear_to_xyz(red, green, blue)
+    }
+
+    /// Returns `RGB` components encoded within space from `XYZ` relative to `WhitePoint::D65`
+    ///
+    /// **Note** colors outside of gamut are clamped, convert result with `HSL::from` or `HSV::from`
+    /// for cylindrical forms of space
+    pub fn from_xyz(&self, xyz: XYZ) -> RGB {
+        let ( red, green, blue ) = self.xyz_to_linear(xyz);
+        self.from_linear(red, green, blue)
+    }
+
+    /// Returns color with components encoded within this space converted to be encoded within
+    /// destination space
+    pub fn convert<C>(&self, color: C, destination: &Self) -> RGB
+    where
+        C: Into<RGB>
+    {
+        destination.from_xyz(self.to_xyz(color))
+    }
+}
+
+
+/// Returns linear light from value encoded by BT.709 style curve
+fn rec_decode(value: f64, alpha: f64, beta: f64) -> f64 {
+    if value < beta * 4.5 {
+        return value / 4.5;
+    }
+    ((value + alpha - 1.0) / alpha).powf(1.0 / 0.45)
+}
+
+
+/// Returns value encoded by BT.709 style curve from linear light
+fn rec_encode(value: f64, alpha: f64, beta: f64) -> f64 {
+    if value < beta {
+        return value * 4.5;
+    }
+    alpha * value.powf(0.45) - (alpha - 1.0)
+}
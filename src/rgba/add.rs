@@ -0,0 +1,60 @@
+#!/usr/bin/env rust
+
+
+use std::ops::Add;
+
+
+use crate::color::Color;
+use crate::rgb::RGB;
+use crate::rgba::RGBA;
+
+
+/// Adds color components
+///
+/// **Note** this uses `RGB::add` internally, and alpha of left side is kept
+impl Add for RGBA {
+    type Output = Self;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let left = RGBA::new(155, 0, 0, 128);
+    /// let right = RGBA::new(100, 0, 0, 255);
+    /// let result = left + right;
+    /// let expected = RGBA::new(255, 0, 0, 128);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    fn add(self, other: Self) -> Self::Output {
+        let alpha = self.alpha;
+        let left = RGB::from(self);
+        let right = RGB::from(other);
+        Self::from_rgb(left + right, alpha)
+    }
+}
+
+
+/// Converts right side from `Color` to `RGBA` prior to arithmetic
+impl Add<Color> for RGBA {
+    type Output = Self;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let left = RGBA::new(25, 0, 0, 128);
+    /// let right = Color::new_rgb(25, 0, 0);
+    ///
+    /// let expected = RGBA::new(50, 0, 0, 128);
+    ///
+    /// assert_eq!(left + right, expected);
+    /// ```
+    fn add(self, other: Color) -> Self::Output {
+        let other: RGBA = other.into();
+        self + other
+    }
+}
@@ -0,0 +1,53 @@
+#!/usr/bin/env rust
+
+
+use crate::rgba::RGBA;
+
+
+/// Convert from array of unsigned 8-bit integers
+impl<T> From<[T; 4]> for RGBA
+where
+    T: Into<u8> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let array = [ 255, 42, 90, 128 ];
+    /// let rgba = RGBA::from(array);
+    ///
+    /// assert_eq!(rgba.get("red"), Ok(255));
+    /// assert_eq!(rgba.get("green"), Ok(42));
+    /// assert_eq!(rgba.get("blue"), Ok(90));
+    /// assert_eq!(rgba.get("alpha"), Ok(128));
+    /// ```
+    fn from(array: [T; 4]) -> Self {
+        let red = array[0];
+        let green = array[1];
+        let blue = array[2];
+        let alpha = array[3];
+        Self::new(red, green, blue, alpha)
+    }
+}
+
+
+/// Convert into array of unsigned 8-bit integers
+impl Into<[u8; 4]> for RGBA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let rgba = RGBA::new(255, 42, 90, 128);
+    /// let array: [u8; 4] = rgba.into();
+    ///
+    /// assert_eq!(array[0], 255);
+    /// assert_eq!(array[1], 42);
+    /// assert_eq!(array[2], 90);
+    /// assert_eq!(array[3], 128);
+    /// ```
+    fn into(self) -> [u8; 4] {
+        [ self.red, self.green, self.blue, self.alpha ]
+    }
+}
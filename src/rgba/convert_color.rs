@@ -0,0 +1,28 @@
+#!/usr/bin/env rust
+
+
+use crate::rgba::RGBA;
+use crate::color::Color;
+use crate::rgb::RGB;
+
+
+impl From<Color> for RGBA {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::CMYK(v) => Self::from(RGB::from(v)),
+            Color::HSL(v) => Self::from(RGB::from(v)),
+            Color::HSLA(v) => Self::from(v),
+            Color::HSV(v) => Self::from(RGB::from(v)),
+            Color::HSVA(v) => Self::from(v),
+            Color::HWB(v) => Self::from(RGB::from(v)),
+            Color::Lab(v) => Self::from(RGB::from(v)),
+            Color::LCh(v) => Self::from(RGB::from(v)),
+            Color::Oklab(v) => Self::from(RGB::from(v)),
+            Color::Oklch(v) => Self::from(RGB::from(v)),
+            Color::RGB(v) => Self::from(v),
+            Color::RGBA(v) => v,
+            Color::XYZ(v) => Self::from(RGB::from(v)),
+            Color::YCbCr(v) => Self::from(RGB::from(v)),
+        }
+    }
+}
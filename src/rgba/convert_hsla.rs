@@ -0,0 +1,30 @@
+#!/usr/bin/env rust
+
+
+use crate::hsl::HSL;
+use crate::hsla::HSLA;
+use crate::rgb::RGB;
+use crate::rgba::RGBA;
+
+
+/// Convert from `HSLA` to `RGBA`
+///
+/// **Note** uses `RGB::from(hsl)` internally, and alpha is rounded to nearest unsigned 8-bit integer
+impl From<HSLA> for RGBA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsla::HSLA;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let rgba = RGBA::new(255, 42, 90, 128);
+    /// let hsla = HSLA::from(rgba.clone());
+    ///
+    /// assert_eq!(RGBA::from(hsla), rgba);
+    /// ```
+    fn from(hsla: HSLA) -> Self {
+        let ( hue, saturation, lightness, alpha ) = hsla.into();
+        let rgb = RGB::from(HSL::new(hue, saturation, lightness));
+        Self::from_rgb(rgb, (alpha * 255.0).round() as u8)
+    }
+}
@@ -0,0 +1,30 @@
+#!/usr/bin/env rust
+
+
+use crate::hsv::HSV;
+use crate::hsva::HSVA;
+use crate::rgb::RGB;
+use crate::rgba::RGBA;
+
+
+/// Convert from `HSVA` to `RGBA`
+///
+/// **Note** uses `RGB::from(hsv)` internally, and alpha is rounded to nearest unsigned 8-bit integer
+impl From<HSVA> for RGBA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsva::HSVA;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let rgba = RGBA::new(255, 42, 90, 128);
+    /// let hsva = HSVA::from(rgba.clone());
+    ///
+    /// assert_eq!(RGBA::from(hsva), rgba);
+    /// ```
+    fn from(hsva: HSVA) -> Self {
+        let ( hue, saturation, value, alpha ) = hsva.into();
+        let rgb = RGB::from(HSV::new(hue, saturation, value));
+        Self::from_rgb(rgb, (alpha * 255.0).round() as u8)
+    }
+}
@@ -0,0 +1,107 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+extern crate json;
+use json::JsonValue;
+
+
+use crate::error::{ json_u8, Error };
+use crate::rgba::RGBA;
+
+
+/// Converts from `JsonValue` key value pares, or error for missing or invalid components
+///
+/// **Note** missing alpha defaults to fully opaque
+impl TryFrom<&JsonValue> for RGBA {
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::rgba::RGBA;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{ "red": "nope" }"#).unwrap();
+    ///
+    /// assert_eq!(RGBA::try_from(&data), Err(Error::InvalidComponent("red".to_string())));
+    /// ```
+    fn try_from(data: &JsonValue) -> Result<Self, Self::Error> {
+        let red = json_u8(data, "red")?;
+        let green = json_u8(data, "green")?;
+        let blue = json_u8(data, "blue")?;
+        let alpha = if data["alpha"].is_null() { 255 } else { json_u8(data, "alpha")? };
+        Ok(Self::new(red, green, blue, alpha))
+    }
+}
+
+
+/// Converts from `JsonValue` key value pares
+///
+/// **Note** missing or invalid components default individually, where `RGBA::try_from` returns
+/// error
+impl From<JsonValue> for RGBA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgba::RGBA;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{
+    ///     "red": 255,
+    ///     "green": 42,
+    ///     "blue": 90,
+    ///     "alpha": 128
+    /// }"#).unwrap();
+    ///
+    /// let rgba = RGBA::from(data);
+    ///
+    /// assert_eq!(rgba.get("red"), Ok(255));
+    /// assert_eq!(rgba.get("green"), Ok(42));
+    /// assert_eq!(rgba.get("blue"), Ok(90));
+    /// assert_eq!(rgba.get("alpha"), Ok(128));
+    /// ```
+    fn from(data: JsonValue) -> Self {
+        let red = data["red"].as_u8().unwrap_or_default();
+        let green = data["green"].as_u8().unwrap_or_default();
+        let blue = data["blue"].as_u8().unwrap_or_default();
+        let alpha = data["alpha"].as_u8().unwrap_or(255);
+        Self { red, green, blue, alpha }
+    }
+}
+
+
+/// Converts to `JsonValue`
+impl Into<JsonValue> for RGBA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgba::RGBA;
+    /// extern crate json;
+    /// use json::JsonValue;
+    ///
+    /// let rgba = RGBA::new(255, 42, 90, 128);
+    /// let data: JsonValue = rgba.into();
+    ///
+    /// let expected = json::parse(r#"{
+    ///     "red": 255,
+    ///     "green": 42,
+    ///     "blue": 90,
+    ///     "alpha": 128
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(data, expected);
+    /// ```
+    fn into(self) -> JsonValue {
+        json::object!{
+            "red" => self.red,
+            "green" => self.green,
+            "blue" => self.blue,
+            "alpha" => self.alpha,
+        }
+    }
+}
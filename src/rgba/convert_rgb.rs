@@ -0,0 +1,23 @@
+#!/usr/bin/env rust
+
+
+use crate::rgb::RGB;
+use crate::rgba::RGBA;
+
+
+/// Convert from `RGB` to fully opaque `RGBA`
+impl From<RGB> for RGBA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let rgba = RGBA::from(RGB::new(255, 42, 90));
+    ///
+    /// assert_eq!(rgba, RGBA::new(255, 42, 90, 255));
+    /// ```
+    fn from(rgb: RGB) -> Self {
+        Self::from_rgb(rgb, 255)
+    }
+}
@@ -0,0 +1,28 @@
+#!/usr/bin/env rust
+
+
+use std::str::FromStr;
+
+
+use crate::error::Error;
+use crate::rgba::RGBA;
+
+
+/// Parses hexadecimal string, with or without leading `#`, into `RGBA`
+impl FromStr for RGBA {
+    type Err = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let rgba: RGBA = "#FF2A5A80".parse().unwrap();
+    ///
+    /// assert_eq!(rgba, RGBA::new(255, 42, 90, 128));
+    /// assert!("#NOPE".parse::<RGBA>().is_err());
+    /// ```
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::try_from_hex_string(string.trim().trim_start_matches('#'))
+    }
+}
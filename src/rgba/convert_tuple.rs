@@ -0,0 +1,50 @@
+#!/usr/bin/env rust
+
+
+use crate::rgba::RGBA;
+
+
+/// Convert from tuple of unsigned 8-bit integers
+impl<T> From<(T, T, T, T)> for RGBA
+where
+    T: Into<u8> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let tuple = ( 255, 42, 90, 128 );
+    /// let rgba = RGBA::from(tuple);
+    ///
+    /// assert_eq!(rgba.get("red"), Ok(255));
+    /// assert_eq!(rgba.get("green"), Ok(42));
+    /// assert_eq!(rgba.get("blue"), Ok(90));
+    /// assert_eq!(rgba.get("alpha"), Ok(128));
+    /// ```
+    fn from(tuple: (T, T, T, T)) -> Self {
+        let ( red, green, blue, alpha ) = tuple;
+        Self::new(red, green, blue, alpha)
+    }
+}
+
+
+/// Convert into tuple of unsigned 8-bit integers
+impl Into<(u8, u8, u8, u8)> for RGBA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let rgba = RGBA::new(255, 42, 90, 128);
+    /// let ( red, green, blue, alpha ) = rgba.into();
+    ///
+    /// assert_eq!(red, 255);
+    /// assert_eq!(green, 42);
+    /// assert_eq!(blue, 90);
+    /// assert_eq!(alpha, 128);
+    /// ```
+    fn into(self) -> (u8, u8, u8, u8) {
+        ( self.red, self.green, self.blue, self.alpha )
+    }
+}
@@ -0,0 +1,99 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+use crate::error::{ check_length, Error };
+use crate::rgba::RGBA;
+
+
+/// Convert from slice of unsigned 8-bit integers, or error for invalid length
+///
+/// **Note** missing alpha defaults to fully opaque
+impl<T> TryFrom<&[T]> for RGBA
+where
+    T: Into<u8> + Copy
+{
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let rgba = RGBA::try_from(&[255, 42, 90, 128][..]).unwrap();
+    /// assert_eq!(rgba, RGBA::from(vec![255, 42, 90, 128]));
+    ///
+    /// let result = RGBA::try_from(&[255, 42][..]);
+    /// assert_eq!(result, Err(Error::Length { expected: 4, found: 2 }));
+    /// ```
+    fn try_from(values: &[T]) -> Result<Self, Self::Error> {
+        check_length(values.len(), 3, 4)?;
+        let red = values[0].into();
+        let green = values[1].into();
+        let blue = values[2].into();
+        let alpha = match values.get(3) {
+            Some(v) => (*v).into(),
+            None => 255,
+        };
+        Ok(Self::new(red, green, blue, alpha))
+    }
+}
+
+
+/// Convert from vector of unsigned 8-bit integers
+///
+/// **Note** this uses `RGBA::try_from` internally, values past the fourth are ignored, and too few
+/// values return default values
+///
+/// **Note** missing alpha defaults to fully opaque
+impl<T> From<Vec<T>> for RGBA
+where
+    T: Into<u8> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let vector = vec![255, 42, 90, 128];
+    /// let rgba = RGBA::from(vector);
+    ///
+    /// assert_eq!(rgba.get("red"), Ok(255));
+    /// assert_eq!(rgba.get("green"), Ok(42));
+    /// assert_eq!(rgba.get("blue"), Ok(90));
+    /// assert_eq!(rgba.get("alpha"), Ok(128));
+    ///
+    /// let opaque = RGBA::from(vec![255, 42, 90]);
+    ///
+    /// assert_eq!(opaque.get("alpha"), Ok(255));
+    /// ```
+    fn from(vector: Vec<T>) -> Self {
+        let length = vector.len().min(4);
+        Self::try_from(&vector[..length]).unwrap_or_default()
+    }
+}
+
+
+/// Convert into vector of unsigned 8-bit integers
+impl Into<Vec<u8>> for RGBA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let rgba = RGBA::new(255, 42, 90, 128);
+    /// let vector: Vec<u8> = rgba.into();
+    ///
+    /// assert_eq!(vector[0], 255);
+    /// assert_eq!(vector[1], 42);
+    /// assert_eq!(vector[2], 90);
+    /// assert_eq!(vector[3], 128);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        vec![ self.red, self.green, self.blue, self.alpha ]
+    }
+}
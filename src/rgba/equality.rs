@@ -0,0 +1,49 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::rgba::RGBA;
+
+
+impl Eq for RGBA {}
+impl PartialEq for RGBA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let left = RGBA::new(255, 42, 90, 128);
+    /// let right = RGBA::new(255, 42, 90, 128);
+    ///
+    /// assert_eq!(left, right);
+    /// assert_ne!(left, RGBA::new(255, 42, 90, 255));
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        if self.red != other.red || self.green != other.green || self.blue != other.blue || self.alpha != other.alpha {
+            return false;
+        }
+        true
+    }
+}
+
+
+/// Converts right side from `Color` to `RGBA` prior to checking equality
+///
+/// **Note** colors without alpha are only equal to fully opaque colors
+impl PartialEq<Color> for RGBA {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let c_rgb = Color::new_rgb(255, 42, 90);
+    ///
+    /// assert!(RGBA::new(255, 42, 90, 255) == c_rgb);
+    /// assert!(RGBA::new(255, 42, 90, 128) != c_rgb);
+    /// ```
+    fn eq(&self, other: &Color) -> bool {
+        let other: RGBA = other.clone().into();
+        self == &other
+    }
+}
@@ -0,0 +1,386 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+
+extern crate hex;
+
+
+use crate::blend::BlendMode;
+use crate::css::Options;
+use crate::css::serialize;
+use crate::error::Error;
+use crate::hsla::HSLA;
+use crate::rgb::RGB;
+
+
+/// Adds color components for `RGBA` data structures
+mod add;
+
+/// Subtracts color components for `RGBA` data structures
+mod subtract;
+
+/// Equal and not-equal checks for `RGBA` data structures
+mod equality;
+
+/// Converts from/to array for `RGBA` data structures
+mod convert_array;
+
+/// Converts from/to `Color` enum
+mod convert_color;
+
+/// Converts from `HSLA` to `RGBA` data structure
+mod convert_hsla;
+
+/// Converts from `HSVA` to `RGBA` data structure
+mod convert_hsva;
+
+/// Converts from/to `JsonValue` for `RGBA` data structures
+mod convert_json_value;
+
+/// Converts from `RGB` to `RGBA` data structure
+mod convert_rgb;
+
+/// Parses hexadecimal string into `RGBA` data structure
+mod convert_string;
+
+/// Converts from/to tuple for `RGBA` data structures
+mod convert_tuple;
+
+/// Converts from/to vector for `RGBA` data structures
+mod convert_vector;
+
+
+/// Data structure for Red, Green, Blue, Alpha encoded colors
+///
+/// **Note** alpha of `0` is fully transparent and `255` is fully opaque, alpha is straight, i.e.
+/// not premultiplied into red, green, and blue
+#[derive(Clone, Debug)]
+pub struct RGBA {
+    red: u8,
+    green: u8,
+    blue: u8,
+    alpha: u8,
+}
+
+
+/// Defaults to fully opaque black
+impl Default for RGBA {
+    fn default() -> Self {
+        Self { red: 0, green: 0, blue: 0, alpha: 255 }
+    }
+}
+
+
+impl RGBA {
+    /// Returns new instance of `RGBA` data structure
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let color = RGBA::new(255, 0, 0, 128);
+    ///
+    /// assert_eq!(color.get("red"), Ok(255));
+    /// assert_eq!(color.get("alpha"), Ok(128));
+    /// ```
+    pub fn new<T>(red: T, green: T, blue: T, alpha: T) -> Self
+    where
+        T: Into<u8>
+    {
+        Self { red: red.into(), green: green.into(), blue: blue.into(), alpha: alpha.into() }
+    }
+
+    /// Returns new instance of `RGBA` from `RGB` values and alpha
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let rgba = RGBA::from_rgb(RGB::new(255, 42, 90), 128);
+    ///
+    /// assert_eq!(rgba, RGBA::new(255, 42, 90, 128));
+    /// ```
+    pub fn from_rgb(rgb: RGB, alpha: u8) -> Self {
+        let ( red, green, blue ) = rgb.into();
+        Self { red, green, blue, alpha }
+    }
+
+    /// Returns named component value or error
+    ///
+    /// ```rust
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let color = RGBA::new(255, 0, 0, 128);
+    ///
+    /// assert_eq!(color.get("nothing"), Err("No color component named -> nothing".to_string()));
+    /// ```
+    pub fn get<S>(&self, component: S) -> Result<u8, String>
+    where
+        S: Into<String>
+    {
+        let component: String = component.into();
+        match component.as_str() {
+            "red" => Ok(self.red),
+            "green" => Ok(self.green),
+            "blue" => Ok(self.blue),
+            "alpha" => Ok(self.alpha),
+            _ => Err(format!("No color component named -> {}", component)),
+        }
+    }
+
+    /// Returns alpha scaled between `0.0` and `1.0`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let color = RGBA::new(255, 0, 0, 51);
+    ///
+    /// assert_eq!(color.opacity(), 0.2);
+    /// ```
+    pub fn opacity(&self) -> f64 {
+        self.alpha as f64 / 255.0
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or default values
+    ///
+    /// **Note** missing or invalid components default individually, see `From<JsonValue>`
+    ///
+    /// **Note** missing alpha defaults to fully opaque
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let rgba = RGBA::from_json_string(r#"{ "red": 255, "green": 42, "blue": 90, "alpha": 128 }"#);
+    ///
+    /// assert_eq!(rgba.get("red"), Ok(255));
+    /// assert_eq!(rgba.get("green"), Ok(42));
+    /// assert_eq!(rgba.get("blue"), Ok(90));
+    /// assert_eq!(rgba.get("alpha"), Ok(128));
+    /// ```
+    pub fn from_json_string<S>(string: S) -> Self
+    where
+        S: Into<String>
+    {
+        json::parse(&string.into()).map(Self::from).unwrap_or_default()
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or error for invalid JSON and missing
+    /// or invalid components
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::error::Error;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let result = RGBA::try_from_json_str(r#"{ "red": 1 }"#);
+    ///
+    /// assert_eq!(result, Err(Error::MissingComponent("green".to_string())));
+    /// ```
+    pub fn try_from_json_str(string: &str) -> Result<Self, Error> {
+        let data = json::parse(string)?;
+        Self::try_from(&data)
+    }
+
+    /// Serializes data structure as JSON string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let rgba = RGBA::new(255, 42, 90, 128);
+    /// let data = rgba.to_json_string();
+    ///
+    /// let object = json::object!{
+    ///     "red" => 255,
+    ///     "green" => 42,
+    ///     "blue" => 90,
+    ///     "alpha" => 128
+    /// };
+    ///
+    /// let expected = json::stringify(object);
+    ///
+    /// assert_eq!(data, expected);
+    /// ```
+    pub fn to_json_string(&self) -> String {
+        json::stringify(self.clone())
+    }
+
+    /// Converts hexadecimal string, `RRGGBBAA` or `RRGGBB`, into `RGBA`
+    ///
+    /// **Note** missing alpha defaults to fully opaque
+    ///
+    /// **Note** bytes past alpha are ignored, and invalid input returns default values
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// assert_eq!(RGBA::from_hex_string("ABCDEF80"), RGBA::new(171, 205, 239, 128));
+    /// assert_eq!(RGBA::from_hex_string("ABCDEF"), RGBA::new(171, 205, 239, 255));
+    /// assert_eq!(RGBA::from_hex_string("ABCDEF8001"), RGBA::new(171, 205, 239, 128));
+    /// ```
+    pub fn from_hex_string<S>(input: S) -> Self
+    where
+        S: Into<String>
+    {
+        hex::decode(input.into()).map(Self::from).unwrap_or_default()
+    }
+
+    /// Converts hexadecimal string into `RGBA`, or returns error for invalid input
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::error::Error;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// assert!(RGBA::try_from_hex_string("ABCDEF").is_ok());
+    /// assert!(matches!(RGBA::try_from_hex_string("NOPE"), Err(Error::Hex(_))));
+    /// assert_eq!(RGBA::try_from_hex_string("ABCD"), Err(Error::Length { expected: 4, found: 2 }));
+    /// ```
+    pub fn try_from_hex_string<S>(input: S) -> Result<Self, Error>
+    where
+        S: Into<String>
+    {
+        let values = hex::decode(input.into())?;
+        Self::try_from(values.as_slice())
+    }
+
+    /// Returns hexadecimal string representation of `RGBA` values
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let color = RGBA::new(255, 255, 255, 255);
+    /// let hex = color.to_hex_string();
+    ///
+    /// assert_eq!(hex, "FFFFFFFF");
+    /// assert_eq!(RGBA::new(5, 5, 5, 5).to_hex_string(), "05050505");
+    /// ```
+    pub fn to_hex_string(&self) -> String {
+        let vector: Vec<u8> = self.clone().into();
+        vector.iter().map(|v| { format!("{:02X}", v) }).collect::<String>()
+    }
+
+    /// Returns CSS representation of `RGBA` values
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::css::{ Notation, Options, Syntax, Units };
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let color = RGBA::new(255, 42, 90, 51);
+    ///
+    /// assert_eq!(color.to_css_string(Options::new()), "rgb(255 42 90 / 0.2)");
+    /// assert_eq!(color.to_css_string(Options::new().syntax(Syntax::Legacy)), "rgba(255, 42, 90, 0.2)");
+    /// assert_eq!(color.to_css_string(Options::new().units(Units::Percent).precision(0)), "rgb(100% 16% 35% / 20%)");
+    /// assert_eq!(color.to_css_string(Options::new().notation(Notation::Hex)), "#FF2A5A33");
+    /// ```
+    pub fn to_css_string(&self, options: Options) -> String {
+        serialize::rgb(self.red, self.green, self.blue, Some(self.opacity()), options)
+    }
+
+    /// Attempts to rotate hue by some amount of degrees
+    ///
+    /// **Note** this method uses `HSLA::rotate_hue` internally
+    pub fn rotate_hue<T>(&self, amount: T) -> Self
+    where
+        T: Into<f64>
+    {
+        let hsla = HSLA::from(self.clone());
+        Self::from(hsla.rotate_hue(amount.into()))
+    }
+
+    /// Attempts to rotate this color with another `RGBA` value
+    ///
+    /// **Note** this method uses `RGB::rotate_rgb` internally, and alpha of left side is kept
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let red = RGBA::new(25, 0, 0, 128);
+    /// let blue = RGBA::new(0, 0, 25, 255);
+    /// let result = red.rotate_rgb(blue);
+    /// let expected = RGBA::new(25, 0, 25, 128);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn rotate_rgb(&self, other: Self) -> Self {
+        let left = RGB::from(self.clone());
+        let right = RGB::from(other);
+        Self::from_rgb(left.rotate_rgb(right), self.alpha)
+    }
+
+    /// Blends source color into this backdrop color, then composites result over backdrop by alpha
+    /// of source, as defined by W3C Compositing and Blending
+    ///
+    /// **Note** this method uses `BlendMode::apply` internally, where backdrop is translucent the
+    /// source is partially kept unblended
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::blend::BlendMode;
+    /// use color_operators::rgba::RGBA;
+    ///
+    /// let backdrop = RGBA::new(255, 128, 0, 255);
+    ///
+    /// let source = RGBA::new(128, 128, 128, 255);
+    /// assert_eq!(backdrop.blend(source, BlendMode::Multiply), RGBA::new(128, 64, 0, 255));
+    ///
+    /// let source = RGBA::new(128, 128, 128, 51);
+    /// assert_eq!(backdrop.blend(source, BlendMode::Multiply), RGBA::new(230, 115, 0, 255));
+    /// ```
+    pub fn blend(&self, source: Self, mode: BlendMode) -> Self {
+        let backdrop_alpha = self.opacity();
+        let source_alpha = source.opacity();
+        let alpha = source_alpha + backdrop_alpha * (1.0 - source_alpha);
+
+        let blended = mode.apply(RGB::from(self.clone()), RGB::from(source.clone()));
+
+        let channel = |backdrop: u8, source: u8, blended: u8| -> u8 {
+            if alpha <= 0.0 {
+                return 0;
+            }
+
+            let ( backdrop, source, blended ) = ( backdrop as f64 / 255.0, source as f64 / 255.0, blended as f64 / 255.0 );
+            let source = (1.0 - backdrop_alpha) * source + backdrop_alpha * blended;
+            let premultiplied = source_alpha * source + backdrop_alpha * backdrop * (1.0 - source_alpha);
+            (premultiplied / alpha * 255.0).round() as u8
+        };
+
+        let ( red, green, blue ) = blended.into();
+        Self::new(
+            channel(self.red, source.red, red),
+            channel(self.green, source.green, green),
+            channel(self.blue, source.blue, blue),
+            (alpha * 255.0).round() as u8,
+        )
+    }
+}
+
+
+impl Display for RGBA {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "red: {}, green: {}, blue: {}, alpha: {}", self.red, self.green, self.blue, self.alpha)
+    }
+}
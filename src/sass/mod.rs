@@ -0,0 +1,765 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::error::Error;
+use crate::hsl::HSL;
+use crate::hsla::HSLA;
+use crate::hsv::HSV;
+use crate::hsva::HSVA;
+use crate::mix;
+use crate::rgb::RGB;
+use crate::rgba::RGBA;
+
+
+/// Tolerance of dart-sass for comparing numbers, i.e. `10` to the power of `-(precision + 1)`
+const EPSILON: f64 = 0.00000000001;
+
+
+/// Named components for `sass::adjust`, `sass::scale`, and `sass::change`, matching keyword
+/// arguments of Sass, where red, green, and blue are `0.0` to `255.0`, hue is degrees, and
+/// saturation, lightness, whiteness, and blackness are percentages `0.0` to `100.0`
+///
+/// **Note** alpha is `0.0` to `1.0`, except within `sass::scale` where every component is a
+/// percentage `-100.0` to `100.0`
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::sass::Components;
+///
+/// let components = Components::new().red(15.0).alpha(-0.4);
+///
+/// assert_eq!(components.get("red"), Some(15.0));
+/// assert_eq!(components.get("blue"), None);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Components {
+    red: Option<f64>,
+    green: Option<f64>,
+    blue: Option<f64>,
+    hue: Option<f64>,
+    saturation: Option<f64>,
+    lightness: Option<f64>,
+    whiteness: Option<f64>,
+    blackness: Option<f64>,
+    alpha: Option<f64>,
+}
+
+
+/// How `Components` are applied to color
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Update {
+    Adjust,
+    Scale,
+    Change,
+}
+
+
+/// Color as modeled by dart-sass, integer red, green, blue with hue, saturation, and lightness
+/// kept unrounded
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct SassColor {
+    red: f64,
+    green: f64,
+    blue: f64,
+    hue: f64,
+    saturation: f64,
+    lightness: f64,
+    alpha: f64,
+}
+
+
+impl Components {
+    /// Returns components with none set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns named component value, if set
+    pub fn get<S>(&self, component: S) -> Option<f64>
+    where
+        S: Into<String>
+    {
+        match component.into().as_str() {
+            "red" => self.red,
+            "green" => self.green,
+            "blue" => self.blue,
+            "hue" => self.hue,
+            "saturation" => self.saturation,
+            "lightness" => self.lightness,
+            "whiteness" => self.whiteness,
+            "blackness" => self.blackness,
+            "alpha" => self.alpha,
+            _ => None,
+        }
+    }
+
+    /// Returns components with red set
+    pub fn red(self, red: f64) -> Self {
+        Self { red: Some(red), ..self }
+    }
+
+    /// Returns components with green set
+    pub fn green(self, green: f64) -> Self {
+        Self { green: Some(green), ..self }
+    }
+
+    /// Returns components with blue set
+    pub fn blue(self, blue: f64) -> Self {
+        Self { blue: Some(blue), ..self }
+    }
+
+    /// Returns components with hue set
+    pub fn hue(self, hue: f64) -> Self {
+        Self { hue: Some(hue), ..self }
+    }
+
+    /// Returns components with saturation set
+    pub fn saturation(self, saturation: f64) -> Self {
+        Self { saturation: Some(saturation), ..self }
+    }
+
+    /// Returns components with lightness set
+    pub fn lightness(self, lightness: f64) -> Self {
+        Self { lightness: Some(lightness), ..self }
+    }
+
+    /// Returns components with whiteness set
+    pub fn whiteness(self, whiteness: f64) -> Self {
+        Self { whiteness: Some(whiteness), ..self }
+    }
+
+    /// Returns components with blackness set
+    pub fn blackness(self, blackness: f64) -> Self {
+        Self { blackness: Some(blackness), ..self }
+    }
+
+    /// Returns components with alpha set
+    pub fn alpha(self, alpha: f64) -> Self {
+        Self { alpha: Some(alpha), ..self }
+    }
+
+    /// Returns error naming first component that may not be combined with others, or is out of
+    /// range for update
+    fn validate(&self, update: Update) -> Result<(), Error> {
+        let invalid = |name: &str| Err(Error::InvalidComponent(name.to_string()));
+
+        let has_rgb = self.red.is_some() || self.green.is_some() || self.blue.is_some();
+        let has_sl = self.saturation.is_some() || self.lightness.is_some();
+        let has_wb = self.whiteness.is_some() || self.blackness.is_some();
+
+        if update == Update::Scale && self.hue.is_some() {
+            return invalid("hue");
+        }
+        if has_rgb && (self.hue.is_some() || has_sl || has_wb) {
+            let name = [ ("hue", self.hue), ("saturation", self.saturation), ("lightness", self.lightness), ("whiteness", self.whiteness) ]
+                .iter().find(|(_, value)| value.is_some()).map(|(name, _)| *name).unwrap_or("blackness");
+            return invalid(name);
+        }
+        if has_sl && has_wb {
+            return invalid(if self.whiteness.is_some() { "whiteness" } else { "blackness" });
+        }
+
+        let ranges = [
+            ("red", self.red, 255.0),
+            ("green", self.green, 255.0),
+            ("blue", self.blue, 255.0),
+            ("saturation", self.saturation, 100.0),
+            ("lightness", self.lightness, 100.0),
+            ("whiteness", self.whiteness, 100.0),
+            ("blackness", self.blackness, 100.0),
+            ("alpha", self.alpha, 1.0),
+        ];
+        for (name, value, max) in ranges.iter() {
+            let ( low, high ) = match update {
+                Update::Adjust => (-max, *max),
+                Update::Scale => (-100.0, 100.0),
+                Update::Change => (0.0, *max),
+            };
+            if let Some(value) = value {
+                if !value.is_finite() || *value < low - EPSILON || *value > high + EPSILON {
+                    return invalid(name);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+
+impl SassColor {
+    /// Returns color from integer red, green, blue, and alpha between `0.0` and `1.0`
+    pub(crate) fn from_rgb(red: f64, green: f64, blue: f64, alpha: f64) -> Self {
+        let ( hue, saturation, lightness ) = rgb_to_hsl(red, green, blue);
+        Self { red, green, blue, hue, saturation, lightness, alpha: alpha.min(1.0).max(0.0) }
+    }
+
+    /// Returns color from hue in degrees, and saturation and lightness percentages
+    pub(crate) fn from_hsl(hue: f64, saturation: f64, lightness: f64, alpha: f64) -> Self {
+        let hue = hue.rem_euclid(360.0);
+        let saturation = saturation.min(100.0).max(0.0);
+        let lightness = lightness.min(100.0).max(0.0);
+        let ( red, green, blue ) = hsl_to_rgb(hue, saturation, lightness);
+        Self { red, green, blue, hue, saturation, lightness, alpha: alpha.min(1.0).max(0.0) }
+    }
+
+    /// Returns color from hue in degrees, and whiteness and blackness percentages
+    fn from_hwb(hue: f64, whiteness: f64, blackness: f64, alpha: f64) -> Self {
+        let hue = hue.rem_euclid(360.0) / 360.0;
+        let mut whiteness = whiteness / 100.0;
+        let mut blackness = blackness / 100.0;
+        let sum = whiteness + blackness;
+        if sum > 1.0 {
+            whiteness /= sum;
+            blackness /= sum;
+        }
+
+        let factor = 1.0 - whiteness - blackness;
+        let channel = |hue: f64| fuzzy_round((hue_to_rgb(0.0, 1.0, hue) * factor + whiteness) * 255.0);
+        Self::from_rgb(channel(hue + 1.0 / 3.0), channel(hue), channel(hue - 1.0 / 3.0), alpha)
+    }
+
+    /// Returns color from any `Color`, keeping hue, saturation, and lightness of `HSL` based types
+    pub(crate) fn from_color(color: &Color) -> Self {
+        match color {
+            Color::HSL(v) => {
+                let ( hue, saturation, lightness ) = v.clone().into();
+                Self::from_hsl(hue, saturation * 100.0, lightness * 100.0, 1.0)
+            },
+            Color::HSLA(v) => {
+                let ( hue, saturation, lightness, alpha ) = v.clone().into();
+                Self::from_hsl(hue, saturation * 100.0, lightness * 100.0, alpha)
+            },
+            _ => {
+                let ( red, green, blue ) = RGB::from(color.clone()).into();
+                Self::from_rgb(red as f64, green as f64, blue as f64, mix::opacity(color))
+            },
+        }
+    }
+
+    /// Returns color converted to type of given color, where translucent results of types without
+    /// alpha become `HSLA` for `HSL`, `HSVA` for `HSV`, and `RGBA` otherwise
+    pub(crate) fn into_color(self, like: &Color) -> Color {
+        let rgb = RGB::new(self.red as u8, self.green as u8, self.blue as u8);
+        let hsl = HSL::new(self.hue, self.saturation / 100.0, self.lightness / 100.0);
+        let translucent = self.alpha < 1.0;
+
+        match like {
+            Color::HSL(_) if translucent => Color::HSLA(HSLA::from_hsl(hsl, self.alpha)),
+            Color::HSL(_) => Color::HSL(hsl),
+            Color::HSLA(_) => Color::HSLA(HSLA::from_hsl(hsl, self.alpha)),
+            Color::HSV(_) if translucent => Color::HSVA(HSVA::from_hsv(HSV::from(rgb), self.alpha)),
+            Color::RGB(_) if !translucent => Color::RGB(rgb),
+            Color::HSVA(_) => like.convert_like(Color::RGB(rgb), self.alpha),
+            _ if translucent => Color::RGBA(RGBA::from_rgb(rgb, (self.alpha * 255.0).round() as u8)),
+            _ => like.convert_like(Color::RGB(rgb), self.alpha),
+        }
+    }
+
+    /// Returns whiteness percentage
+    fn whiteness(&self) -> f64 {
+        self.red.min(self.green).min(self.blue) / 255.0 * 100.0
+    }
+
+    /// Returns blackness percentage
+    fn blackness(&self) -> f64 {
+        100.0 - self.red.max(self.green).max(self.blue) / 255.0 * 100.0
+    }
+
+    /// Returns color with hue, saturation, and lightness replaced
+    fn change_hsl(&self, hue: f64, saturation: f64, lightness: f64) -> Self {
+        Self::from_hsl(hue, saturation, lightness, self.alpha)
+    }
+
+    /// Returns color with components updated as Sass `adjust`, `scale`, or `change`
+    fn update(&self, components: &Components, update: Update) -> Result<Self, Error> {
+        components.validate(update)?;
+
+        let value = |param: Option<f64>, current: f64, max: f64| -> f64 {
+            match (param, update) {
+                (None, _) => current,
+                (Some(param), Update::Change) => param,
+                (Some(param), Update::Adjust) => (current + param).min(max).max(0.0),
+                (Some(param), Update::Scale) if param > 0.0 => current + (max - current) * param / 100.0,
+                (Some(param), Update::Scale) => current + current * param / 100.0,
+            }
+        };
+        let hue = match (components.hue, update) {
+            (Some(hue), Update::Change) => hue,
+            (hue, _) => self.hue + hue.unwrap_or(0.0),
+        };
+        let alpha = value(components.alpha, self.alpha, 1.0);
+
+        let c = components;
+        if c.red.is_some() || c.green.is_some() || c.blue.is_some() {
+            let channel = |param: Option<f64>, current: f64| fuzzy_round(value(param.map(fuzzy_round), current, 255.0));
+            return Ok(Self::from_rgb(channel(c.red, self.red), channel(c.green, self.green), channel(c.blue, self.blue), alpha));
+        }
+        if c.whiteness.is_some() || c.blackness.is_some() {
+            let whiteness = value(c.whiteness, self.whiteness(), 100.0);
+            let blackness = value(c.blackness, self.blackness(), 100.0);
+            return Ok(Self::from_hwb(hue, whiteness, blackness, alpha));
+        }
+        if c.hue.is_some() || c.saturation.is_some() || c.lightness.is_some() {
+            let saturation = value(c.saturation, self.saturation, 100.0);
+            let lightness = value(c.lightness, self.lightness, 100.0);
+            return Ok(Self::from_hsl(hue, saturation, lightness, alpha));
+        }
+
+        Ok(Self { alpha: alpha.min(1.0).max(0.0), ..*self })
+    }
+
+    /// Returns colors mixed as Sass `mix`, with weight of first color as percentage
+    pub(crate) fn mix(&self, other: &Self, weight: f64) -> Self {
+        let scale = weight.min(100.0).max(0.0) / 100.0;
+        let normalized = scale * 2.0 - 1.0;
+        let alpha_distance = self.alpha - other.alpha;
+
+        let combined = if normalized * alpha_distance == -1.0 {
+            normalized
+        } else {
+            (normalized + alpha_distance) / (1.0 + normalized * alpha_distance)
+        };
+        let first = (combined + 1.0) / 2.0;
+        let second = 1.0 - first;
+
+        Self::from_rgb(
+            fuzzy_round(self.red * first + other.red * second),
+            fuzzy_round(self.green * first + other.green * second),
+            fuzzy_round(self.blue * first + other.blue * second),
+            self.alpha * scale + other.alpha * (1.0 - scale),
+        )
+    }
+
+    /// Returns color with alpha between `0.0` and `1.0` replaced
+    pub(crate) fn with_alpha(&self, alpha: f64) -> Self {
+        Self { alpha: alpha.min(1.0).max(0.0), ..*self }
+    }
+
+    /// Returns alpha between `0.0` and `1.0`
+    pub(crate) fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Returns hue in degrees
+    pub(crate) fn hue(&self) -> f64 {
+        self.hue
+    }
+
+    /// Returns color with hue replaced
+    pub(crate) fn with_hue(&self, hue: f64) -> Self {
+        self.change_hsl(hue, self.saturation, self.lightness)
+    }
+}
+
+
+/// Returns color with components added, e.g. `color.adjust($color, $red: 15)`, or error naming
+/// component that is out of range or may not be combined with others
+///
+/// **Note** red, green, and blue may not be combined with hue, saturation, lightness, whiteness,
+/// or blackness, and saturation or lightness may not be combined with whiteness or blackness
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::error::Error;
+/// use color_operators::sass::{ self, Components };
+///
+/// let color = Color::new_rgb(0xd2, 0xe1, 0xdd);
+///
+/// assert_eq!(sass::adjust(color.clone(), Components::new().red(-10.0).blue(10.0)), Ok(Color::new_rgb(0xc8, 0xe1, 0xe7)));
+/// assert_eq!(sass::adjust(color, Components::new().red(10.0).hue(30.0)), Err(Error::InvalidComponent("hue".to_string())));
+///
+/// let color = Color::new_rgb(0x99, 0x80, 0x99);
+///
+/// assert_eq!(sass::adjust(color, Components::new().lightness(-30.0).alpha(-0.4)), Ok(Color::new_rgba(71, 57, 71, 153)));
+/// ```
+pub fn adjust<C>(color: C, components: Components) -> Result<Color, Error>
+where
+    C: Into<Color>
+{
+    let color: Color = color.into();
+    Ok(SassColor::from_color(&color).update(&components, Update::Adjust)?.into_color(&color))
+}
+
+
+/// Returns color with components scaled by percentages toward their limits, e.g.
+/// `color.scale($color, $lightness: -10%)`, or error naming component that is out of range or may
+/// not be combined with others
+///
+/// **Note** hue may not be scaled
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::sass::{ self, Components };
+///
+/// let color = Color::new_rgb(0xd2, 0xe1, 0xdd);
+///
+/// assert_eq!(sass::scale(color, Components::new().lightness(-10.0).saturation(10.0)), Ok(Color::new_rgb(0xb3, 0xd4, 0xcb)));
+///
+/// let color = Color::new_rgb(0x99, 0x80, 0x99);
+///
+/// assert_eq!(sass::scale(color, Components::new().alpha(-40.0)), Ok(Color::new_rgba(153, 128, 153, 153)));
+/// ```
+pub fn scale<C>(color: C, components: Components) -> Result<Color, Error>
+where
+    C: Into<Color>
+{
+    let color: Color = color.into();
+    Ok(SassColor::from_color(&color).update(&components, Update::Scale)?.into_color(&color))
+}
+
+
+/// Returns color with components replaced, e.g. `color.change($color, $red: 100)`, or error naming
+/// component that is out of range or may not be combined with others
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::sass::{ self, Components };
+///
+/// let color = Color::new_rgb(0xd2, 0xe1, 0xdd);
+///
+/// assert_eq!(sass::change(color, Components::new().red(100.0).blue(50.0)), Ok(Color::new_rgb(0x64, 0xe1, 0x32)));
+///
+/// let color = Color::new_rgb(0x99, 0x80, 0x99);
+///
+/// assert_eq!(sass::change(color, Components::new().lightness(30.0).alpha(0.5)), Ok(Color::new_rgba(85, 68, 85, 128)));
+/// ```
+pub fn change<C>(color: C, components: Components) -> Result<Color, Error>
+where
+    C: Into<Color>
+{
+    let color: Color = color.into();
+    Ok(SassColor::from_color(&color).update(&components, Update::Change)?.into_color(&color))
+}
+
+
+/// Returns color with lightness percentage increased by amount, or error when amount is not between `0.0` and `100.0`
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::error::Error;
+/// use color_operators::sass;
+///
+/// assert_eq!(sass::lighten(Color::new_rgb(0x6b, 0x71, 0x7f), 20.0), Ok(Color::new_rgb(0xa1, 0xa5, 0xaf)));
+/// assert_eq!(sass::lighten(Color::new_rgb(0xe1, 0xd7, 0xd2), 30.0), Ok(Color::new_rgb(255, 255, 255)));
+/// assert_eq!(sass::lighten(Color::new_rgb(0x6b, 0x71, 0x7f), 120.0), Err(Error::InvalidComponent("amount".to_string())));
+/// ```
+pub fn lighten<C>(color: C, amount: f64) -> Result<Color, Error>
+where
+    C: Into<Color>
+{
+    let amount = percentage("amount", amount)?;
+    Ok(update_hsl(color.into(), |c| (c.hue, c.saturation, c.lightness + amount)))
+}
+
+
+/// Returns color with lightness percentage decreased by amount, or error when amount is not between `0.0` and `100.0`
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::error::Error;
+/// use color_operators::sass;
+///
+/// assert_eq!(sass::darken(Color::new_rgb(0xb3, 0x73, 0x99), 20.0), Ok(Color::new_rgb(0x7c, 0x44, 0x65)));
+/// assert_eq!(sass::darken(Color::new_rgb(0xf2, 0xec, 0xe4), 40.0), Ok(Color::new_rgb(0xb0, 0x8b, 0x5a)));
+/// assert_eq!(sass::darken(Color::new_rgb(0x6b, 0x71, 0x7f), 120.0), Err(Error::InvalidComponent("amount".to_string())));
+/// ```
+pub fn darken<C>(color: C, amount: f64) -> Result<Color, Error>
+where
+    C: Into<Color>
+{
+    let amount = percentage("amount", amount)?;
+    Ok(update_hsl(color.into(), |c| (c.hue, c.saturation, c.lightness - amount)))
+}
+
+
+/// Returns color with saturation percentage increased by amount, or error when amount is not between `0.0` and `100.0`
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::error::Error;
+/// use color_operators::sass;
+///
+/// assert_eq!(sass::saturate(Color::new_rgb(0x0e, 0x49, 0x82), 30.0), Ok(Color::new_rgb(0x00, 0x49, 0x90)));
+/// assert_eq!(sass::saturate(Color::new_rgb(0x6b, 0x71, 0x7f), 120.0), Err(Error::InvalidComponent("amount".to_string())));
+/// ```
+pub fn saturate<C>(color: C, amount: f64) -> Result<Color, Error>
+where
+    C: Into<Color>
+{
+    let amount = percentage("amount", amount)?;
+    Ok(update_hsl(color.into(), |c| (c.hue, c.saturation + amount, c.lightness)))
+}
+
+
+/// Returns color with saturation percentage decreased by amount, or error when amount is not between `0.0` and `100.0`
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::error::Error;
+/// use color_operators::sass;
+///
+/// assert_eq!(sass::desaturate(Color::new_rgb(0x00, 0x33, 0x66), 20.0), Ok(Color::new_rgb(0x0a, 0x33, 0x5c)));
+/// assert_eq!(sass::desaturate(Color::new_rgb(0x6b, 0x71, 0x7f), 120.0), Err(Error::InvalidComponent("amount".to_string())));
+/// ```
+pub fn desaturate<C>(color: C, amount: f64) -> Result<Color, Error>
+where
+    C: Into<Color>
+{
+    let amount = percentage("amount", amount)?;
+    Ok(update_hsl(color.into(), |c| (c.hue, c.saturation - amount, c.lightness)))
+}
+
+
+/// Returns color with hue rotated by degrees, a.k.a. `adjust-hue`
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::sass;
+///
+/// assert_eq!(sass::adjust_hue(Color::new_rgb(0x6b, 0x71, 0x7f), 60.0), Color::new_rgb(0x79, 0x6b, 0x7f));
+/// assert_eq!(sass::adjust_hue(Color::new_rgb(0x6b, 0x71, 0x7f), -60.0), Color::new_rgb(0x6b, 0x7f, 0x79));
+/// ```
+pub fn adjust_hue<C>(color: C, degrees: f64) -> Color
+where
+    C: Into<Color>
+{
+    update_hsl(color.into(), |c| (c.hue + degrees, c.saturation, c.lightness))
+}
+
+
+/// Returns color with hue rotated by `180` degrees
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::sass;
+///
+/// assert_eq!(sass::complement(Color::new_rgb(0x6b, 0x71, 0x7f)), Color::new_rgb(0x7f, 0x79, 0x6b));
+/// ```
+pub fn complement<C>(color: C) -> Color
+where
+    C: Into<Color>
+{
+    update_hsl(color.into(), |c| (c.hue + 180.0, c.saturation, c.lightness))
+}
+
+
+/// Returns color with saturation of `0.0`
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::sass;
+///
+/// assert_eq!(sass::grayscale(Color::new_rgb(0x6b, 0x71, 0x7f)), Color::new_rgb(0x75, 0x75, 0x75));
+/// ```
+pub fn grayscale<C>(color: C) -> Color
+where
+    C: Into<Color>
+{
+    update_hsl(color.into(), |c| (c.hue, 0.0, c.lightness))
+}
+
+
+/// Returns red, green, and blue inverted, mixed with original color by weight of inverse as
+/// percentage, where `100.0` is fully inverted, or error when weight is not between `0.0` and
+/// `100.0`
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::sass;
+///
+/// assert_eq!(sass::invert(Color::new_rgb(0xb3, 0x73, 0x99), 100.0), Ok(Color::new_rgb(0x4c, 0x8c, 0x66)));
+/// assert_eq!(sass::invert(Color::new_rgb(0x55, 0x0e, 0x0c), 20.0), Ok(Color::new_rgb(0x66, 0x3b, 0x3a)));
+/// assert!(sass::invert(Color::new_rgb(0x55, 0x0e, 0x0c), -20.0).is_err());
+/// ```
+pub fn invert<C>(color: C, weight: f64) -> Result<Color, Error>
+where
+    C: Into<Color>
+{
+    let weight = percentage("weight", weight)?;
+    let color: Color = color.into();
+    let sass = SassColor::from_color(&color);
+    let inverse = SassColor::from_rgb(255.0 - sass.red, 255.0 - sass.green, 255.0 - sass.blue, sass.alpha);
+    if weight >= 100.0 {
+        return Ok(inverse.into_color(&color));
+    }
+    Ok(inverse.mix(&sass, weight).into_color(&color))
+}
+
+
+/// Returns colors mixed within sRGB, as Sass and Less `mix`, where weight of first color is a
+/// percentage, or error when weight is not between `0.0` and `100.0`
+///
+/// **Note** result is converted to type of first color, see `Color::mix` for mixing within other
+/// color spaces
+///
+/// # Example
+///
+/// ```rust
+/// use color_operators::color::Color;
+/// use color_operators::error::Error;
+/// use color_operators::sass;
+///
+/// let first = Color::new_rgb(0x00, 0x33, 0x66);
+/// let second = Color::new_rgb(0xd2, 0xe1, 0xdd);
+///
+/// assert_eq!(sass::mix(first.clone(), second.clone(), 50.0), Ok(Color::new_rgb(0x69, 0x8a, 0xa2)));
+/// assert_eq!(sass::mix(first.clone(), second.clone(), 75.0), Ok(Color::new_rgb(0x35, 0x5f, 0x84)));
+/// assert_eq!(sass::mix(first.clone(), second.clone(), 25.0), Ok(Color::new_rgb(0x9e, 0xb6, 0xbf)));
+/// assert_eq!(sass::mix(first, second, 150.0), Err(Error::InvalidComponent("weight".to_string())));
+/// ```
+pub fn mix<F, S>(first: F, second: S, weight: f64) -> Result<Color, Error>
+where
+    F: Into<Color>,
+    S: Into<Color>
+{
+    let weight = percentage("weight", weight)?;
+    let first: Color = first.into();
+    let second: Color = second.into();
+    Ok(SassColor::from_color(&first).mix(&SassColor::from_color(&second), weight).into_color(&first))
+}
+
+
+/// Returns color with hue, saturation, and lightness replaced by callback
+fn update_hsl<F>(color: Color, callback: F) -> Color
+where
+    F: Fn(&SassColor) -> (f64, f64, f64)
+{
+    let sass = SassColor::from_color(&color);
+    let ( hue, saturation, lightness ) = callback(&sass);
+    sass.change_hsl(hue, saturation, lightness).into_color(&color)
+}
+
+
+/// Returns percentage clamped between `0.0` and `100.0`, or error naming argument when outside of
+/// that range by more than `EPSILON`
+fn percentage(name: &str, value: f64) -> Result<f64, Error> {
+    if !(-EPSILON..=100.0 + EPSILON).contains(&value) {
+        return Err(Error::InvalidComponent(name.to_string()));
+    }
+    Ok(value.min(100.0).max(0.0))
+}
+
+
+/// Returns `true` when numbers differ by less than `EPSILON`
+fn fuzzy_equals(first: f64, second: f64) -> bool {
+    (first - second).abs() < EPSILON
+}
+
+
+/// Returns number rounded as dart-sass `fuzzyRound`, i.e. halves round away from zero within
+/// `EPSILON`
+fn fuzzy_round(number: f64) -> f64 {
+    let fraction = number.rem_euclid(1.0);
+    let round_down = if number > 0.0 {
+        fraction < 0.5 && !fuzzy_equals(fraction, 0.5)
+    } else {
+        fraction < 0.5 || fuzzy_equals(fraction, 0.5)
+    };
+    if round_down {
+        number.floor()
+    } else {
+        number.ceil()
+    }
+}
+
+
+/// Returns hue in degrees, and saturation and lightness percentages from integer red, green, blue
+fn rgb_to_hsl(red: f64, green: f64, blue: f64) -> (f64, f64, f64) {
+    let red = red / 255.0;
+    let green = green / 255.0;
+    let blue = blue / 255.0;
+
+    let max = red.max(green).max(blue);
+    let min = red.min(green).min(blue);
+    let delta = max - min;
+
+    let hue = if max == min {
+        0.0
+    } else if max == red {
+        (60.0 * (green - blue) / delta).rem_euclid(360.0)
+    } else if max == green {
+        (120.0 + 60.0 * (blue - red) / delta).rem_euclid(360.0)
+    } else {
+        (240.0 + 60.0 * (red - green) / delta).rem_euclid(360.0)
+    };
+
+    let lightness = 50.0 * (max + min);
+
+    let saturation = if max == min {
+        0.0
+    } else if lightness < 50.0 {
+        100.0 * delta / (max + min)
+    } else {
+        100.0 * delta / (2.0 - max - min)
+    };
+
+    (hue, saturation, lightness)
+}
+
+
+/// Returns integer red, green, blue from hue in degrees, and saturation and lightness percentages
+fn hsl_to_rgb(hue: f64, saturation: f64, lightness: f64) -> (f64, f64, f64) {
+    let hue = hue / 360.0;
+    let saturation = saturation / 100.0;
+    let lightness = lightness / 100.0;
+
+    let m2 = if lightness <= 0.5 {
+        lightness * (saturation + 1.0)
+    } else {
+        lightness + saturation - lightness * saturation
+    };
+    let m1 = lightness * 2.0 - m2;
+
+    (
+        fuzzy_round(hue_to_rgb(m1, m2, hue + 1.0 / 3.0) * 255.0),
+        fuzzy_round(hue_to_rgb(m1, m2, hue) * 255.0),
+        fuzzy_round(hue_to_rgb(m1, m2, hue - 1.0 / 3.0) * 255.0),
+    )
+}
+
+
+/// Returns channel between `0.0` and `1.0` for hue as fraction of turn
+fn hue_to_rgb(m1: f64, m2: f64, hue: f64) -> f64 {
+    let hue = if hue < 0.0 {
+        hue + 1.0
+    } else if hue > 1.0 {
+        hue - 1.0
+    } else {
+        hue
+    };
+
+    if hue < 1.0 / 6.0 {
+        m1 + (m2 - m1) * hue * 6.0
+    } else if hue < 1.0 / 2.0 {
+        m2
+    } else if hue < 2.0 / 3.0 {
+        m1 + (m2 - m1) * (2.0 / 3.0 - hue) * 6.0
+    } else {
+        m1
+    }
+}
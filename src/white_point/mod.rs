@@ -0,0 +1,90 @@
+#!/usr/bin/env rust
+
+
+/// Standard illuminants a device independent color may be relative to
+///
+/// **Note** tristimulus values are derived from CIE 1931 2° observer chromaticity coordinates and
+/// normalized such that `Y` is `1.0`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WhitePoint {
+    /// Incandescent tungsten light
+    A,
+
+    /// Average daylight, superseded by `D65`
+    C,
+
+    /// Horizon light, used by ICC profiles and print workflows
+    D50,
+
+    /// Mid-morning or mid-afternoon daylight, used by photography
+    D55,
+
+    /// Approximately D60, as defined by the Academy for ACES color spaces
+    D60,
+
+    /// Noon daylight, used by sRGB and most displays
+    #[default]
+    D65,
+
+    /// North sky daylight
+    D75,
+
+    /// Equal energy, with all wavelengths equally weighted
+    E,
+
+    /// Cool white fluorescent light
+    F2,
+
+    /// Broadband daylight fluorescent light
+    F7,
+
+    /// Narrow tri-band fluorescent light
+    F11,
+}
+
+
+impl WhitePoint {
+    /// Returns `x` and `y` chromaticity coordinates of white point
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::white_point::WhitePoint;
+    ///
+    /// assert_eq!(WhitePoint::D65.chromaticity(), (0.3127, 0.3290));
+    /// ```
+    pub fn chromaticity(&self) -> (f64, f64) {
+        match self {
+            Self::A => (0.44757, 0.40745),
+            Self::C => (0.31006, 0.31616),
+            Self::D50 => (0.3457, 0.3585),
+            Self::D55 => (0.33242, 0.34743),
+            Self::D60 => (0.32168, 0.33767),
+            Self::D65 => (0.3127, 0.3290),
+            Self::D75 => (0.29902, 0.31485),
+            Self::E => (1.0 / 3.0, 1.0 / 3.0),
+            Self::F2 => (0.37208, 0.37529),
+            Self::F7 => (0.31292, 0.32933),
+            Self::F11 => (0.38052, 0.37713),
+        }
+    }
+
+    /// Returns `X`, `Y`, `Z` tristimulus values of white point
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::white_point::WhitePoint;
+    ///
+    /// let ( x, y, z ) = WhitePoint::D50.tristimulus();
+    ///
+    /// assert_eq!(x, 0.3457 / 0.3585);
+    /// assert_eq!(y, 1.0);
+    /// assert_eq!(z, (1.0 - 0.3457 - 0.3585) / 0.3585);
+    /// ```
+    pub fn tristimulus(&self) -> (f64, f64, f64) {
+        let ( x, y ) = self.chromaticity();
+        ( x / y, 1.0, (1.0 - x - y) / y )
+    }
+}
+
@@ -0,0 +1,50 @@
+#!/usr/bin/env rust
+
+
+use crate::xyz::XYZ;
+
+
+/// Convert from array of 64-bit precision floating point numbers
+impl<T> From<[T; 3]> for XYZ
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let array = [ 0.5, 0.25, 0.125 ];
+    /// let xyz = XYZ::from(array);
+    ///
+    /// assert_eq!(xyz.get("x"), Ok(0.5));
+    /// assert_eq!(xyz.get("y"), Ok(0.25));
+    /// assert_eq!(xyz.get("z"), Ok(0.125));
+    /// ```
+    fn from(array: [T; 3]) -> Self {
+        let x = array[0];
+        let y = array[1];
+        let z = array[2];
+        Self::new(x, y, z)
+    }
+}
+
+
+/// Convert into array of 64-bit precision floating point numbers
+impl Into<[f64; 3]> for XYZ {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let xyz = XYZ::new(0.5, 0.25, 0.125);
+    /// let array: [f64; 3] = xyz.into();
+    ///
+    /// assert_eq!(array[0], 0.5);
+    /// assert_eq!(array[1], 0.25);
+    /// assert_eq!(array[2], 0.125);
+    /// ```
+    fn into(self) -> [f64; 3] {
+        [ self.x, self.y, self.z ]
+    }
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::hsl::HSL;
+use crate::rgb_space::RgbSpace;
+use crate::xyz::XYZ;
+
+
+/// Convert from `HSL` to `XYZ`
+///
+/// **Note** uses `RgbSpace::srgb().to_xyz` internally
+impl From<HSL> for XYZ {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsl::HSL;
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let hsl = HSL::from(rgb.clone());
+    /// let xyz = XYZ::from(hsl);
+    ///
+    /// assert_eq!(xyz, XYZ::from(rgb));
+    /// ```
+    fn from(hsl: HSL) -> Self {
+        RgbSpace::srgb().to_xyz(hsl)
+    }
+}
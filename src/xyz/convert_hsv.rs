@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::hsv::HSV;
+use crate::rgb_space::RgbSpace;
+use crate::xyz::XYZ;
+
+
+/// Convert from `HSV` to `XYZ`
+///
+/// **Note** uses `RgbSpace::srgb().to_xyz` internally
+impl From<HSV> for XYZ {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsv::HSV;
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let hsv = HSV::from(rgb.clone());
+    /// let xyz = XYZ::from(hsv);
+    ///
+    /// assert_eq!(xyz, XYZ::from(rgb));
+    /// ```
+    fn from(hsv: HSV) -> Self {
+        RgbSpace::srgb().to_xyz(hsv)
+    }
+}
@@ -0,0 +1,99 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+extern crate json;
+use json::JsonValue;
+
+
+use crate::error::{ json_f64, Error };
+use crate::xyz::XYZ;
+
+
+/// Converts from `JsonValue` key value pares, or error for missing or invalid components
+impl TryFrom<&JsonValue> for XYZ {
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::xyz::XYZ;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{ "x": "nope" }"#).unwrap();
+    ///
+    /// assert_eq!(XYZ::try_from(&data), Err(Error::InvalidComponent("x".to_string())));
+    /// ```
+    fn try_from(data: &JsonValue) -> Result<Self, Self::Error> {
+        let x = json_f64(data, "x")?;
+        let y = json_f64(data, "y")?;
+        let z = json_f64(data, "z")?;
+        Ok(Self::new(x, y, z))
+    }
+}
+
+
+/// Converts from `JsonValue` key value pares
+///
+/// **Note** missing or invalid components default individually, where `XYZ::try_from` returns
+/// error
+impl From<JsonValue> for XYZ {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::xyz::XYZ;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{
+    ///     "x": 0.5,
+    ///     "y": 0.25,
+    ///     "z": 0.125
+    /// }"#).unwrap();
+    ///
+    /// let xyz = XYZ::from(data);
+    ///
+    /// assert_eq!(xyz.get("x"), Ok(0.5));
+    /// assert_eq!(xyz.get("y"), Ok(0.25));
+    /// assert_eq!(xyz.get("z"), Ok(0.125));
+    /// ```
+    fn from(data: JsonValue) -> Self {
+        let x = data["x"].as_f64().unwrap_or_default();
+        let y = data["y"].as_f64().unwrap_or_default();
+        let z = data["z"].as_f64().unwrap_or_default();
+        Self::new(x, y, z)
+    }
+}
+
+
+/// Converts to `JsonValue`
+impl Into<JsonValue> for XYZ {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::xyz::XYZ;
+    /// extern crate json;
+    /// use json::JsonValue;
+    ///
+    /// let xyz = XYZ::new(0.5, 0.25, 0.125);
+    /// let data: JsonValue = xyz.into();
+    ///
+    /// let expected = json::parse(r#"{
+    ///     "x": 0.5,
+    ///     "y": 0.25,
+    ///     "z": 0.125
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(data, expected);
+    /// ```
+    fn into(self) -> JsonValue {
+        json::object!{
+            "x" => self.x,
+            "y" => self.y,
+            "z" => self.z,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+#!/usr/bin/env rust
+
+
+use crate::lab::Lab;
+use crate::white_point::WhitePoint;
+use crate::xyz::XYZ;
+
+
+/// Convert from `Lab` to `XYZ`
+///
+/// **Note** uses `WhitePoint::D65` as reference white, see `Lab::to_xyz` for alternatives
+impl From<Lab> for XYZ {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lab::Lab;
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let lab = Lab::new(0.0, 0.0, 0.0);
+    /// let xyz = XYZ::from(lab);
+    ///
+    /// assert_eq!(xyz, XYZ::new(0.0, 0.0, 0.0));
+    /// ```
+    fn from(lab: Lab) -> Self {
+        lab.to_xyz(WhitePoint::D65)
+    }
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::lab::Lab;
+use crate::lch::LCh;
+use crate::xyz::XYZ;
+
+
+/// Convert from `LCh` to `XYZ`
+///
+/// **Note** uses `Lab::from(lch)` internally
+impl From<LCh> for XYZ {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::lch::LCh;
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let lch = LCh::from(rgb.clone());
+    /// let xyz = XYZ::from(lch);
+    ///
+    /// assert_eq!(RGB::from(xyz), rgb);
+    /// ```
+    fn from(lch: LCh) -> Self {
+        Self::from(Lab::from(lch))
+    }
+}
@@ -0,0 +1,32 @@
+#!/usr/bin/env rust
+
+
+use crate::oklab::Oklab;
+use crate::xyz::XYZ;
+
+
+/// Convert from `Oklab` to `XYZ`
+impl From<Oklab> for XYZ {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklab::Oklab;
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let oklab = Oklab::from(rgb.clone());
+    /// let xyz = XYZ::from(oklab);
+    ///
+    /// assert_eq!(RGB::from(xyz), rgb);
+    /// ```
+    fn from(oklab: Oklab) -> Self {
+        let ( l, m, s ) = oklab.to_lms();
+
+        let x = 1.2270138511 * l - 0.5577999807 * m + 0.2812561490 * s;
+        let y = -0.0405801784 * l + 1.1122568696 * m - 0.0716766787 * s;
+        let z = -0.0763812845 * l - 0.4214819784 * m + 1.5861632204 * s;
+
+        Self { x, y, z }
+    }
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::oklab::Oklab;
+use crate::oklch::Oklch;
+use crate::xyz::XYZ;
+
+
+/// Convert from `Oklch` to `XYZ`
+///
+/// **Note** uses `Oklab::from(oklch)` internally
+impl From<Oklch> for XYZ {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::oklch::Oklch;
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let oklch = Oklch::from(rgb.clone());
+    /// let xyz = XYZ::from(oklch);
+    ///
+    /// assert_eq!(RGB::from(xyz), rgb);
+    /// ```
+    fn from(oklch: Oklch) -> Self {
+        Self::from(Oklab::from(oklch))
+    }
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::rgb::RGB;
+use crate::rgb_space::RgbSpace;
+use crate::xyz::XYZ;
+
+
+/// Convert from `RGB` to `XYZ`
+///
+/// **Note** uses `RgbSpace::srgb` internally, see `RgbSpace::to_xyz` for other spaces
+impl From<RGB> for XYZ {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let rgb = RGB::new(0, 0, 0);
+    /// let xyz = XYZ::from(rgb);
+    ///
+    /// assert_eq!(xyz.get("x"), Ok(0.0));
+    /// assert_eq!(xyz.get("y"), Ok(0.0));
+    /// assert_eq!(xyz.get("z"), Ok(0.0));
+    /// ```
+    fn from(rgb: RGB) -> Self {
+        RgbSpace::srgb().to_xyz(rgb)
+    }
+}
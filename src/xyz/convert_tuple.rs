@@ -0,0 +1,48 @@
+#!/usr/bin/env rust
+
+
+use crate::xyz::XYZ;
+
+
+/// Convert from tuple of 64-bit precision floating point numbers
+impl<T> From<(T, T, T)> for XYZ
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let tuple = ( 0.5, 0.25, 0.125 );
+    /// let xyz = XYZ::from(tuple);
+    ///
+    /// assert_eq!(xyz.get("x"), Ok(0.5));
+    /// assert_eq!(xyz.get("y"), Ok(0.25));
+    /// assert_eq!(xyz.get("z"), Ok(0.125));
+    /// ```
+    fn from(tuple: (T, T, T)) -> Self {
+        let ( x, y, z ) = tuple;
+        Self::new(x, y, z)
+    }
+}
+
+
+/// Convert into tuple of 64-bit precision floating point numbers
+impl Into<(f64, f64, f64)> for XYZ {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let xyz = XYZ::new(0.5, 0.25, 0.125);
+    /// let ( x, y, z ) = xyz.into();
+    ///
+    /// assert_eq!(x, 0.5);
+    /// assert_eq!(y, 0.25);
+    /// assert_eq!(z, 0.125);
+    /// ```
+    fn into(self) -> (f64, f64, f64) {
+        ( self.x, self.y, self.z )
+    }
+}
@@ -0,0 +1,85 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+use crate::error::{ check_length, Error };
+use crate::xyz::XYZ;
+
+
+/// Convert from slice of 64-bit precision floating point numbers, or error for invalid length
+impl<T> TryFrom<&[T]> for XYZ
+where
+    T: Into<f64> + Copy
+{
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let xyz = XYZ::try_from(&[0.5, 0.25, 0.125][..]).unwrap();
+    /// assert_eq!(xyz, XYZ::from(vec![0.5, 0.25, 0.125]));
+    ///
+    /// let result = XYZ::try_from(&[0.5, 0.25][..]);
+    /// assert_eq!(result, Err(Error::Length { expected: 3, found: 2 }));
+    /// ```
+    fn try_from(values: &[T]) -> Result<Self, Self::Error> {
+        check_length(values.len(), 3, 3)?;
+        let x = values[0].into();
+        let y = values[1].into();
+        let z = values[2].into();
+        Ok(Self::new(x, y, z))
+    }
+}
+
+
+/// Convert from vector of 64-bit precision floating point numbers
+///
+/// **Note** this uses `XYZ::try_from` internally, values past the third are ignored, and too few
+/// values return default values
+impl<T> From<Vec<T>> for XYZ
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let vector = vec![0.5, 0.25, 0.125];
+    /// let xyz = XYZ::from(vector);
+    ///
+    /// assert_eq!(xyz.get("x"), Ok(0.5));
+    /// assert_eq!(xyz.get("y"), Ok(0.25));
+    /// assert_eq!(xyz.get("z"), Ok(0.125));
+    /// ```
+    fn from(vector: Vec<T>) -> Self {
+        let length = vector.len().min(3);
+        Self::try_from(&vector[..length]).unwrap_or_default()
+    }
+}
+
+
+/// Convert into vector of 64-bit precision floating point numbers
+impl Into<Vec<f64>> for XYZ {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let xyz = XYZ::new(0.5, 0.25, 0.125);
+    /// let vector: Vec<f64> = xyz.into();
+    ///
+    /// assert_eq!(vector[0], 0.5);
+    /// assert_eq!(vector[1], 0.25);
+    /// assert_eq!(vector[2], 0.125);
+    /// ```
+    fn into(self) -> Vec<f64> {
+        vec![ self.x, self.y, self.z ]
+    }
+}
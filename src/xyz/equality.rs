@@ -0,0 +1,54 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::color::equality::alpha_eq;
+use crate::xyz::XYZ;
+
+
+impl Eq for XYZ {}
+impl PartialEq for XYZ {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let left = XYZ::new(0.5, 0.25, 0.125);
+    /// let right = XYZ::new(0.5, 0.25, 0.125);
+    ///
+    /// assert_eq!(left, right);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        if self.x != other.x || self.y != other.y || self.z != other.z {
+            return false;
+        }
+        true
+    }
+}
+
+
+/// Converts right side from `Color` to `XYZ` prior to checking equality
+///
+/// **Note** colors with alpha are compared as `RGBA`, so are only equal when fully opaque
+impl PartialEq<Color> for XYZ {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::xyz::XYZ;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let xyz = XYZ::from(rgb.clone());
+    ///
+    /// assert!(xyz == Color::from(rgb));
+    /// ```
+    fn eq(&self, other: &Color) -> bool {
+        if let Some(equal) = alpha_eq(&Color::from(self.clone()), other) {
+            return equal;
+        }
+
+        let other: XYZ = other.clone().into();
+        self == &other
+    }
+}
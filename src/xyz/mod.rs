@@ -0,0 +1,248 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+
+use crate::adaptation::Method;
+use crate::error::Error;
+use crate::lch::LCh;
+use crate::rgb::RGB;
+use crate::white_point::WhitePoint;
+
+
+/// Adds color components for `XYZ` data structures
+mod add;
+
+/// Subtracts color components for `XYZ` data structures
+mod subtract;
+
+/// Equal and not-equal checks for `XYZ` data structures
+mod equality;
+
+/// Converts from/to array for `XYZ` data structures
+mod convert_array;
+
+/// Converts from/to `Color` enum
+mod convert_color;
+
+/// Converts from `HSL` to `XYZ` data structure
+mod convert_hsl;
+
+/// Converts from `HSV` to `XYZ` data structure
+mod convert_hsv;
+
+/// Converts from/to `JsonValue` for `XYZ` data structures
+mod convert_json_value;
+
+/// Converts from `Lab` to `XYZ` data structure
+mod convert_lab;
+
+/// Converts from `LCh` to `XYZ` data structure
+mod convert_lch;
+
+/// Converts from `Oklab` to `XYZ` data structure
+mod convert_oklab;
+
+/// Converts from `Oklch` to `XYZ` data structure
+mod convert_oklch;
+
+/// Converts from `RGB` to `XYZ` data structure
+mod convert_rgb;
+
+/// Converts from/to tuple for `XYZ` data structures
+mod convert_tuple;
+
+/// Converts from/to vector for `XYZ` data structures
+mod convert_vector;
+
+
+/// Data structure for CIE 1931 XYZ encoded colors
+///
+/// **Note** values are relative to `WhitePoint::D65` with `Y` of reference white being `1.0`
+#[derive(Clone, Debug, Default)]
+pub struct XYZ {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+
+impl XYZ {
+    /// Returns new instance of `XYZ` data structure
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let color = XYZ::new(0.5, 0.25, 0.125);
+    ///
+    /// assert_eq!(color.get("x"), Ok(0.5));
+    /// assert_eq!(color.get("y"), Ok(0.25));
+    /// assert_eq!(color.get("z"), Ok(0.125));
+    /// ```
+    pub fn new<T>(x: T, y: T, z: T) -> Self
+    where
+        T: Into<f64>
+    {
+        Self { x: x.into(), y: y.into(), z: z.into() }
+    }
+
+    /// Returns named component value or error
+    ///
+    /// ```rust
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let color = XYZ::new(0.5, 0.25, 0.125);
+    ///
+    /// assert_eq!(color.get("nothing"), Err("No color component named -> nothing".to_string()));
+    /// ```
+    pub fn get<S>(&self, component: S) -> Result<f64, String>
+    where
+        S: Into<String>
+    {
+        let component: String = component.into();
+        match component.as_str() {
+            "x" => Ok(self.x),
+            "y" => Ok(self.y),
+            "z" => Ok(self.z),
+            _ => Err(format!("No color component named -> {}", component)),
+        }
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or default values
+    ///
+    /// **Note** missing or invalid components default individually, see `From<JsonValue>`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let xyz = XYZ::from_json_string(r#"{ "x": 0.5, "y": 0.25, "z": 0.125 }"#);
+    ///
+    /// assert_eq!(xyz.get("x"), Ok(0.5));
+    /// assert_eq!(xyz.get("y"), Ok(0.25));
+    /// assert_eq!(xyz.get("z"), Ok(0.125));
+    /// ```
+    pub fn from_json_string<S>(string: S) -> Self
+    where
+        S: Into<String>
+    {
+        json::parse(&string.into()).map(Self::from).unwrap_or_default()
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or error for invalid JSON and missing
+    /// or invalid components
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::error::Error;
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let result = XYZ::try_from_json_str(r#"{ "x": 1 }"#);
+    ///
+    /// assert_eq!(result, Err(Error::MissingComponent("y".to_string())));
+    /// ```
+    pub fn try_from_json_str(string: &str) -> Result<Self, Error> {
+        let data = json::parse(string)?;
+        Self::try_from(&data)
+    }
+
+    /// Serializes data structure as JSON string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let xyz = XYZ::new(0.5, 0.25, 0.125);
+    /// let data = xyz.to_json_string();
+    ///
+    /// let object = json::object!{
+    ///     "x" => 0.5,
+    ///     "y" => 0.25,
+    ///     "z" => 0.125
+    /// };
+    ///
+    /// let expected = json::stringify(object);
+    ///
+    /// assert_eq!(data, expected);
+    /// ```
+    pub fn to_json_string(&self) -> String {
+        json::stringify(self.clone())
+    }
+
+    /// Converts hexadecimal string into `XYZ`
+    ///
+    /// **Note** this method uses `RGB::from_hex_string` internally, which returns default values
+    /// for invalid input
+    pub fn from_hex_string<S>(input: S) -> Self
+    where
+        S: Into<String>
+    {
+        Self::from(RGB::from_hex_string(input))
+    }
+
+    /// Converts hexadecimal string into `XYZ`, or returns error for invalid input
+    ///
+    /// **Note** this method uses `RGB::try_from_hex_string` internally
+    pub fn try_from_hex_string<S>(input: S) -> Result<Self, Error>
+    where
+        S: Into<String>
+    {
+        RGB::try_from_hex_string(input).map(Self::from)
+    }
+
+    /// Returns hexadecimal string representation of `XYZ` values
+    ///
+    /// **Note** this method uses `RGB::to_hex_string` internally
+    pub fn to_hex_string(&self) -> String {
+        RGB::from(self.clone()).to_hex_string()
+    }
+
+    /// Returns values relative to source white point adapted to be relative to destination white
+    /// point
+    ///
+    /// **Note** this method uses `Method::adapt` internally
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::adaptation::Method;
+    /// use color_operators::white_point::WhitePoint;
+    /// use color_operators::xyz::XYZ;
+    ///
+    /// let ( x, y, z ) = WhitePoint::D50.tristimulus();
+    /// let adapted = XYZ::new(x, y, z).adapt(WhitePoint::D50, WhitePoint::E, Method::VonKries);
+    ///
+    /// let ( x, y, z ) = adapted.into();
+    ///
+    /// assert_eq!(((x * 1e9).round(), (y * 1e9).round(), (z * 1e9).round()), (1e9, 1e9, 1e9));
+    /// ```
+    pub fn adapt(&self, source: WhitePoint, destination: WhitePoint, method: Method) -> Self {
+        method.adapt(self.clone(), source, destination)
+    }
+
+    /// Attempts to rotate hue by some amount of degrees
+    ///
+    /// **Note** this method uses `LCh::rotate_hue` internally
+    pub fn rotate_hue<T>(&self, amount: T) -> Self
+    where
+        T: Into<f64>
+    {
+        let lch = LCh::from(self.clone());
+        Self::from(lch.rotate_hue(amount))
+    }
+}
+
+
+impl Display for XYZ {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "x: {}, y: {}, z: {}", self.x, self.y, self.z)
+    }
+}
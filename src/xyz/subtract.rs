@@ -0,0 +1,61 @@
+#!/usr/bin/env rust
+
+
+use std::ops::Sub;
+
+
+use crate::color::Color;
+use crate::xyz::XYZ;
+use crate::rgb::RGB;
+
+
+/// Subtracts color components
+///
+/// **Note** this uses `RGB::sub` internally
+impl Sub for XYZ {
+    type Output = Self;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::xyz::XYZ;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let left = XYZ::from(RGB::new(50, 0, 0));
+    /// let right = XYZ::from(RGB::new(25, 0, 0));
+    /// let result = left - right;
+    ///
+    /// let expected = XYZ::from(RGB::new(25, 0, 0));
+    /// assert_eq!(result, expected);
+    /// ```
+    fn sub(self, other: Self) -> Self::Output {
+        let left = RGB::from(self);
+        let right = RGB::from(other);
+        XYZ::from(left - right)
+    }
+}
+
+
+/// Converts right side from `Color` to `XYZ` prior to arithmetic
+impl Sub<Color> for XYZ {
+    type Output = Self;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::xyz::XYZ;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let left = XYZ::from(RGB::new(50, 0, 0));
+    /// let right = Color::new_rgb(25, 0, 0);
+    ///
+    /// let expected = XYZ::from(RGB::new(25, 0, 0));
+    ///
+    /// assert_eq!(left - right, expected);
+    /// ```
+    fn sub(self, other: Color) -> Self::Output {
+        let other: XYZ = other.into();
+        self - other
+    }
+}
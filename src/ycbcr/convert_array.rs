@@ -0,0 +1,50 @@
+#!/usr/bin/env rust
+
+
+use crate::ycbcr::YCbCr;
+
+
+/// Convert from array of 64-bit precision floating point numbers
+impl<T> From<[T; 3]> for YCbCr
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::ycbcr::YCbCr;
+    ///
+    /// let array = [ 0.5, 0.25, -0.25 ];
+    /// let ycbcr = YCbCr::from(array);
+    ///
+    /// assert_eq!(ycbcr.get("y"), Ok(0.5));
+    /// assert_eq!(ycbcr.get("cb"), Ok(0.25));
+    /// assert_eq!(ycbcr.get("cr"), Ok(-0.25));
+    /// ```
+    fn from(array: [T; 3]) -> Self {
+        let y = array[0];
+        let cb = array[1];
+        let cr = array[2];
+        Self::new(y, cb, cr)
+    }
+}
+
+
+/// Convert into array of 64-bit precision floating point numbers
+impl Into<[f64; 3]> for YCbCr {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::ycbcr::YCbCr;
+    ///
+    /// let ycbcr = YCbCr::new(0.5, 0.25, -0.25);
+    /// let array: [f64; 3] = ycbcr.into();
+    ///
+    /// assert_eq!(array[0], 0.5);
+    /// assert_eq!(array[1], 0.25);
+    /// assert_eq!(array[2], -0.25);
+    /// ```
+    fn into(self) -> [f64; 3] {
+        [ self.y, self.cb, self.cr ]
+    }
+}
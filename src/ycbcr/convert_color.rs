@@ -0,0 +1,30 @@
+#!/usr/bin/env rust
+
+
+use crate::ycbcr::YCbCr;
+use crate::color::Color;
+use crate::hsl::HSL;
+use crate::hsv::HSV;
+use crate::rgb::RGB;
+
+
+impl From<Color> for YCbCr {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::CMYK(v) => Self::from(RGB::from(v)),
+            Color::HSL(v) => Self::from(v),
+            Color::HSLA(v) => Self::from(HSL::from(v)),
+            Color::HSV(v) => Self::from(v),
+            Color::HSVA(v) => Self::from(HSV::from(v)),
+            Color::HWB(v) => Self::from(RGB::from(v)),
+            Color::Lab(v) => Self::from(RGB::from(v)),
+            Color::LCh(v) => Self::from(RGB::from(v)),
+            Color::Oklab(v) => Self::from(RGB::from(v)),
+            Color::Oklch(v) => Self::from(RGB::from(v)),
+            Color::RGB(v) => Self::from(v),
+            Color::RGBA(v) => Self::from(RGB::from(v)),
+            Color::XYZ(v) => Self::from(RGB::from(v)),
+            Color::YCbCr(v) => v,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::hsl::HSL;
+use crate::rgb::RGB;
+use crate::ycbcr::YCbCr;
+
+
+/// Convert from `HSL` to `YCbCr`
+///
+/// **Note** uses `RGB::from(hsl)` internally
+impl From<HSL> for YCbCr {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsl::HSL;
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::ycbcr::YCbCr;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let hsl = HSL::from(rgb.clone());
+    /// let ycbcr = YCbCr::from(hsl);
+    ///
+    /// assert_eq!(RGB::from(ycbcr), rgb);
+    /// ```
+    fn from(hsl: HSL) -> Self {
+        Self::from(RGB::from(hsl))
+    }
+}
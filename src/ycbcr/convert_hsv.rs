@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use crate::hsv::HSV;
+use crate::rgb::RGB;
+use crate::ycbcr::YCbCr;
+
+
+/// Convert from `HSV` to `YCbCr`
+///
+/// **Note** uses `RGB::from(hsv)` internally
+impl From<HSV> for YCbCr {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::hsv::HSV;
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::ycbcr::YCbCr;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let hsv = HSV::from(rgb.clone());
+    /// let ycbcr = YCbCr::from(hsv);
+    ///
+    /// assert_eq!(RGB::from(ycbcr), rgb);
+    /// ```
+    fn from(hsv: HSV) -> Self {
+        Self::from(RGB::from(hsv))
+    }
+}
@@ -0,0 +1,99 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+extern crate json;
+use json::JsonValue;
+
+
+use crate::error::{ json_f64, Error };
+use crate::ycbcr::YCbCr;
+
+
+/// Converts from `JsonValue` key value pares, or error for missing or invalid components
+impl TryFrom<&JsonValue> for YCbCr {
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::ycbcr::YCbCr;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{ "y": "nope" }"#).unwrap();
+    ///
+    /// assert_eq!(YCbCr::try_from(&data), Err(Error::InvalidComponent("y".to_string())));
+    /// ```
+    fn try_from(data: &JsonValue) -> Result<Self, Self::Error> {
+        let y = json_f64(data, "y")?;
+        let cb = json_f64(data, "cb")?;
+        let cr = json_f64(data, "cr")?;
+        Ok(Self::new(y, cb, cr))
+    }
+}
+
+
+/// Converts from `JsonValue` key value pares
+///
+/// **Note** missing or invalid components default individually, where `YCbCr::try_from` returns
+/// error
+impl From<JsonValue> for YCbCr {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::ycbcr::YCbCr;
+    /// extern crate json;
+    ///
+    /// let data = json::parse(r#"{
+    ///     "y": 0.5,
+    ///     "cb": 0.25,
+    ///     "cr": -0.25
+    /// }"#).unwrap();
+    ///
+    /// let ycbcr = YCbCr::from(data);
+    ///
+    /// assert_eq!(ycbcr.get("y"), Ok(0.5));
+    /// assert_eq!(ycbcr.get("cb"), Ok(0.25));
+    /// assert_eq!(ycbcr.get("cr"), Ok(-0.25));
+    /// ```
+    fn from(data: JsonValue) -> Self {
+        let y = data["y"].as_f64().unwrap_or_default();
+        let cb = data["cb"].as_f64().unwrap_or_default();
+        let cr = data["cr"].as_f64().unwrap_or_default();
+        Self::new(y, cb, cr)
+    }
+}
+
+
+/// Converts to `JsonValue`
+impl Into<JsonValue> for YCbCr {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::ycbcr::YCbCr;
+    /// extern crate json;
+    /// use json::JsonValue;
+    ///
+    /// let ycbcr = YCbCr::new(0.5, 0.25, -0.25);
+    /// let data: JsonValue = ycbcr.into();
+    ///
+    /// let expected = json::parse(r#"{
+    ///     "y": 0.5,
+    ///     "cb": 0.25,
+    ///     "cr": -0.25
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(data, expected);
+    /// ```
+    fn into(self) -> JsonValue {
+        json::object!{
+            "y" => self.y,
+            "cb" => self.cb,
+            "cr" => self.cr,
+        }
+    }
+}
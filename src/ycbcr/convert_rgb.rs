@@ -0,0 +1,25 @@
+#!/usr/bin/env rust
+
+
+use crate::rgb::RGB;
+use crate::ycbcr::{ Matrix, YCbCr };
+
+
+/// Convert from `RGB` to `YCbCr`
+///
+/// **Note** assumes `Matrix::BT601`, use `YCbCr::from_rgb` for other matrices
+impl From<RGB> for YCbCr {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::ycbcr::{ BitDepth, Range, YCbCr };
+    ///
+    /// let ycbcr = YCbCr::from(RGB::new(255, 0, 0));
+    ///
+    /// assert_eq!(ycbcr.to_digital(Range::Full, BitDepth::Eight), (76, 85, 255));
+    /// ```
+    fn from(rgb: RGB) -> Self {
+        Self::from_rgb(rgb, Matrix::BT601)
+    }
+}
@@ -0,0 +1,48 @@
+#!/usr/bin/env rust
+
+
+use crate::ycbcr::YCbCr;
+
+
+/// Convert from tuple of 64-bit precision floating point numbers
+impl<T> From<(T, T, T)> for YCbCr
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::ycbcr::YCbCr;
+    ///
+    /// let tuple = ( 0.5, 0.25, -0.25 );
+    /// let ycbcr = YCbCr::from(tuple);
+    ///
+    /// assert_eq!(ycbcr.get("y"), Ok(0.5));
+    /// assert_eq!(ycbcr.get("cb"), Ok(0.25));
+    /// assert_eq!(ycbcr.get("cr"), Ok(-0.25));
+    /// ```
+    fn from(tuple: (T, T, T)) -> Self {
+        let ( y, cb, cr ) = tuple;
+        Self::new(y, cb, cr)
+    }
+}
+
+
+/// Convert into tuple of 64-bit precision floating point numbers
+impl Into<(f64, f64, f64)> for YCbCr {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::ycbcr::YCbCr;
+    ///
+    /// let ycbcr = YCbCr::new(0.5, 0.25, -0.25);
+    /// let ( y, cb, cr ) = ycbcr.into();
+    ///
+    /// assert_eq!(y, 0.5);
+    /// assert_eq!(cb, 0.25);
+    /// assert_eq!(cr, -0.25);
+    /// ```
+    fn into(self) -> (f64, f64, f64) {
+        ( self.y, self.cb, self.cr )
+    }
+}
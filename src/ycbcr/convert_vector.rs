@@ -0,0 +1,85 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+use crate::error::{ check_length, Error };
+use crate::ycbcr::YCbCr;
+
+
+/// Convert from slice of 64-bit precision floating point numbers, or error for invalid length
+impl<T> TryFrom<&[T]> for YCbCr
+where
+    T: Into<f64> + Copy
+{
+    type Error = Error;
+
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use color_operators::error::Error;
+    /// use color_operators::ycbcr::YCbCr;
+    ///
+    /// let ycbcr = YCbCr::try_from(&[0.5, 0.25, -0.25][..]).unwrap();
+    /// assert_eq!(ycbcr, YCbCr::from(vec![0.5, 0.25, -0.25]));
+    ///
+    /// let result = YCbCr::try_from(&[0.5, 0.25][..]);
+    /// assert_eq!(result, Err(Error::Length { expected: 3, found: 2 }));
+    /// ```
+    fn try_from(values: &[T]) -> Result<Self, Self::Error> {
+        check_length(values.len(), 3, 3)?;
+        let y = values[0].into();
+        let cb = values[1].into();
+        let cr = values[2].into();
+        Ok(Self::new(y, cb, cr))
+    }
+}
+
+
+/// Convert from vector of 64-bit precision floating point numbers
+///
+/// **Note** this uses `YCbCr::try_from` internally, values past the third are ignored, and too few
+/// values return default values
+impl<T> From<Vec<T>> for YCbCr
+where
+    T: Into<f64> + Copy
+{
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::ycbcr::YCbCr;
+    ///
+    /// let vector = vec![0.5, 0.25, -0.25];
+    /// let ycbcr = YCbCr::from(vector);
+    ///
+    /// assert_eq!(ycbcr.get("y"), Ok(0.5));
+    /// assert_eq!(ycbcr.get("cb"), Ok(0.25));
+    /// assert_eq!(ycbcr.get("cr"), Ok(-0.25));
+    /// ```
+    fn from(vector: Vec<T>) -> Self {
+        let length = vector.len().min(3);
+        Self::try_from(&vector[..length]).unwrap_or_default()
+    }
+}
+
+
+/// Convert into vector of 64-bit precision floating point numbers
+impl Into<Vec<f64>> for YCbCr {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::ycbcr::YCbCr;
+    ///
+    /// let ycbcr = YCbCr::new(0.5, 0.25, -0.25);
+    /// let vector: Vec<f64> = ycbcr.into();
+    ///
+    /// assert_eq!(vector[0], 0.5);
+    /// assert_eq!(vector[1], 0.25);
+    /// assert_eq!(vector[2], -0.25);
+    /// ```
+    fn into(self) -> Vec<f64> {
+        vec![ self.y, self.cb, self.cr ]
+    }
+}
@@ -0,0 +1,54 @@
+#!/usr/bin/env rust
+
+
+use crate::color::Color;
+use crate::color::equality::alpha_eq;
+use crate::ycbcr::YCbCr;
+
+
+impl Eq for YCbCr {}
+impl PartialEq for YCbCr {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::ycbcr::YCbCr;
+    ///
+    /// let left = YCbCr::new(0.5, 0.25, -0.25);
+    /// let right = YCbCr::new(0.5, 0.25, -0.25);
+    ///
+    /// assert_eq!(left, right);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        if self.y != other.y || self.cb != other.cb || self.cr != other.cr {
+            return false;
+        }
+        true
+    }
+}
+
+
+/// Converts right side from `Color` to `YCbCr` prior to checking equality
+///
+/// **Note** colors with alpha are compared as `RGBA`, so are only equal when fully opaque
+impl PartialEq<Color> for YCbCr {
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::color::Color;
+    /// use color_operators::ycbcr::YCbCr;
+    /// use color_operators::rgb::RGB;
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let ycbcr = YCbCr::from(rgb.clone());
+    ///
+    /// assert!(ycbcr == Color::from(rgb));
+    /// ```
+    fn eq(&self, other: &Color) -> bool {
+        if let Some(equal) = alpha_eq(&Color::from(self.clone()), other) {
+            return equal;
+        }
+
+        let other: YCbCr = other.clone().into();
+        self == &other
+    }
+}
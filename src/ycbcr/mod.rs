@@ -0,0 +1,452 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+
+use crate::error::Error;
+use crate::rgb::RGB;
+
+
+/// Adds color components for `YCbCr` data structures
+mod add;
+
+/// Subtracts color components for `YCbCr` data structures
+mod subtract;
+
+/// Equal and not-equal checks for `YCbCr` data structures
+mod equality;
+
+/// Converts from/to array for `YCbCr` data structures
+mod convert_array;
+
+/// Converts from/to `Color` enum
+mod convert_color;
+
+/// Converts from `HSL` to `YCbCr` data structure
+mod convert_hsl;
+
+/// Converts from `HSV` to `YCbCr` data structure
+mod convert_hsv;
+
+/// Converts from/to `JsonValue` for `YCbCr` data structures
+mod convert_json_value;
+
+/// Converts from `RGB` to `YCbCr` data structure
+mod convert_rgb;
+
+/// Converts from/to tuple for `YCbCr` data structures
+mod convert_tuple;
+
+/// Converts from/to vector for `YCbCr` data structures
+mod convert_vector;
+
+
+/// Data structure for luma and blue/red difference chroma encoded colors, a.k.a. YUV
+///
+/// **Note** values are analog, i.e. luma between `0.0` and `1.0` and chroma between `-0.5` and
+/// `0.5`, use `YCbCr::to_digital` and `YCbCr::from_digital` for integer code values
+///
+/// **Note** `From` conversions assume `Matrix::BT601`, use `YCbCr::from_rgb` and `YCbCr::to_rgb`
+/// for other matrices
+#[derive(Clone, Debug, Default)]
+pub struct YCbCr {
+    y: f64,
+    cb: f64,
+    cr: f64,
+}
+
+
+/// Luma coefficients of supported ITU-R recommendations
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Matrix {
+    /// Standard definition television, also used by JPEG/JFIF
+    #[default]
+    BT601,
+
+    /// High definition television
+    BT709,
+
+    /// Ultra high definition television, non-constant luminance
+    BT2020,
+}
+
+
+/// Range of integer code values used by digital encodings
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Range {
+    /// Every code value is used, e.g. `0` through `255` for 8-bit
+    #[default]
+    Full,
+
+    /// Studio swing, e.g. `16` through `235` for 8-bit luma and `16` through `240` for chroma
+    Limited,
+}
+
+
+/// Number of bits per component of digital encodings
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BitDepth {
+    /// Code values between `0` and `255`
+    #[default]
+    Eight,
+
+    /// Code values between `0` and `1023`
+    Ten,
+}
+
+
+impl YCbCr {
+    /// Returns new instance of `YCbCr` data structure
+    ///
+    /// **Note** luma is clamped between `0.0` and `1.0`, and chroma between `-0.5` and `0.5`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::ycbcr::YCbCr;
+    ///
+    /// let color = YCbCr::new(0.5, 0.25, -0.25);
+    ///
+    /// assert_eq!(color.get("y"), Ok(0.5));
+    /// assert_eq!(color.get("cb"), Ok(0.25));
+    /// assert_eq!(color.get("cr"), Ok(-0.25));
+    /// ```
+    pub fn new<T>(y: T, cb: T, cr: T) -> Self
+    where
+        T: Into<f64>
+    {
+        let y: f64 = y.into().min(1.0).max(0.0);
+        let cb: f64 = cb.into().min(0.5).max(-0.5);
+        let cr: f64 = cr.into().min(0.5).max(-0.5);
+        Self { y, cb, cr }
+    }
+
+    /// Returns named component value or error
+    ///
+    /// ```rust
+    /// use color_operators::ycbcr::YCbCr;
+    ///
+    /// let color = YCbCr::new(0.5, 0.25, -0.25);
+    ///
+    /// assert_eq!(color.get("nothing"), Err("No color component named -> nothing".to_string()));
+    /// ```
+    pub fn get<S>(&self, component: S) -> Result<f64, String>
+    where
+        S: Into<String>
+    {
+        let component: String = component.into();
+        match component.as_str() {
+            "y" => Ok(self.y),
+            "cb" => Ok(self.cb),
+            "cr" => Ok(self.cr),
+            _ => Err(format!("No color component named -> {}", component)),
+        }
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or default values
+    ///
+    /// **Note** missing or invalid components default individually, see `From<JsonValue>`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::ycbcr::YCbCr;
+    ///
+    /// let ycbcr = YCbCr::from_json_string(r#"{ "y": 0.5, "cb": 0.25, "cr": -0.25 }"#);
+    ///
+    /// assert_eq!(ycbcr.get("y"), Ok(0.5));
+    /// assert_eq!(ycbcr.get("cb"), Ok(0.25));
+    /// assert_eq!(ycbcr.get("cr"), Ok(-0.25));
+    /// ```
+    pub fn from_json_string<S>(string: S) -> Self
+    where
+        S: Into<String>
+    {
+        json::parse(&string.into()).map(Self::from).unwrap_or_default()
+    }
+
+    /// Returns parsed JSON string for color key/value pares, or error for invalid JSON and missing
+    /// or invalid components
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::error::Error;
+    /// use color_operators::ycbcr::YCbCr;
+    ///
+    /// let result = YCbCr::try_from_json_str(r#"{ "y": 1 }"#);
+    ///
+    /// assert_eq!(result, Err(Error::MissingComponent("cb".to_string())));
+    /// ```
+    pub fn try_from_json_str(string: &str) -> Result<Self, Error> {
+        let data = json::parse(string)?;
+        Self::try_from(&data)
+    }
+
+    /// Serializes data structure as JSON string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::ycbcr::YCbCr;
+    ///
+    /// let ycbcr = YCbCr::new(0.5, 0.25, -0.25);
+    /// let data = ycbcr.to_json_string();
+    ///
+    /// let object = json::object!{
+    ///     "y" => 0.5,
+    ///     "cb" => 0.25,
+    ///     "cr" => -0.25
+    /// };
+    ///
+    /// let expected = json::stringify(object);
+    ///
+    /// assert_eq!(data, expected);
+    /// ```
+    pub fn to_json_string(&self) -> String {
+        json::stringify(self.clone())
+    }
+
+    /// Converts hexadecimal string into `YCbCr`
+    ///
+    /// **Note** this method uses `RGB::from_hex_string` internally, which returns default values
+    /// for invalid input
+    pub fn from_hex_string<S>(input: S) -> Self
+    where
+        S: Into<String>
+    {
+        Self::from(RGB::from_hex_string(input))
+    }
+
+    /// Converts hexadecimal string into `YCbCr`, or returns error for invalid input
+    ///
+    /// **Note** this method uses `RGB::try_from_hex_string` internally
+    pub fn try_from_hex_string<S>(input: S) -> Result<Self, Error>
+    where
+        S: Into<String>
+    {
+        RGB::try_from_hex_string(input).map(Self::from)
+    }
+
+    /// Returns hexadecimal string representation of `YCbCr` values
+    ///
+    /// **Note** this method uses `RGB::to_hex_string` internally
+    pub fn to_hex_string(&self) -> String {
+        RGB::from(self.clone()).to_hex_string()
+    }
+
+    /// Returns `YCbCr` from gamma encoded `RGB` values using luma coefficients of given matrix
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::ycbcr::{ Matrix, YCbCr };
+    ///
+    /// let ycbcr = YCbCr::from_rgb(RGB::new(255, 255, 255), Matrix::BT709);
+    ///
+    /// assert_eq!(ycbcr, YCbCr::new(1.0, 0.0, 0.0));
+    /// ```
+    pub fn from_rgb(rgb: RGB, matrix: Matrix) -> Self {
+        let ( red, green, blue ) = rgb.into();
+        let ( kr, kb ) = matrix.coefficients();
+        let kg = 1.0 - kr - kb;
+
+        let red = red as f64 / 255.0;
+        let green = green as f64 / 255.0;
+        let blue = blue as f64 / 255.0;
+
+        let y = kr * red + kg * green + kb * blue;
+        let cb = (blue - y) / (2.0 * (1.0 - kb));
+        let cr = (red - y) / (2.0 * (1.0 - kr));
+
+        Self::new(y, cb, cr)
+    }
+
+    /// Returns gamma encoded `RGB` values using luma coefficients of given matrix
+    ///
+    /// **Note** out of gamut values are clamped
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::ycbcr::{ Matrix, YCbCr };
+    ///
+    /// let rgb = RGB::new(255, 42, 90);
+    /// let ycbcr = YCbCr::from_rgb(rgb.clone(), Matrix::BT2020);
+    ///
+    /// assert_eq!(ycbcr.to_rgb(Matrix::BT2020), rgb);
+    /// ```
+    pub fn to_rgb(&self, matrix: Matrix) -> RGB {
+        let ( kr, kb ) = matrix.coefficients();
+        let kg = 1.0 - kr - kb;
+
+        let red = self.y + 2.0 * (1.0 - kr) * self.cr;
+        let blue = self.y + 2.0 * (1.0 - kb) * self.cb;
+        let green = (self.y - kr * red - kb * blue) / kg;
+
+        let encode = |v: f64| -> u8 {
+            (v.min(1.0).max(0.0) * 255.0).round() as u8
+        };
+
+        RGB::new(encode(red), encode(green), encode(blue))
+    }
+
+    /// Returns integer code values of luma, blue difference, and red difference for given range
+    /// and bit depth
+    ///
+    /// **Note** rounds half away from zero as reference formulas of ITU-R BT.601, BT.709, and
+    /// BT.2020 do, and clamps to code values available at given bit depth
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::ycbcr::{ BitDepth, Matrix, Range, YCbCr };
+    ///
+    /// let ycbcr = YCbCr::from_rgb(RGB::new(255, 0, 0), Matrix::BT709);
+    ///
+    /// assert_eq!(ycbcr.to_digital(Range::Limited, BitDepth::Eight), (63, 102, 240));
+    /// assert_eq!(ycbcr.to_digital(Range::Limited, BitDepth::Ten), (250, 409, 960));
+    /// ```
+    pub fn to_digital(&self, range: Range, bit_depth: BitDepth) -> (u16, u16, u16) {
+        let max = bit_depth.max_code() as f64;
+        let scale = bit_depth.scale();
+
+        let quantize = |v: f64| -> u16 {
+            v.round().min(max).max(0.0) as u16
+        };
+
+        match range {
+            Range::Full => (
+                quantize(max * self.y),
+                quantize(max * self.cb + 128.0 * scale),
+                quantize(max * self.cr + 128.0 * scale),
+            ),
+            Range::Limited => (
+                quantize((219.0 * self.y + 16.0) * scale),
+                quantize((224.0 * self.cb + 128.0) * scale),
+                quantize((224.0 * self.cr + 128.0) * scale),
+            ),
+        }
+    }
+
+    /// Returns `YCbCr` from integer code values of luma, blue difference, and red difference for
+    /// given range and bit depth
+    ///
+    /// **Note** code values outside of nominal range, e.g. footroom and headroom of limited range,
+    /// are clamped
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::rgb::RGB;
+    /// use color_operators::ycbcr::{ BitDepth, Matrix, Range, YCbCr };
+    ///
+    /// let white = YCbCr::from_digital(235, 128, 128, Range::Limited, BitDepth::Eight);
+    /// assert_eq!(white.to_rgb(Matrix::BT709), RGB::new(255, 255, 255));
+    ///
+    /// let black = YCbCr::from_digital(64, 512, 512, Range::Limited, BitDepth::Ten);
+    /// assert_eq!(black.to_rgb(Matrix::BT709), RGB::new(0, 0, 0));
+    /// ```
+    pub fn from_digital(y: u16, cb: u16, cr: u16, range: Range, bit_depth: BitDepth) -> Self {
+        let y = y as f64;
+        let cb = cb as f64;
+        let cr = cr as f64;
+
+        let max = bit_depth.max_code() as f64;
+        let scale = bit_depth.scale();
+
+        match range {
+            Range::Full => Self::new(
+                y / max,
+                (cb - 128.0 * scale) / max,
+                (cr - 128.0 * scale) / max,
+            ),
+            Range::Limited => Self::new(
+                (y / scale - 16.0) / 219.0,
+                (cb / scale - 128.0) / 224.0,
+                (cr / scale - 128.0) / 224.0,
+            ),
+        }
+    }
+
+    /// Attempts to rotate hue by some amount of degrees
+    ///
+    /// **Note** this method uses `RGB::rotate_hue` and `Matrix::BT601` internally
+    pub fn rotate_hue<T>(&self, amount: T) -> Self
+    where
+        T: Into<f64>
+    {
+        let rgb = RGB::from(self.clone());
+        Self::from(rgb.rotate_hue(amount))
+    }
+}
+
+
+impl Matrix {
+    /// Returns red and blue luma coefficients, green being the remainder of `1.0`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::ycbcr::Matrix;
+    ///
+    /// assert_eq!(Matrix::BT709.coefficients(), (0.2126, 0.0722));
+    /// ```
+    pub fn coefficients(&self) -> (f64, f64) {
+        match self {
+            Self::BT601 => (0.299, 0.114),
+            Self::BT709 => (0.2126, 0.0722),
+            Self::BT2020 => (0.2627, 0.0593),
+        }
+    }
+}
+
+
+impl BitDepth {
+    /// Returns number of bits per component
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::ycbcr::BitDepth;
+    ///
+    /// assert_eq!(BitDepth::Ten.bits(), 10);
+    /// ```
+    pub fn bits(&self) -> u32 {
+        match self {
+            Self::Eight => 8,
+            Self::Ten => 10,
+        }
+    }
+
+    /// Returns largest code value, e.g. `255` for 8-bit
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color_operators::ycbcr::BitDepth;
+    ///
+    /// assert_eq!(BitDepth::Ten.max_code(), 1023);
+    /// ```
+    pub fn max_code(&self) -> u16 {
+        (1 << self.bits()) - 1
+    }
+
+    /// Multiplier from 8-bit code values to code values of this bit depth
+    fn scale(&self) -> f64 {
+        (1 << (self.bits() - 8)) as f64
+    }
+}
+
+
+impl Display for YCbCr {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "y: {}, cb: {}, cr: {}", self.y, self.cb, self.cr)
+    }
+}
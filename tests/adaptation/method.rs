@@ -0,0 +1,73 @@
+#!/usr/bin/env rust
+
+
+use color_operators::adaptation::Method;
+use color_operators::rgb::RGB;
+use color_operators::white_point::WhitePoint;
+use color_operators::xyz::XYZ;
+
+
+const METHODS: [Method; 5] = [
+    Method::Bradford,
+    Method::VonKries,
+    Method::XyzScaling,
+    Method::CAT02,
+    Method::CAT16,
+];
+
+
+const WHITE_POINTS: [WhitePoint; 11] = [
+    WhitePoint::A,
+    WhitePoint::C,
+    WhitePoint::D50,
+    WhitePoint::D55,
+    WhitePoint::D60,
+    WhitePoint::D65,
+    WhitePoint::D75,
+    WhitePoint::E,
+    WhitePoint::F2,
+    WhitePoint::F7,
+    WhitePoint::F11,
+];
+
+
+fn close(left: XYZ, right: XYZ) -> bool {
+    let ( lx, ly, lz ) = left.into();
+    let ( rx, ry, rz ) = right.into();
+    (lx - rx).abs() < 1e-9 && (ly - ry).abs() < 1e-9 && (lz - rz).abs() < 1e-9
+}
+
+
+#[test]
+fn source_white_maps_to_destination_white() {
+    for method in METHODS.iter() {
+        for source in WHITE_POINTS.iter() {
+            let ( x, y, z ) = source.tristimulus();
+            let ( dx, dy, dz ) = WhitePoint::D65.tristimulus();
+            let adapted = method.adapt(XYZ::new(x, y, z), *source, WhitePoint::D65);
+            assert!(close(adapted, XYZ::new(dx, dy, dz)), "{:?} from {:?}", method, source);
+        }
+    }
+}
+
+
+#[test]
+fn round_trip_between_white_points() {
+    let xyz = XYZ::from(RGB::new(255, 42, 90));
+    for method in METHODS.iter() {
+        for white_point in WHITE_POINTS.iter() {
+            let there = xyz.adapt(WhitePoint::D65, *white_point, *method);
+            let back = there.adapt(*white_point, WhitePoint::D65, *method);
+            assert!(close(back, xyz.clone()), "{:?} via {:?}", method, white_point);
+        }
+    }
+}
+
+
+#[test]
+fn rgb_round_trip_through_d50() {
+    for rgb in [ RGB::new(255, 42, 90), RGB::new(12, 200, 64), RGB::new(128, 128, 128) ].iter() {
+        let lab = rgb.to_lab(WhitePoint::D50, Method::Bradford);
+        assert_eq!(&RGB::from_lab(lab, WhitePoint::D50, Method::Bradford), rgb);
+    }
+}
@@ -0,0 +1,46 @@
+#!/usr/bin/env rust
+
+
+use color_operators::blend::BlendMode;
+use color_operators::hsla::HSLA;
+use color_operators::rgba::RGBA;
+
+
+#[test]
+fn translucent_source_over_opaque_backdrop() {
+    let backdrop = RGBA::new(255, 128, 0, 255);
+    let source = RGBA::new(128, 128, 128, 51);
+
+    // Multiply gives 128, 64, 0, then 0.2 * blended + 0.8 * backdrop
+    assert_eq!(backdrop.blend(source, BlendMode::Multiply), RGBA::new(230, 115, 0, 255));
+}
+
+
+#[test]
+fn translucent_source_over_translucent_backdrop() {
+    let backdrop = RGBA::new(255, 0, 0, 102);
+    let source = RGBA::new(0, 0, 255, 153);
+
+    // Alpha 0.6 + 0.4 * 0.4 = 0.76, red 0.4 * 0.4 / 0.76, blue 0.6 / 0.76
+    assert_eq!(backdrop.blend(source.clone(), BlendMode::Normal), RGBA::new(54, 0, 201, 194));
+
+    // Source is blended with black where backdrop is present, blue 0.6 * 0.6 / 0.76
+    assert_eq!(backdrop.blend(source, BlendMode::Multiply), RGBA::new(54, 0, 121, 194));
+}
+
+
+#[test]
+fn transparent_source_keeps_backdrop() {
+    let backdrop = RGBA::new(255, 42, 90, 128);
+    let source = RGBA::new(0, 0, 0, 0);
+    assert_eq!(backdrop.blend(source, BlendMode::Difference), backdrop);
+}
+
+
+#[test]
+fn hsla_alpha_is_composited() {
+    let backdrop = HSLA::new(30.0, 1.0, 0.5, 0.5);
+    let source = HSLA::new(210.0, 1.0, 0.5, 0.5);
+    let result = backdrop.blend(source, BlendMode::Normal);
+    assert_eq!(result.get("alpha"), Ok(0.75));
+}
@@ -0,0 +1,49 @@
+#!/usr/bin/env rust
+
+
+use color_operators::blend::BlendMode;
+use color_operators::color::Color;
+use color_operators::hsl::HSL;
+use color_operators::rgb::RGB;
+
+
+#[test]
+fn color_onto_gray() {
+    let result = BlendMode::Color.apply(RGB::new(128, 128, 128), RGB::new(255, 0, 0));
+    assert_eq!(result, RGB::new(255, 74, 74));
+}
+
+
+#[test]
+fn hue_keeps_gray_backdrop() {
+    let gray = RGB::new(128, 128, 128);
+    assert_eq!(BlendMode::Hue.apply(gray.clone(), RGB::new(255, 0, 0)), gray);
+}
+
+
+#[test]
+fn luminosity_extremes() {
+    let red = RGB::new(255, 0, 0);
+    assert_eq!(BlendMode::Luminosity.apply(red.clone(), RGB::new(255, 255, 255)), RGB::new(255, 255, 255));
+    assert_eq!(BlendMode::Luminosity.apply(red, RGB::new(0, 0, 0)), RGB::new(0, 0, 0));
+}
+
+
+#[test]
+fn saturation_of_gray_desaturates() {
+    let result = BlendMode::Saturation.apply(RGB::new(0, 255, 0), RGB::new(10, 10, 10));
+    let ( red, green, blue ) = result.into();
+    assert_eq!(red, green);
+    assert_eq!(green, blue);
+}
+
+
+#[test]
+fn hsl_and_color_keep_type() {
+    let backdrop = HSL::from(RGB::new(128, 128, 128));
+    let source = HSL::from(RGB::new(255, 0, 0));
+    assert_eq!(RGB::from(backdrop.blend(source, BlendMode::Color)), RGB::new(255, 74, 74));
+
+    let result = Color::new_hsv(0.0, 0.0, 0.5).blend(Color::new_rgb(255, 0, 0), BlendMode::Color);
+    assert!(result.is_hsv());
+}
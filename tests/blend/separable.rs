@@ -0,0 +1,61 @@
+#!/usr/bin/env rust
+
+
+use color_operators::blend::BlendMode;
+use color_operators::rgb::RGB;
+
+
+fn backdrop() -> RGB {
+    RGB::new(255, 42, 90)
+}
+
+
+#[test]
+fn multiply_with_white_is_identity() {
+    assert_eq!(BlendMode::Multiply.apply(backdrop(), RGB::new(255, 255, 255)), backdrop());
+}
+
+
+#[test]
+fn screen_with_black_is_identity() {
+    assert_eq!(BlendMode::Screen.apply(backdrop(), RGB::new(0, 0, 0)), backdrop());
+}
+
+
+#[test]
+fn difference_with_self_is_black() {
+    assert_eq!(BlendMode::Difference.apply(backdrop(), backdrop()), RGB::new(0, 0, 0));
+}
+
+
+#[test]
+fn exclusion_with_white_inverts() {
+    assert_eq!(BlendMode::Exclusion.apply(backdrop(), RGB::new(255, 255, 255)), RGB::new(0, 213, 165));
+}
+
+
+#[test]
+fn dodge_and_burn_identities() {
+    assert_eq!(BlendMode::ColorDodge.apply(backdrop(), RGB::new(0, 0, 0)), backdrop());
+    assert_eq!(BlendMode::ColorBurn.apply(backdrop(), RGB::new(255, 255, 255)), backdrop());
+}
+
+
+#[test]
+fn hard_light_extremes() {
+    assert_eq!(BlendMode::HardLight.apply(backdrop(), RGB::new(255, 255, 255)), RGB::new(255, 255, 255));
+    assert_eq!(BlendMode::HardLight.apply(backdrop(), RGB::new(0, 0, 0)), RGB::new(0, 0, 0));
+}
+
+
+#[test]
+fn overlay_swaps_hard_light() {
+    let source = RGB::new(20, 200, 128);
+    assert_eq!(BlendMode::Overlay.apply(backdrop(), source.clone()), BlendMode::HardLight.apply(source, backdrop()));
+}
+
+
+#[test]
+fn soft_light_with_black_darkens() {
+    assert_eq!(BlendMode::SoftLight.apply(RGB::new(128, 128, 128), RGB::new(0, 0, 0)), RGB::new(64, 64, 64));
+}
@@ -0,0 +1,61 @@
+#!/usr/bin/env rust
+
+
+use color_operators::cmyk::{ BlackGeneration, CMYK, Separation };
+use color_operators::rgb::RGB;
+
+
+#[test]
+fn naive_red() {
+    let cmyk = CMYK::from(RGB::new(255, 0, 0));
+    assert_eq!(cmyk, CMYK::new(0.0, 1.0, 1.0, 0.0));
+}
+
+
+#[test]
+fn naive_black() {
+    let cmyk = CMYK::from(RGB::new(0, 0, 0));
+    assert_eq!(cmyk, CMYK::new(0.0, 0.0, 0.0, 1.0));
+}
+
+
+#[test]
+fn under_color_removal_skips_highlights() {
+    let rgb = RGB::new(204, 153, 102);
+    let cmyk = CMYK::from_rgb(rgb, Separation::under_color_removal(3.0));
+    assert_eq!(cmyk.get("key"), Ok(0.0));
+}
+
+
+#[test]
+fn under_color_removal_replaces_shadows() {
+    let rgb = RGB::new(51, 51, 51);
+    let cmyk = CMYK::from_rgb(rgb, Separation::under_color_removal(3.0));
+    let key = cmyk.get("key").unwrap();
+    assert!(key > 0.0 && key < 0.8);
+}
+
+
+#[test]
+fn gray_component_replacement() {
+    let rgb = RGB::new(102, 102, 102);
+    let cmyk = CMYK::from_rgb(rgb, Separation::gray_component_replacement(0.5, 4.0));
+    assert_eq!(cmyk.get("key"), Ok(0.3));
+}
+
+
+#[test]
+fn total_ink_limit() {
+    let rgb = RGB::new(20, 10, 5);
+    let separation = Separation::new(BlackGeneration::new(0.0, 0.25, 2.0), 2.4);
+    let cmyk = CMYK::from_rgb(rgb, separation);
+    assert!(cmyk.total_ink() <= 2.4 + 1e-9);
+}
+
+
+#[test]
+fn round_trip() {
+    let rgb = RGB::new(255, 42, 90);
+    let cmyk = CMYK::from(rgb.clone());
+    assert_eq!(RGB::from(cmyk), rgb);
+}
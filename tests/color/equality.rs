@@ -0,0 +1,49 @@
+#!/usr/bin/env rust
+
+
+use color_operators::color::Color;
+use color_operators::hsl::HSL;
+use color_operators::rgb::RGB;
+
+
+#[test]
+fn transparent_is_not_opaque() {
+    let a = Color::new_rgb(1, 2, 3);
+    let b = Color::new_rgba(1, 2, 3, 0);
+    assert!(a != b);
+    assert!(b != a);
+}
+
+
+#[test]
+fn opaque_equals_missing_alpha() {
+    let a = Color::new_rgb(1, 2, 3);
+    let b = Color::new_rgba(1, 2, 3, 255);
+    assert!(a == b);
+    assert!(b == a);
+}
+
+
+#[test]
+fn translucent_across_types() {
+    let a = Color::new_rgba(255, 0, 0, 128);
+    let b = Color::new_hsla(0.0, 1.0, 0.5, 128.0 / 255.0);
+    assert!(a == b);
+    assert!(b == a);
+
+    let c = Color::new_hsl(0.0, 1.0, 0.5);
+    assert!(a != c);
+    assert!(c != a);
+}
+
+
+#[test]
+fn typed_matches_color() {
+    let rgb = RGB::new(1, 2, 3);
+    let hsl = HSL::from(rgb.clone());
+    let transparent = Color::new_rgba(1, 2, 3, 0);
+    assert!(rgb != transparent);
+    assert!(transparent != rgb);
+    assert!(hsl != transparent);
+    assert!(transparent != hsl);
+}
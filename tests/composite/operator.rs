@@ -0,0 +1,90 @@
+#!/usr/bin/env rust
+
+
+use color_operators::color::Color;
+use color_operators::composite::{ AlphaMode, Compositor, Operator, Space };
+use color_operators::rgba::RGBA;
+
+
+#[test]
+fn clear() {
+    let result = Operator::Clear.composite(RGBA::new(255, 0, 0, 255), RGBA::new(0, 0, 255, 255));
+    assert_eq!(result, RGBA::new(0, 0, 0, 0));
+}
+
+
+#[test]
+fn source_over_transparent() {
+    let source = RGBA::new(255, 42, 90, 255);
+    let destination = RGBA::new(0, 0, 0, 0);
+    assert_eq!(Operator::SourceOver.composite(source.clone(), destination), source);
+}
+
+
+#[test]
+fn destination_over_is_reversed_source_over() {
+    let source = RGBA::new(255, 0, 0, 128);
+    let destination = RGBA::new(0, 0, 255, 64);
+    let forward = Operator::SourceOver.composite(destination.clone(), source.clone());
+    let reverse = Operator::DestinationOver.composite(source, destination);
+    assert_eq!(forward, reverse);
+}
+
+
+#[test]
+fn source_in_and_out() {
+    let source = RGBA::new(255, 0, 0, 255);
+    let destination = RGBA::new(0, 0, 255, 51);
+    assert_eq!(Operator::SourceIn.composite(source.clone(), destination.clone()), RGBA::new(255, 0, 0, 51));
+    assert_eq!(Operator::SourceOut.composite(source, destination), RGBA::new(255, 0, 0, 204));
+}
+
+
+#[test]
+fn atop_keeps_destination_alpha() {
+    let source = RGBA::new(255, 0, 0, 128);
+    let destination = RGBA::new(0, 0, 255, 51);
+    let result = Operator::SourceAtop.composite(source, destination);
+    assert_eq!(result.get("alpha"), Ok(51));
+}
+
+
+#[test]
+fn plus_clamps() {
+    let compositor = Compositor::legacy();
+    let result = compositor.composite(Operator::Plus, RGBA::new(200, 0, 0, 255), RGBA::new(100, 0, 0, 255));
+    assert_eq!(result, RGBA::new(255, 0, 0, 255));
+}
+
+
+#[test]
+fn premultiplied_matches_straight() {
+    let straight = Compositor::new(AlphaMode::Straight, Space::Gamma);
+    let premultiplied = Compositor::new(AlphaMode::Premultiplied, Space::Gamma);
+
+    // Red at alpha 0.6 and blue at alpha 0.4, premultiplied channels are 153 and 102
+    let source = RGBA::new(153, 0, 0, 153);
+    let destination = RGBA::new(0, 0, 102, 102);
+
+    // Xor: alpha 0.6 * 0.6 + 0.4 * 0.4 = 0.52, red 153 * 0.6 = 91.8, blue 102 * 0.4 = 40.8
+    let xor = premultiplied.composite(Operator::Xor, source.clone(), destination.clone());
+    assert_eq!(xor, RGBA::new(92, 0, 41, 133));
+
+    // Source over: alpha 0.6 + 0.4 * 0.4 = 0.76, red 153, blue 102 * 0.4 = 40.8
+    let over = premultiplied.composite(Operator::SourceOver, source, destination);
+    assert_eq!(over, RGBA::new(153, 0, 41, 194));
+
+    // Straight results are unpremultiplied, red 0.36 / 0.52 and blue 0.16 / 0.52
+    let source = RGBA::new(255, 0, 0, 153);
+    let destination = RGBA::new(0, 0, 255, 102);
+    assert_eq!(straight.composite(Operator::Xor, source, destination), RGBA::new(177, 0, 78, 133));
+}
+
+
+#[test]
+fn accepts_colors_without_alpha() {
+    let source = Color::new_hsla(0.0, 1.0, 0.5, 0.0);
+    let destination = Color::new_rgb(0, 0, 255);
+    let result = Operator::SourceOver.composite(source, destination);
+    assert_eq!(result, RGBA::new(0, 0, 255, 255));
+}
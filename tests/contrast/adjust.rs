@@ -0,0 +1,69 @@
+#!/usr/bin/env rust
+
+
+use color_operators::color::Color;
+use color_operators::contrast::Target;
+use color_operators::hsl::HSL;
+use color_operators::oklch::Oklch;
+use color_operators::rgb::RGB;
+use color_operators::rgb_space::RgbSpace;
+
+
+#[test]
+fn keeps_hue_and_chroma() {
+    let foreground = Color::new_oklch(0.7, 0.1, 150.0);
+    let background = HSL::new(0.0, 0.0, 1.0);
+
+    let adjusted = Target::Wcag(4.5).adjust(foreground, background.clone()).unwrap();
+    let ( l, c, h ) = Oklch::from(adjusted.clone()).into();
+
+    assert!(l < 0.7);
+    assert_eq!(( c, h ), ( 0.1, 150.0 ));
+    assert!(Target::Wcag(4.5).is_met(adjusted, background.clone()));
+    assert!(!Target::Wcag(4.5).is_met(Color::new_oklch(l + 0.01, c, h), background));
+}
+
+
+#[test]
+fn maps_candidates_within_gamut() {
+    let foreground = Color::new_oklch(0.6, 0.3, 145.0);
+    let background = Color::new_rgb(255, 255, 255);
+
+    let adjusted = Target::Wcag(4.5).adjust(foreground, background.clone()).unwrap();
+    let ( _l, c, h ) = Oklch::from(adjusted.clone()).into();
+
+    assert!(adjusted.is_oklch());
+    assert!(adjusted.in_gamut(&RgbSpace::srgb()));
+    assert!(c < 0.3);
+    assert!((h - 145.0).abs() < 5.0);
+    assert!(Target::Wcag(4.5).is_met(adjusted.clone(), background.clone()));
+    assert_eq!(adjusted.contrast_ratio(&background), RGB::from(adjusted).contrast_ratio(&RGB::new(255, 255, 255)));
+}
+
+
+#[test]
+fn picks_closest_direction() {
+    let background = Color::new_rgb(128, 128, 128);
+    let foreground = Color::new_oklch(0.55, 0.05, 30.0);
+
+    let adjusted = Target::Apca(45.0).adjust(foreground, background.clone()).unwrap();
+    let ( l, _c, _h ) = Oklch::from(adjusted.clone()).into();
+
+    assert!(l > 0.55);
+    assert!(Target::Apca(45.0).is_met(adjusted, background));
+}
+
+
+#[test]
+fn unchanged_when_met() {
+    let foreground = Color::new_rgb(0, 0, 0);
+    let adjusted = Target::Wcag(7.0).adjust(foreground.clone(), Color::new_rgb(255, 255, 255));
+    assert_eq!(adjusted, Some(foreground));
+}
+
+
+#[test]
+fn black_or_white() {
+    assert_eq!(Target::Wcag(4.5).black_or_white(Color::new_hsl(60.0, 1.0, 0.5)), Color::new_rgb(0, 0, 0));
+    assert_eq!(Target::Wcag(4.5).black_or_white(Color::new_hsl(240.0, 1.0, 0.3)), Color::new_rgb(255, 255, 255));
+}
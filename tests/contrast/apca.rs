@@ -0,0 +1,48 @@
+#!/usr/bin/env rust
+
+
+use color_operators::color::Color;
+use color_operators::contrast::{ Apca, Polarity };
+use color_operators::hsl::HSL;
+use color_operators::rgb::RGB;
+
+
+fn rounded(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+
+#[test]
+fn polarity() {
+    let black = RGB::new(0, 0, 0);
+    let white = RGB::new(255, 255, 255);
+
+    let dark_on_light = Apca::between(black.clone(), white.clone());
+    let light_on_dark = Apca::between(white, black);
+
+    assert_eq!(rounded(dark_on_light.lc()), 106.04);
+    assert_eq!(rounded(light_on_dark.lc()), -107.88);
+    assert_eq!(dark_on_light.polarity(), Polarity::DarkOnLight);
+    assert_eq!(light_on_dark.polarity(), Polarity::LightOnDark);
+}
+
+
+#[test]
+fn mixed_types() {
+    let text = Color::new_hsl(0.0, 0.0, 0.0);
+    let background = HSL::from(RGB::new(136, 136, 136));
+    assert_eq!(rounded(Apca::between(text, background).lc()), 41.02);
+}
+
+
+#[test]
+fn low_contrast() {
+    let apca = Apca::between(RGB::new(200, 200, 200), RGB::new(210, 210, 210));
+    assert_eq!(apca.lc(), 0.0);
+    assert_eq!(apca.minimum_font_size(900), None);
+    assert!(!apca.is_non_text_visible());
+
+    let apca = Apca::between(RGB::new(160, 160, 160), RGB::new(200, 200, 200));
+    assert_eq!(apca.minimum_weight(96.0), None);
+    assert!(apca.is_non_text_visible());
+}
@@ -0,0 +1,44 @@
+#!/usr/bin/env rust
+
+
+use color_operators::color::Color;
+use color_operators::contrast::{ Level, Report, TextSize };
+use color_operators::hsl::HSL;
+use color_operators::rgb::RGB;
+
+
+fn rounded(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+
+#[test]
+fn contrast_ratio_is_symmetric() {
+    let red = RGB::new(255, 0, 0);
+    let white = RGB::new(255, 255, 255);
+    assert_eq!(rounded(red.contrast_ratio(&white)), 4.0);
+    assert_eq!(red.contrast_ratio(&white), white.contrast_ratio(&red));
+}
+
+
+#[test]
+fn mixed_types() {
+    let hsl = HSL::new(0.0, 0.0, 0.5);
+    let c_rgb = Color::new_rgb(0, 0, 0);
+    assert_eq!(rounded(c_rgb.contrast_ratio(&hsl)), 5.32);
+    assert_eq!(rounded(hsl.contrast_ratio(&RGB::new(0, 0, 0))), 5.32);
+}
+
+
+#[test]
+fn json_palette() {
+    let text = Color::from_json_string(r#"{ "red": 0, "green": 102, "blue": 204 }"#);
+    let background = Color::from_json_string(r#"{ "hue": 0.0, "saturation": 0.0, "lightness": 1.0 }"#);
+
+    let report = Report::between(text, background);
+
+    assert_eq!(rounded(report.ratio()), 5.57);
+    assert!(report.passes(Level::AA, TextSize::Normal));
+    assert!(report.passes(Level::AAA, TextSize::Large));
+    assert!(!report.passes(Level::AAA, TextSize::Normal));
+}
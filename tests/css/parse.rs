@@ -0,0 +1,91 @@
+#!/usr/bin/env rust
+
+
+use color_operators::color::Color;
+use color_operators::css;
+use color_operators::error::Error;
+use color_operators::lab::Lab;
+use color_operators::rgb::RGB;
+
+
+#[test]
+fn hex() {
+    assert_eq!(css::parse("#abc"), Ok(Color::new_rgb(170, 187, 204)));
+    assert_eq!(css::parse("#abc8"), Ok(Color::new_rgba(170, 187, 204, 136)));
+    assert_eq!(css::parse("#AABBCC"), Ok(Color::new_rgb(170, 187, 204)));
+    assert_eq!(css::parse("  #aabbcc80  "), Ok(Color::new_rgba(170, 187, 204, 128)));
+}
+
+
+#[test]
+fn rgb() {
+    let expected = Color::new_rgb(255, 128, 0);
+    assert_eq!(css::parse("rgb(255, 128, 0)"), Ok(expected.clone()));
+    assert_eq!(css::parse("rgb(255 128 0)"), Ok(expected.clone()));
+    assert_eq!(css::parse("RGB(100% 50.2% 0%)"), Ok(expected));
+    assert_eq!(css::parse("rgba(255, 128, 0, 0.5)"), Ok(Color::new_rgba(255, 128, 0, 128)));
+    assert_eq!(css::parse("rgb(255 none 0 / 25%)"), Ok(Color::new_rgba(255, 0, 0, 64)));
+    assert_eq!(css::parse("color(srgb 1 0.5 0)"), Ok(Color::new_rgb(255, 128, 0)));
+}
+
+
+#[test]
+fn hue_functions() {
+    assert_eq!(css::parse("hsl(120deg 100% 25%)"), Ok(Color::new_hsl(120.0, 1.0, 0.25)));
+    assert_eq!(css::parse("hsl(-240, 100%, 25%)"), Ok(Color::new_hsl(120.0, 1.0, 0.25)));
+    assert_eq!(css::parse("hsla(200grad 100 25 / 0.5)"), Ok(Color::new_hsla(180.0, 1.0, 0.25, 0.5)));
+    assert_eq!(css::parse("hwb(90 25% 50%)"), Ok(Color::new_hwb(90.0, 0.25, 0.5)));
+}
+
+
+#[test]
+fn device_independent() {
+    let rgb = |input: &str| -> Result<RGB, Error> { css::parse(input).map(RGB::from) };
+    assert_eq!(rgb("lab(54.29 80.8 69.89)"), Ok(RGB::new(255, 0, 0)));
+    assert_eq!(rgb("lab(54.29% 64.64% 55.912%)"), Ok(RGB::new(255, 0, 0)));
+    assert_eq!(rgb("lch(54.29 106.84 40.85)"), Ok(RGB::new(255, 0, 0)));
+    assert_eq!(rgb("lch(54.29 71.2267% 40.85deg)"), Ok(RGB::new(255, 0, 0)));
+
+    let ( l, a, b ) = css::parse("lab(100 0 0)").map(Lab::from).unwrap().into();
+    assert_eq!((l.round(), a.round(), b.round()), (100.0, 0.0, 0.0));
+    assert_eq!(css::parse("oklab(0.5 -50% 0.1)"), Ok(Color::new_oklab(0.5, -0.2, 0.1)));
+    assert_eq!(css::parse("oklch(50% 0.1 none)"), Ok(Color::new_oklch(0.5, 0.1, 0.0)));
+}
+
+
+#[test]
+fn alpha() {
+    assert!(matches!(css::parse("lab(50 40 -20 / 1)"), Ok(Color::Lab(_))));
+    assert_eq!(css::parse("oklch(0.5 0.1 30 / 100%)"), Ok(Color::new_oklch(0.5, 0.1, 30.0)));
+    assert_eq!(css::parse("hwb(90 25% 50% / 1)"), Ok(Color::new_hwb(90.0, 0.25, 0.5)));
+
+    assert_eq!(css::parse("hwb(0 0% 0% / 0.5)"), Ok(Color::new_rgba(255, 0, 0, 128)));
+    assert_eq!(css::parse("hwb(90, 25%, 50%, 0)"), Ok(Color::new_rgba(96, 128, 64, 0)));
+    assert_eq!(css::parse("oklab(1 0 0 / 50%)"), Ok(Color::new_rgba(255, 255, 255, 128)));
+}
+
+
+#[test]
+fn keywords() {
+    assert_eq!(css::parse("cornflowerblue"), Ok(Color::new_rgb(100, 149, 237)));
+    assert_eq!(css::parse("DarkSlateGrey"), Ok(Color::new_rgb(47, 79, 79)));
+    assert_eq!(css::parse("Transparent"), Ok(Color::new_rgba(0, 0, 0, 0)));
+}
+
+
+#[test]
+fn errors() {
+    let error = |position: usize, message: &str| -> Result<Color, Error> {
+        Err(Error::Parse { position, message: message.to_string() })
+    };
+    assert_eq!(css::parse(""), error(0, "expected `#`, color name or color function"));
+    assert_eq!(css::parse(" #abcde"), error(1, "expected 3, 4, 6, or 8 hexadecimal digits"));
+    assert_eq!(css::parse("notacolor"), error(0, "unknown color name `notacolor`"));
+    assert_eq!(css::parse("rgb(1, 2 3)"), error(9, "expected `,`"));
+    assert_eq!(css::parse("rgb(1 2 3"), error(9, "expected `/` or `)`"));
+    assert_eq!(css::parse("hsl(1 2% 3%) red"), error(13, "unexpected input after color"));
+    assert_eq!(css::parse("hsl(10% 2% 3%)"), error(4, "expected number or angle"));
+    assert_eq!(css::parse("lab(1px 2 3)"), error(5, "unknown unit `px`"));
+    assert_eq!(css::parse("color(display-p3 1 0 0)"), error(6, "unsupported color space `display-p3`, expected `srgb`"));
+    assert_eq!(css::parse("cmyk(1 0 0)"), error(0, "unknown color function `cmyk`"));
+}
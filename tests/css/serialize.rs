@@ -0,0 +1,111 @@
+#!/usr/bin/env rust
+
+
+use color_operators::color::Color;
+use color_operators::css;
+use color_operators::css::{ Notation, Options, Syntax, Units };
+use color_operators::difference::DeltaE;
+use color_operators::hsv::HSV;
+use color_operators::lab::Lab;
+use color_operators::lch::LCh;
+use color_operators::rgb::RGB;
+use color_operators::rgba::RGBA;
+
+
+#[test]
+fn hex_is_zero_padded() {
+    assert_eq!(RGB::new(5, 5, 5).to_hex_string(), "050505");
+    assert_eq!(RGBA::new(0, 10, 255, 1).to_hex_string(), "000AFF01");
+    assert_eq!(RGB::new(0, 0, 0).to_css_string(Options::new().notation(Notation::Hex)), "#000000");
+}
+
+
+#[test]
+fn named() {
+    let options = Options::new().named(true);
+    assert_eq!(RGB::new(0, 255, 255).to_css_string(options), "aqua");
+    assert_eq!(RGB::new(1, 255, 255).to_css_string(options), "rgb(1 255 255)");
+    assert_eq!(RGBA::new(0, 0, 0, 0).to_css_string(options), "transparent");
+    assert_eq!(RGBA::new(255, 0, 0, 128).to_css_string(options), "rgb(255 0 0 / 0.502)");
+}
+
+
+#[test]
+fn precision() {
+    let hsv = HSV::new(200.0, 1.0 / 3.0, 0.5);
+    assert_eq!(hsv.to_css_string(Options::new()), "hwb(200 33.33 50)");
+    assert_eq!(hsv.to_css_string(Options::new().precision(0).units(Units::Percent)), "hwb(200 33% 50%)");
+}
+
+
+#[test]
+fn round_trip() {
+    let colors = [
+        Color::new_rgb(12, 34, 56),
+        Color::new_rgba(12, 34, 56, 78),
+        Color::new_hsl(210.0, 0.5, 0.25),
+    ];
+    let options = [
+        Options::new(),
+        Options::new().syntax(Syntax::Legacy),
+        Options::new().units(Units::Percent).precision(4),
+        Options::new().notation(Notation::Hex),
+    ];
+    for color in colors.iter() {
+        for option in options.iter() {
+            let css = color.to_css_string(*option);
+            assert_eq!(css::parse(&css).as_ref(), Ok(color), "{}", css);
+        }
+    }
+}
+
+
+#[test]
+fn device_independent_round_trip() {
+    let colors = [
+        Color::new_oklab(0.5, 0.1, -0.2),
+        Color::new_oklch(0.75, 0.125, 30.0),
+    ];
+    let options = [
+        Options::new(),
+        Options::new().units(Units::Percent).precision(4),
+    ];
+    for color in colors.iter() {
+        for option in options.iter() {
+            let css = color.to_css_string(*option);
+            assert_eq!(css::parse(&css).as_ref(), Ok(color), "{}", css);
+        }
+    }
+
+    assert_eq!(colors[0].to_css_string(Options::new()), "oklab(0.5 0.1 -0.2)");
+    assert_eq!(colors[1].to_css_string(Options::new()), "oklch(0.75 0.125 30)");
+    assert_eq!(colors[1].to_css_string(Options::new().units(Units::Percent)), "oklch(75% 31.25% 30)");
+}
+
+
+#[test]
+fn d50_round_trip() {
+    let colors = [
+        Color::new_lab(50.0, 40.0, -20.0),
+        Color::new_lch(62.5, 30.0, 275.5),
+        Color::new_rgb(255, 0, 0),
+    ];
+    let options = [
+        Options::new(),
+        Options::new().units(Units::Percent).precision(4),
+    ];
+    for color in colors.iter() {
+        for option in options.iter() {
+            let lab = Color::from(Lab::from(color.clone()));
+            let lch = Color::from(LCh::from(color.clone()));
+            for css in [ lab.to_css_string(*option), lch.to_css_string(*option) ].iter() {
+                let parsed = css::parse(css).unwrap();
+                assert!(parsed.is_close(color, DeltaE::CIEDE2000, 0.01), "{}", css);
+            }
+        }
+    }
+
+    let red = Color::new_rgb(255, 0, 0);
+    assert_eq!(Color::from(Lab::from(red.clone())).to_css_string(Options::new()), "lab(54.29 80.8 69.89)");
+    assert_eq!(Color::from(LCh::from(red)).to_css_string(Options::new()), "lch(54.29 106.84 40.86)");
+}
@@ -0,0 +1,39 @@
+#!/usr/bin/env rust
+
+
+use color_operators::cvd::{ Checker, Deficiency };
+use color_operators::difference::DeltaE;
+use color_operators::rgb::RGB;
+
+
+#[test]
+fn duplicate_colors_conflict_with_every_deficiency() {
+    let palette = [ RGB::new(255, 42, 90), RGB::new(255, 42, 90) ];
+    let conflicts = Checker::new(DeltaE::CIE76, 1.0).check(&palette);
+
+    let deficiencies: Vec<Deficiency> = conflicts.iter().map(|c| c.deficiency()).collect();
+    assert_eq!(deficiencies, vec![ Deficiency::Protanopia, Deficiency::Deuteranopia, Deficiency::Tritanopia ]);
+    assert!(conflicts.iter().all(|c| c.pair() == (0, 1) && c.difference() == 0.0));
+}
+
+
+#[test]
+fn zero_severity_only_reports_similar_colors() {
+    let palette = [ RGB::new(255, 0, 0), RGB::new(0, 128, 0), RGB::new(0, 0, 255) ];
+    let checker = Checker::new(DeltaE::CIEDE2000, 10.0).severity(0.0);
+    assert!(checker.check(&palette).is_empty());
+}
+
+
+#[test]
+fn selected_deficiencies() {
+    let palette = [ RGB::new(255, 0, 0), RGB::new(0, 128, 0) ];
+    let checker = Checker::new(DeltaE::CIEDE2000, 10.0).deficiencies(&[ Deficiency::Tritanopia ]);
+    assert!(checker.check(&palette).is_empty());
+
+    let checker = checker.deficiencies(&[ Deficiency::Protanopia ]);
+    let conflicts = checker.check(&palette);
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].pair(), (0, 1));
+    assert_eq!(conflicts[0].deficiency(), Deficiency::Protanopia);
+}
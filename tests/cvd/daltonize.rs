@@ -0,0 +1,47 @@
+#!/usr/bin/env rust
+
+
+use color_operators::color::Color;
+use color_operators::cvd::Deficiency;
+use color_operators::difference::DeltaE;
+use color_operators::rgb::RGB;
+
+
+#[test]
+fn improves_simulated_difference() {
+    let red = RGB::new(255, 0, 0);
+    let green = RGB::new(0, 128, 0);
+    for deficiency in [ Deficiency::Protanopia, Deficiency::Deuteranopia ].iter() {
+        let before = DeltaE::CIEDE2000.between(deficiency.simulate(red.clone(), 1.0), deficiency.simulate(green.clone(), 1.0));
+        let corrected = red.daltonize(*deficiency, 1.0);
+        let after = DeltaE::CIEDE2000.between(deficiency.simulate(corrected, 1.0), deficiency.simulate(green.clone(), 1.0));
+        assert!(after > before);
+    }
+}
+
+
+#[test]
+fn neutral_colors_are_unchanged() {
+    for deficiency in [ Deficiency::Protanopia, Deficiency::Deuteranopia, Deficiency::Tritanopia ].iter() {
+        for value in [0, 128, 255].iter() {
+            let gray = RGB::new(*value, *value, *value);
+            assert_eq!(gray.daltonize(*deficiency, 1.0), gray);
+        }
+    }
+}
+
+
+#[test]
+fn achromatopsia_is_unchanged() {
+    let rgb = RGB::new(255, 42, 90);
+    assert_eq!(rgb.daltonize(Deficiency::Achromatopsia, 1.0), rgb);
+}
+
+
+#[test]
+fn color_keeps_variant() {
+    let c_hsl = Color::new_hsl(0.0, 1.0, 0.5);
+    let result = c_hsl.daltonize(Deficiency::Protanopia, 1.0);
+    assert!(result.is_hsl());
+    assert_eq!(RGB::from(result), RGB::new(255, 0, 0).daltonize(Deficiency::Protanopia, 1.0));
+}
@@ -0,0 +1,90 @@
+#!/usr/bin/env rust
+
+
+use color_operators::color::Color;
+use color_operators::cvd::{ Deficiency, Method, Simulator };
+use color_operators::hsl::HSL;
+use color_operators::rgb::RGB;
+
+
+const DEFICIENCIES: [Deficiency; 4] = [
+    Deficiency::Protanopia,
+    Deficiency::Deuteranopia,
+    Deficiency::Tritanopia,
+    Deficiency::Achromatopsia,
+];
+
+
+const METHODS: [Method; 3] = [ Method::Machado, Method::Brettel, Method::Vienot ];
+
+
+#[test]
+fn neutral_colors_are_unchanged() {
+    for method in METHODS.iter() {
+        let simulator = Simulator::new(*method);
+        for deficiency in DEFICIENCIES.iter() {
+            for value in [0, 128, 255].iter() {
+                let gray = RGB::new(*value, *value, *value);
+                assert_eq!(simulator.simulate(*deficiency, gray.clone(), 1.0), gray);
+            }
+        }
+    }
+}
+
+
+#[test]
+fn zero_severity_is_identity() {
+    let rgb = RGB::new(255, 42, 90);
+    for method in METHODS.iter() {
+        let simulator = Simulator::new(*method);
+        for deficiency in DEFICIENCIES.iter() {
+            assert_eq!(simulator.simulate(*deficiency, rgb.clone(), 0.0), rgb);
+        }
+    }
+}
+
+
+#[test]
+fn severity_is_clamped() {
+    let rgb = RGB::new(255, 42, 90);
+    for deficiency in DEFICIENCIES.iter() {
+        assert_eq!(deficiency.simulate(rgb.clone(), 2.0), deficiency.simulate(rgb.clone(), 1.0));
+        assert_eq!(deficiency.simulate(rgb.clone(), -1.0), rgb);
+    }
+}
+
+
+#[test]
+fn machado_interpolates_between_severities() {
+    let red = RGB::new(255, 0, 0);
+    let lower: (u8, u8, u8) = Deficiency::Protanopia.simulate(red.clone(), 0.5).into();
+    let middle: (u8, u8, u8) = Deficiency::Protanopia.simulate(red.clone(), 0.55).into();
+    let upper: (u8, u8, u8) = Deficiency::Protanopia.simulate(red, 0.6).into();
+
+    assert!(lower.0 > middle.0 && middle.0 > upper.0);
+    assert!(lower.1 < middle.1 && middle.1 < upper.1);
+}
+
+
+#[test]
+fn red_and_green_are_confused_by_dichromats() {
+    let red = RGB::new(255, 0, 0);
+    let green = RGB::new(0, 128, 0);
+    for deficiency in [ Deficiency::Protanopia, Deficiency::Deuteranopia ].iter() {
+        let red_hue = HSL::from(deficiency.simulate(red.clone(), 1.0)).get("hue").unwrap();
+        let green_hue = HSL::from(deficiency.simulate(green.clone(), 1.0)).get("hue").unwrap();
+        assert!((red_hue - green_hue).abs() < 30.0);
+    }
+}
+
+
+#[test]
+fn color_keeps_variant_and_alpha() {
+    let c_hsla = Color::new_hsla(0.0, 1.0, 0.5, 0.4);
+    let result = c_hsla.simulate_cvd(Deficiency::Achromatopsia, 1.0);
+    assert!(result.is_hsla());
+    assert_eq!(RGB::from(result.clone()), RGB::new(127, 127, 127));
+
+    let c_rgba = Color::new_rgba(255, 0, 0, 102);
+    assert_eq!(c_rgba.simulate_cvd(Deficiency::Achromatopsia, 1.0), Color::new_rgba(127, 127, 127, 102));
+}
@@ -0,0 +1,96 @@
+#!/usr/bin/env rust
+
+
+use color_operators::color::Color;
+use color_operators::difference::{ Application, DeltaE };
+use color_operators::hsv::HSV;
+use color_operators::lab::Lab;
+use color_operators::oklab::Oklab;
+use color_operators::rgb::RGB;
+
+
+fn rounded(value: f64) -> f64 {
+    (value * 10_000.0).round() / 10_000.0
+}
+
+
+/// Pairs and expected values from Sharma, Wu, and Dalal test data
+#[test]
+fn ciede2000_reference_data() {
+    let data = [
+        ( (50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485), 2.0425 ),
+        ( (50.0, -1.3802, -84.2814), (50.0, 0.0, -82.7485), 1.0000 ),
+        ( (50.0, 0.0, 0.0), (50.0, -1.0, 2.0), 2.3669 ),
+        ( (50.0, 2.49, -0.001), (50.0, -2.49, 0.0011), 7.2195 ),
+        ( (50.0, 2.5, 0.0), (73.0, 25.0, -18.0), 27.1492 ),
+        ( (60.2574, -34.0099, 36.2677), (60.4626, -34.1751, 39.4387), 1.2644 ),
+        ( (2.0776, 0.0795, -1.135), (0.9033, -0.0636, -0.5514), 0.9082 ),
+    ];
+    for ( reference, sample, expected ) in data.iter() {
+        let reference = Lab::from(*reference);
+        let sample = Lab::from(*sample);
+        assert_eq!(rounded(DeltaE::CIEDE2000.between(reference.clone(), sample.clone())), *expected);
+        assert_eq!(rounded(DeltaE::CIEDE2000.between(sample, reference)), *expected);
+    }
+}
+
+
+/// Distances between `Oklab` values of sRGB primaries, white, and black published by Björn Ottosson
+#[test]
+fn ok_reference_data() {
+    let data = [
+        ( RGB::new(255, 255, 255), RGB::new(0, 0, 0), 1.0 ),
+        ( RGB::new(255, 0, 0), RGB::new(0, 0, 255), 0.5371 ),
+        ( RGB::new(255, 0, 0), RGB::new(0, 255, 0), 0.5198 ),
+        ( RGB::new(0, 255, 0), RGB::new(0, 0, 255), 0.6734 ),
+    ];
+    for ( reference, sample, expected ) in data.iter() {
+        assert_eq!(rounded(DeltaE::Ok.between(reference.clone(), sample.clone())), *expected);
+        assert_eq!(rounded(DeltaE::Ok.between(sample.clone(), reference.clone())), *expected);
+    }
+
+    let reference = Oklab::new(0.5, 0.1, 0.0);
+    let sample = Oklab::new(0.5, 0.0, 0.1);
+    assert_eq!(rounded(DeltaE::Ok.between(reference, sample)), rounded(0.02_f64.sqrt()));
+}
+
+
+#[test]
+fn weighted_lightness() {
+    let reference = Lab::new(50.0, 10.0, 10.0);
+    let sample = Lab::new(60.0, 10.0, 10.0);
+
+    assert_eq!(DeltaE::CIE76.between(reference.clone(), sample.clone()), 10.0);
+    assert_eq!(DeltaE::CIE94(Application::Textiles).between(reference.clone(), sample.clone()), 5.0);
+
+    let perceptibility = DeltaE::CMC { lightness: 1.0, chroma: 1.0 }.between(reference.clone(), sample.clone());
+    let acceptability = DeltaE::CMC { lightness: 2.0, chroma: 1.0 }.between(reference, sample);
+    assert_eq!(rounded(perceptibility / acceptability), 2.0);
+}
+
+
+#[test]
+fn identical_colors() {
+    let formulas = [
+        DeltaE::CIE76,
+        DeltaE::CIE94(Application::GraphicArts),
+        DeltaE::CIEDE2000,
+        DeltaE::CMC { lightness: 2.0, chroma: 1.0 },
+        DeltaE::Ok,
+    ];
+    for formula in formulas.iter() {
+        assert_eq!(formula.between(RGB::new(255, 42, 90), RGB::new(255, 42, 90)), 0.0);
+    }
+}
+
+
+#[test]
+fn is_close_mixed_types() {
+    let c_rgb = Color::new_rgb(255, 42, 90);
+    let c_hsv = Color::from(HSV::from(RGB::new(255, 42, 90)));
+    let c_lab = Color::new_lab(50.0, 0.0, 0.0);
+
+    assert!(c_rgb.is_close(&c_hsv, DeltaE::CIEDE2000, 0.5));
+    assert!(c_rgb.is_close(&RGB::new(255, 43, 90), DeltaE::CIEDE2000, 1.0));
+    assert!(!c_rgb.is_close(&c_lab, DeltaE::CIE76, 10.0));
+}
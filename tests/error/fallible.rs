@@ -0,0 +1,59 @@
+#!/usr/bin/env rust
+
+
+use std::convert::TryFrom;
+
+
+use color_operators::color::Color;
+use color_operators::error::Error;
+use color_operators::hsl::HSL;
+use color_operators::rgb::RGB;
+use color_operators::rgba::RGBA;
+
+
+#[test]
+fn hex() {
+    assert_eq!(RGB::try_from_hex_string("ABCDEF"), Ok(RGB::new(171, 205, 239)));
+    assert!(matches!(RGB::try_from_hex_string("ABCDEG"), Err(Error::Hex(_))));
+    assert!(matches!(HSL::try_from_hex_string("ABC"), Err(Error::Hex(_))));
+    assert_eq!(RGBA::try_from_hex_string("ABCDEF"), Ok(RGBA::new(171, 205, 239, 255)));
+    assert_eq!(RGBA::try_from_hex_string("ABCDEF0102"), Err(Error::Length { expected: 4, found: 5 }));
+}
+
+
+#[test]
+fn slice() {
+    assert_eq!(RGB::try_from(&[1_u8, 2, 3][..]), Ok(RGB::new(1, 2, 3)));
+    assert_eq!(RGB::try_from(&[1_u8, 2, 3, 4][..]), Err(Error::Length { expected: 3, found: 4 }));
+    assert_eq!(HSL::try_from(&[120.0][..]), Err(Error::Length { expected: 3, found: 1 }));
+}
+
+
+#[test]
+fn json() {
+    assert!(matches!(RGB::try_from_json_str("{ nope"), Err(Error::Json(_))));
+    assert_eq!(
+        RGB::try_from_json_str(r#"{ "red": 256, "green": 0, "blue": 0 }"#),
+        Err(Error::InvalidComponent("red".to_string()))
+    );
+    assert_eq!(
+        Color::try_from_json_str(r#"{ "red": 255, "green": 42, "blue": 90, "alpha": 128 }"#),
+        Ok(Color::new_rgba(255, 42, 90, 128))
+    );
+    assert_eq!(
+        Color::try_from_json_str(r#"{ "cyan": 1.0, "key": 0.0 }"#),
+        Err(Error::MissingComponent("magenta".to_string()))
+    );
+}
+
+
+#[test]
+fn from_str() {
+    assert_eq!("#ABCDEF".parse::<RGB>(), Ok(RGB::new(171, 205, 239)));
+    assert_eq!("#ABCDEF80".parse::<Color>(), Ok(Color::new_rgba(171, 205, 239, 128)));
+    assert_eq!("ABCDEF80".parse::<RGBA>().map(Color::from), Ok(Color::new_rgba(171, 205, 239, 128)));
+    assert!("ABCDEF80".parse::<Color>().is_err());
+    assert!("beef".parse::<Color>().is_err());
+    assert!("add".parse::<Color>().is_err());
+    assert!("".parse::<RGB>().is_err());
+}
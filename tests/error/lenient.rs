@@ -0,0 +1,40 @@
+#!/usr/bin/env rust
+
+
+use color_operators::color::Color;
+use color_operators::hsla::HSLA;
+use color_operators::rgb::RGB;
+use color_operators::rgba::RGBA;
+
+
+#[test]
+fn invalid_hex_defaults() {
+    assert_eq!(RGB::from_hex_string("NOPE"), RGB::default());
+    assert_eq!(RGB::from_hex_string("ABCD"), RGB::default());
+    assert_eq!(RGBA::from_hex_string("#ABCDEF"), RGBA::new(0, 0, 0, 255));
+}
+
+
+#[test]
+fn short_vector_defaults() {
+    assert_eq!(RGB::from(vec![255_u8, 42]), RGB::default());
+    assert_eq!(HSLA::from(vec![60.0]), HSLA::new(0.0, 0.0, 0.0, 1.0));
+}
+
+
+#[test]
+fn long_input_ignores_extra_values() {
+    assert_eq!(RGB::from(vec![1_u8, 2, 3, 4]), RGB::new(1, 2, 3));
+    assert_eq!(RGB::from_hex_string("FF2A5A80"), RGB::new(255, 42, 90));
+    assert_eq!(RGBA::from(vec![1_u8, 2, 3, 4, 5]), RGBA::new(1, 2, 3, 4));
+    assert_eq!(HSLA::from(vec![60.0, 0.5, 0.25, 0.5, 1.0]), HSLA::new(60.0, 0.5, 0.25, 0.5));
+}
+
+
+#[test]
+fn invalid_json_defaults() {
+    assert_eq!(RGB::from_json_string("{ nope"), RGB::default());
+    assert_eq!(Color::from_json_string(r#"{ "red": 255 }"#), Color::new_rgb(255, 0, 0));
+    assert_eq!(RGBA::from_json_string(r#"{ "red": 255, "green": "nope", "blue": 90 }"#), RGBA::new(255, 0, 90, 255));
+    assert_eq!(HSLA::from_json_string(r#"{ "hue": 60.0, "alpha": 0.5 }"#), HSLA::new(60.0, 0.0, 0.0, 0.5));
+}
@@ -0,0 +1,49 @@
+#!/usr/bin/env rust
+
+
+use color_operators::color::Color;
+use color_operators::gamut::{ self, Method };
+use color_operators::oklch::Oklch;
+use color_operators::rgb_space::RgbSpace;
+
+
+#[test]
+fn colors_within_gamut_are_unchanged() {
+    let rgb = Color::new_rgb(255, 42, 90);
+    assert!(rgb.in_gamut(&RgbSpace::srgb()));
+    assert_eq!(rgb.to_gamut(&RgbSpace::srgb(), Method::Css), rgb);
+    assert_eq!(rgb.to_gamut(&RgbSpace::srgb(), Method::Clip), rgb);
+}
+
+
+#[test]
+fn css_mapping_keeps_hue_and_lands_within_gamut() {
+    for space in [ RgbSpace::srgb(), RgbSpace::display_p3() ].iter() {
+        for hue in (0..12).map(|step| step as f64 * 30.0) {
+            let color = Color::new_oklch(0.7, 0.4, hue);
+            let mapped = color.to_gamut(space, Method::Css);
+            assert!(mapped.in_gamut(space), "hue {} should be mapped within gamut", hue);
+
+            let ( l, c, h ) = Oklch::from(mapped).into();
+            let delta = ((h - hue + 540.0) % 360.0 - 180.0).abs();
+            assert!((l - 0.7).abs() < 0.05, "hue {} lightness {}", hue, l);
+            assert!(c < 0.4);
+            assert!(delta < 8.0, "hue {} became {}", hue, h);
+        }
+    }
+}
+
+
+#[test]
+fn display_p3_keeps_more_chroma_than_srgb() {
+    let color = Color::new_oklch(0.75, 0.3, 150.0);
+    let chroma = |space: RgbSpace| Oklch::from(color.to_gamut(&space, Method::Css)).get("c").unwrap();
+    assert!(chroma(RgbSpace::display_p3()) > chroma(RgbSpace::srgb()));
+}
+
+
+#[test]
+fn lightness_beyond_white_and_black() {
+    assert_eq!(gamut::map(Color::new_oklch(1.2, 0.1, 90.0), &RgbSpace::srgb(), Method::Css), (1.0, 1.0, 1.0));
+    assert_eq!(gamut::map(Color::new_oklab(-0.1, 0.1, 0.0), &RgbSpace::srgb(), Method::Css), (0.0, 0.0, 0.0));
+}
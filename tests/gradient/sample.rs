@@ -0,0 +1,80 @@
+#!/usr/bin/env rust
+
+
+use color_operators::color::Color;
+use color_operators::gradient::{ Easing, Gradient };
+use color_operators::mix::Space;
+
+
+fn black_to_white() -> Gradient {
+    Gradient::new().space(Space::Srgb).stop(Color::new_rgb(0, 0, 0), 0.0)
+}
+
+
+#[test]
+fn multiple_stops() {
+    let gradient = Gradient::new()
+        .space(Space::Srgb)
+        .stop(Color::new_rgb(255, 0, 0), 0.0)
+        .stop(Color::new_rgb(0, 255, 0), 0.5)
+        .stop(Color::new_rgb(0, 0, 255), 1.0);
+
+    assert_eq!(gradient.sample(0.25), Color::new_rgb(128, 128, 0));
+    assert_eq!(gradient.sample(0.5), Color::new_rgb(0, 255, 0));
+    assert_eq!(gradient.sample(0.75), Color::new_rgb(0, 128, 128));
+    assert_eq!(gradient.sample(2.0), Color::new_rgb(0, 0, 255));
+}
+
+
+#[test]
+fn hard_stops() {
+    let gradient = Gradient::new()
+        .space(Space::Srgb)
+        .stop(Color::new_rgb(255, 0, 0), 0.0)
+        .stop(Color::new_rgb(255, 0, 0), 0.5)
+        .stop(Color::new_rgb(0, 0, 255), 0.25)
+        .stop(Color::new_rgb(0, 0, 255), 1.0);
+
+    assert_eq!(gradient.stops()[2].position(), 0.5);
+    assert_eq!(gradient.sample(0.49), Color::new_rgb(255, 0, 0));
+    assert_eq!(gradient.sample(0.51), Color::new_rgb(0, 0, 255));
+}
+
+
+#[test]
+fn hints_at_edges() {
+    let white = Color::new_rgb(255, 255, 255);
+
+    let at_start = black_to_white().hint(0.0).stop(white.clone(), 1.0);
+    assert_eq!(at_start.sample(0.01), white);
+
+    let at_end = black_to_white().hint(1.0).stop(white.clone(), 1.0);
+    assert_eq!(at_end.sample(0.99), Color::new_rgb(0, 0, 0));
+}
+
+
+#[test]
+fn easing_between_stops() {
+    let white = Color::new_rgb(255, 255, 255);
+    let linear = black_to_white().stop(white.clone(), 1.0);
+    let eased = black_to_white().easing(Easing::EaseIn).stop(white.clone(), 1.0);
+
+    let red = |color: Color| -> u8 {
+        let ( red, _green, _blue ) = color_operators::rgb::RGB::from(color).into();
+        red
+    };
+
+    assert!(red(eased.sample(0.25)) < red(linear.sample(0.25)));
+    assert_eq!(eased.sample(0.0), linear.sample(0.0));
+    assert_eq!(eased.sample(1.0), white);
+}
+
+
+#[test]
+fn evenly_spaced_colors() {
+    let gradient = black_to_white().stop(Color::new_rgb(255, 255, 255), 1.0);
+    assert!(gradient.colors(0).is_empty());
+    assert_eq!(gradient.colors(1), vec![ Color::new_rgb(0, 0, 0) ]);
+    assert_eq!(gradient.colors(3)[1], Color::new_rgb(128, 128, 128));
+    assert!(Gradient::new().colors(3).is_empty());
+}
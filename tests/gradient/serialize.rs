@@ -0,0 +1,80 @@
+#!/usr/bin/env rust
+
+
+use color_operators::color::Color;
+use color_operators::css::{ Notation, Options };
+use color_operators::error::Error;
+use color_operators::gradient::{ Easing, Gradient };
+use color_operators::mix::{ HueMethod, Space };
+
+
+fn hex() -> Options {
+    Options::new().notation(Notation::Hex)
+}
+
+
+#[test]
+fn css_defaults() {
+    let gradient = Gradient::new()
+        .stop(Color::new_rgb(255, 0, 0), 0.0)
+        .stop(Color::new_rgb(0, 0, 255), 1.0);
+    assert_eq!(gradient.to_css_string(hex()), "linear-gradient(in oklab, #FF0000 0%, #0000FF 100%)");
+
+    let shorter = gradient.clone().space(Space::Oklch).hue_method(HueMethod::Shorter);
+    assert_eq!(shorter.to_css_string(hex()), "linear-gradient(in oklch, #FF0000 0%, #0000FF 100%)");
+
+    let ignored = gradient.space(Space::Lab).hue_method(HueMethod::Longer);
+    assert_eq!(ignored.to_css_string(hex()), "linear-gradient(in lab, #FF0000 0%, #0000FF 100%)");
+}
+
+
+#[test]
+fn css_approximates_easing_and_hsv() {
+    let eased = Gradient::new()
+        .stop(Color::new_rgb(0, 0, 0), 0.0)
+        .easing(Easing::EaseOut)
+        .hint(0.25)
+        .stop(Color::new_rgb(255, 255, 255), 1.0);
+    let css = eased.to_css_string(hex());
+    assert_eq!(css.matches('#').count(), 9);
+    assert!(css.contains("#FFFFFF 100%"));
+
+    let hsv = Gradient::new()
+        .space(Space::HSV)
+        .stop(Color::new_rgb(255, 0, 0), 0.0)
+        .stop(Color::new_rgb(0, 0, 255), 1.0);
+    let css = hsv.to_css_string(hex());
+    assert!(css.starts_with("linear-gradient(in srgb, #FF0000 0%, "));
+    assert!(css.contains("#FF0040 12.5%"));
+}
+
+
+#[test]
+fn json_round_trip() {
+    let gradient = Gradient::new()
+        .angle(45.0)
+        .space(Space::Oklch)
+        .hue_method(HueMethod::Decreasing)
+        .stop(Color::new_oklch(0.5, 0.1, 30.0), 0.0)
+        .hint(0.4)
+        .easing(Easing::EaseInOut)
+        .stop(Color::new_rgba(0, 128, 255, 128), 0.75)
+        .stop(Color::new_hsl(60.0, 1.0, 0.5), 1.0);
+
+    assert_eq!(Gradient::try_from_json_str(&gradient.to_json_string()), Ok(gradient));
+}
+
+
+#[test]
+fn json_errors() {
+    let missing_color = r#"{ "stops": [ { "position": 0 } ] }"#;
+    assert_eq!(Gradient::try_from_json_str(missing_color), Err(Error::MissingComponent("color".to_string())));
+
+    let missing_position = r#"{ "stops": [ { "color": { "red": 0, "green": 0, "blue": 0 } } ] }"#;
+    assert_eq!(Gradient::try_from_json_str(missing_position), Err(Error::MissingComponent("position".to_string())));
+
+    let invalid_easing = r#"{ "stops": [ { "color": { "red": 0, "green": 0, "blue": 0 }, "position": 0, "easing": [ 1 ] } ] }"#;
+    assert_eq!(Gradient::try_from_json_str(invalid_easing), Err(Error::InvalidComponent("easing".to_string())));
+
+    assert_eq!(Gradient::from_json_string("nope"), Gradient::new());
+}
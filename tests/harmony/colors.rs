@@ -0,0 +1,43 @@
+#!/usr/bin/env rust
+
+
+use color_operators::color::Color;
+use color_operators::harmony::{ Harmony, Wheel };
+use color_operators::hsl::HSL;
+use color_operators::oklch::Oklch;
+
+
+fn hues(colors: Vec<Color>) -> Vec<f64> {
+    colors.into_iter().map(|c| HSL::from(c).get("hue").unwrap().round()).collect()
+}
+
+
+#[test]
+fn hsl_wheel() {
+    let base = Color::new_hsl(30.0, 0.8, 0.5);
+    assert_eq!(hues(Harmony::Complementary.colors(base.clone(), Wheel::HSL)), vec![30.0, 210.0]);
+    assert_eq!(hues(Harmony::SplitComplementary { angle: 30.0 }.colors(base.clone(), Wheel::HSL)), vec![30.0, 180.0, 240.0]);
+    assert_eq!(hues(Harmony::Analogous { angle: 45.0 }.colors(base.clone(), Wheel::HSL)), vec![30.0, 345.0, 75.0]);
+    assert_eq!(hues(Harmony::Tetradic { angle: 60.0 }.colors(base.clone(), Wheel::HSL)), vec![30.0, 90.0, 210.0, 270.0]);
+    assert_eq!(hues(Harmony::Square.colors(base, Wheel::HSL)), vec![30.0, 120.0, 210.0, 300.0]);
+}
+
+
+#[test]
+fn oklch_wheel_keeps_lightness_and_chroma() {
+    let base = Oklch::new(0.6, 0.08, 100.0);
+    for color in Harmony::Triadic.colors(base, Wheel::Oklch).into_iter() {
+        let ( l, c, _h ) = Oklch::from(color).into();
+        assert_eq!(( l, c ), ( 0.6, 0.08 ));
+    }
+}
+
+
+#[test]
+fn keeps_type_and_alpha() {
+    let base = Color::new_rgba(255, 42, 90, 128);
+    let colors = Harmony::Square.colors(base.clone(), Wheel::HSL);
+    assert_eq!(colors[0], base);
+    assert!(colors.iter().all(|c| c.is_rgba()));
+    assert!(colors.iter().all(|c| color_operators::rgba::RGBA::from(c.clone()).get("alpha") == Ok(128)));
+}
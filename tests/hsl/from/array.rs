@@ -7,7 +7,7 @@ use color_operators::hsl::HSL;
 #[test]
 fn array_to_tuple() {
     let array = [200.0, 0.5, 0.0];
-    let hsl = HSL::from(array.clone());
+    let hsl = HSL::from(array);
     let ( hue, saturation, lightness ) = hsl.into();
     assert_eq!(hue, array[0]);
     assert_eq!(saturation, array[1]);
@@ -0,0 +1,38 @@
+#!/usr/bin/env rust
+
+
+use color_operators::color::Color;
+use color_operators::hsla::HSLA;
+use color_operators::hsva::HSVA;
+use color_operators::rgba::RGBA;
+
+
+#[test]
+fn carries_alpha() {
+    let hsla = HSLA::from(RGBA::new(255, 0, 0, 51));
+    assert_eq!(hsla, HSLA::new(0.0, 1.0, 0.5, 0.2));
+}
+
+
+#[test]
+fn round_trip() {
+    let rgba = RGBA::new(255, 42, 90, 128);
+    let hsla = HSLA::from(rgba.clone());
+    assert_eq!(RGBA::from(hsla), rgba);
+}
+
+
+#[test]
+fn through_hsva() {
+    let hsla = HSLA::new(120.0, 0.5, 0.5, 0.25);
+    let hsva = HSVA::from(hsla.clone());
+    assert_eq!(hsva.get("alpha"), Ok(0.25));
+    assert_eq!(HSLA::from(hsva), hsla);
+}
+
+
+#[test]
+fn color_without_alpha_is_opaque() {
+    let hsla = HSLA::from(Color::new_hsl(120.0, 0.5, 0.5));
+    assert_eq!(hsla.get("alpha"), Ok(1.0));
+}
@@ -0,0 +1,38 @@
+#!/usr/bin/env rust
+
+
+use color_operators::hsv::HSV;
+use color_operators::hwb::HWB;
+use color_operators::rgb::RGB;
+
+
+#[test]
+fn pure_hue() {
+    let hwb = HWB::from(HSV::new(240.0, 1.0, 1.0));
+    assert_eq!(hwb, HWB::new(240.0, 0.0, 0.0));
+}
+
+
+#[test]
+fn round_trip() {
+    let hsv = HSV::new(120.0, 0.5, 0.5);
+    let hwb = HWB::from(hsv.clone());
+    assert_eq!(HSV::from(hwb), hsv);
+}
+
+
+#[test]
+fn normalizes_achromatic() {
+    let hwb = HWB::new(0.0, 0.75, 0.75);
+    assert_eq!(hwb.get("whiteness"), Ok(0.5));
+    assert_eq!(hwb.get("blackness"), Ok(0.5));
+    assert_eq!(RGB::from(hwb), RGB::new(128, 128, 128));
+}
+
+
+#[test]
+fn rgb_round_trip() {
+    let rgb = RGB::new(255, 42, 90);
+    let hwb = HWB::from(rgb.clone());
+    assert_eq!(RGB::from(hwb), rgb);
+}
@@ -0,0 +1,40 @@
+#!/usr/bin/env rust
+
+
+use color_operators::lab::Lab;
+use color_operators::rgb::RGB;
+
+
+fn rounded(lab: Lab) -> Vec<f64> {
+    let vector: Vec<f64> = lab.into();
+    vector.iter().map(|v| { (v * 100.0).round() / 100.0 }).collect()
+}
+
+
+#[test]
+fn red() {
+    let lab = Lab::from(RGB::new(255, 0, 0));
+    assert_eq!(rounded(lab), vec![53.24, 80.09, 67.2]);
+}
+
+
+#[test]
+fn green() {
+    let lab = Lab::from(RGB::new(0, 255, 0));
+    assert_eq!(rounded(lab), vec![87.74, -86.18, 83.19]);
+}
+
+
+#[test]
+fn blue() {
+    let lab = Lab::from(RGB::new(0, 0, 255));
+    assert_eq!(rounded(lab), vec![32.3, 79.2, -107.86]);
+}
+
+
+#[test]
+fn round_trip() {
+    let rgb = RGB::new(56, 42, 90);
+    let lab = Lab::from(rgb.clone());
+    assert_eq!(RGB::from(lab), rgb);
+}
@@ -0,0 +1,34 @@
+#!/usr/bin/env rust
+
+
+use color_operators::lab::Lab;
+use color_operators::white_point::WhitePoint;
+use color_operators::xyz::XYZ;
+
+
+#[test]
+fn white_d65() {
+    let ( x, y, z ) = WhitePoint::D65.tristimulus();
+    let lab = Lab::from(XYZ::new(x, y, z));
+    assert_eq!(lab, Lab::new(100.0, 0.0, 0.0));
+}
+
+
+#[test]
+fn white_d50() {
+    let ( x, y, z ) = WhitePoint::D50.tristimulus();
+    let lab = Lab::from_xyz(XYZ::new(x, y, z), WhitePoint::D50);
+    assert_eq!(lab, Lab::new(100.0, 0.0, 0.0));
+}
+
+
+#[test]
+fn round_trip_d50() {
+    let lab = Lab::new(50.0, 25.0, -25.0);
+    let xyz = lab.to_xyz(WhitePoint::D50);
+    let result: Vec<f64> = Lab::from_xyz(xyz, WhitePoint::D50).into();
+    let expected: Vec<f64> = lab.into();
+    for (left, right) in result.iter().zip(expected.iter()) {
+        assert!((left - right).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,26 @@
+#!/usr/bin/env rust
+
+
+use color_operators::lab::Lab;
+use color_operators::lch::LCh;
+
+
+#[test]
+fn positive_b() {
+    let lch = LCh::from(Lab::new(50.0, 0.0, 25.0));
+    assert_eq!(lch, LCh::new(50.0, 25.0, 90.0));
+}
+
+
+#[test]
+fn negative_b() {
+    let lch = LCh::from(Lab::new(50.0, 0.0, -25.0));
+    assert_eq!(lch, LCh::new(50.0, 25.0, 270.0));
+}
+
+
+#[test]
+fn achromatic() {
+    let lch = LCh::from(Lab::new(50.0, 0.0, 0.0));
+    assert_eq!(lch, LCh::new(50.0, 0.0, 0.0));
+}
@@ -0,0 +1,20 @@
+#!/usr/bin/env rust
+
+
+use color_operators::lch::LCh;
+
+
+#[test]
+fn clockwise() {
+    let lch = LCh::new(50.0, 25.0, 350.0);
+    let result = lch.rotate_hue(20.0);
+    assert_eq!(result, LCh::new(50.0, 25.0, 10.0));
+}
+
+
+#[test]
+fn anticlockwise() {
+    let lch = LCh::new(50.0, 25.0, 10.0);
+    let result = lch.rotate_hue(-20.0);
+    assert_eq!(result, LCh::new(50.0, 25.0, 350.0));
+}
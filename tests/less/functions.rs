@@ -0,0 +1,29 @@
+#!/usr/bin/env rust
+
+
+use color_operators::color::Color;
+use color_operators::less;
+
+
+#[test]
+fn spin_wraps_hue_of_rgb() {
+    let red = Color::new_rgb(255, 0, 0);
+    assert_eq!(less::spin(red.clone(), -120.0), Color::new_rgb(0, 0, 255));
+    assert_eq!(less::spin(red, 480.0), Color::new_rgb(0, 255, 0));
+}
+
+
+#[test]
+fn fade_clamps_alpha() {
+    let color = Color::new_rgba(255, 0, 0, 128);
+    assert_eq!(less::fade(color.clone(), 150.0), Color::new_rgba(255, 0, 0, 255));
+    assert_eq!(less::fadeout(color, 75.0), Color::new_rgba(255, 0, 0, 0));
+}
+
+
+#[test]
+fn tint_and_shade_extremes() {
+    let color = Color::new_rgb(0x12, 0x34, 0x56);
+    assert_eq!(less::tint(color.clone(), 100.0), Color::new_rgb(255, 255, 255));
+    assert_eq!(less::shade(color.clone(), 0.0), color);
+}
@@ -0,0 +1,80 @@
+#!/usr/bin/env rust
+
+
+use color_operators::color::Color;
+use color_operators::hsl::HSL;
+use color_operators::hsla::HSLA;
+use color_operators::mix::{ HueMethod, Space };
+use color_operators::oklch::Oklch;
+
+
+const SPACES: [Space; 10] = [
+    Space::Srgb,
+    Space::SrgbLinear,
+    Space::HSL,
+    Space::HSV,
+    Space::HWB,
+    Space::Lab,
+    Space::LCh,
+    Space::Oklab,
+    Space::Oklch,
+    Space::XYZ,
+];
+
+
+#[test]
+fn endpoints_return_either_color() {
+    let left = Color::new_rgb(255, 42, 90);
+    let right = Color::new_rgb(20, 140, 60);
+    for space in SPACES.iter() {
+        assert_eq!(left.mix(&right, 0.0, *space, HueMethod::Shorter), left);
+        assert_eq!(left.mix(&right, 1.0, *space, HueMethod::Shorter), right);
+        assert_eq!(left.mix(&right, -1.0, *space, HueMethod::Shorter), left);
+        assert_eq!(left.mix(&right, 2.0, *space, HueMethod::Shorter), right);
+    }
+}
+
+
+#[test]
+fn achromatic_adopts_other_hue() {
+    let white = Color::new_oklch(1.0, 0.0, 0.0);
+    let blue = Color::new_rgb(0, 0, 255);
+    let ( _, _, blue_hue ) = Oklch::from(blue.clone()).into();
+
+    for amount in [0.25, 0.5, 0.75].iter() {
+        let mixed = Oklch::from(white.mix(&blue, *amount, Space::Oklch, HueMethod::Shorter));
+        let ( _, _, hue ) = mixed.into();
+        assert!((hue - blue_hue).abs() < 1.0);
+    }
+
+    let gray = Color::new_hsl(0.0, 0.0, 0.5);
+    let mixed = gray.mix(&Color::new_hsl(200.0, 1.0, 0.5), 0.5, Space::HSL, HueMethod::Longer);
+    assert_eq!(HSL::from(mixed).get("hue"), Ok(200.0));
+}
+
+
+#[test]
+fn hue_methods() {
+    let left = Color::new_oklch(0.6, 0.1, 30.0);
+    let right = Color::new_oklch(0.6, 0.1, 90.0);
+    let hue = |method: HueMethod| -> f64 {
+        let ( _, _, h ) = Oklch::from(left.mix(&right, 0.5, Space::Oklch, method)).into();
+        h.round()
+    };
+    assert_eq!(hue(HueMethod::Shorter), 60.0);
+    assert_eq!(hue(HueMethod::Longer), 240.0);
+    assert_eq!(hue(HueMethod::Increasing), 60.0);
+    assert_eq!(hue(HueMethod::Decreasing), 240.0);
+}
+
+
+#[test]
+fn premultiplied_alpha() {
+    let opaque_red = Color::new_hsla(0.0, 1.0, 0.5, 1.0);
+    let clear_blue = Color::new_hsla(240.0, 1.0, 0.0, 0.0);
+    let mixed = opaque_red.mix(&clear_blue, 0.5, Space::Srgb, HueMethod::Shorter);
+    let ( _, saturation, lightness, alpha ) = HSLA::from(mixed).into();
+    assert_eq!(alpha, 0.5);
+    assert_eq!(saturation, 1.0);
+    assert_eq!(lightness, 0.5);
+}
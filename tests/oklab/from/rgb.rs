@@ -0,0 +1,40 @@
+#!/usr/bin/env rust
+
+
+use color_operators::oklab::Oklab;
+use color_operators::rgb::RGB;
+
+
+fn rounded(oklab: Oklab) -> Vec<f64> {
+    let vector: Vec<f64> = oklab.into();
+    vector.iter().map(|v| { (v * 1000.0).round() / 1000.0 }).collect()
+}
+
+
+#[test]
+fn white() {
+    let oklab = Oklab::from(RGB::new(255, 255, 255));
+    assert_eq!(rounded(oklab), vec![1.0, 0.0, 0.0]);
+}
+
+
+#[test]
+fn red() {
+    let oklab = Oklab::from(RGB::new(255, 0, 0));
+    assert_eq!(rounded(oklab), vec![0.628, 0.225, 0.126]);
+}
+
+
+#[test]
+fn blue() {
+    let oklab = Oklab::from(RGB::new(0, 0, 255));
+    assert_eq!(rounded(oklab), vec![0.452, -0.032, -0.312]);
+}
+
+
+#[test]
+fn round_trip() {
+    let rgb = RGB::new(56, 42, 90);
+    let oklab = Oklab::from(rgb.clone());
+    assert_eq!(RGB::from(oklab), rgb);
+}
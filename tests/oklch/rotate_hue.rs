@@ -0,0 +1,30 @@
+#!/usr/bin/env rust
+
+
+use color_operators::oklch::Oklch;
+use color_operators::rgb::RGB;
+
+
+#[test]
+fn preserves_lightness() {
+    let oklch = Oklch::from(RGB::new(255, 42, 90));
+    let rotated = oklch.rotate_hue(90.0);
+    assert_eq!(rotated.get("l"), oklch.get("l"));
+    assert_eq!(rotated.get("c"), oklch.get("c"));
+}
+
+
+#[test]
+fn wraps() {
+    let oklch = Oklch::new(0.5, 0.125, 300.0);
+    let rotated = oklch.rotate_hue(90.0);
+    assert_eq!(rotated, Oklch::new(0.5, 0.125, 30.0));
+}
+
+
+#[test]
+fn lighten_clamps() {
+    let oklch = Oklch::new(0.875, 0.125, 300.0);
+    let lighter = oklch.lighten(0.25);
+    assert_eq!(lighter, Oklch::new(1.0, 0.125, 300.0));
+}
@@ -0,0 +1,74 @@
+#!/usr/bin/env rust
+
+
+use color_operators::color::Color;
+use color_operators::oklch::Oklch;
+use color_operators::ramp::{ ChromaCurve, Ramp };
+use color_operators::rgb::RGB;
+use color_operators::rgba::RGBA;
+
+
+fn lightness(color: Color) -> f64 {
+    Oklch::from(color).get("l").unwrap()
+}
+
+
+#[test]
+fn lightness_decreases_from_first_to_last_step() {
+    let scale = Ramp::new().generate(Color::new_rgb(255, 42, 90));
+    let values: Vec<f64> = scale.colors().into_iter().map(lightness).collect();
+    for pair in values.windows(2) {
+        assert!(pair[0] > pair[1], "{} should be lighter than {}", pair[0], pair[1]);
+    }
+}
+
+
+#[test]
+fn oklch_steps_fit_within_srgb() {
+    let scale = Ramp::new().chroma(ChromaCurve::Constant).generate(Oklch::new(0.7, 0.3, 145.0));
+    for color in scale.colors().into_iter() {
+        let oklch = Oklch::from(color.clone());
+        let round_trip = Oklch::from(RGB::from(color));
+        let ( c, clipped ) = ( oklch.get("c").unwrap(), round_trip.get("c").unwrap() );
+        assert!(c < 0.3, "chroma {} should be reduced from brand", c);
+        assert!((c - clipped).abs() < 0.01, "chroma {} should survive conversion to RGB as {}", c, clipped);
+    }
+}
+
+
+#[test]
+fn custom_chroma_curve() {
+    let brand = Color::new_oklch(0.5, 0.1, 250.0);
+    let scale = Ramp::new()
+        .targets(&[ ("a", 0.5), ("b", 0.5), ("c", 0.5) ])
+        .chroma(ChromaCurve::Custom(vec![0.0, 0.5]))
+        .generate(brand);
+
+    let chroma: Vec<f64> = scale.colors().into_iter().map(|c| {
+        (Oklch::from(c).get("c").unwrap() * 1000.0).round()
+    }).collect();
+    assert_eq!(chroma, vec![0.0, 50.0, 100.0]);
+}
+
+
+#[test]
+fn keeps_type_and_alpha_of_brand() {
+    let scale = Ramp::new().steps(3).generate(RGBA::new(255, 42, 90, 128));
+    for color in scale.colors().into_iter() {
+        match color {
+            Color::RGBA(v) => assert_eq!(v.get("alpha"), Ok(128)),
+            _ => panic!("expected RGBA"),
+        }
+    }
+}
+
+
+#[test]
+fn json_keeps_label_order() {
+    let scale = Ramp::new().steps(3).generate(Color::new_rgb(0, 0, 0));
+    let json = scale.to_json_string();
+    let one = json.find("\"1\"").unwrap();
+    let two = json.find("\"2\"").unwrap();
+    let three = json.find("\"3\"").unwrap();
+    assert!(one < two && two < three);
+}
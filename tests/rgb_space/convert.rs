@@ -0,0 +1,84 @@
+#!/usr/bin/env rust
+
+
+use color_operators::hsv::HSV;
+use color_operators::rgb::RGB;
+use color_operators::rgb_space::{ RgbSpace, TransferFunction };
+
+
+fn spaces() -> Vec<RgbSpace> {
+    vec![
+        RgbSpace::srgb(),
+        RgbSpace::display_p3(),
+        RgbSpace::adobe_rgb(),
+        RgbSpace::prophoto_rgb(),
+        RgbSpace::rec709(),
+        RgbSpace::rec2020(),
+        RgbSpace::acescg(),
+    ]
+}
+
+
+#[test]
+fn srgb_matrix_matches_css_color_4() {
+    let expected = [
+        [0.41239079926595934, 0.357584339383878, 0.1804807884018343],
+        [0.21263900587151027, 0.715168678767756, 0.07219231536073371],
+        [0.01933081871559182, 0.11919477979462598, 0.9505321522496607],
+    ];
+    let matrix = RgbSpace::srgb().to_xyz_matrix();
+    for (row, expected_row) in matrix.iter().zip(expected.iter()) {
+        for (value, expected_value) in row.iter().zip(expected_row.iter()) {
+            assert!((value - expected_value).abs() < 1e-12, "{} should be {}", value, expected_value);
+        }
+    }
+}
+
+
+#[test]
+fn white_and_black_are_shared_by_every_space() {
+    let srgb = RgbSpace::srgb();
+    for space in spaces().iter() {
+        assert_eq!(space.convert(RGB::new(255, 255, 255), &srgb), RGB::new(255, 255, 255));
+        assert_eq!(space.convert(RGB::new(0, 0, 0), &srgb), RGB::new(0, 0, 0));
+    }
+}
+
+
+#[test]
+fn linear_light_round_trips_through_xyz() {
+    for space in spaces().iter() {
+        for ( red, green, blue ) in [ (1.0, 0.164, 0.35), (0.05, 0.8, 0.25), (-0.1, 0.5, 1.2) ].iter() {
+            let ( r, g, b ) = space.xyz_to_linear(space.linear_to_xyz(*red, *green, *blue));
+            assert!((r - red).abs() < 1e-9 && (g - green).abs() < 1e-9 && (b - blue).abs() < 1e-9, "via {:?}", space.primaries());
+        }
+    }
+}
+
+
+#[test]
+fn wide_gamut_colors_are_outside_srgb() {
+    let ( red, green, blue ) = RgbSpace::srgb().xyz_to_linear(RgbSpace::rec2020().to_xyz(HSV::new(120.0, 1.0, 1.0)));
+    assert!(red < 0.0);
+    assert!(green > 1.0);
+    assert!(blue < 0.0);
+}
+
+
+#[test]
+fn transfer_functions_round_trip() {
+    let transfers = [
+        TransferFunction::Linear,
+        TransferFunction::Srgb,
+        TransferFunction::Gamma(2.2),
+        TransferFunction::Rec709,
+        TransferFunction::Rec2020,
+        TransferFunction::ProPhoto,
+    ];
+    for transfer in transfers.iter() {
+        for value in [ -0.5, 0.0, 0.001, 0.01, 0.2, 0.75, 1.0 ].iter() {
+            let round_trip = transfer.decode(transfer.encode(*value));
+            assert!((round_trip - value).abs() < 1e-12, "{:?} of {}", transfer, value);
+        }
+    }
+}
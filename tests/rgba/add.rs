@@ -0,0 +1,21 @@
+#!/usr/bin/env rust
+
+
+use color_operators::color::Color;
+use color_operators::rgba::RGBA;
+
+
+#[test]
+fn keeps_left_alpha() {
+    let left = RGBA::new(100, 0, 0, 64);
+    let right = RGBA::new(50, 0, 0, 255);
+    assert_eq!(left + right, RGBA::new(150, 0, 0, 64));
+}
+
+
+#[test]
+fn color_keeps_left_alpha() {
+    let left = Color::new_rgba(100, 0, 0, 64);
+    let right = Color::new_rgb(50, 0, 0);
+    assert_eq!(left + right, RGBA::new(150, 0, 0, 64));
+}
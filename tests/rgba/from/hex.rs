@@ -0,0 +1,25 @@
+#!/usr/bin/env rust
+
+
+use color_operators::rgba::RGBA;
+
+
+#[test]
+fn with_alpha() {
+    let rgba = RGBA::from_hex_string("FF2A5A80");
+    assert_eq!(rgba, RGBA::new(255, 42, 90, 128));
+}
+
+
+#[test]
+fn without_alpha_is_opaque() {
+    let rgba = RGBA::from_hex_string("FF2A5A");
+    assert_eq!(rgba.get("alpha"), Ok(255));
+}
+
+
+#[test]
+fn round_trip() {
+    let rgba = RGBA::new(255, 170, 187, 204);
+    assert_eq!(RGBA::from_hex_string(rgba.to_hex_string()), rgba);
+}
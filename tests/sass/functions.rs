@@ -0,0 +1,96 @@
+#!/usr/bin/env rust
+
+
+use color_operators::color::Color;
+use color_operators::error::Error;
+use color_operators::hsla::HSLA;
+use color_operators::rgb::RGB;
+use color_operators::rgba::RGBA;
+use color_operators::sass::{ self, Components };
+
+
+#[test]
+fn mix_weights_by_alpha() {
+    let first = Color::HSLA(HSLA::new(34.28571428571428, 0.4117647058823523, 0.9215686274509804, 0.5));
+    let second = Color::new_rgb(0x6b, 0x71, 0x7f);
+
+    let mixed = sass::mix(first, second, 50.0).unwrap();
+    assert!(mixed.is_hsla());
+
+    let ( red, green, blue, alpha ) = RGBA::from(mixed).into();
+    assert_eq!((red, green, blue), (141, 144, 152));
+    assert_eq!(alpha, 191);
+}
+
+
+#[test]
+fn hsl_input_keeps_unrounded_components() {
+    let color = Color::new_hsl(210.0, 0.5, 0.4);
+    assert_eq!(sass::lighten(color.clone(), 10.0), Ok(Color::new_hsl(210.0, 0.5, 0.5)));
+    assert_eq!(sass::adjust_hue(color.clone(), 200.0), Color::new_hsl(50.0, 0.5, 0.4));
+    assert_eq!(sass::grayscale(color), Color::new_hsl(210.0, 0.0, 0.4));
+}
+
+
+#[test]
+fn lighten_and_darken_clamp() {
+    assert_eq!(sass::lighten(Color::new_rgb(0xee, 0xee, 0xee), 50.0), Ok(Color::new_rgb(255, 255, 255)));
+    assert_eq!(sass::darken(Color::new_rgb(0x11, 0x11, 0x11), 50.0), Ok(Color::new_rgb(0, 0, 0)));
+}
+
+
+/// Examples and results from dart-sass documentation of global color functions
+#[test]
+fn documented_amounts() {
+    let hex = |hex: &str| Color::from(RGB::from_hex_string(hex));
+
+    assert_eq!(sass::lighten(hex("6b717f"), 20.0), Ok(hex("a1a5af")));
+    assert_eq!(sass::lighten(hex("e1d7d2"), 30.0), Ok(hex("ffffff")));
+    assert_eq!(sass::darken(hex("b37399"), 20.0), Ok(hex("7c4465")));
+    assert_eq!(sass::darken(hex("f2ece4"), 40.0), Ok(hex("b08b5a")));
+    assert_eq!(sass::saturate(hex("0e4982"), 30.0), Ok(hex("004990")));
+    assert_eq!(sass::saturate(hex("cc6699"), 20.0), Ok(hex("e05299")));
+    assert_eq!(sass::desaturate(hex("003366"), 20.0), Ok(hex("0a335c")));
+    assert_eq!(sass::desaturate(hex("f2ece4"), 20.0), Ok(hex("eeebe8")));
+    assert_eq!(sass::desaturate(hex("d2e1dd"), 30.0), Ok(hex("dadada")));
+    assert_eq!(sass::invert(hex("b37399"), 100.0), Ok(hex("4c8c66")));
+    assert_eq!(sass::invert(hex("550e0c"), 20.0), Ok(hex("663b3a")));
+    assert_eq!(sass::mix(hex("003366"), hex("d2e1dd"), 50.0), Ok(hex("698aa2")));
+}
+
+
+#[test]
+fn out_of_range_amounts() {
+    let color = Color::new_rgb(0x6b, 0x71, 0x7f);
+    let invalid = |name: &str| Err(Error::InvalidComponent(name.to_string()));
+
+    assert_eq!(sass::lighten(color.clone(), 120.0), invalid("amount"));
+    assert_eq!(sass::darken(color.clone(), -10.0), invalid("amount"));
+    assert_eq!(sass::saturate(color.clone(), 100.5), invalid("amount"));
+    assert_eq!(sass::desaturate(color.clone(), f64::NAN), invalid("amount"));
+    assert_eq!(sass::invert(color.clone(), 101.0), invalid("weight"));
+    assert_eq!(sass::mix(color.clone(), color.clone(), -1.0), invalid("weight"));
+
+    assert_eq!(sass::lighten(color.clone(), 0.0), Ok(color.clone()));
+    assert_eq!(sass::darken(color.clone(), 100.0), Ok(Color::new_rgb(0, 0, 0)));
+}
+
+
+#[test]
+fn whiteness_and_blackness() {
+    let color = Color::new_rgb(0x99, 0x80, 0x99);
+    assert_eq!(sass::change(color.clone(), Components::new().whiteness(0.0).blackness(0.0)), Ok(Color::new_rgb(255, 0, 255)));
+    assert_eq!(sass::scale(color, Components::new().whiteness(-100.0).blackness(100.0)), Ok(Color::new_rgb(0, 0, 0)));
+}
+
+
+#[test]
+fn invalid_components() {
+    let color = Color::new_rgb(0x6b, 0x71, 0x7f);
+    let invalid = |name: &str| Err(Error::InvalidComponent(name.to_string()));
+
+    assert_eq!(sass::scale(color.clone(), Components::new().hue(30.0)), invalid("hue"));
+    assert_eq!(sass::adjust(color.clone(), Components::new().red(300.0)), invalid("red"));
+    assert_eq!(sass::change(color.clone(), Components::new().alpha(-0.5)), invalid("alpha"));
+    assert_eq!(sass::adjust(color, Components::new().lightness(10.0).blackness(10.0)), invalid("blackness"));
+}
@@ -2,6 +2,82 @@
 
 
 
+mod adaptation {
+    mod method;
+}
+
+
+mod blend {
+    mod alpha;
+    mod non_separable;
+    mod separable;
+}
+
+
+mod cmyk {
+    mod from {
+        mod rgb;
+    }
+}
+
+
+mod color {
+    mod equality;
+}
+
+
+mod composite {
+    mod operator;
+}
+
+
+mod contrast {
+    mod adjust;
+    mod apca;
+    mod wcag;
+}
+
+
+mod css {
+    mod parse;
+    mod serialize;
+}
+
+
+mod cvd {
+    mod checker;
+    mod daltonize;
+    mod simulate;
+}
+
+
+mod difference {
+    mod delta_e;
+}
+
+
+mod error {
+    mod fallible;
+    mod lenient;
+}
+
+
+mod gamut {
+    mod map;
+}
+
+
+mod gradient {
+    mod sample;
+    mod serialize;
+}
+
+
+mod harmony {
+    mod colors;
+}
+
+
 mod hsl {
     mod add;
     mod rotate_rgb;
@@ -17,6 +93,13 @@ mod hsl {
 }
 
 
+mod hsla {
+    mod from {
+        mod rgba;
+    }
+}
+
+
 mod hsv {
     mod add;
     mod rotate_rgb;
@@ -32,6 +115,57 @@ mod hsv {
 }
 
 
+mod hwb {
+    mod from {
+        mod hsv;
+    }
+}
+
+
+mod lab {
+    mod from {
+        mod rgb;
+        mod xyz;
+    }
+}
+
+
+mod lch {
+    mod rotate_hue;
+
+    mod from {
+        mod lab;
+    }
+}
+
+
+mod less {
+    mod functions;
+}
+
+
+mod mix {
+    mod interpolate;
+}
+
+
+mod oklab {
+    mod from {
+        mod rgb;
+    }
+}
+
+
+mod oklch {
+    mod rotate_hue;
+}
+
+
+mod ramp {
+    mod generate;
+}
+
+
 mod rgb {
     mod add;
     mod rotate_rgb;
@@ -46,3 +180,35 @@ mod rgb {
     }
 }
 
+
+mod rgb_space {
+    mod convert;
+}
+
+
+mod rgba {
+    mod add;
+
+    mod from {
+        mod hex;
+    }
+}
+
+
+mod sass {
+    mod functions;
+}
+
+
+mod xyz {
+    mod from {
+        mod rgb;
+    }
+}
+
+
+mod ycbcr {
+    mod from {
+        mod rgb;
+    }
+}
@@ -0,0 +1,33 @@
+#!/usr/bin/env rust
+
+
+use color_operators::rgb::RGB;
+use color_operators::xyz::XYZ;
+
+
+fn rounded(xyz: XYZ) -> Vec<f64> {
+    let vector: Vec<f64> = xyz.into();
+    vector.iter().map(|v| { (v * 10000.0).round() / 10000.0 }).collect()
+}
+
+
+#[test]
+fn white() {
+    let xyz = XYZ::from(RGB::new(255, 255, 255));
+    assert_eq!(rounded(xyz), vec![0.9505, 1.0, 1.0891]);
+}
+
+
+#[test]
+fn red() {
+    let xyz = XYZ::from(RGB::new(255, 0, 0));
+    assert_eq!(rounded(xyz), vec![0.4124, 0.2126, 0.0193]);
+}
+
+
+#[test]
+fn round_trip() {
+    let rgb = RGB::new(255, 42, 90);
+    let xyz = XYZ::from(rgb.clone());
+    assert_eq!(RGB::from(xyz), rgb);
+}
@@ -0,0 +1,51 @@
+#!/usr/bin/env rust
+
+
+use color_operators::rgb::RGB;
+use color_operators::ycbcr::{ BitDepth, Matrix, Range, YCbCr };
+
+
+#[test]
+fn bt601_full_range() {
+    let ycbcr = YCbCr::from_rgb(RGB::new(0, 255, 0), Matrix::BT601);
+    assert_eq!(ycbcr.to_digital(Range::Full, BitDepth::Eight), (150, 44, 21));
+}
+
+
+#[test]
+fn bt709_limited_range() {
+    let white = YCbCr::from_rgb(RGB::new(255, 255, 255), Matrix::BT709);
+    assert_eq!(white.to_digital(Range::Limited, BitDepth::Eight), (235, 128, 128));
+
+    let black = YCbCr::from_rgb(RGB::new(0, 0, 0), Matrix::BT709);
+    assert_eq!(black.to_digital(Range::Limited, BitDepth::Eight), (16, 128, 128));
+}
+
+
+#[test]
+fn bt2020_limited_range_ten_bit() {
+    let white = YCbCr::from_rgb(RGB::new(255, 255, 255), Matrix::BT2020);
+    assert_eq!(white.to_digital(Range::Limited, BitDepth::Ten), (940, 512, 512));
+
+    let blue = YCbCr::from_rgb(RGB::new(0, 0, 255), Matrix::BT2020);
+    assert_eq!(blue.to_digital(Range::Limited, BitDepth::Ten), (116, 960, 476));
+}
+
+
+#[test]
+fn digital_round_trip() {
+    let rgb = RGB::new(255, 42, 90);
+    let ycbcr = YCbCr::from_rgb(rgb.clone(), Matrix::BT709);
+    let ( y, cb, cr ) = ycbcr.to_digital(Range::Limited, BitDepth::Ten);
+    let decoded = YCbCr::from_digital(y, cb, cr, Range::Limited, BitDepth::Ten);
+    assert_eq!(decoded.to_rgb(Matrix::BT709), rgb);
+}
+
+
+#[test]
+fn matrices_differ() {
+    let rgb = RGB::new(255, 42, 90);
+    let bt601 = YCbCr::from_rgb(rgb.clone(), Matrix::BT601);
+    let bt709 = YCbCr::from_rgb(rgb, Matrix::BT709);
+    assert_ne!(bt601, bt709);
+}